@@ -0,0 +1,542 @@
+//! The `wasmbuilder` command line interface.
+//!
+//! The interface loads compositions saved from the application, either as a
+//! project file or as a WAC document, and encodes them natively using the same
+//! graph logic as the application.
+use anyhow::{bail, Context, Result};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use graph_core::{
+    diagram,
+    diff::{self, DifferenceKind},
+    disassemble, preview,
+    project::Project,
+    state::{AddOptions, ComponentId, Connection, ExportItem, InstanceId, State},
+    wac,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use wit_component::WitPrinter;
+
+mod run;
+
+/// Composes WebAssembly components designed with wasmbuilder.app.
+#[derive(Parser)]
+#[command(name = "wasmbuilder", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Encodes a composition into a component.
+    Compose {
+        #[command(flatten)]
+        input: Input,
+        /// The path to write the component to.
+        #[arg(short, long)]
+        output: PathBuf,
+        /// Imports the components of the composition instead of defining them.
+        #[arg(long)]
+        import_components: bool,
+    },
+    /// Prints the components, instances and connections of a composition.
+    Inspect {
+        #[command(flatten)]
+        input: Input,
+    },
+    /// Prints a diagram of the instances and connections of a composition.
+    Diagram {
+        #[command(flatten)]
+        input: Input,
+        /// The format of the diagram.
+        #[arg(long, value_enum, default_value_t = DiagramFormat::Dot)]
+        format: DiagramFormat,
+    },
+    /// Prints the WIT of a component or of the component encoded from a composition.
+    ///
+    /// The imports and exports of a composition name the instances they come from.
+    Wit {
+        #[command(flatten)]
+        input: Input,
+    },
+    /// Prints the text format of a component or of the component encoded from a composition.
+    Wat {
+        #[command(flatten)]
+        input: Input,
+        /// Prints instructions in folded form.
+        #[arg(long)]
+        fold: bool,
+        /// Skips function bodies and the contents of data and element segments.
+        #[arg(long)]
+        skip_bodies: bool,
+        /// Prints only the component-level structure, eliding core modules.
+        #[arg(long)]
+        structure_only: bool,
+        /// The maximum length of the text in bytes.
+        #[arg(long, value_name = "BYTES", default_value_t = disassemble::DEFAULT_MAX_LENGTH)]
+        max_length: u32,
+    },
+    /// Validates the component encoded from a composition.
+    Validate {
+        #[command(flatten)]
+        input: Input,
+    },
+    /// Compares a component of a composition with a replacement.
+    ///
+    /// Differences that would break a connection in the composition are marked
+    /// as breaking; the command fails if there are any.
+    Diff {
+        #[command(flatten)]
+        input: Input,
+        /// The component to replace, by id or name.
+        component: String,
+        /// The replacement component (`.wasm` or `.wat`).
+        replacement: PathBuf,
+    },
+    /// Runs the component encoded from a composition with wasmtime.
+    ///
+    /// The exported instance's `wasi:cli/run` export is run with the given
+    /// arguments unless `--invoke` is given.
+    Run {
+        #[command(flatten)]
+        input: Input,
+        /// The exported function to invoke, as `name` or `instance#name`.
+        ///
+        /// The arguments are parsed as the function's parameters and its results
+        /// are printed.
+        #[arg(long, value_name = "FUNCTION")]
+        invoke: Option<String>,
+        /// The arguments of the program or of the invoked function.
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+}
+
+/// Represents the format of a diagram.
+#[derive(Clone, Copy, ValueEnum)]
+enum DiagramFormat {
+    /// A Graphviz DOT document.
+    Dot,
+    /// A Mermaid flowchart.
+    Mermaid,
+}
+
+impl From<DiagramFormat> for diagram::Format {
+    fn from(format: DiagramFormat) -> Self {
+        match format {
+            DiagramFormat::Dot => Self::Dot,
+            DiagramFormat::Mermaid => Self::Mermaid,
+        }
+    }
+}
+
+/// Represents the composition to load.
+#[derive(Args)]
+struct Input {
+    /// The project file or WAC document (`.wac`) to load.
+    ///
+    /// The `wit` and `wat` commands also accept a component (`.wasm` or `.wat`).
+    path: PathBuf,
+    /// A package referenced by a WAC document, as `name=path`.
+    #[arg(long = "dep", value_name = "NAME=PATH", value_parser = parse_dep)]
+    deps: Vec<(String, PathBuf)>,
+    /// A directory of packages referenced by a WAC document.
+    ///
+    /// Packages are found at `<namespace>/<name>.wasm` in the directory.
+    #[arg(long, value_name = "DIR")]
+    deps_dir: Option<PathBuf>,
+    /// The instance to export, by id or name; defaults to the composition's export.
+    #[arg(long, value_name = "INSTANCE")]
+    export: Option<String>,
+}
+
+/// Represents a loaded composition.
+struct Composition {
+    state: State,
+    export: Option<InstanceId>,
+    exports: Vec<ExportItem>,
+}
+
+impl Composition {
+    /// Loads the composition of the given input.
+    fn load(input: &Input) -> Result<Self> {
+        let path = &input.path;
+        let (state, export, exports) = if has_extension(path, "wac") {
+            let source = fs::read_to_string(path)
+                .with_context(|| format!("failed to read `{path}`", path = path.display()))?;
+            wac::load(&source, packages(input)?)
+                .with_context(|| format!("failed to load `{path}`", path = path.display()))?
+        } else {
+            let bytes = fs::read(path)
+                .with_context(|| format!("failed to read `{path}`", path = path.display()))?;
+            let project = Project::from_bytes(&bytes)?;
+            let export = project.export;
+            let exports = project.exports.clone();
+            let state = State::from_project(project)
+                .with_context(|| format!("failed to load `{path}`", path = path.display()))?;
+            (state, export, exports)
+        };
+
+        let export = match &input.export {
+            Some(export) => Some(find_instance(&state, export)?),
+            None => export,
+        };
+
+        Ok(Self {
+            state,
+            export,
+            exports,
+        })
+    }
+
+    /// Encodes the composition into a component.
+    fn encode(&self, define_components: bool, validate: bool) -> Result<Vec<u8>> {
+        Ok(self
+            .state
+            .encode(define_components, self.export, &self.exports, validate)?)
+    }
+
+    /// Describes the instances of a component.
+    fn instances_of(&self, component: ComponentId) -> String {
+        let name = self.state.component(component).unwrap().name();
+        let instances = self
+            .state
+            .instances()
+            .filter(|(_, c)| *c == component)
+            .map(|(id, _)| format!("`{}`", self.instance(id)))
+            .collect::<Vec<_>>();
+
+        match &instances[..] {
+            [instance] => format!("instance {instance} of `{name}`"),
+            _ => format!("an instance of `{name}` ({})", instances.join(" or ")),
+        }
+    }
+
+    /// Describes a connection by the names of its instances, export and import.
+    fn connection(&self, connection: &Connection) -> String {
+        let component = |id| {
+            self.state
+                .instances()
+                .find(|(i, _)| *i == id)
+                .and_then(|(_, c)| self.state.component(c))
+        };
+
+        let source_export = match connection.source_export {
+            Some(index) => component(connection.source)
+                .and_then(|c| c.export(index))
+                .map(|(name, _)| format!(".{name}"))
+                .unwrap_or_default(),
+            None => String::new(),
+        };
+        let target_import = component(connection.target)
+            .and_then(|c| c.import(connection.target_import))
+            .map(|(name, _)| name)
+            .unwrap_or_default();
+
+        format!(
+            "{source}{source_export} -> {target}.{target_import}",
+            source = self.instance(connection.source),
+            target = self.instance(connection.target),
+        )
+    }
+
+    /// Gets the display name of an instance.
+    fn instance(&self, id: InstanceId) -> String {
+        match self.state.instance_name(id).unwrap() {
+            Some(name) => name.to_string(),
+            None => format!("#{id}"),
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().command {
+        Command::Compose {
+            input,
+            output,
+            import_components,
+        } => {
+            let bytes = Composition::load(&input)?.encode(!import_components, true)?;
+            fs::write(&output, bytes)
+                .with_context(|| format!("failed to write `{path}`", path = output.display()))?;
+        }
+        Command::Inspect { input } => inspect(&Composition::load(&input)?)?,
+        Command::Diagram { input, format } => {
+            let composition = Composition::load(&input)?;
+            print!("{}", diagram::render(&composition.state, format.into()));
+        }
+        Command::Wit { input } => {
+            if has_extension(&input.path, "wasm") || has_extension(&input.path, "wat") {
+                print!("{}", wit(&component(&input.path)?)?);
+            } else {
+                let composition = Composition::load(&input)?;
+                let preview =
+                    preview::preview(&composition.state, composition.export, &composition.exports)?;
+                print!("{}", preview.wit);
+            }
+        }
+        Command::Wat {
+            input,
+            fold,
+            skip_bodies,
+            structure_only,
+            max_length,
+        } => {
+            let bytes = if has_extension(&input.path, "wasm") || has_extension(&input.path, "wat") {
+                component(&input.path)?
+            } else {
+                Composition::load(&input)?.encode(true, false)?
+            };
+
+            let wat = disassemble::disassemble(
+                &bytes,
+                &disassemble::Options {
+                    fold_expressions: fold,
+                    skip_function_bodies: skip_bodies,
+                    structure_only,
+                    max_length: Some(max_length),
+                },
+            )?;
+            print!("{}", wat.text);
+            if wat.truncated {
+                eprintln!("warning: the text was truncated; use `--max-length` to print more");
+            }
+        }
+        Command::Validate { input } => {
+            let bytes = Composition::load(&input)?.encode(true, true)?;
+            println!(
+                "`{path}` encodes a valid component ({len} bytes)",
+                path = input.path.display(),
+                len = bytes.len()
+            );
+        }
+        Command::Diff {
+            input,
+            component: name,
+            replacement,
+        } => {
+            let mut composition = Composition::load(&input)?;
+            let old = find_component(&composition.state, &name)?;
+            let (new, _) = composition.state.add_component(
+                format!(
+                    "{name}-replacement",
+                    name = composition.state.component(old).unwrap().name()
+                ),
+                None,
+                component(&replacement)?,
+                AddOptions::default(),
+            )?;
+
+            let diff = diff::diff(&composition.state, old, new)?;
+            print_diff(&composition, &diff);
+            if !diff.compatible {
+                bail!(
+                    "`{path}` cannot replace component `{name}` in the composition",
+                    path = replacement.display()
+                );
+            }
+        }
+        Command::Run {
+            input,
+            invoke,
+            args,
+        } => {
+            let composition = Composition::load(&input)?;
+            let program = input.path.to_string_lossy();
+            let code = run::run(&composition, &program, invoke.as_deref(), &args)?;
+            if code != 0 {
+                std::process::exit(code);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints the components, instances and connections of a composition.
+fn inspect(composition: &Composition) -> Result<()> {
+    let state = &composition.state;
+
+    println!("components:");
+    for (id, component) in state.components() {
+        match component.version() {
+            Some(version) => println!("  #{id} {}@{version}", component.name()),
+            None => println!("  #{id} {}", component.name()),
+        }
+        println!("    digest: {}", component.digest());
+        for (_, name, _) in component.imports() {
+            println!("    import: {name}");
+        }
+        for (_, name, _) in component.exports() {
+            println!("    export: {name}");
+        }
+    }
+
+    println!("instances:");
+    for (id, component) in state.instances() {
+        let export = if composition.export == Some(id) {
+            ", exported"
+        } else {
+            ""
+        };
+        println!(
+            "  {instance} (#{component} {name}{export})",
+            instance = composition.instance(id),
+            name = state.component(component).unwrap().name()
+        );
+    }
+
+    println!("connections:");
+    for connection in state.connections() {
+        println!("  {}", composition.connection(connection));
+    }
+
+    let analysis = state.analyze(composition.export.as_slice())?;
+    println!("imports:");
+    for (id, imports) in &analysis.imports {
+        for import in imports {
+            println!("  {} from {}", import.name, composition.instance(*id));
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints the differences between a component and its replacement.
+fn print_diff(composition: &Composition, diff: &diff::ComponentDiff) {
+    if diff.differences.is_empty() {
+        println!("the worlds of the components are identical");
+    }
+
+    for difference in &diff.differences {
+        let kind = match difference.kind {
+            DifferenceKind::Added => "added",
+            DifferenceKind::Removed => "removed",
+            DifferenceKind::Changed => "changed",
+        };
+        let breaking = if difference.breaking { "breaking" } else { "" };
+        println!(
+            "{breaking:8} {kind:7} {item}: {description}",
+            item = difference.item,
+            description = difference.description
+        );
+    }
+
+    if !diff.broken_connections.is_empty() {
+        println!("broken connections:");
+        for broken in &diff.broken_connections {
+            println!(
+                "  {connection}: {message}",
+                connection = composition.connection(&broken.connection),
+                message = broken.message
+            );
+        }
+    }
+}
+
+/// Reads a component, converting it from a module or text format if needed.
+fn component(path: &Path) -> Result<Vec<u8>> {
+    let bytes = fs::read(path)
+        .with_context(|| format!("failed to read `{path}`", path = path.display()))?;
+
+    let mut state = State::default();
+    let (id, _) =
+        state.add_component("component".to_string(), None, bytes, AddOptions::default())?;
+    Ok(state.component(id).unwrap().bytes().to_vec())
+}
+
+/// Prints the WIT of a component.
+fn wit(bytes: &[u8]) -> Result<String> {
+    let decoded = wit_component::decode(bytes).context("failed to decode the component")?;
+    let resolve = decoded.resolve();
+    let nested = resolve
+        .packages
+        .iter()
+        .map(|(id, _)| id)
+        .filter(|id| *id != decoded.package())
+        .collect::<Vec<_>>();
+
+    let mut printer = WitPrinter::default();
+    printer.print(resolve, decoded.package(), &nested)?;
+    Ok(printer.output.to_string())
+}
+
+/// Gets the packages referenced by a WAC document.
+fn packages(input: &Input) -> Result<Vec<(String, Vec<u8>)>> {
+    let mut packages = Vec::new();
+
+    if let Some(dir) = &input.deps_dir {
+        let entries = fs::read_dir(dir)
+            .with_context(|| format!("failed to read `{dir}`", dir = dir.display()))?;
+        for namespace in entries {
+            let namespace = namespace?;
+            if !namespace.file_type()?.is_dir() {
+                continue;
+            }
+
+            for package in fs::read_dir(namespace.path())? {
+                let path = package?.path();
+                if !has_extension(&path, "wasm") {
+                    continue;
+                }
+
+                let name = format!(
+                    "{namespace}:{name}",
+                    namespace = namespace.file_name().to_string_lossy(),
+                    name = path.file_stem().unwrap().to_string_lossy()
+                );
+                packages.push((name, component(&path)?));
+            }
+        }
+    }
+
+    // Explicit dependencies take precedence over those in the directory
+    for (name, path) in &input.deps {
+        packages.push((name.clone(), component(path)?));
+    }
+
+    Ok(packages)
+}
+
+/// Finds an instance by id or name.
+fn find_instance(state: &State, instance: &str) -> Result<InstanceId> {
+    let found = state.instances().map(|(id, _)| id).find(|id| {
+        instance.parse() == Ok(*id) || state.instance_name(*id).unwrap() == Some(instance)
+    });
+
+    match found {
+        Some(id) => Ok(id),
+        None => bail!("instance `{instance}` does not exist in the composition"),
+    }
+}
+
+/// Finds a component by id or name.
+fn find_component(state: &State, component: &str) -> Result<ComponentId> {
+    let found = state
+        .components()
+        .find(|(id, c)| component.parse() == Ok(*id) || c.name() == component);
+
+    match found {
+        Some((id, _)) => Ok(id),
+        None => bail!("component `{component}` does not exist in the composition"),
+    }
+}
+
+/// Parses a `name=path` dependency.
+fn parse_dep(s: &str) -> Result<(String, PathBuf)> {
+    match s.split_once('=') {
+        Some((name, path)) if !name.is_empty() && !path.is_empty() => {
+            Ok((name.to_string(), path.into()))
+        }
+        _ => bail!("expected a dependency in the form `name=path`"),
+    }
+}
+
+/// Determines if a path has the given extension.
+fn has_extension(path: &Path, extension: &str) -> bool {
+    path.extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case(extension))
+}
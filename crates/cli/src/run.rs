@@ -0,0 +1,384 @@
+//! Module for running the component encoded from a composition.
+//!
+//! The component is instantiated in wasmtime with the host's implementation of
+//! WASI preview2; traps are attributed to the instances of the composition they
+//! occurred in.
+use crate::Composition;
+use anyhow::{anyhow, bail, Context, Result};
+use graph_core::state::ComponentId;
+use std::{fmt::Write, ops::Range};
+use wasmtime::{
+    component::{Component, Func, Instance, Linker, ResourceTable, Type, Val},
+    Config, Engine, Store, WasmBacktrace,
+};
+use wasmtime_wasi::{bindings::sync::Command, I32Exit, IoView, WasiCtx, WasiCtxBuilder, WasiView};
+
+/// Represents the host state of the store.
+struct Host {
+    ctx: WasiCtx,
+    table: ResourceTable,
+}
+
+impl IoView for Host {
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.table
+    }
+}
+
+impl WasiView for Host {
+    fn ctx(&mut self) -> &mut WasiCtx {
+        &mut self.ctx
+    }
+}
+
+/// Runs the component encoded from a composition.
+///
+/// If `invoke` is `None`, the `wasi:cli/run` export is called with the given
+/// arguments as the program arguments; otherwise the named export is called
+/// with the arguments parsed as its parameters and its results are printed.
+///
+/// Returns the exit code of the program.
+pub fn run(
+    composition: &Composition,
+    program: &str,
+    invoke: Option<&str>,
+    args: &[String],
+) -> Result<i32> {
+    if composition.export.is_none() {
+        bail!("the composition does not export an instance to run; use `--export` to choose one");
+    }
+
+    let bytes = composition.encode(true, true)?;
+    let ranges = composition.state.component_ranges(&bytes)?;
+
+    let mut config = Config::new();
+    config.wasm_backtrace(true);
+    let engine = Engine::new(&config)?;
+    let component = Component::new(&engine, &bytes).context("failed to compile the composition")?;
+
+    let mut linker = Linker::new(&engine);
+    wasmtime_wasi::add_to_linker_sync(&mut linker)?;
+
+    let mut ctx = WasiCtxBuilder::new();
+    ctx.inherit_stdio().arg(program);
+    if invoke.is_none() {
+        ctx.args(args);
+    }
+
+    let mut store = Store::new(
+        &engine,
+        Host {
+            ctx: ctx.build(),
+            table: ResourceTable::new(),
+        },
+    );
+
+    let result = match invoke {
+        None => {
+            if !component
+                .component_type()
+                .exports(&engine)
+                .any(|(name, _)| name.starts_with("wasi:cli/run@"))
+            {
+                bail!(
+                    "the composition does not export `wasi:cli/run`; use `--invoke` to call one \
+                     of its functions"
+                );
+            }
+
+            let command = Command::instantiate(&mut store, &component, &linker)
+                .context("failed to instantiate the composition")?;
+            command
+                .wasi_cli_run()
+                .call_run(&mut store)
+                .map(|r| if r.is_ok() { 0 } else { 1 })
+        }
+        Some(name) => {
+            let instance = linker
+                .instantiate(&mut store, &component)
+                .context("failed to instantiate the composition")?;
+            let func = find_func(&mut store, &instance, name)?;
+            let params = parse_params(name, &func.params(&store), args)?;
+            let mut results = vec![Val::Bool(false); func.results(&store).len()];
+            func.call(&mut store, &params, &mut results)
+                .and_then(|()| func.post_return(&mut store))
+                .map(|()| {
+                    for result in &results {
+                        println!("{}", display(result));
+                    }
+                    0
+                })
+        }
+    };
+
+    match result {
+        Ok(code) => Ok(code),
+        Err(e) => {
+            if let Some(exit) = e.downcast_ref::<I32Exit>() {
+                return Ok(exit.0);
+            }
+
+            let message = match invoke {
+                Some(name) => format!("failed to invoke `{name}`"),
+                None => "failed to run the composition".to_string(),
+            };
+
+            Err(match attribute(composition, &ranges, &e) {
+                Some(attribution) => e.context(attribution),
+                None => e,
+            }
+            .context(message))
+        }
+    }
+}
+
+/// Finds an exported function by name.
+///
+/// Functions exported from an instance are named `<instance>#<function>`.
+fn find_func(store: &mut Store<Host>, instance: &Instance, name: &str) -> Result<Func> {
+    let index = match name.rsplit_once('#') {
+        Some((interface, func)) => instance
+            .get_export(&mut *store, None, interface)
+            .and_then(|i| instance.get_export(&mut *store, Some(&i), func)),
+        None => instance.get_export(&mut *store, None, name),
+    };
+
+    index
+        .and_then(|i| instance.get_func(&mut *store, i))
+        .ok_or_else(|| anyhow!("the composition does not export a function named `{name}`"))
+}
+
+/// Parses the arguments of an invoked function.
+fn parse_params(name: &str, params: &[(String, Type)], args: &[String]) -> Result<Vec<Val>> {
+    if params.len() != args.len() {
+        bail!(
+            "`{name}` expects {expected} argument(s) but {given} were given",
+            expected = params.len(),
+            given = args.len()
+        );
+    }
+
+    params
+        .iter()
+        .zip(args)
+        .map(|((param, ty), arg)| {
+            parse(ty, arg).with_context(|| format!("invalid value `{arg}` for parameter `{param}`"))
+        })
+        .collect()
+}
+
+/// Parses a value of the given type from a command line argument.
+///
+/// Only values of scalar types, strings, enums and flags (separated by commas)
+/// can be given on the command line.
+fn parse(ty: &Type, arg: &str) -> Result<Val> {
+    Ok(match ty {
+        Type::Bool => Val::Bool(arg.parse()?),
+        Type::S8 => Val::S8(arg.parse()?),
+        Type::U8 => Val::U8(arg.parse()?),
+        Type::S16 => Val::S16(arg.parse()?),
+        Type::U16 => Val::U16(arg.parse()?),
+        Type::S32 => Val::S32(arg.parse()?),
+        Type::U32 => Val::U32(arg.parse()?),
+        Type::S64 => Val::S64(arg.parse()?),
+        Type::U64 => Val::U64(arg.parse()?),
+        Type::Float32 => Val::Float32(arg.parse()?),
+        Type::Float64 => Val::Float64(arg.parse()?),
+        Type::Char => {
+            let mut chars = arg.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Val::Char(c),
+                _ => bail!("expected a single character"),
+            }
+        }
+        Type::String => Val::String(arg.to_string()),
+        Type::Enum(ty) => {
+            if !ty.names().any(|n| n == arg) {
+                bail!(
+                    "expected one of {}",
+                    ty.names().collect::<Vec<_>>().join(", ")
+                );
+            }
+
+            Val::Enum(arg.to_string())
+        }
+        Type::Flags(ty) => Val::Flags(
+            arg.split(',')
+                .filter(|flag| !flag.is_empty())
+                .map(|flag| {
+                    if !ty.names().any(|n| n == flag) {
+                        bail!("unknown flag `{flag}`");
+                    }
+
+                    Ok(flag.to_string())
+                })
+                .collect::<Result<_>>()?,
+        ),
+        _ => bail!("values of this type cannot be given on the command line"),
+    })
+}
+
+/// Displays a value returned from an invoked function.
+fn display(val: &Val) -> String {
+    let list = |vals: &mut dyn Iterator<Item = String>| vals.collect::<Vec<_>>().join(", ");
+    let payload = |name: &str, val: &Option<Box<Val>>| match val {
+        Some(val) => format!("{name}({})", display(val)),
+        None => name.to_string(),
+    };
+
+    match val {
+        Val::Bool(v) => v.to_string(),
+        Val::S8(v) => v.to_string(),
+        Val::U8(v) => v.to_string(),
+        Val::S16(v) => v.to_string(),
+        Val::U16(v) => v.to_string(),
+        Val::S32(v) => v.to_string(),
+        Val::U32(v) => v.to_string(),
+        Val::S64(v) => v.to_string(),
+        Val::U64(v) => v.to_string(),
+        Val::Float32(v) => v.to_string(),
+        Val::Float64(v) => v.to_string(),
+        Val::Char(v) => format!("{v:?}"),
+        Val::String(v) => format!("{v:?}"),
+        Val::List(vals) => format!("[{}]", list(&mut vals.iter().map(display))),
+        Val::Record(fields) => format!(
+            "{{{}}}",
+            list(
+                &mut fields
+                    .iter()
+                    .map(|(name, val)| format!("{name}: {}", display(val)))
+            )
+        ),
+        Val::Tuple(vals) => format!("({})", list(&mut vals.iter().map(display))),
+        Val::Variant(name, val) => payload(name, val),
+        Val::Enum(name) => name.clone(),
+        Val::Option(None) => "none".to_string(),
+        Val::Option(Some(val)) => format!("some({})", display(val)),
+        Val::Result(Ok(val)) => payload("ok", val),
+        Val::Result(Err(val)) => payload("err", val),
+        Val::Flags(names) => format!("{{{}}}", names.join(", ")),
+        Val::Resource(_) => "<resource>".to_string(),
+    }
+}
+
+/// Describes the instances of the composition a trap occurred in.
+///
+/// The frames of the trap's backtrace are attributed to the components defined
+/// in the encoded graph, innermost first.
+fn attribute(
+    composition: &Composition,
+    ranges: &[(Range<usize>, ComponentId)],
+    error: &anyhow::Error,
+) -> Option<String> {
+    let backtrace = error.downcast_ref::<WasmBacktrace>()?;
+
+    let mut components = Vec::new();
+    for frame in backtrace.frames() {
+        let Some(offset) = frame.module_offset() else {
+            continue;
+        };
+
+        let Some((_, id)) = ranges.iter().find(|(range, _)| range.contains(&offset)) else {
+            continue;
+        };
+
+        if components.last() != Some(id) {
+            components.push(*id);
+        }
+    }
+
+    let (first, callers) = components.split_first()?;
+    let mut attribution = format!("the trap occurred in {}", composition.instances_of(*first));
+    for caller in callers {
+        write!(
+            attribution,
+            ", called from {}",
+            composition.instances_of(*caller)
+        )
+        .unwrap();
+    }
+
+    Some(attribution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use graph_core::state::{AddOptions, Connection, State};
+    use std::{fs, path::Path};
+
+    /// A component exporting `example:greeter/greeter` with a `greet` function that traps.
+    const TRAPPING_GREETER: &str = r#"
+        (component
+          (core module $m
+            (func (export "greet") (result i32)
+              unreachable))
+          (core instance $i (instantiate $m))
+          (func $greet (result u32) (canon lift (core func $i "greet")))
+          (instance $greeter (export "greet" (func $greet)))
+          (export "example:greeter/greeter" (instance $greeter))
+        )
+    "#;
+
+    /// Creates a composition of the app fixture connected to the given greeter.
+    fn composition(greeter: Vec<u8>) -> Composition {
+        let fixture =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("../graph-core/tests/fixtures/app.wat");
+        let mut state = State::default();
+        let mut add = |name: &str, bytes| {
+            let (id, _) = state
+                .add_component(name.to_string(), None, bytes, AddOptions::default())
+                .unwrap();
+            state.instantiate(id).unwrap()
+        };
+
+        let greeter = add("example:greeter", greeter);
+        let app = add("example:app", fs::read(fixture).unwrap());
+        state
+            .connect(Connection {
+                source: greeter,
+                source_export: Some(0),
+                target: app,
+                target_import: 0,
+            })
+            .unwrap();
+        state
+            .set_instance_name(app, Some("app".to_string()))
+            .unwrap();
+
+        Composition {
+            state,
+            export: Some(app),
+            exports: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn traps_are_attributed_to_instances() {
+        let composition = composition(TRAPPING_GREETER.as_bytes().to_vec());
+        let e = run(&composition, "app", Some("run"), &[]).unwrap_err();
+
+        // The trap's frames are found in the ranges of the defined components
+        assert_eq!(
+            e.chain()
+                .take(2)
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "failed to invoke `run`",
+                "the trap occurred in instance `#0` of `example:greeter`, called from instance \
+                 `app` of `example:app`"
+            ]
+        );
+    }
+
+    #[test]
+    fn errors_without_a_backtrace_are_not_attributed() {
+        let composition = composition(TRAPPING_GREETER.as_bytes().to_vec());
+        let bytes = composition.encode(true, true).unwrap();
+        let ranges = composition.state.component_ranges(&bytes).unwrap();
+        assert_eq!(ranges.len(), 2);
+
+        assert_eq!(attribute(&composition, &ranges, &anyhow!("failed")), None);
+    }
+}
@@ -0,0 +1,173 @@
+//! Module for auditing the host capabilities reachable in a graph.
+//!
+//! The capabilities of an instance are the imports of the encoded graph, such as
+//! WASI interfaces, that it can reach through its own imports and the imports
+//! of the instances it depends on.
+use crate::state::{InstanceId, State};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+/// Represents a host import reachable from an instance.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Capability {
+    /// The name of the import of the encoded graph.
+    pub name: String,
+    /// The instances in the dependency chain that import it.
+    pub imported_by: Vec<InstanceId>,
+}
+
+/// Represents the capabilities reachable from an instance.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceAudit {
+    /// The audited instance.
+    pub instance: InstanceId,
+    /// The instances the audited instance depends on, directly or indirectly.
+    pub dependencies: Vec<InstanceId>,
+    /// The capabilities reachable from the instance, sorted by name.
+    pub capabilities: Vec<Capability>,
+}
+
+/// Audits the capabilities reachable from every instance in the graph.
+pub fn audit(state: &State) -> Vec<InstanceAudit> {
+    // Find the unconnected imports of each instance and what each depends on
+    let mut imports = BTreeMap::new();
+    let mut arguments = BTreeMap::new();
+    for (id, component) in state.instances() {
+        let args = state.arguments(id).unwrap();
+        let component = state.component(component).unwrap();
+        imports.insert(
+            id,
+            component
+                .imports()
+                .map(|(_, name, _)| name)
+                .filter(|name| !args.iter().any(|(arg, _)| arg == name))
+                .collect::<Vec<_>>(),
+        );
+        arguments.insert(
+            id,
+            args.into_iter()
+                .map(|(_, source)| source)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    imports
+        .keys()
+        .map(|id| {
+            let mut dependencies = BTreeSet::new();
+            let mut stack = arguments[id].clone();
+            while let Some(dependency) = stack.pop() {
+                if dependency != *id && dependencies.insert(dependency) {
+                    stack.extend(&arguments[&dependency]);
+                }
+            }
+
+            let mut capabilities: BTreeMap<&str, Vec<InstanceId>> = BTreeMap::new();
+            for instance in std::iter::once(id).chain(&dependencies) {
+                for name in &imports[instance] {
+                    capabilities.entry(name).or_default().push(*instance);
+                }
+            }
+
+            InstanceAudit {
+                instance: *id,
+                dependencies: dependencies.into_iter().collect(),
+                capabilities: capabilities
+                    .into_iter()
+                    .map(|(name, imported_by)| Capability {
+                        name: name.to_string(),
+                        imported_by,
+                    })
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+/// Formats an audit of the graph as a Markdown report.
+///
+/// Names are written as code spans and escaped for the report's tables.
+pub fn markdown(state: &State, audit: &[InstanceAudit]) -> String {
+    let instance = |id: InstanceId| {
+        let (_, component) = state.instances().find(|(i, _)| *i == id).unwrap();
+        let component = code(state.component(component).unwrap().name());
+        match state.instance_name(id).unwrap() {
+            Some(name) => format!("{} (instance {id} of {component})", code(name)),
+            None => format!("instance {id} of {component}"),
+        }
+    };
+
+    let mut report = String::from("# Capability audit\n\n");
+    let all: BTreeSet<_> = audit
+        .iter()
+        .flat_map(|a| a.capabilities.iter().map(|c| c.name.as_str()))
+        .collect();
+    if all.is_empty() {
+        report.push_str("The composition imports nothing from the host.\n");
+    } else {
+        report.push_str("The composition imports the following from the host:\n\n");
+        for name in &all {
+            writeln!(report, "- {}", code(name)).unwrap();
+        }
+    }
+
+    for entry in audit {
+        writeln!(report, "\n## {}\n", instance(entry.instance)).unwrap();
+
+        if entry.capabilities.is_empty() {
+            report.push_str("No host imports are reachable.\n");
+            continue;
+        }
+
+        report.push_str("| Import | Imported by |\n| --- | --- |\n");
+        for capability in &entry.capabilities {
+            let imported_by = capability
+                .imported_by
+                .iter()
+                .map(|id| {
+                    if *id == entry.instance {
+                        "itself".to_string()
+                    } else {
+                        instance(*id)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(
+                report,
+                "| {} | {} |",
+                cell(&code(&capability.name)),
+                cell(&imported_by)
+            )
+            .unwrap();
+        }
+    }
+
+    report
+}
+
+/// Formats text as a Markdown code span.
+///
+/// The span is delimited by more backticks than the longest run in the text.
+fn code(text: &str) -> String {
+    let run = text
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or_default();
+    let delimiter = "`".repeat(run + 1);
+    let padding = if text.starts_with('`') || text.ends_with('`') {
+        " "
+    } else {
+        ""
+    };
+    format!("{delimiter}{padding}{text}{padding}{delimiter}")
+}
+
+/// Escapes the pipes of text written to a cell of a Markdown table.
+fn cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
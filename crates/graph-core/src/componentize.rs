@@ -0,0 +1,55 @@
+//! Module for converting core WebAssembly modules into components.
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use wasi_preview1_component_adapter_provider::{
+    WASI_SNAPSHOT_PREVIEW1_ADAPTER_NAME, WASI_SNAPSHOT_PREVIEW1_COMMAND_ADAPTER,
+    WASI_SNAPSHOT_PREVIEW1_PROXY_ADAPTER, WASI_SNAPSHOT_PREVIEW1_REACTOR_ADAPTER,
+};
+use wit_component::ComponentEncoder;
+
+/// Represents a bundled WASI preview1 adapter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Adapter {
+    /// The adapter for modules that export functions to be called.
+    Reactor,
+    /// The adapter for modules with a `_start` function.
+    Command,
+    /// The adapter for modules that handle HTTP requests.
+    Proxy,
+}
+
+impl Adapter {
+    fn bytes(self) -> &'static [u8] {
+        match self {
+            Self::Reactor => WASI_SNAPSHOT_PREVIEW1_REACTOR_ADAPTER,
+            Self::Command => WASI_SNAPSHOT_PREVIEW1_COMMAND_ADAPTER,
+            Self::Proxy => WASI_SNAPSHOT_PREVIEW1_PROXY_ADAPTER,
+        }
+    }
+}
+
+/// Converts a core module into a component.
+///
+/// The imports and exports of the component are described by the module's
+/// `component-type` custom sections, if any. If an adapter is given, imports of
+/// `wasi_snapshot_preview1` are satisfied by the adapter.
+pub fn componentize(module: &[u8], adapter: Option<Adapter>) -> Result<Vec<u8>> {
+    let error = |e: anyhow::Error| Error::Validation {
+        message: format!("failed to convert the module into a component: {e:#}"),
+        offset: None,
+    };
+
+    let mut encoder = ComponentEncoder::default()
+        .validate(true)
+        .module(module)
+        .map_err(error)?;
+
+    if let Some(adapter) = adapter {
+        encoder = encoder
+            .adapter(WASI_SNAPSHOT_PREVIEW1_ADAPTER_NAME, adapter.bytes())
+            .map_err(error)?;
+    }
+
+    encoder.encode().map_err(error)
+}
@@ -0,0 +1,262 @@
+//! Module for generating components that provide fixed configuration.
+//!
+//! A configuration component exports `wasi:cli/environment` and/or
+//! `wasi:config/store` with the given values baked into its data segment.
+//!
+//! The interfaces are exported at the pinned versions `wasi:cli@0.2.0` and
+//! `wasi:config@0.2.0-draft`; instances importing other versions are not
+//! auto-connected to the component.
+use crate::componentize::componentize;
+use crate::error::{Error, Result};
+use crate::stub::{push_memory, MANGLING};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt::Write;
+use wit_component::StringEncoding;
+use wit_parser::{Resolve, WasmExport, WasmExportKind, WorldItem};
+
+/// The WIT of the interfaces exported by configuration components.
+const WIT: &str = r#"
+package wasmbuilder-app:config;
+
+world config {
+    export wasi:cli/environment@0.2.0;
+    export wasi:config/store@0.2.0-draft;
+}
+
+package wasi:cli@0.2.0 {
+    interface environment {
+        get-environment: func() -> list<tuple<string, string>>;
+        get-arguments: func() -> list<string>;
+        initial-cwd: func() -> option<string>;
+    }
+}
+
+package wasi:config@0.2.0-draft {
+    interface store {
+        variant error {
+            upstream(string),
+            io(string),
+        }
+
+        get: func(key: string) -> result<option<string>, error>;
+        get-all: func() -> result<list<tuple<string, string>>, error>;
+    }
+}
+"#;
+
+/// The size of the zeroed area at the start of memory.
+///
+/// The area is returned for empty lists, `none` and `ok(none)`.
+const ZEROS: usize = 16;
+
+/// Represents the values of a configuration component.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Values {
+    /// The environment variables returned by `wasi:cli/environment`.
+    ///
+    /// If `None`, the interface is not exported.
+    pub environment: Option<Vec<(String, String)>>,
+    /// The values returned by `wasi:config/store`.
+    ///
+    /// If `None`, the interface is not exported.
+    pub config: Option<Vec<(String, String)>>,
+}
+
+/// Generates a configuration component for the given values.
+///
+/// The component exports `wasi:cli/environment@0.2.0` and
+/// `wasi:config/store@0.2.0-draft`.
+pub fn generate(values: &Values) -> Result<Vec<u8>> {
+    if values.environment.is_none() && values.config.is_none() {
+        return Err(Error::InvalidOperation(
+            "a configuration component must export at least one interface".to_string(),
+        ));
+    }
+
+    if let Some(config) = &values.config {
+        let mut keys = HashSet::new();
+        if let Some((key, _)) = config.iter().find(|(key, _)| !keys.insert(key)) {
+            return Err(Error::InvalidOperation(format!(
+                "configuration key `{key}` is specified more than once"
+            )));
+        }
+    }
+
+    let mut resolve = Resolve::default();
+    let package = resolve
+        .push_str("config.wit", WIT)
+        .expect("configuration WIT should parse");
+    let world = resolve
+        .select_world(package, None)
+        .expect("configuration world should exist");
+
+    // Remove the exports that were not requested
+    resolve.worlds[world].exports.retain(|_, item| {
+        let WorldItem::Interface { id, .. } = item else {
+            return false;
+        };
+
+        match resolve.interfaces[*id].name.as_deref() {
+            Some("environment") => values.environment.is_some(),
+            Some("store") => values.config.is_some(),
+            _ => false,
+        }
+    });
+
+    let mut data = vec![0; ZEROS];
+    let mut wat = String::from("(module\n");
+
+    for (name, item) in &resolve.worlds[world].exports {
+        let WorldItem::Interface { id, .. } = item else {
+            continue;
+        };
+
+        for func in resolve.interfaces[*id].functions.values() {
+            let export = resolve.wasm_export_name(
+                MANGLING,
+                WasmExport::Func {
+                    interface: Some(name),
+                    func,
+                    kind: WasmExportKind::Normal,
+                },
+            );
+
+            let body = match func.name.as_str() {
+                "get-environment" => {
+                    let list = push_pairs(&mut data, values.environment.as_deref().unwrap());
+                    format!("(result i32) i32.const {}", push(&mut data, &list, 4))
+                }
+                "get-arguments" | "initial-cwd" => "(result i32) i32.const 0".to_string(),
+                "get" => get(&mut data, values.config.as_deref().unwrap()),
+                "get-all" => {
+                    // `ok` of the list of pairs
+                    let list = push_pairs(&mut data, values.config.as_deref().unwrap());
+                    let mut result = vec![0; 4];
+                    result.extend(list);
+                    result.resize(ZEROS, 0);
+                    format!("(result i32) i32.const {}", push(&mut data, &result, 4))
+                }
+                _ => unreachable!("unexpected configuration function"),
+            };
+
+            writeln!(wat, "(func (export {export:?}) {body})").unwrap();
+        }
+    }
+
+    wat.push_str(EQ);
+    push_memory(&mut wat, &resolve, data.len());
+    write!(wat, "(data (i32.const 0) \"").unwrap();
+    for byte in &data {
+        write!(wat, "\\{byte:02x}").unwrap();
+    }
+    wat.push_str("\")\n)\n");
+
+    let mut module = wat::parse_str(&wat).map_err(|e| Error::Validation {
+        message: format!("failed to generate the configuration module: {e}"),
+        offset: None,
+    })?;
+    wit_component::embed_component_metadata(&mut module, &resolve, world, StringEncoding::UTF8)
+        .map_err(|e| Error::Wit(format!("{e:#}")))?;
+
+    componentize(&module, None)
+}
+
+/// A function that compares two strings in memory for equality.
+const EQ: &str = r#"(func $eq (param $a i32) (param $a-len i32) (param $b i32) (param $b-len i32) (result i32)
+  local.get $a-len
+  local.get $b-len
+  i32.ne
+  if
+    i32.const 0
+    return
+  end
+  block
+    loop
+      local.get $a-len
+      i32.eqz
+      br_if 1
+      local.get $a
+      i32.load8_u
+      local.get $b
+      i32.load8_u
+      i32.ne
+      if
+        i32.const 0
+        return
+      end
+      local.get $a
+      i32.const 1
+      i32.add
+      local.set $a
+      local.get $b
+      i32.const 1
+      i32.add
+      local.set $b
+      local.get $a-len
+      i32.const 1
+      i32.sub
+      local.set $a-len
+      br 0
+    end
+  end
+  i32.const 1)
+"#;
+
+/// Gets the signature and body of the `wasi:config/store#get` function.
+///
+/// Each key is compared with the given key in turn; the result of a match is
+/// `ok(some(value))` and the result otherwise is `ok(none)`.
+fn get(data: &mut Vec<u8>, pairs: &[(String, String)]) -> String {
+    let mut body = String::from("(param i32 i32) (result i32)\n");
+    for (key, value) in pairs {
+        let key_ptr = push(data, key.as_bytes(), 1);
+        let value_ptr = push(data, value.as_bytes(), 1);
+
+        let mut result = vec![0; ZEROS];
+        result[4] = 1;
+        result[8..12].copy_from_slice(&value_ptr.to_le_bytes());
+        result[12..16].copy_from_slice(&(value.len() as u32).to_le_bytes());
+        let result = push(data, &result, 4);
+
+        writeln!(
+            body,
+            "  (if (call $eq (local.get 0) (local.get 1) (i32.const {key_ptr}) (i32.const {len})) (then (return (i32.const {result}))))",
+            len = key.len()
+        )
+        .unwrap();
+    }
+
+    body.push_str("  i32.const 0");
+    body
+}
+
+/// Pushes the strings of the given pairs to the data segment.
+///
+/// Returns the pointer and length of the list of pairs as they are laid out in
+/// memory.
+fn push_pairs(data: &mut Vec<u8>, pairs: &[(String, String)]) -> Vec<u8> {
+    let mut elements = Vec::with_capacity(pairs.len() * 16);
+    for (key, value) in pairs {
+        for s in [key, value] {
+            let ptr = push(data, s.as_bytes(), 1);
+            elements.extend(ptr.to_le_bytes());
+            elements.extend((s.len() as u32).to_le_bytes());
+        }
+    }
+
+    let mut list = push(data, &elements, 4).to_le_bytes().to_vec();
+    list.extend((pairs.len() as u32).to_le_bytes());
+    list
+}
+
+/// Pushes bytes with the given alignment to the data segment.
+///
+/// Returns the offset of the bytes.
+fn push(data: &mut Vec<u8>, bytes: &[u8], align: usize) -> u32 {
+    data.resize(data.len().next_multiple_of(align), 0);
+    let offset = data.len();
+    data.extend_from_slice(bytes);
+    offset as u32
+}
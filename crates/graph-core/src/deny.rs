@@ -0,0 +1,144 @@
+//! Module for generating components that deny access to interfaces.
+//!
+//! A deny component exports the given interfaces with stub implementations.
+//! Connecting it to instances in place of the host prevents those instances
+//! from reaching the host's implementation of the interfaces.
+use crate::error::{Error, Result};
+use crate::stub::{self, Behavior};
+use std::collections::HashSet;
+use wit_component::DecodedWasm;
+use wit_parser::{InterfaceId, Resolve, Stability, WorldItem, WorldKey};
+
+/// Generates a deny component for the given interfaces.
+///
+/// The types of the interfaces come from the WIT of the given components. An
+/// interface name may end with `/*` to match every interface of a package, such
+/// as `wasi:sockets/*`; a name without a version matches any version of the
+/// interface.
+pub fn generate<'a>(
+    interfaces: &[String],
+    components: impl IntoIterator<Item = &'a [u8]>,
+    behavior: Behavior,
+) -> Result<Vec<u8>> {
+    if interfaces.is_empty() {
+        return Err(Error::InvalidOperation(
+            "a deny component must export at least one interface".to_string(),
+        ));
+    }
+
+    let mut resolve = Resolve::default();
+    let package = resolve
+        .push_str("deny.wit", "package wasmbuilder-app:deny; world deny {}")
+        .expect("deny WIT should parse");
+    let world = resolve
+        .select_world(package, None)
+        .expect("deny world should exist");
+
+    // Find the interfaces in the worlds of the components
+    let mut names = HashSet::new();
+    let mut matched = vec![false; interfaces.len()];
+    let mut exports = Vec::new();
+    for bytes in components {
+        let Ok(DecodedWasm::Component(decoded, decoded_world)) = wit_component::decode(bytes)
+        else {
+            continue;
+        };
+
+        let found: Vec<_> = decoded.worlds[decoded_world]
+            .imports
+            .values()
+            .chain(decoded.worlds[decoded_world].exports.values())
+            .filter_map(|item| match item {
+                WorldItem::Interface { id, .. } => {
+                    let name = decoded.id_of(*id)?;
+                    let mut any = false;
+                    for (pattern, matched) in interfaces.iter().zip(matched.iter_mut()) {
+                        if matches(pattern, &name) {
+                            *matched = true;
+                            any = true;
+                        }
+                    }
+
+                    (any && names.insert(name)).then_some(*id)
+                }
+                _ => None,
+            })
+            .collect();
+
+        if found.is_empty() {
+            continue;
+        }
+
+        let remap = resolve
+            .merge(decoded)
+            .map_err(|e| Error::Wit(format!("{e:#}")))?;
+        exports.extend(
+            found
+                .into_iter()
+                .map(|id| remap.interfaces[id.index()].unwrap()),
+        );
+    }
+
+    if let Some((pattern, _)) = interfaces.iter().zip(&matched).find(|(_, m)| !**m) {
+        return Err(Error::InvalidOperation(format!(
+            "interface `{pattern}` is not used by any component in the graph"
+        )));
+    }
+
+    // Import the dependencies of the exported interfaces; an exported
+    // interface that depends on another uses the exported one
+    let exported: HashSet<_> = exports.iter().copied().collect();
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+    for id in exports {
+        push_interface(&resolve, id, &mut visited, &mut order);
+    }
+
+    let (exports, imports): (Vec<_>, Vec<_>) =
+        order.into_iter().partition(|id| exported.contains(id));
+
+    let item = |id: InterfaceId| {
+        (
+            WorldKey::Interface(id),
+            WorldItem::Interface {
+                id,
+                stability: Stability::Unknown,
+            },
+        )
+    };
+    resolve.worlds[world].imports = imports.into_iter().map(item).collect();
+    resolve.worlds[world].exports = exports.into_iter().map(item).collect();
+
+    stub::encode(&resolve, world, behavior)
+}
+
+/// Determines if an interface name matches the given pattern.
+fn matches(pattern: &str, name: &str) -> bool {
+    if let Some(package) = pattern.strip_suffix("/*") {
+        return name.split_once('/').is_some_and(|(p, _)| p == package);
+    }
+
+    if pattern.contains('@') {
+        pattern == name
+    } else {
+        name.split_once('@').map(|(n, _)| n).unwrap_or(name) == pattern
+    }
+}
+
+/// Pushes an interface after its dependencies.
+fn push_interface(
+    resolve: &Resolve,
+    id: InterfaceId,
+    visited: &mut HashSet<InterfaceId>,
+    order: &mut Vec<InterfaceId>,
+) {
+    if !visited.insert(id) {
+        return;
+    }
+
+    for dep in resolve.interface_direct_deps(id) {
+        push_interface(resolve, dep, visited, order);
+    }
+
+    order.push(id);
+}
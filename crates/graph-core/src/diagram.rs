@@ -0,0 +1,112 @@
+//! Module for rendering the graph as a diagram.
+//!
+//! Instances are rendered as nodes labelled with their component (and their
+//! name, if they have one) and connections as edges labelled with the export
+//! and import they connect.
+use crate::state::{Connection, InstanceId, State};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// Represents the format of a diagram.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Format {
+    /// A Graphviz DOT document.
+    #[default]
+    Dot,
+    /// A Mermaid flowchart.
+    Mermaid,
+}
+
+/// Renders the graph as a diagram in the given format.
+pub fn render(state: &State, format: Format) -> String {
+    let mut diagram = String::new();
+    match format {
+        Format::Dot => {
+            diagram.push_str("digraph composition {\n  rankdir=LR;\n  node [shape=box];\n");
+            for (id, component) in state.instances() {
+                let component = dot(state.component(component).unwrap().name());
+                let label = match state.instance_name(id).unwrap() {
+                    Some(name) => format!("{name}\\n{component}", name = dot(name)),
+                    None => component,
+                };
+                writeln!(diagram, "  i{id} [label=\"{label}\"];").unwrap();
+            }
+
+            for connection in state.connections() {
+                writeln!(
+                    diagram,
+                    "  i{source} -> i{target} [label=\"{label}\"];",
+                    source = connection.source,
+                    target = connection.target,
+                    label = dot(&label(state, connection))
+                )
+                .unwrap();
+            }
+
+            diagram.push_str("}\n");
+        }
+        Format::Mermaid => {
+            diagram.push_str("flowchart LR\n");
+            for (id, component) in state.instances() {
+                let component = mermaid(state.component(component).unwrap().name());
+                let label = match state.instance_name(id).unwrap() {
+                    Some(name) => format!("{name}<br>{component}", name = mermaid(name)),
+                    None => component,
+                };
+                writeln!(diagram, "  i{id}[\"{label}\"]").unwrap();
+            }
+
+            for connection in state.connections() {
+                writeln!(
+                    diagram,
+                    "  i{source} -->|\"{label}\"| i{target}",
+                    source = connection.source,
+                    target = connection.target,
+                    label = mermaid(&label(state, connection))
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    diagram
+}
+
+/// Gets the label of a connection.
+///
+/// The label is the name of the target import, preceded by the name of the
+/// source export if it differs.
+fn label(state: &State, connection: &Connection) -> String {
+    let component = |id: InstanceId| {
+        let (_, component) = state.instances().find(|(i, _)| *i == id).unwrap();
+        state.component(component).unwrap()
+    };
+
+    let (import, _) = component(connection.target)
+        .import(connection.target_import)
+        .unwrap();
+    match connection.source_export {
+        Some(index) => {
+            let (export, _) = component(connection.source).export(index).unwrap();
+            if export == import {
+                import.to_string()
+            } else {
+                format!("{export} → {import}")
+            }
+        }
+        None => import.to_string(),
+    }
+}
+
+/// Escapes text for a quoted DOT string.
+fn dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Escapes text for a quoted Mermaid label.
+fn mermaid(text: &str) -> String {
+    text.replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+}
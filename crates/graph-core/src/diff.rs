@@ -0,0 +1,475 @@
+//! Module for comparing the WIT worlds of two components.
+//!
+//! A difference is breaking if replacing the first component with the second
+//! would break a connection of one of the first component's instances.
+use crate::{
+    error::{Error, Result},
+    signature,
+    state::{ComponentId, Connection, State},
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt};
+use wit_component::DecodedWasm;
+use wit_parser::{Function, FunctionKind, Resolve, TypeDefKind, TypeId, WorldId, WorldItem};
+
+/// Represents the kind of a difference between two components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DifferenceKind {
+    /// The item was added by the second component.
+    Added,
+    /// The item was removed by the second component.
+    Removed,
+    /// The item was changed by the second component.
+    Changed,
+}
+
+/// Represents a difference between the worlds of two components.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Difference {
+    /// The path of the item that differs.
+    ///
+    /// Members of an imported or exported instance are given as
+    /// `<item>#<member>` and the fields of a record as `<record>.<field>`.
+    pub item: String,
+    /// The kind of the difference.
+    pub kind: DifferenceKind,
+    /// The description of the difference.
+    pub description: String,
+    /// Whether the difference breaks a connection in the graph.
+    pub breaking: bool,
+}
+
+/// Represents a connection that the second component cannot be used in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokenConnection {
+    /// The connection of an instance of the first component.
+    pub connection: Connection,
+    /// The reason the connection cannot be made.
+    pub message: String,
+}
+
+/// Represents the differences between the worlds of two components.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentDiff {
+    /// The differences, imports first.
+    pub differences: Vec<Difference>,
+    /// The connections that would break if the first component were replaced.
+    pub broken_connections: Vec<BrokenConnection>,
+    /// Whether the second component can replace the first in the graph.
+    pub compatible: bool,
+}
+
+/// Compares the world of component `a` with the world of component `b`.
+pub fn diff(state: &State, a: ComponentId, b: ComponentId) -> Result<ComponentDiff> {
+    let old = World::decode(state, a)?;
+    let new = World::decode(state, b)?;
+    let failures = state.replacement_failures(a, b)?;
+
+    // Find the items of the first component used by the broken connections
+    let instances = state.instances().collect::<HashMap<_, _>>();
+    let component = state.component(a).unwrap();
+    let mut broken = HashMap::new();
+    for (connection, error) in &failures {
+        if instances[&connection.target] == a {
+            let (name, _) = component.import(connection.target_import).unwrap();
+            broken
+                .entry((Side::Import, name))
+                .or_insert_with(|| error.to_string());
+        }
+
+        if instances[&connection.source] == a {
+            let names = match connection.source_export {
+                Some(index) => vec![component.export(index).unwrap().0],
+                None => component.exports().map(|(_, name, _)| name).collect(),
+            };
+
+            for name in names {
+                broken
+                    .entry((Side::Export, name))
+                    .or_insert_with(|| error.to_string());
+            }
+        }
+    }
+
+    let mut differences = Vec::new();
+    for side in [Side::Import, Side::Export] {
+        let old_items = side.items(&old);
+        let new_items = side.items(&new);
+
+        for (name, old_item) in &old_items {
+            let message = broken.get(&(side, name.as_str()));
+            let mut item = Item {
+                side,
+                broken: message.is_some(),
+                differences: &mut differences,
+            };
+            let start = item.differences.len();
+
+            match new_items.iter().find(|(n, _)| n == name) {
+                Some((_, new_item)) => item.compare(name, &old, old_item, &new, new_item),
+                None => item.push(
+                    name.clone(),
+                    DifferenceKind::Removed,
+                    format!("{side} `{name}` was removed"),
+                ),
+            }
+
+            // A broken connection is reported even if no difference explains it
+            if let Some(message) = message {
+                if !item.differences[start..].iter().any(|d| d.breaking) {
+                    item.differences.push(Difference {
+                        item: name.clone(),
+                        kind: DifferenceKind::Changed,
+                        description: message.clone(),
+                        breaking: true,
+                    });
+                }
+            }
+        }
+
+        for (name, _) in &new_items {
+            if !old_items.iter().any(|(n, _)| n == name) {
+                differences.push(Difference {
+                    item: name.clone(),
+                    kind: DifferenceKind::Added,
+                    description: format!("{side} `{name}` was added"),
+                    breaking: false,
+                });
+            }
+        }
+    }
+
+    Ok(ComponentDiff {
+        differences,
+        compatible: failures.is_empty(),
+        broken_connections: failures
+            .into_iter()
+            .map(|(connection, e)| BrokenConnection {
+                connection,
+                message: e.to_string(),
+            })
+            .collect(),
+    })
+}
+
+/// Represents the decoded world of a component.
+struct World {
+    resolve: Resolve,
+    world: WorldId,
+}
+
+impl World {
+    /// Decodes the world of a component in the graph.
+    fn decode(state: &State, id: ComponentId) -> Result<Self> {
+        let component = state.component(id).ok_or(Error::UnknownComponent(id))?;
+        match wit_component::decode(component.bytes()) {
+            Ok(DecodedWasm::Component(resolve, world)) => Ok(Self { resolve, world }),
+            Ok(DecodedWasm::WitPackage(..)) => Err(Error::InvalidOperation(format!(
+                "component {id} is a WIT package"
+            ))),
+            Err(e) => Err(Error::InvalidOperation(format!(
+                "failed to decode the world of component {id}: {e:#}"
+            ))),
+        }
+    }
+}
+
+/// Represents the side of a world an item is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Side {
+    Import,
+    Export,
+}
+
+impl Side {
+    /// Gets the items of a world on this side, by name.
+    fn items(self, world: &World) -> Vec<(String, &WorldItem)> {
+        let items = match self {
+            Self::Import => &world.resolve.worlds[world.world].imports,
+            Self::Export => &world.resolve.worlds[world.world].exports,
+        };
+
+        items
+            .iter()
+            .map(|(key, item)| (world.resolve.name_world_key(key), item))
+            .collect()
+    }
+
+    /// Determines if a difference to an item on this side can break a connection.
+    ///
+    /// A replacement may import less and export more, but not the reverse.
+    fn can_break(self, kind: DifferenceKind) -> bool {
+        match self {
+            Self::Import => kind != DifferenceKind::Removed,
+            Self::Export => kind != DifferenceKind::Added,
+        }
+    }
+}
+
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Import => write!(f, "import"),
+            Self::Export => write!(f, "export"),
+        }
+    }
+}
+
+/// Collects the differences of an imported or exported item.
+struct Item<'a> {
+    side: Side,
+    /// Whether a connection using the item is broken.
+    broken: bool,
+    differences: &'a mut Vec<Difference>,
+}
+
+impl Item<'_> {
+    /// Records a difference to the item.
+    fn push(&mut self, item: String, kind: DifferenceKind, description: String) {
+        self.differences.push(Difference {
+            item,
+            kind,
+            description,
+            breaking: self.broken && self.side.can_break(kind),
+        });
+    }
+
+    /// Compares the old and new definitions of the item.
+    fn compare(
+        &mut self,
+        name: &str,
+        old: &World,
+        old_item: &WorldItem,
+        new: &World,
+        new_item: &WorldItem,
+    ) {
+        match (old_item, new_item) {
+            (WorldItem::Interface { id: old_id, .. }, WorldItem::Interface { id: new_id, .. }) => {
+                let old_interface = &old.resolve.interfaces[*old_id];
+                let new_interface = &new.resolve.interfaces[*new_id];
+
+                for (member, id) in &old_interface.types {
+                    let path = format!("{name}#{member}");
+                    match new_interface.types.get(member) {
+                        Some(new_id) => self.compare_type(path, old, *id, new, *new_id),
+                        None => self.push(
+                            path,
+                            DifferenceKind::Removed,
+                            format!("type `{member}` was removed"),
+                        ),
+                    }
+                }
+
+                for member in new_interface.types.keys() {
+                    if !old_interface.types.contains_key(member) {
+                        self.push(
+                            format!("{name}#{member}"),
+                            DifferenceKind::Added,
+                            format!("type `{member}` was added"),
+                        );
+                    }
+                }
+
+                let old_functions = old_interface
+                    .functions
+                    .values()
+                    .map(|f| (signature::function_name(&old.resolve, f), f))
+                    .collect::<Vec<_>>();
+                let new_functions = new_interface
+                    .functions
+                    .values()
+                    .map(|f| (signature::function_name(&new.resolve, f), f))
+                    .collect::<Vec<_>>();
+
+                for (member, f) in &old_functions {
+                    let path = format!("{name}#{member}");
+                    match new_functions.iter().find(|(n, _)| n == member) {
+                        Some((_, new_f)) => self.compare_function(path, old, f, new, new_f),
+                        None => self.push(
+                            path,
+                            DifferenceKind::Removed,
+                            format!("{} was removed", describe(&old.resolve, f)),
+                        ),
+                    }
+                }
+
+                for (member, f) in &new_functions {
+                    if !old_functions.iter().any(|(n, _)| n == member) {
+                        self.push(
+                            format!("{name}#{member}"),
+                            DifferenceKind::Added,
+                            format!("{} was added", describe(&new.resolve, f)),
+                        );
+                    }
+                }
+            }
+            (WorldItem::Function(old_f), WorldItem::Function(new_f)) => {
+                self.compare_function(name.to_string(), old, old_f, new, new_f)
+            }
+            (WorldItem::Type(old_id), WorldItem::Type(new_id)) => {
+                self.compare_type(name.to_string(), old, *old_id, new, *new_id)
+            }
+            _ => {
+                let side = self.side;
+                self.push(
+                    name.to_string(),
+                    DifferenceKind::Changed,
+                    format!(
+                        "{side} `{name}` changed from {old} to {new}",
+                        old = kind(old_item),
+                        new = kind(new_item)
+                    ),
+                )
+            }
+        }
+    }
+
+    /// Records a difference to a type used by the item.
+    ///
+    /// Unlike functions, the fields of a record or cases of a variant cannot
+    /// be added or removed on either side of a connection.
+    fn push_type(&mut self, item: String, kind: DifferenceKind, description: String) {
+        self.differences.push(Difference {
+            item,
+            kind,
+            description,
+            breaking: self.broken,
+        });
+    }
+
+    /// Compares the old and new signatures of a function.
+    fn compare_function(
+        &mut self,
+        path: String,
+        old: &World,
+        old_f: &Function,
+        new: &World,
+        new_f: &Function,
+    ) {
+        let old_signature = signature::function(&old.resolve, old_f);
+        let new_signature = signature::function(&new.resolve, new_f);
+        if old_signature != new_signature {
+            self.push(
+                path,
+                DifferenceKind::Changed,
+                format!(
+                    "{f} changed from `{old_signature}` to `{new_signature}`",
+                    f = describe(&old.resolve, old_f)
+                ),
+            );
+        }
+    }
+
+    /// Compares the old and new definitions of a type.
+    ///
+    /// Records are compared field by field.
+    fn compare_type(
+        &mut self,
+        path: String,
+        old: &World,
+        old_id: TypeId,
+        new: &World,
+        new_id: TypeId,
+    ) {
+        let old_def = signature::type_def(&old.resolve, old_id);
+        let new_def = signature::type_def(&new.resolve, new_id);
+        if old_def == new_def {
+            return;
+        }
+
+        let start = self.differences.len();
+        if let (TypeDefKind::Record(old_record), TypeDefKind::Record(new_record)) = (
+            &old.resolve.types[old_id].kind,
+            &new.resolve.types[new_id].kind,
+        ) {
+            let record = signature::type_name(&old.resolve, old_id);
+            for field in &old_record.fields {
+                let field_path = format!("{path}.{name}", name = field.name);
+                match new_record.fields.iter().find(|f| f.name == field.name) {
+                    Some(new_field) => {
+                        let old_ty = signature::ty(&old.resolve, &field.ty);
+                        let new_ty = signature::ty(&new.resolve, &new_field.ty);
+                        if old_ty != new_ty {
+                            self.push_type(
+                                field_path,
+                                DifferenceKind::Changed,
+                                format!(
+                                    "field `{name}` of record `{record}` changed from \
+                                     `{old_ty}` to `{new_ty}`",
+                                    name = field.name
+                                ),
+                            );
+                        }
+                    }
+                    None => self.push_type(
+                        field_path,
+                        DifferenceKind::Removed,
+                        format!(
+                            "field `{name}` was removed from record `{record}`",
+                            name = field.name
+                        ),
+                    ),
+                }
+            }
+
+            for field in &new_record.fields {
+                if !old_record.fields.iter().any(|f| f.name == field.name) {
+                    self.push_type(
+                        format!("{path}.{name}", name = field.name),
+                        DifferenceKind::Added,
+                        format!(
+                            "field `{name}` was added to record `{record}`",
+                            name = field.name
+                        ),
+                    );
+                }
+            }
+        }
+
+        // Types that differ in other ways, such as the order of record fields,
+        // are reported as a whole
+        if self.differences.len() == start {
+            self.push_type(
+                path,
+                DifferenceKind::Changed,
+                format!("`{old_def}` changed to `{new_def}`"),
+            );
+        }
+    }
+}
+
+/// Describes a function for a difference.
+fn describe(resolve: &Resolve, f: &Function) -> String {
+    match f.kind {
+        FunctionKind::Freestanding | FunctionKind::AsyncFreestanding => {
+            format!("function `{name}`", name = f.name)
+        }
+        FunctionKind::Method(id) | FunctionKind::AsyncMethod(id) => format!(
+            "method `{name}` of resource `{resource}`",
+            name = f.item_name(),
+            resource = signature::type_name(resolve, id)
+        ),
+        FunctionKind::Static(id) | FunctionKind::AsyncStatic(id) => format!(
+            "static function `{name}` of resource `{resource}`",
+            name = f.item_name(),
+            resource = signature::type_name(resolve, id)
+        ),
+        FunctionKind::Constructor(id) => format!(
+            "constructor of resource `{resource}`",
+            resource = signature::type_name(resolve, id)
+        ),
+    }
+}
+
+/// Describes the kind of a world item.
+fn kind(item: &WorldItem) -> &'static str {
+    match item {
+        WorldItem::Interface { .. } => "an interface",
+        WorldItem::Function(_) => "a function",
+        WorldItem::Type(_) => "a type",
+    }
+}
@@ -0,0 +1,180 @@
+//! Module for disassembling components into the WebAssembly text format.
+//!
+//! Text is printed by `wasmprinter` into a sink that elides the contents of
+//! core modules when only the component-level structure is wanted, and that
+//! stops printing once the text reaches its maximum length.
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::{io, mem};
+use wasmparser::BinaryReaderError;
+use wasmprinter::{Config, Print};
+
+/// The default maximum length of disassembled text, in bytes.
+pub const DEFAULT_MAX_LENGTH: u32 = 256 * 1024;
+
+/// Represents options for disassembling a component.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Options {
+    /// Whether to print instructions in folded form.
+    pub fold_expressions: bool,
+    /// Whether to skip function bodies and the contents of data and element segments.
+    pub skip_function_bodies: bool,
+    /// Whether to print only the component-level structure.
+    ///
+    /// The contents of core modules are elided.
+    pub structure_only: bool,
+    /// The maximum length of the text in bytes; defaults to [`DEFAULT_MAX_LENGTH`].
+    ///
+    /// The length includes the note appended to truncated text.
+    pub max_length: Option<u32>,
+}
+
+/// Represents the text format of a component.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Wat {
+    /// The text of the component.
+    pub text: String,
+    /// Whether the text was truncated at the maximum length.
+    pub truncated: bool,
+}
+
+/// Disassembles a component into the WebAssembly text format.
+pub fn disassemble(bytes: &[u8], options: &Options) -> Result<Wat> {
+    let max_length = options.max_length.unwrap_or(DEFAULT_MAX_LENGTH) as usize;
+    let note = format!(";; the text was truncated at {max_length} bytes\n");
+    let mut output = Output {
+        text: String::new(),
+        line: String::new(),
+        // Room is left for the note in case the text is truncated
+        max_length: max_length.saturating_sub(note.len()),
+        truncated: false,
+        structure_only: options.structure_only,
+        elided: None,
+    };
+
+    // The bodies of elided modules need not be printed
+    let mut config = Config::new();
+    config
+        .fold_instructions(options.fold_expressions)
+        .print_skeleton(options.skip_function_bodies || options.structure_only);
+
+    let result = config
+        .print(bytes, &mut output)
+        .and_then(|()| Ok(output.finish()?));
+    if let Err(e) = result {
+        if !output.truncated {
+            return Err(Error::Parse {
+                message: format!("failed to print the component: {e:#}"),
+                offset: e
+                    .downcast_ref::<BinaryReaderError>()
+                    .map(|e| e.offset() as u64),
+            });
+        }
+
+        // The note is omitted if even it does not fit
+        if note.len() <= max_length {
+            output.text.push_str(&note);
+        }
+    }
+
+    Ok(Wat {
+        text: output.text,
+        truncated: output.truncated,
+    })
+}
+
+/// Represents the sink of the printed text.
+///
+/// Text is collected a line at a time so that the lines of core modules can be
+/// elided by their indentation.
+struct Output {
+    text: String,
+    line: String,
+    max_length: usize,
+    truncated: bool,
+    structure_only: bool,
+    /// The indentation of the core module whose contents are being elided.
+    elided: Option<usize>,
+}
+
+impl Output {
+    /// Finishes the text, appending the last line if it was not ended.
+    fn finish(&mut self) -> io::Result<()> {
+        if self.line.is_empty() {
+            return Ok(());
+        }
+
+        self.finish_line()
+    }
+
+    /// Finishes the current line, appending it to the text.
+    fn finish_line(&mut self) -> io::Result<()> {
+        let line = mem::take(&mut self.line);
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+
+        if self.structure_only {
+            match self.elided {
+                Some(depth) if indent > depth => return Ok(()),
+                Some(depth) if indent == depth && trimmed == ")" => self.elided = None,
+                _ => {
+                    // Modules that are not closed on the same line have contents to elide
+                    if trimmed.starts_with("(core module")
+                        && trimmed.matches('(').count() > trimmed.matches(')').count()
+                    {
+                        self.push(&line)?;
+                        self.elided = Some(indent);
+                        return self.push(&format!(
+                            "{:indent$};; the contents of the module are elided",
+                            "",
+                            indent = indent + 2
+                        ));
+                    }
+                }
+            }
+        }
+
+        self.push(&line)
+    }
+
+    /// Pushes a line onto the text, stopping if the maximum length is reached.
+    fn push(&mut self, line: &str) -> io::Result<()> {
+        if self.text.len() + line.len() + 1 > self.max_length {
+            return Err(self.truncate());
+        }
+
+        self.text.push_str(line);
+        self.text.push('\n');
+        Ok(())
+    }
+
+    /// Marks the text as truncated, returning the error that stops printing.
+    fn truncate(&mut self) -> io::Error {
+        self.truncated = true;
+        io::Error::other("the maximum length was reached")
+    }
+}
+
+impl Print for Output {
+    fn write_str(&mut self, s: &str) -> io::Result<()> {
+        // Only the start of a line in an elided module is needed to find its end
+        if let Some(depth) = self.elided {
+            if self.line.len() > depth {
+                return Ok(());
+            }
+        }
+
+        self.line.push_str(s);
+        if self.text.len() + self.line.len() > self.max_length {
+            return Err(self.truncate());
+        }
+
+        Ok(())
+    }
+
+    fn newline(&mut self) -> io::Result<()> {
+        self.finish_line()
+    }
+}
@@ -0,0 +1,84 @@
+//! Module for graph errors.
+use crate::state::{ComponentId, InstanceId};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Represents an error from the graph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Error {
+    /// The component bytes could not be parsed.
+    Parse {
+        /// The error message.
+        message: String,
+        /// The byte offset of the error, if known.
+        offset: Option<u64>,
+    },
+    /// The component failed validation.
+    Validation {
+        /// The error message.
+        message: String,
+        /// The byte offset of the error, if known.
+        offset: Option<u64>,
+    },
+    /// The source of a connection is not compatible with the target import.
+    TypeMismatch {
+        /// The name of the source export; `None` if the source instance itself was used.
+        export: Option<String>,
+        /// The name of the target import.
+        import: String,
+        /// The error message.
+        message: String,
+    },
+    /// A connection would form a cycle in the instantiation graph.
+    Cycle {
+        /// The instances forming the cycle, in instantiation order.
+        instances: Vec<InstanceId>,
+        /// The error message.
+        message: String,
+    },
+    /// The component does not exist in the graph.
+    UnknownComponent(ComponentId),
+    /// The instance does not exist in the graph.
+    UnknownInstance(InstanceId),
+    /// The graph could not be encoded.
+    Encode(String),
+    /// The operation is not valid for the current graph.
+    InvalidOperation(String),
+    /// The project file is not valid.
+    InvalidProject(String),
+    /// The WAC document is not valid.
+    Wac {
+        /// The error message.
+        message: String,
+        /// The line of the error, starting at 1.
+        line: u32,
+        /// The column of the error, starting at 1.
+        column: u32,
+    },
+    /// The WIT document is not valid.
+    Wit(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse { message, .. }
+            | Self::Validation { message, .. }
+            | Self::TypeMismatch { message, .. }
+            | Self::Cycle { message, .. }
+            | Self::Wac { message, .. }
+            | Self::Encode(message)
+            | Self::InvalidOperation(message)
+            | Self::InvalidProject(message)
+            | Self::Wit(message) => write!(f, "{message}"),
+            Self::UnknownComponent(id) => write!(f, "component {id} does not exist in the graph"),
+            Self::UnknownInstance(id) => write!(f, "instance {id} does not exist in the graph"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The result type for graph operations.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -0,0 +1,349 @@
+//! Module for the undo/redo history of a composition graph.
+use crate::{
+    error::Result,
+    state::{ComponentId, Connection, InstanceId, State},
+};
+use semver::Version;
+
+/// Represents a change made to the graph by undoing or redoing an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    /// A component was added to the graph.
+    AddComponent(ComponentId),
+    /// A component was removed from the graph, along with its instances.
+    RemoveComponent(ComponentId),
+    /// An instance was added to the graph.
+    AddInstance(InstanceId, ComponentId),
+    /// An instance was removed from the graph, along with its connections.
+    RemoveInstance(InstanceId),
+    /// An instance was renamed.
+    RenameInstance(InstanceId),
+    /// Two instances were connected.
+    Connect(Connection),
+    /// Two instances were disconnected.
+    Disconnect(Connection),
+}
+
+/// Represents a reversible operation performed on the graph.
+#[derive(Debug)]
+pub enum Operation {
+    /// A component was added to the graph.
+    ///
+    /// The component is only captured when the operation is reverted, so the
+    /// history does not keep a copy of every component in the graph.
+    AddComponent {
+        id: ComponentId,
+        removed: Option<RemovedComponent>,
+    },
+    /// A component was removed from the graph.
+    RemoveComponent {
+        id: ComponentId,
+        name: String,
+        version: Option<Version>,
+        bytes: Vec<u8>,
+        instances: Vec<(InstanceId, Option<String>)>,
+        connections: Vec<Connection>,
+    },
+    /// A component was instantiated.
+    Instantiate {
+        id: InstanceId,
+        component: ComponentId,
+    },
+    /// An instance was removed from the graph.
+    RemoveInstance {
+        id: InstanceId,
+        component: ComponentId,
+        name: Option<String>,
+        connections: Vec<Connection>,
+    },
+    /// An instance was renamed.
+    RenameInstance {
+        id: InstanceId,
+        from: Option<String>,
+        to: Option<String>,
+    },
+    /// Two instances were connected.
+    Connect(Connection),
+    /// Two instances were disconnected.
+    Disconnect(Connection),
+    /// Multiple operations performed as one.
+    Batch(Vec<Operation>),
+}
+
+impl Operation {
+    /// Creates an operation for adding the given component.
+    ///
+    /// The component must exist in the graph.
+    pub fn add_component(id: ComponentId) -> Self {
+        Self::AddComponent { id, removed: None }
+    }
+
+    /// Creates an operation for removing the given component.
+    ///
+    /// This must be called prior to removing the component from the graph.
+    ///
+    /// Returns `None` if the component does not exist.
+    pub fn remove_component(state: &State, id: ComponentId) -> Option<Self> {
+        let component = state.component(id)?;
+        let instances = state
+            .instances()
+            .filter(|(_, c)| *c == id)
+            .map(|(i, _)| (i, state.instance_name(i).unwrap().map(ToString::to_string)))
+            .collect::<Vec<_>>();
+        let removed = |id| instances.iter().any(|(i, _)| *i == id);
+
+        Some(Self::RemoveComponent {
+            id,
+            name: component.name().to_string(),
+            version: component.version().cloned(),
+            bytes: component.bytes().to_vec(),
+            connections: state
+                .connections()
+                .iter()
+                .filter(|c| removed(c.source) || removed(c.target))
+                .copied()
+                .collect(),
+            instances,
+        })
+    }
+
+    /// Creates an operation for removing the given instance.
+    ///
+    /// This must be called prior to removing the instance from the graph.
+    ///
+    /// Returns `None` if the instance does not exist.
+    pub fn remove_instance(state: &State, id: InstanceId) -> Option<Self> {
+        let (_, component) = state.instances().find(|(i, _)| *i == id)?;
+        Some(Self::RemoveInstance {
+            id,
+            component,
+            name: state.instance_name(id).unwrap().map(ToString::to_string),
+            connections: state
+                .connections()
+                .iter()
+                .filter(|c| c.source == id || c.target == id)
+                .copied()
+                .collect(),
+        })
+    }
+
+    /// Applies the operation to the graph.
+    fn apply(&mut self, state: &mut State) -> Result<Vec<Change>> {
+        match self {
+            Self::AddComponent { id, removed } => {
+                // The component is captured again if the operation is reverted
+                let RemovedComponent {
+                    name,
+                    version,
+                    bytes,
+                } = removed.take().expect("the operation was reverted");
+                state.insert_component(*id, name, version, bytes)?;
+                Ok(vec![Change::AddComponent(*id)])
+            }
+            Self::RemoveComponent { id, .. } => {
+                state.remove_component(*id);
+                Ok(vec![Change::RemoveComponent(*id)])
+            }
+            Self::Instantiate { id, component } => {
+                state.insert_instance(*id, *component)?;
+                Ok(vec![Change::AddInstance(*id, *component)])
+            }
+            Self::RemoveInstance { id, .. } => {
+                state.remove_instance(*id);
+                Ok(vec![Change::RemoveInstance(*id)])
+            }
+            Self::RenameInstance { id, to, .. } => {
+                state.set_instance_name(*id, to.clone())?;
+                Ok(vec![Change::RenameInstance(*id)])
+            }
+            Self::Connect(connection) => {
+                state.connect(*connection)?;
+                Ok(vec![Change::Connect(*connection)])
+            }
+            Self::Disconnect(connection) => {
+                state.disconnect(
+                    connection.source,
+                    connection.target,
+                    connection.target_import,
+                )?;
+                Ok(vec![Change::Disconnect(*connection)])
+            }
+            Self::Batch(operations) => {
+                let mut changes = Vec::new();
+                for operation in operations.iter_mut() {
+                    changes.extend(operation.apply(state)?);
+                }
+                Ok(changes)
+            }
+        }
+    }
+
+    /// Reverts the operation on the graph.
+    fn revert(&mut self, state: &mut State) -> Result<Vec<Change>> {
+        match self {
+            Self::AddComponent { id, removed } => {
+                let component = state.component(*id).unwrap();
+                *removed = Some(RemovedComponent {
+                    name: component.name().to_string(),
+                    version: component.version().cloned(),
+                    bytes: component.bytes().to_vec(),
+                });
+                state.remove_component(*id);
+                Ok(vec![Change::RemoveComponent(*id)])
+            }
+            Self::RemoveComponent {
+                id,
+                name,
+                version,
+                bytes,
+                instances,
+                connections,
+            } => {
+                state.insert_component(*id, name.clone(), version.clone(), bytes.clone())?;
+
+                let mut changes = vec![Change::AddComponent(*id)];
+                for (instance, name) in instances {
+                    state.insert_instance(*instance, *id)?;
+                    state.set_instance_name(*instance, name.clone())?;
+                    changes.push(Change::AddInstance(*instance, *id));
+                }
+
+                changes.extend(restore_connections(state, connections)?);
+                Ok(changes)
+            }
+            Self::Instantiate { id, .. } => {
+                state.remove_instance(*id);
+                Ok(vec![Change::RemoveInstance(*id)])
+            }
+            Self::RemoveInstance {
+                id,
+                component,
+                name,
+                connections,
+            } => {
+                state.insert_instance(*id, *component)?;
+                state.set_instance_name(*id, name.clone())?;
+
+                let mut changes = vec![Change::AddInstance(*id, *component)];
+                changes.extend(restore_connections(state, connections)?);
+                Ok(changes)
+            }
+            Self::RenameInstance { id, from, .. } => {
+                state.set_instance_name(*id, from.clone())?;
+                Ok(vec![Change::RenameInstance(*id)])
+            }
+            Self::Connect(connection) => {
+                state.disconnect(
+                    connection.source,
+                    connection.target,
+                    connection.target_import,
+                )?;
+                Ok(vec![Change::Disconnect(*connection)])
+            }
+            Self::Disconnect(connection) => {
+                state.connect(*connection)?;
+                Ok(vec![Change::Connect(*connection)])
+            }
+            Self::Batch(operations) => {
+                let mut changes = Vec::new();
+                for operation in operations.iter_mut().rev() {
+                    changes.extend(operation.revert(state)?);
+                }
+                Ok(changes)
+            }
+        }
+    }
+}
+
+/// Represents a component captured when its addition was undone.
+#[derive(Debug)]
+pub struct RemovedComponent {
+    name: String,
+    version: Option<Version>,
+    bytes: Vec<u8>,
+}
+
+fn restore_connections(state: &mut State, connections: &[Connection]) -> Result<Vec<Change>> {
+    connections
+        .iter()
+        .map(|c| {
+            state.connect(*c)?;
+            Ok(Change::Connect(*c))
+        })
+        .collect()
+}
+
+/// Represents the undo/redo history of a composition graph.
+#[derive(Debug, Default)]
+pub struct History {
+    undo: Vec<Operation>,
+    redo: Vec<Operation>,
+}
+
+impl History {
+    /// Records an operation that was performed on the graph.
+    ///
+    /// Recording an operation clears the redo history.
+    pub fn record(&mut self, operation: Operation) {
+        self.undo.push(operation);
+        self.redo.clear();
+    }
+
+    /// Clears the history.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    /// Determines if there is an operation to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Determines if there is an operation to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Undoes the last operation performed on the graph.
+    ///
+    /// Returns the changes made to the graph.
+    pub fn undo(&mut self, state: &mut State) -> Result<Vec<Change>> {
+        let Some(mut operation) = self.undo.pop() else {
+            return Ok(Vec::new());
+        };
+
+        match operation.revert(state) {
+            Ok(changes) => {
+                self.redo.push(operation);
+                Ok(changes)
+            }
+            Err(e) => {
+                // The graph may be partially reverted, so the history can no longer be trusted
+                self.clear();
+                Err(e)
+            }
+        }
+    }
+
+    /// Redoes the last undone operation on the graph.
+    ///
+    /// Returns the changes made to the graph.
+    pub fn redo(&mut self, state: &mut State) -> Result<Vec<Change>> {
+        let Some(mut operation) = self.redo.pop() else {
+            return Ok(Vec::new());
+        };
+
+        match operation.apply(state) {
+            Ok(changes) => {
+                self.undo.push(operation);
+                Ok(changes)
+            }
+            Err(e) => {
+                // The graph may be partially applied, so the history can no longer be trusted
+                self.clear();
+                Err(e)
+            }
+        }
+    }
+}
@@ -0,0 +1,419 @@
+//! The composition graph behind wasmbuilder.app.
+//!
+//! [`Graph`] combines the state of a composition graph with its undo/redo
+//! history. It is used natively by the command line interface and is wrapped
+//! by the `graph` component used by the application.
+pub mod audit;
+pub mod componentize;
+pub mod config;
+mod deny;
+pub mod diagram;
+pub mod diff;
+pub mod disassemble;
+pub mod error;
+mod history;
+pub mod metadata;
+pub mod model;
+pub mod preview;
+pub mod project;
+pub mod signature;
+pub mod state;
+pub mod stub;
+pub mod wac;
+
+pub use error::{Error, Result};
+
+use history::{History, Operation};
+use model::{AuditReport, Change, Component, EncodeOptions, Snapshot};
+use state::{
+    AddOptions, Alias, Analysis, AutoConnect, ComponentId, Connection, Duplicates, InstanceId,
+    State,
+};
+
+/// Represents a composition graph with an undo/redo history.
+#[derive(Debug, Default)]
+pub struct Graph {
+    state: State,
+    history: History,
+}
+
+impl Graph {
+    /// Creates a new, empty graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the state of the graph.
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Gets the record of a component in the graph.
+    pub fn component(&self, id: ComponentId) -> Result<Component> {
+        self.state
+            .component(id)
+            .map(|c| Component::new(id, c))
+            .ok_or(Error::UnknownComponent(id))
+    }
+
+    /// Gets a snapshot of the components, instances and connections of the graph.
+    pub fn snapshot(&self, export: Option<InstanceId>) -> Snapshot {
+        Snapshot::new(&self.state, export)
+    }
+
+    /// Adds a component to the graph.
+    pub fn add_component(
+        &mut self,
+        name: String,
+        bytes: Vec<u8>,
+        options: AddOptions,
+    ) -> Result<Component> {
+        self.register_package(name, None, bytes, options)
+    }
+
+    /// Adds a component to the graph as a package with an optional version.
+    pub fn register_package(
+        &mut self,
+        name: String,
+        version: Option<&str>,
+        bytes: Vec<u8>,
+        options: AddOptions,
+    ) -> Result<Component> {
+        let version = version.map(state::parse_version).transpose()?;
+        let (id, added) = self.state.add_component(name, version, bytes, options)?;
+
+        if added {
+            self.history.record(Operation::add_component(id));
+        }
+
+        Ok(Component::new(id, self.state.component(id).unwrap()))
+    }
+
+    /// Adds a stub component generated from a world of a WIT document.
+    pub fn add_stub_component(
+        &mut self,
+        name: String,
+        wit: &str,
+        world: &str,
+        behavior: stub::Behavior,
+    ) -> Result<Component> {
+        let bytes = stub::generate(wit, world, behavior)?;
+        self.add_generated(name, bytes)
+    }
+
+    /// Adds a configuration component that provides the given values.
+    ///
+    /// See [`config::generate`] for the versions of the exported interfaces.
+    pub fn add_config_component(
+        &mut self,
+        name: String,
+        values: &config::Values,
+    ) -> Result<Component> {
+        let bytes = config::generate(values)?;
+        self.add_generated(name, bytes)
+    }
+
+    /// Adds a deny component that stubs out the given interfaces.
+    ///
+    /// The types of the interfaces come from the components in the graph.
+    pub fn add_deny_component(
+        &mut self,
+        name: String,
+        interfaces: &[String],
+        behavior: stub::Behavior,
+    ) -> Result<Component> {
+        let bytes = deny::generate(
+            interfaces,
+            self.state.components().map(|(_, c)| c.bytes()),
+            behavior,
+        )?;
+        self.add_generated(name, bytes)
+    }
+
+    /// Finds a component registered as a package with the given name and version.
+    pub fn find_package(&self, name: &str, version: Option<&str>) -> Result<Option<ComponentId>> {
+        let version = version.map(state::parse_version).transpose()?;
+        Ok(self.state.find_component(name, version.as_ref()))
+    }
+
+    /// Instantiates a component in the graph.
+    pub fn instantiate_component(&mut self, id: ComponentId) -> Result<InstanceId> {
+        let instance = self.state.instantiate(id)?;
+        self.history.record(Operation::Instantiate {
+            id: instance,
+            component: id,
+        });
+
+        Ok(instance)
+    }
+
+    /// Connects an export of a source instance to an import of a target instance.
+    ///
+    /// If `source_export` is `None`, the source instance itself is connected.
+    pub fn connect_instances(
+        &mut self,
+        source: InstanceId,
+        source_export: Option<u32>,
+        target: InstanceId,
+        target_import: u32,
+    ) -> Result<()> {
+        let connection = Connection {
+            source,
+            source_export,
+            target,
+            target_import,
+        };
+
+        self.state.connect(connection)?;
+        self.history.record(Operation::Connect(connection));
+        Ok(())
+    }
+
+    /// Gets the connections that could be made to an import of a target instance.
+    pub fn compatible_sources(
+        &self,
+        target: InstanceId,
+        target_import: u32,
+    ) -> Result<Vec<Connection>> {
+        self.state.compatible_sources(target, target_import)
+    }
+
+    /// Gets the connections that could be made from an export of a source instance.
+    pub fn compatible_targets(
+        &self,
+        source: InstanceId,
+        source_export: Option<u32>,
+    ) -> Result<Vec<Connection>> {
+        self.state.compatible_targets(source, source_export)
+    }
+
+    /// Connects the imports of an instance to the exports of matching name.
+    pub fn auto_connect(&mut self, target: InstanceId) -> Result<AutoConnect> {
+        let result = self.state.auto_connect(target)?;
+
+        if !result.connections.is_empty() {
+            self.history.record(Operation::Batch(
+                result
+                    .connections
+                    .iter()
+                    .copied()
+                    .map(Operation::Connect)
+                    .collect(),
+            ));
+        }
+
+        Ok(result)
+    }
+
+    /// Sets the name of an instance in the encoded graph.
+    pub fn set_instance_name(&mut self, id: InstanceId, name: Option<String>) -> Result<()> {
+        let from = self.state.set_instance_name(id, name.clone())?;
+
+        if from != name {
+            self.history
+                .record(Operation::RenameInstance { id, from, to: name });
+        }
+
+        Ok(())
+    }
+
+    /// Gets the aliases of instance exports in the graph.
+    pub fn aliases(&self) -> Vec<Alias> {
+        self.state.aliases()
+    }
+
+    /// Removes a component from the graph, along with its instances.
+    pub fn remove_component(&mut self, id: ComponentId) {
+        if let Some(operation) = Operation::remove_component(&self.state, id) {
+            self.state.remove_component(id);
+            self.history.record(operation);
+        }
+    }
+
+    /// Removes an instance from the graph, along with its connections.
+    pub fn remove_instance(&mut self, id: InstanceId) {
+        if let Some(operation) = Operation::remove_instance(&self.state, id) {
+            self.state.remove_instance(id);
+            self.history.record(operation);
+        }
+    }
+
+    /// Disconnects an import of a target instance from a source instance.
+    pub fn disconnect_instances(
+        &mut self,
+        source: InstanceId,
+        target: InstanceId,
+        target_import: u32,
+    ) -> Result<()> {
+        let connection =
+            self.state.connections().iter().copied().find(|c| {
+                c.source == source && c.target == target && c.target_import == target_import
+            });
+
+        self.state.disconnect(source, target, target_import)?;
+
+        if let Some(connection) = connection {
+            self.history.record(Operation::Disconnect(connection));
+        }
+
+        Ok(())
+    }
+
+    /// Undoes the last operation performed on the graph.
+    pub fn undo(&mut self) -> Result<Vec<Change>> {
+        let changes = self.history.undo(&mut self.state)?;
+        Ok(changes
+            .into_iter()
+            .map(|c| Change::new(&self.state, c))
+            .collect())
+    }
+
+    /// Redoes the last undone operation.
+    pub fn redo(&mut self) -> Result<Vec<Change>> {
+        let changes = self.history.redo(&mut self.state)?;
+        Ok(changes
+            .into_iter()
+            .map(|c| Change::new(&self.state, c))
+            .collect())
+    }
+
+    /// Determines if there is an operation to undo.
+    pub fn can_undo(&self) -> bool {
+        self.history.can_undo()
+    }
+
+    /// Determines if there is an operation to redo.
+    pub fn can_redo(&self) -> bool {
+        self.history.can_redo()
+    }
+
+    /// Renders the instances and connections of the graph as a diagram.
+    pub fn print_graph(&self, format: diagram::Format) -> String {
+        diagram::render(&self.state, format)
+    }
+
+    /// Analyzes what the encoded graph will import and export.
+    pub fn analyze(&self, options: &EncodeOptions) -> Result<Analysis> {
+        let exports = options
+            .export
+            .into_iter()
+            .chain(options.exports.iter().map(|e| e.instance))
+            .collect::<Vec<_>>();
+
+        self.state.analyze(&exports)
+    }
+
+    /// Audits the host capabilities reachable from every instance in the graph.
+    pub fn audit(&self) -> AuditReport {
+        let instances = audit::audit(&self.state);
+        AuditReport {
+            markdown: audit::markdown(&self.state, &instances),
+            instances,
+        }
+    }
+
+    /// Compares the world of component `a` with the world of component `b`.
+    ///
+    /// Differences are breaking if they break a connection of an instance of
+    /// component `a` were it replaced by component `b`.
+    pub fn diff_components(&self, a: ComponentId, b: ComponentId) -> Result<diff::ComponentDiff> {
+        diff::diff(&self.state, a, b)
+    }
+
+    /// Previews the WIT world of the graph encoded with the given options.
+    ///
+    /// Each import and export is attributed to the instances it comes from.
+    pub fn preview_world(&self, options: &EncodeOptions) -> Result<preview::WorldPreview> {
+        preview::preview(&self.state, options.export, &options.exports)
+    }
+
+    /// Disassembles a component into the WebAssembly text format.
+    ///
+    /// If `id` is `None`, the graph encoded with the given options is disassembled.
+    pub fn component_wat(
+        &self,
+        id: Option<ComponentId>,
+        encode: &EncodeOptions,
+        options: &disassemble::Options,
+    ) -> Result<disassemble::Wat> {
+        match id {
+            Some(id) => {
+                let component = self
+                    .state
+                    .component(id)
+                    .ok_or(Error::UnknownComponent(id))?;
+                disassemble::disassemble(component.bytes(), options)
+            }
+            None => disassemble::disassemble(&self.encode_graph(encode)?, options),
+        }
+    }
+
+    /// Encodes the graph as a new component.
+    pub fn encode_graph(&self, options: &EncodeOptions) -> Result<Vec<u8>> {
+        self.state.encode(
+            options.define_components,
+            options.export,
+            &options.exports,
+            options.validate,
+        )
+    }
+
+    /// Prints the graph as a WAC document for the given package.
+    pub fn emit_wac(&self, package: &str, options: &EncodeOptions) -> Result<String> {
+        wac::emit(&self.state, package, options.export, &options.exports)
+    }
+
+    /// Saves the graph as a project file.
+    ///
+    /// Only the exports of the given options are saved.
+    pub fn save_project(&self, options: &EncodeOptions) -> Result<Vec<u8>> {
+        self.state
+            .to_project(options.export, &options.exports)?
+            .to_bytes()
+    }
+
+    /// Replaces the graph with the contents of a project file.
+    pub fn load_project(&mut self, bytes: &[u8]) -> Result<Snapshot> {
+        let project = project::Project::from_bytes(bytes)?;
+        let export = project.export;
+        let exports = project.exports.clone();
+        let state = State::from_project(project)?;
+        Ok(Snapshot {
+            exports,
+            ..self.replace_state(state, export)
+        })
+    }
+
+    /// Replaces the graph with the composition of a WAC document.
+    ///
+    /// The packages referenced by the document are given by name.
+    pub fn load_wac(&mut self, source: &str, packages: Vec<(String, Vec<u8>)>) -> Result<Snapshot> {
+        let (state, export, exports) = wac::load(source, packages)?;
+        Ok(Snapshot {
+            exports,
+            ..self.replace_state(state, export)
+        })
+    }
+
+    /// Adds a generated component to the graph.
+    fn add_generated(&mut self, name: String, bytes: Vec<u8>) -> Result<Component> {
+        self.register_package(
+            name,
+            None,
+            bytes,
+            AddOptions {
+                duplicates: Duplicates::Allow,
+                adapter: None,
+            },
+        )
+    }
+
+    /// Replaces the current graph state, clearing the history.
+    ///
+    /// Returns a snapshot of the new state.
+    fn replace_state(&mut self, state: State, export: Option<InstanceId>) -> Snapshot {
+        self.state = state;
+        self.history.clear();
+        self.snapshot(export)
+    }
+}
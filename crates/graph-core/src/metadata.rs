@@ -0,0 +1,135 @@
+//! Module for reading the metadata of a component.
+//!
+//! Metadata is read from the `producers` and `component-name` custom sections
+//! and from either the OCI annotation sections (`authors`, `licenses`, etc.) or
+//! the older `registry-metadata` section.
+use serde::{Deserialize, Serialize};
+use wasmparser::{Parser, Payload};
+
+/// Represents a tool or language recorded in the `producers` section.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Producer {
+    /// The producers field, such as `language`, `processed-by` or `sdk`.
+    pub field: String,
+    /// The name of the tool or language.
+    pub name: String,
+    /// The version of the tool or language.
+    pub version: String,
+}
+
+/// Represents the metadata of a component.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    /// The name of the component from its name section.
+    pub name: Option<String>,
+    /// The tools and languages that produced the component.
+    pub producers: Vec<Producer>,
+    /// The authors of the component.
+    pub authors: Option<String>,
+    /// The description of the component.
+    pub description: Option<String>,
+    /// The licenses of the component as an SPDX expression.
+    pub licenses: Option<String>,
+    /// The URL of the source code of the component.
+    pub source: Option<String>,
+    /// The URL of the homepage of the component.
+    pub homepage: Option<String>,
+    /// The source control revision of the component.
+    pub revision: Option<String>,
+    /// The version of the component.
+    pub version: Option<String>,
+}
+
+/// Represents the contents of a `registry-metadata` custom section.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct RegistryMetadata {
+    authors: Option<Vec<String>>,
+    description: Option<String>,
+    license: Option<String>,
+    links: Option<Vec<Link>>,
+}
+
+/// Represents a link in a `registry-metadata` custom section.
+#[derive(Debug, Deserialize)]
+struct Link {
+    ty: String,
+    value: String,
+}
+
+/// Reads the metadata of a component.
+///
+/// Only the metadata of the outermost component is read; metadata that cannot
+/// be read is omitted.
+pub fn read(bytes: &[u8]) -> Metadata {
+    let mut metadata = match wasm_metadata::Payload::from_binary(bytes) {
+        Ok(payload) => {
+            let m = payload.metadata();
+            Metadata {
+                name: m.name.clone(),
+                producers: m
+                    .producers
+                    .iter()
+                    .flat_map(|p| p.iter())
+                    .flat_map(|(field, values)| {
+                        values.iter().map(move |(name, version)| Producer {
+                            field: field.clone(),
+                            name: name.clone(),
+                            version: version.clone(),
+                        })
+                    })
+                    .collect(),
+                authors: m.authors.as_ref().map(ToString::to_string),
+                description: m.description.as_ref().map(ToString::to_string),
+                licenses: m.licenses.as_ref().map(ToString::to_string),
+                source: m.source.as_ref().map(ToString::to_string),
+                homepage: m.homepage.as_ref().map(ToString::to_string),
+                revision: m.revision.as_ref().map(ToString::to_string),
+                version: m.version.as_ref().map(ToString::to_string),
+            }
+        }
+        Err(_) => Metadata::default(),
+    };
+
+    // Fill in anything missing from the annotations with the registry metadata
+    if let Some(registry) = registry_metadata(bytes) {
+        let links = registry.links.unwrap_or_default();
+        let link = |ty: &str| {
+            links
+                .iter()
+                .find(|l| l.ty.eq_ignore_ascii_case(ty))
+                .map(|l| l.value.clone())
+        };
+
+        metadata.authors = metadata
+            .authors
+            .or_else(|| registry.authors.map(|a| a.join(", ")));
+        metadata.description = metadata.description.or(registry.description);
+        metadata.licenses = metadata.licenses.or(registry.license);
+        metadata.source = metadata.source.or_else(|| link("repository"));
+        metadata.homepage = metadata.homepage.or_else(|| link("homepage"));
+    }
+
+    metadata
+}
+
+/// Reads the `registry-metadata` section of the outermost component, if present.
+fn registry_metadata(bytes: &[u8]) -> Option<RegistryMetadata> {
+    let mut depth = 0;
+    for payload in Parser::new(0).parse_all(bytes) {
+        match payload.ok()? {
+            Payload::ModuleSection { .. } | Payload::ComponentSection { .. } => depth += 1,
+            Payload::End(_) => depth -= 1,
+            Payload::CustomSection(reader)
+                if depth == 0 && reader.name() == "registry-metadata" =>
+            {
+                return serde_json::from_slice(reader.data()).ok();
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
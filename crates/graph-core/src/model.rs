@@ -0,0 +1,337 @@
+//! Module for the records that describe a composition graph.
+//!
+//! Unlike the views returned by [`State`], the records own their data and can
+//! be serialized.
+use crate::{
+    audit::InstanceAudit,
+    history,
+    metadata::{self, Metadata},
+    signature::{self, Member},
+    state::{self, ComponentId, Connection, ExportItem, InstanceId, State},
+};
+use serde::{Deserialize, Serialize};
+use wit_component::{DecodedWasm, WitPrinter};
+
+/// Represents the kind of an imported or exported item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ItemKind {
+    /// The item is a core module.
+    Module,
+    /// The item is a function.
+    Function,
+    /// The item is a value.
+    Value,
+    /// The item is a type.
+    Type,
+    /// The item is an instance.
+    Instance,
+    /// The item is a component.
+    Component,
+}
+
+impl From<wac_graph::types::ItemKind> for ItemKind {
+    fn from(kind: wac_graph::types::ItemKind) -> Self {
+        match kind {
+            wac_graph::types::ItemKind::Module(_) => Self::Module,
+            wac_graph::types::ItemKind::Func(_) => Self::Function,
+            wac_graph::types::ItemKind::Value(_) => Self::Value,
+            wac_graph::types::ItemKind::Type(_) => Self::Type,
+            wac_graph::types::ItemKind::Instance(_) => Self::Instance,
+            wac_graph::types::ItemKind::Component(_) => Self::Component,
+        }
+    }
+}
+
+/// Represents an import of a component.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Import {
+    /// The name of the import.
+    pub name: String,
+    /// The kind of the imported item.
+    pub kind: ItemKind,
+    /// The WIT signature of the imported item.
+    pub signature: String,
+    /// The members of the imported item if it is an instance.
+    pub members: Vec<Member>,
+}
+
+/// Represents an export of a component.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Export {
+    /// The name of the export.
+    pub name: String,
+    /// The kind of the exported item.
+    pub kind: ItemKind,
+    /// The WIT signature of the exported item.
+    pub signature: String,
+    /// The members of the exported item if it is an instance.
+    pub members: Vec<Member>,
+}
+
+/// Represents a component in the graph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Component {
+    /// The id of the component.
+    pub id: ComponentId,
+    /// The name of the component.
+    pub name: String,
+    /// The name of the package the component is registered as.
+    ///
+    /// This is the name used to reference the component in WAC documents and
+    /// when importing it into the encoded graph.
+    pub package_name: String,
+    /// The version of the component, if it has one.
+    pub version: Option<String>,
+    /// The SHA-256 digest of the component's bytes, in the form `sha256:<hex>`.
+    pub digest: String,
+    /// The imports of the component.
+    pub imports: Vec<Import>,
+    /// The exports of the component.
+    pub exports: Vec<Export>,
+    /// The WIT of the component.
+    ///
+    /// If the WIT could not be decoded, this is the error text instead.
+    pub wit: String,
+    /// The metadata of the component.
+    pub metadata: Metadata,
+}
+
+impl Component {
+    /// Creates the record for a component in the graph.
+    pub(crate) fn new(id: ComponentId, component: state::Component) -> Self {
+        let (wit, mut imports, mut exports) = match wit_component::decode(component.bytes()) {
+            Ok(decoded) => {
+                let wit = match print_wit(&decoded) {
+                    Ok(wit) => wit,
+                    // If we can't print the document, just use the error text
+                    Err(e) => format!("{e:#}"),
+                };
+
+                match &decoded {
+                    DecodedWasm::Component(resolve, world) => (
+                        wit,
+                        signature::imports(resolve, *world),
+                        signature::exports(resolve, *world),
+                    ),
+                    DecodedWasm::WitPackage(..) => (wit, Default::default(), Default::default()),
+                }
+            }
+            Err(e) => {
+                // If we can't decode the component, just use the error text
+                (format!("{e:#}"), Default::default(), Default::default())
+            }
+        };
+
+        Self {
+            id,
+            name: component.name().to_string(),
+            package_name: component.package_name().to_string(),
+            version: component.version().map(ToString::to_string),
+            digest: component.digest().to_string(),
+            imports: component
+                .imports()
+                .map(|(_, name, kind)| {
+                    let kind = kind.into();
+                    let (signature, members) = signature_info(imports.remove(name), kind);
+                    Import {
+                        name: name.to_string(),
+                        kind,
+                        signature,
+                        members,
+                    }
+                })
+                .collect(),
+            exports: component
+                .exports()
+                .map(|(_, name, kind)| {
+                    let kind = kind.into();
+                    let (signature, members) = signature_info(exports.remove(name), kind);
+                    Export {
+                        name: name.to_string(),
+                        kind,
+                        signature,
+                        members,
+                    }
+                })
+                .collect(),
+            wit,
+            metadata: metadata::read(component.bytes()),
+        }
+    }
+}
+
+/// Represents an instance in the graph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Instance {
+    /// The id of the instance.
+    pub id: InstanceId,
+    /// The id of the instantiated component.
+    pub component: ComponentId,
+    /// The name of the instance in the encoded graph, if it has one.
+    pub name: Option<String>,
+}
+
+impl Instance {
+    /// Creates the record for an instance in the graph.
+    ///
+    /// The instance must exist in the graph.
+    pub(crate) fn new(state: &State, id: InstanceId) -> Self {
+        let (_, component) = state.instances().find(|(i, _)| *i == id).unwrap();
+        Self {
+            id,
+            component,
+            name: state.instance_name(id).unwrap().map(ToString::to_string),
+        }
+    }
+}
+
+/// Represents a change made to the graph by undoing or redoing an operation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Change {
+    /// A component was added to the graph.
+    AddComponent(Box<Component>),
+    /// A component was removed from the graph, along with its instances.
+    RemoveComponent(ComponentId),
+    /// An instance was added to the graph.
+    AddInstance(Instance),
+    /// An instance was removed from the graph, along with its connections.
+    RemoveInstance(InstanceId),
+    /// An instance was renamed.
+    RenameInstance(Instance),
+    /// Two instances were connected.
+    Connect(Connection),
+    /// Two instances were disconnected.
+    Disconnect(Connection),
+}
+
+impl Change {
+    /// Creates the record for a change made to the graph.
+    pub(crate) fn new(state: &State, change: history::Change) -> Self {
+        match change {
+            history::Change::AddComponent(id) => {
+                Self::AddComponent(Box::new(Component::new(id, state.component(id).unwrap())))
+            }
+            history::Change::RemoveComponent(id) => Self::RemoveComponent(id),
+            history::Change::AddInstance(id, _) => Self::AddInstance(Instance::new(state, id)),
+            history::Change::RemoveInstance(id) => Self::RemoveInstance(id),
+            history::Change::RenameInstance(id) => Self::RenameInstance(Instance::new(state, id)),
+            history::Change::Connect(c) => Self::Connect(c),
+            history::Change::Disconnect(c) => Self::Disconnect(c),
+        }
+    }
+}
+
+/// Represents the components, instances and connections of a graph.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Snapshot {
+    /// The components in the graph.
+    pub components: Vec<Component>,
+    /// The instances in the graph.
+    pub instances: Vec<Instance>,
+    /// The connections between instances in the graph.
+    pub connections: Vec<Connection>,
+    /// The instance to export from the graph.
+    pub export: Option<InstanceId>,
+    /// The additional items to export from the graph.
+    pub exports: Vec<ExportItem>,
+}
+
+impl Snapshot {
+    /// Creates a snapshot of the given graph state.
+    pub(crate) fn new(state: &State, export: Option<InstanceId>) -> Self {
+        Self {
+            components: state
+                .components()
+                .map(|(id, component)| Component::new(id, component))
+                .collect(),
+            instances: state
+                .instances()
+                .map(|(id, _)| Instance::new(state, id))
+                .collect(),
+            connections: state.connections().to_vec(),
+            export,
+            exports: Vec::new(),
+        }
+    }
+}
+
+/// Represents options for encoding the graph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncodeOptions {
+    /// Whether or not to define components in the output.
+    pub define_components: bool,
+    /// The instance to export from the output.
+    pub export: Option<InstanceId>,
+    /// The additional items to export from the output.
+    pub exports: Vec<ExportItem>,
+    /// Whether or not to validate the output.
+    pub validate: bool,
+}
+
+impl Default for EncodeOptions {
+    fn default() -> Self {
+        Self {
+            define_components: true,
+            export: None,
+            exports: Vec::new(),
+            validate: true,
+        }
+    }
+}
+
+/// Represents an audit of the host capabilities reachable in the graph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditReport {
+    /// The capabilities reachable from each instance.
+    pub instances: Vec<InstanceAudit>,
+    /// The audit formatted as a Markdown report.
+    pub markdown: String,
+}
+
+/// Prints the WIT of a decoded component, including the packages it references.
+pub(crate) fn print_wit(decoded: &DecodedWasm) -> anyhow::Result<String> {
+    let resolve = decoded.resolve();
+    let nested = resolve
+        .packages
+        .iter()
+        .map(|(id, _)| id)
+        .filter(|id| *id != decoded.package())
+        .collect::<Vec<_>>();
+
+    let mut printer = WitPrinter::default();
+    printer.print(resolve, decoded.package(), &nested)?;
+    Ok(printer.output.to_string())
+}
+
+/// Gets the signature and members of an imported or exported item.
+///
+/// Items that cannot be described in WIT are rendered by their kind.
+fn signature_info(
+    signature: Option<signature::Signature>,
+    kind: ItemKind,
+) -> (String, Vec<Member>) {
+    match signature {
+        Some(signature) => (signature.signature, signature.members),
+        None => (
+            match kind {
+                ItemKind::Module => "module",
+                ItemKind::Function => "func",
+                ItemKind::Value => "value",
+                ItemKind::Type => "type",
+                ItemKind::Instance => "instance",
+                ItemKind::Component => "component",
+            }
+            .to_string(),
+            Vec::new(),
+        ),
+    }
+}
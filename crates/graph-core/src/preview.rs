@@ -0,0 +1,172 @@
+//! Module for previewing the WIT world of the encoded graph.
+//!
+//! The world is decoded from the graph as it would be encoded, and each import
+//! and export is attributed to the instances it comes from.
+use crate::{
+    error::{Error, Result},
+    model,
+    state::{ExportItem, InstanceId, State},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use wit_component::DecodedWasm;
+
+/// Represents an import or export of the encoded graph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewItem {
+    /// The name of the item.
+    pub name: String,
+    /// The instances the item comes from.
+    ///
+    /// An import comes from every instance with an unconnected import of the
+    /// same name; an export comes from the instance it is exported from.
+    pub instances: Vec<InstanceId>,
+}
+
+/// Represents a preview of the world of the encoded graph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldPreview {
+    /// The WIT of the world, with a comment naming the instances of each item.
+    pub wit: String,
+    /// The imports of the world.
+    pub imports: Vec<PreviewItem>,
+    /// The exports of the world.
+    pub exports: Vec<PreviewItem>,
+}
+
+/// Previews the world of the graph encoded with the given exports.
+///
+/// Components are always defined in the previewed graph as WIT cannot describe
+/// imports of components.
+pub fn preview(
+    state: &State,
+    export: Option<InstanceId>,
+    exports: &[ExportItem],
+) -> Result<WorldPreview> {
+    let bytes = state.encode(true, export, exports, false)?;
+    let decoded = wit_component::decode(&bytes)
+        .map_err(|e| Error::Encode(format!("failed to decode the encoded graph: {e:#}")))?;
+    let DecodedWasm::Component(resolve, world) = &decoded else {
+        unreachable!("the encoded graph is a component");
+    };
+
+    // Imports are merged by name from the unconnected imports of each instance
+    let mut imported = HashMap::<_, Vec<_>>::new();
+    for (id, imports) in state.analyze(&[])?.imports {
+        for import in imports {
+            imported.entry(import.name).or_default().push(id);
+        }
+    }
+
+    let mut exported = HashMap::new();
+    if let Some(id) = export {
+        let (_, component) = state.instances().find(|(i, _)| *i == id).unwrap();
+        for (_, name, _) in state.component(component).unwrap().exports() {
+            exported.insert(name.to_string(), vec![id]);
+        }
+    }
+
+    for item in exports {
+        exported.insert(item.name.clone(), vec![item.instance]);
+    }
+
+    let items = |keys: Vec<_>, sources: &mut HashMap<String, Vec<InstanceId>>| {
+        keys.into_iter()
+            .map(|key| {
+                let name = resolve.name_world_key(key);
+                PreviewItem {
+                    instances: sources.remove(&name).unwrap_or_default(),
+                    name,
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let imports = items(
+        resolve.worlds[*world].imports.keys().collect(),
+        &mut imported,
+    );
+    let exports = items(
+        resolve.worlds[*world].exports.keys().collect(),
+        &mut exported,
+    );
+
+    let wit = model::print_wit(&decoded)
+        .map_err(|e| Error::Encode(format!("failed to print the encoded graph: {e:#}")))?;
+
+    Ok(WorldPreview {
+        wit: annotate(state, &wit, &imports, &exports),
+        imports,
+        exports,
+    })
+}
+
+/// Annotates the items of the printed world with the instances they come from.
+///
+/// The world of the encoded graph is the first in the document; each of its
+/// items is printed on a line of its own, indented once.
+fn annotate(state: &State, wit: &str, imports: &[PreviewItem], exports: &[PreviewItem]) -> String {
+    let mut annotated = String::with_capacity(wit.len());
+    let mut in_world = false;
+    let mut done = false;
+
+    for line in wit.lines() {
+        if !done && line.starts_with("world ") {
+            in_world = true;
+        } else if in_world && line == "}" {
+            in_world = false;
+            done = true;
+        } else if in_world {
+            if let Some(comment) = item_comment(state, line, imports, exports) {
+                annotated.push_str("  // ");
+                annotated.push_str(&comment);
+                annotated.push('\n');
+            }
+        }
+
+        annotated.push_str(line);
+        annotated.push('\n');
+    }
+
+    annotated
+}
+
+/// Gets the comment for the line of an item in the printed world.
+fn item_comment(
+    state: &State,
+    line: &str,
+    imports: &[PreviewItem],
+    exports: &[PreviewItem],
+) -> Option<String> {
+    let line = line.strip_prefix("  ")?;
+    let (verb, items, rest) = if let Some(rest) = line.strip_prefix("import ") {
+        ("imported by", imports, rest)
+    } else if let Some(rest) = line.strip_prefix("export ") {
+        ("exported by", exports, rest)
+    } else {
+        return None;
+    };
+
+    // Interfaces are printed as `import ns:pkg/name;` and other items as `import name: ...`
+    let name = match rest.strip_suffix(';') {
+        Some(name) if !name.contains(": ") => name,
+        _ => rest.split(": ").next()?,
+    };
+
+    let item = items.iter().find(|i| i.name == name)?;
+    if item.instances.is_empty() {
+        return None;
+    }
+
+    Some(format!(
+        "{verb} {instances}",
+        instances = item
+            .instances
+            .iter()
+            .map(|id| state.describe_instance(*id).unwrap())
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))
+}
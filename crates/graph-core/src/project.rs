@@ -0,0 +1,116 @@
+//! Module for the serialized project file format.
+use crate::{
+    error::{Error, Result},
+    state::ExportItem,
+};
+use serde::{Deserialize, Serialize};
+
+/// The current version of the project file format.
+pub const VERSION: u32 = 1;
+
+/// Represents a serialized composition graph.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Project {
+    /// The version of the project file format.
+    pub version: u32,
+    /// The components in the graph.
+    pub components: Vec<ProjectComponent>,
+    /// The instances in the graph.
+    pub instances: Vec<ProjectInstance>,
+    /// The connections between instances in the graph.
+    pub connections: Vec<ProjectConnection>,
+    /// The instance to export from the graph.
+    pub export: Option<u32>,
+    /// The additional items to export from the graph.
+    #[serde(default)]
+    pub exports: Vec<ExportItem>,
+}
+
+/// Represents a serialized component.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectComponent {
+    /// The id of the component.
+    pub id: u32,
+    /// The name of the component.
+    pub name: String,
+    /// The version of the component, if it has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// The bytes of the component.
+    #[serde(with = "base64_bytes")]
+    pub bytes: Vec<u8>,
+}
+
+/// Represents a serialized instance.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectInstance {
+    /// The id of the instance.
+    pub id: u32,
+    /// The id of the instantiated component.
+    pub component: u32,
+    /// The name of the instance, if it has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Represents a serialized connection between two instances.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectConnection {
+    /// The id of the source instance.
+    pub source: u32,
+    /// The index of the source export; `None` if the instance itself is the source.
+    pub source_export: Option<u32>,
+    /// The id of the target instance.
+    pub target: u32,
+    /// The index of the target import.
+    pub target_import: u32,
+}
+
+impl Project {
+    /// Creates a new, empty project of the current version.
+    pub fn new() -> Self {
+        Self {
+            version: VERSION,
+            ..Default::default()
+        }
+    }
+
+    /// Encodes the project as a JSON document.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        serde_json::to_vec_pretty(self)
+            .map_err(|e| Error::Encode(format!("failed to serialize project: {e}")))
+    }
+
+    /// Decodes a project from a JSON document.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let project: Self = serde_json::from_slice(bytes)
+            .map_err(|e| Error::InvalidProject(format!("failed to deserialize project: {e}")))?;
+
+        if project.version != VERSION {
+            return Err(Error::InvalidProject(format!(
+                "unsupported project file version {version} (expected version {VERSION})",
+                version = project.version
+            )));
+        }
+
+        Ok(project)
+    }
+}
+
+mod base64_bytes {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        STANDARD.decode(s).map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,281 @@
+//! Module for rendering the WIT signatures of component imports and exports.
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use wit_parser::{
+    Function, FunctionKind, Handle, InterfaceId, Resolve, Type, TypeDefKind, TypeId, WorldId,
+    WorldItem, WorldKey,
+};
+
+/// Represents a kind of member of an instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MemberKind {
+    /// The member is a function.
+    Function,
+    /// The member is a type.
+    Type,
+    /// The member is a resource.
+    Resource,
+}
+
+/// Represents a member of an imported or exported instance.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Member {
+    /// The name of the member.
+    pub name: String,
+    /// The kind of the member.
+    pub kind: MemberKind,
+    /// The WIT signature of the member.
+    pub signature: String,
+}
+
+/// Represents the signature of an imported or exported item.
+#[derive(Debug, Clone, Default)]
+pub struct Signature {
+    /// The WIT signature of the item.
+    pub signature: String,
+    /// The members of the item if it is an instance.
+    pub members: Vec<Member>,
+}
+
+/// Gets the signatures of the imports of a world, keyed by import name.
+pub fn imports(resolve: &Resolve, world: WorldId) -> HashMap<String, Signature> {
+    resolve.worlds[world]
+        .imports
+        .iter()
+        .map(|(key, item)| {
+            (
+                resolve.name_world_key(key),
+                item_signature(resolve, key, item),
+            )
+        })
+        .collect()
+}
+
+/// Gets the signatures of the exports of a world, keyed by export name.
+pub fn exports(resolve: &Resolve, world: WorldId) -> HashMap<String, Signature> {
+    resolve.worlds[world]
+        .exports
+        .iter()
+        .map(|(key, item)| {
+            (
+                resolve.name_world_key(key),
+                item_signature(resolve, key, item),
+            )
+        })
+        .collect()
+}
+
+fn item_signature(resolve: &Resolve, key: &WorldKey, item: &WorldItem) -> Signature {
+    match item {
+        WorldItem::Interface { id, .. } => Signature {
+            signature: format!(
+                "interface {name}",
+                name = resolve
+                    .id_of(*id)
+                    .unwrap_or_else(|| resolve.name_world_key(key))
+            ),
+            members: interface_members(resolve, *id),
+        },
+        WorldItem::Function(f) => Signature {
+            signature: function(resolve, f),
+            members: Vec::new(),
+        },
+        WorldItem::Type(id) => Signature {
+            signature: type_def(resolve, *id),
+            members: Vec::new(),
+        },
+    }
+}
+
+fn interface_members(resolve: &Resolve, id: InterfaceId) -> Vec<Member> {
+    let interface = &resolve.interfaces[id];
+    let types = interface.types.iter().map(|(name, id)| Member {
+        name: name.clone(),
+        kind: match resolve.types[*id].kind {
+            TypeDefKind::Resource => MemberKind::Resource,
+            _ => MemberKind::Type,
+        },
+        signature: type_def(resolve, *id),
+    });
+
+    let functions = interface.functions.values().map(|f| Member {
+        name: function_name(resolve, f),
+        kind: MemberKind::Function,
+        signature: function(resolve, f),
+    });
+
+    types.chain(functions).collect()
+}
+
+/// Gets the member name of a function.
+///
+/// Functions of a resource are named `<resource>.<function>`.
+pub(crate) fn function_name(resolve: &Resolve, f: &Function) -> String {
+    match f.kind {
+        FunctionKind::Freestanding | FunctionKind::AsyncFreestanding => f.name.clone(),
+        FunctionKind::Method(id)
+        | FunctionKind::AsyncMethod(id)
+        | FunctionKind::Static(id)
+        | FunctionKind::AsyncStatic(id)
+        | FunctionKind::Constructor(id) => {
+            format!(
+                "{resource}.{name}",
+                resource = type_name(resolve, id),
+                name = f.item_name()
+            )
+        }
+    }
+}
+
+/// Renders the signature of a function.
+pub(crate) fn function(resolve: &Resolve, f: &Function) -> String {
+    // Methods take an implicit `self` parameter that is not rendered
+    let skip = match f.kind {
+        FunctionKind::Method(_) | FunctionKind::AsyncMethod(_) => 1,
+        _ => 0,
+    };
+
+    let params = f
+        .params
+        .iter()
+        .skip(skip)
+        .map(|(name, t)| format!("{name}: {ty}", ty = ty(resolve, t)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let result = match &f.result {
+        Some(t) => format!(" -> {ty}", ty = ty(resolve, t)),
+        None => String::new(),
+    };
+
+    match f.kind {
+        FunctionKind::Freestanding | FunctionKind::Method(_) => format!("func({params}){result}"),
+        FunctionKind::AsyncFreestanding | FunctionKind::AsyncMethod(_) => {
+            format!("async func({params}){result}")
+        }
+        FunctionKind::Static(_) => format!("static func({params}){result}"),
+        FunctionKind::AsyncStatic(_) => format!("static async func({params}){result}"),
+        FunctionKind::Constructor(_) => format!("constructor({params})"),
+    }
+}
+
+/// Renders the definition of a named type.
+pub(crate) fn type_def(resolve: &Resolve, id: TypeId) -> String {
+    let name = type_name(resolve, id);
+    match &resolve.types[id].kind {
+        TypeDefKind::Record(r) => format!(
+            "record {name} {{ {fields} }}",
+            fields = r
+                .fields
+                .iter()
+                .map(|f| format!("{name}: {ty}", name = f.name, ty = ty(resolve, &f.ty)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        TypeDefKind::Resource => format!("resource {name}"),
+        TypeDefKind::Flags(f) => format!(
+            "flags {name} {{ {flags} }}",
+            flags = f
+                .flags
+                .iter()
+                .map(|f| f.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        TypeDefKind::Variant(v) => format!(
+            "variant {name} {{ {cases} }}",
+            cases = v
+                .cases
+                .iter()
+                .map(|c| match &c.ty {
+                    Some(t) => format!("{name}({ty})", name = c.name, ty = ty(resolve, t)),
+                    None => c.name.clone(),
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        TypeDefKind::Enum(e) => format!(
+            "enum {name} {{ {cases} }}",
+            cases = e
+                .cases
+                .iter()
+                .map(|c| c.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        TypeDefKind::Type(t) => format!("type {name} = {ty}", ty = ty(resolve, t)),
+        _ => format!("type {name} = {ty}", ty = anonymous_type(resolve, id)),
+    }
+}
+
+pub(crate) fn type_name(resolve: &Resolve, id: TypeId) -> &str {
+    resolve.types[id].name.as_deref().unwrap_or("<anonymous>")
+}
+
+/// Renders a reference to a type.
+pub(crate) fn ty(resolve: &Resolve, ty: &Type) -> String {
+    match ty {
+        Type::Bool => "bool".to_string(),
+        Type::U8 => "u8".to_string(),
+        Type::U16 => "u16".to_string(),
+        Type::U32 => "u32".to_string(),
+        Type::U64 => "u64".to_string(),
+        Type::S8 => "s8".to_string(),
+        Type::S16 => "s16".to_string(),
+        Type::S32 => "s32".to_string(),
+        Type::S64 => "s64".to_string(),
+        Type::F32 => "f32".to_string(),
+        Type::F64 => "f64".to_string(),
+        Type::Char => "char".to_string(),
+        Type::String => "string".to_string(),
+        Type::ErrorContext => "error-context".to_string(),
+        Type::Id(id) => match &resolve.types[*id].name {
+            Some(name) => name.clone(),
+            None => anonymous_type(resolve, *id),
+        },
+    }
+}
+
+/// Renders an anonymous type by its structure.
+fn anonymous_type(resolve: &Resolve, id: TypeId) -> String {
+    match &resolve.types[id].kind {
+        TypeDefKind::Handle(Handle::Own(id)) => type_name(resolve, *id).to_string(),
+        TypeDefKind::Handle(Handle::Borrow(id)) => {
+            format!("borrow<{name}>", name = type_name(resolve, *id))
+        }
+        TypeDefKind::Tuple(t) => format!(
+            "tuple<{types}>",
+            types = t
+                .types
+                .iter()
+                .map(|t| ty(resolve, t))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        TypeDefKind::Option(t) => format!("option<{ty}>", ty = ty(resolve, t)),
+        TypeDefKind::Result(r) => match (&r.ok, &r.err) {
+            (Some(ok), Some(err)) => format!(
+                "result<{ok}, {err}>",
+                ok = ty(resolve, ok),
+                err = ty(resolve, err)
+            ),
+            (Some(ok), None) => format!("result<{ok}>", ok = ty(resolve, ok)),
+            (None, Some(err)) => format!("result<_, {err}>", err = ty(resolve, err)),
+            (None, None) => "result".to_string(),
+        },
+        TypeDefKind::List(t) => format!("list<{ty}>", ty = ty(resolve, t)),
+        TypeDefKind::Future(Some(t)) => format!("future<{ty}>", ty = ty(resolve, t)),
+        TypeDefKind::Future(None) => "future".to_string(),
+        TypeDefKind::Stream(Some(t)) => format!("stream<{ty}>", ty = ty(resolve, t)),
+        TypeDefKind::Stream(None) => "stream".to_string(),
+        TypeDefKind::Type(t) => ty(resolve, t),
+        TypeDefKind::Record(_)
+        | TypeDefKind::Resource
+        | TypeDefKind::Flags(_)
+        | TypeDefKind::Variant(_)
+        | TypeDefKind::Enum(_)
+        | TypeDefKind::Unknown => type_name(resolve, id).to_string(),
+    }
+}
@@ -0,0 +1,1445 @@
+//! Module for the state of a composition graph.
+use crate::{
+    componentize::{self, Adapter},
+    error::{Error, Result},
+    project::{Project, ProjectComponent, ProjectConnection, ProjectInstance},
+};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap, HashSet},
+    ops::Range,
+};
+use wac_graph::{
+    types::{ItemKind, Package, SubtypeChecker, World},
+    AliasError, CompositionGraph, EncodeOptions, ExportError, InstantiationArgumentError, NodeId,
+    PackageId,
+};
+use wasmparser::{names::KebabStr, BinaryReaderError, Chunk, Encoding, Parser, Payload};
+
+/// Represents a component identifier in the graph.
+pub type ComponentId = u32;
+
+/// Represents an instance identifier in the graph.
+pub type InstanceId = u32;
+
+/// Represents a connection between two instances in the graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Connection {
+    /// The source instance of the connection.
+    pub source: InstanceId,
+    /// The source export index; if `None`, the source instance itself is connected.
+    pub source_export: Option<u32>,
+    /// The target instance of the connection.
+    pub target: InstanceId,
+    /// The target import index.
+    pub target_import: u32,
+}
+
+/// Represents an item to export from the encoded graph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportItem {
+    /// The instance to export from.
+    pub instance: InstanceId,
+    /// The index of the instance's export; if `None`, the instance itself is exported.
+    pub export: Option<u32>,
+    /// The name to export the item as.
+    pub name: String,
+}
+
+/// Represents an import that could be connected to more than one source.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ambiguity {
+    /// The index of the import on the target instance.
+    pub import: u32,
+    /// The connections that could be made to the import.
+    pub candidates: Vec<Connection>,
+}
+
+/// Represents the result of automatically connecting an instance.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoConnect {
+    /// The connections that were made.
+    pub connections: Vec<Connection>,
+    /// The imports that were not connected because more than one source matched.
+    pub ambiguities: Vec<Ambiguity>,
+    /// The indexes of the imports that were not connected because no source matched.
+    pub unresolved: Vec<u32>,
+}
+
+/// Represents an import of an instance that is not connected to a source.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnconnectedImport {
+    /// The index of the import on the instance.
+    pub import: u32,
+    /// The name of the import.
+    pub name: String,
+    /// The other instances with an unconnected import of the same name.
+    ///
+    /// These imports are merged into a single import of the encoded graph.
+    pub merged_with: Vec<InstanceId>,
+}
+
+/// Represents an analysis of what the encoded graph will import and export.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Analysis {
+    /// The unconnected imports of each instance; instances with none are omitted.
+    pub imports: Vec<(InstanceId, Vec<UnconnectedImport>)>,
+    /// The instances that are not reachable from any exported instance.
+    pub unreachable: Vec<InstanceId>,
+}
+
+/// Represents an alias of an instance export in the graph.
+///
+/// Aliases are created for the exports used as the sources of connections.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Alias {
+    /// The instance the export is aliased from.
+    pub instance: InstanceId,
+    /// The index of the aliased export.
+    pub export: u32,
+    /// The name of the aliased export.
+    pub name: String,
+}
+
+/// Represents how to add a component that is identical to one already in the graph.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Duplicates {
+    /// The component is added as a separate copy.
+    #[default]
+    Allow,
+    /// Adding the component fails.
+    Reject,
+    /// The existing component is used instead.
+    Alias,
+}
+
+/// Represents options for adding a component to the graph.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddOptions {
+    /// How to add a component that is identical to one already in the graph.
+    pub duplicates: Duplicates,
+    /// The adapter to apply when the bytes are a core module.
+    pub adapter: Option<Adapter>,
+}
+
+/// Represents a view of a component in the graph.
+#[derive(Debug, Clone, Copy)]
+pub struct Component<'a> {
+    name: &'a str,
+    package: &'a Package,
+    world: &'a World,
+    digest: &'a str,
+}
+
+impl<'a> Component<'a> {
+    /// Gets the name of the component.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Gets the name of the package the component is registered as.
+    ///
+    /// This is the component's name, converted to kebab-case if it is not a
+    /// package name and suffixed with a number if another component's name
+    /// converts to the same package name.
+    pub fn package_name(&self) -> &'a str {
+        self.package.name()
+    }
+
+    /// Gets the version of the component, if it has one.
+    pub fn version(&self) -> Option<&'a Version> {
+        self.package.version()
+    }
+
+    /// Gets the bytes of the component.
+    pub fn bytes(&self) -> &'a [u8] {
+        self.package.bytes()
+    }
+
+    /// Gets the SHA-256 digest of the component's bytes, in the form `sha256:<hex>`.
+    pub fn digest(&self) -> &'a str {
+        self.digest
+    }
+
+    /// Gets an iterator over the imports of the component.
+    pub fn imports(&self) -> impl ExactSizeIterator<Item = (u32, &'a str, ItemKind)> + 'a {
+        self.world
+            .imports
+            .iter()
+            .enumerate()
+            .map(|(index, (name, kind))| (index as u32, name.as_str(), *kind))
+    }
+
+    /// Gets an iterator over the exports of the component.
+    pub fn exports(&self) -> impl ExactSizeIterator<Item = (u32, &'a str, ItemKind)> + 'a {
+        self.world
+            .exports
+            .iter()
+            .enumerate()
+            .map(|(index, (name, kind))| (index as u32, name.as_str(), *kind))
+    }
+
+    /// Gets an import of the component by index.
+    pub fn import(&self, index: u32) -> Option<(&'a str, ItemKind)> {
+        self.world
+            .imports
+            .get_index(index as usize)
+            .map(|(name, kind)| (name.as_str(), *kind))
+    }
+
+    /// Gets an export of the component by index.
+    pub fn export(&self, index: u32) -> Option<(&'a str, ItemKind)> {
+        self.world
+            .exports
+            .get_index(index as usize)
+            .map(|(name, kind)| (name.as_str(), *kind))
+    }
+
+    /// Gets an import of the component by name.
+    pub fn import_by_name(&self, name: &str) -> Option<(u32, ItemKind)> {
+        self.world
+            .imports
+            .get_full(name)
+            .map(|(index, _, kind)| (index as u32, *kind))
+    }
+
+    /// Gets an export of the component by name.
+    pub fn export_by_name(&self, name: &str) -> Option<(u32, ItemKind)> {
+        self.world
+            .exports
+            .get_full(name)
+            .map(|(index, _, kind)| (index as u32, *kind))
+    }
+}
+
+/// Represents an instance in the graph.
+#[derive(Debug, Clone)]
+struct InstanceEntry {
+    /// The id of the instantiated component.
+    component: ComponentId,
+    /// The instantiation node in the composition graph.
+    node: NodeId,
+    /// The name of the instance in the encoded graph.
+    name: Option<String>,
+}
+
+/// Represents a component in the graph.
+#[derive(Debug, Clone)]
+struct ComponentEntry {
+    /// The name of the component.
+    name: String,
+    /// The package of the component in the composition graph.
+    package: PackageId,
+    /// The digest of the component's bytes.
+    digest: String,
+}
+
+/// Represents the state of a composition graph.
+///
+/// Components are registered as packages of the composition graph and
+/// instances are its instantiation nodes; the state maps its own identifiers
+/// onto these so that a graph loaded from a project file keeps the identifiers
+/// it was saved with.
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    graph: CompositionGraph,
+    components: BTreeMap<ComponentId, ComponentEntry>,
+    instances: BTreeMap<InstanceId, InstanceEntry>,
+    connections: Vec<Connection>,
+    next_component_id: ComponentId,
+    next_instance_id: InstanceId,
+}
+
+impl State {
+    /// Adds a component to the graph.
+    ///
+    /// If the bytes are a core module, it is first converted into a component.
+    ///
+    /// Components are identical if their bytes have the same digest; the given
+    /// options determine what happens if an identical component is already in
+    /// the graph.
+    ///
+    /// Returns the id of the component and whether it was added.
+    pub fn add_component(
+        &mut self,
+        name: String,
+        version: Option<Version>,
+        bytes: Vec<u8>,
+        options: AddOptions,
+    ) -> Result<(ComponentId, bool)> {
+        let AddOptions {
+            duplicates,
+            adapter,
+        } = options;
+        let bytes = parse(bytes, adapter)?;
+        let digest = digest(&bytes);
+        if duplicates != Duplicates::Allow {
+            if let Some((id, entry)) = self.components.iter().find(|(_, e)| e.digest == digest) {
+                if duplicates == Duplicates::Alias {
+                    return Ok((*id, false));
+                }
+
+                return Err(Error::InvalidOperation(format!(
+                    "the component is identical to component `{name}`",
+                    name = entry.name
+                )));
+            }
+        }
+
+        let id = self.next_component_id;
+        self.insert_parsed(id, name, version, bytes, digest)?;
+        Ok((id, true))
+    }
+
+    /// Inserts a component into the graph with the given identifier.
+    ///
+    /// The component is registered as a package with the given version and a
+    /// name derived from the given name; see [`Component::package_name`].
+    pub fn insert_component(
+        &mut self,
+        id: ComponentId,
+        name: String,
+        version: Option<Version>,
+        bytes: Vec<u8>,
+    ) -> Result<()> {
+        let bytes = parse(bytes, None)?;
+        let digest = digest(&bytes);
+        self.insert_parsed(id, name, version, bytes, digest)
+    }
+
+    /// Inserts a component into the graph from bytes returned by [`parse`].
+    fn insert_parsed(
+        &mut self,
+        id: ComponentId,
+        name: String,
+        version: Option<Version>,
+        bytes: Vec<u8>,
+        digest: String,
+    ) -> Result<()> {
+        if self.components.contains_key(&id) {
+            return Err(Error::InvalidOperation(format!(
+                "a component with id {id} already exists"
+            )));
+        }
+
+        if name.is_empty() {
+            return Err(Error::InvalidOperation(
+                "a component name cannot be empty".to_string(),
+            ));
+        }
+
+        if self
+            .components
+            .values()
+            .any(|e| e.name == name && self.graph[e.package].version() == version.as_ref())
+        {
+            return Err(Error::InvalidOperation(match &version {
+                Some(version) => {
+                    format!("a component with name `{name}` and version {version} already exists")
+                }
+                None => format!("a component with name `{name}` already exists"),
+            }));
+        }
+
+        // Names derived from different component names may be the same
+        let base = package_name(&name);
+        let mut package_name = base.clone();
+        let mut n = 2;
+        while self
+            .graph
+            .get_package_by_name(&package_name, version.as_ref())
+            .is_some()
+        {
+            package_name = format!("{base}{n}");
+            n += 1;
+        }
+
+        let package = Package::from_bytes(
+            &package_name,
+            version.as_ref(),
+            bytes,
+            self.graph.types_mut(),
+        )
+        .map_err(|e| {
+            // The bytes were successfully parsed, so any remaining error is from validation
+            Error::Validation {
+                message: format!("{e:#}"),
+                offset: e
+                    .chain()
+                    .find_map(|e| e.downcast_ref::<BinaryReaderError>())
+                    .map(|e| e.offset() as u64),
+            }
+        })?;
+
+        let package = self
+            .graph
+            .register_package(package)
+            .map_err(|e| Error::InvalidOperation(format!("{e:#}")))?;
+        self.components.insert(
+            id,
+            ComponentEntry {
+                name,
+                package,
+                digest,
+            },
+        );
+        self.next_component_id = self.next_component_id.max(id + 1);
+        Ok(())
+    }
+
+    /// Gets a component in the graph.
+    pub fn component(&self, id: ComponentId) -> Option<Component<'_>> {
+        Some(self.entry(self.components.get(&id)?))
+    }
+
+    /// Gets an iterator over the components in the graph.
+    pub fn components(&self) -> impl Iterator<Item = (ComponentId, Component<'_>)> + '_ {
+        self.components
+            .iter()
+            .map(|(id, entry)| (*id, self.entry(entry)))
+    }
+
+    /// Finds the component registered with the given package name and version.
+    pub fn find_component(&self, name: &str, version: Option<&Version>) -> Option<ComponentId> {
+        let (package, _) = self.graph.get_package_by_name(name, version)?;
+        self.components
+            .iter()
+            .find(|(_, e)| e.package == package)
+            .map(|(id, _)| *id)
+    }
+
+    fn entry<'a>(&'a self, entry: &'a ComponentEntry) -> Component<'a> {
+        let package = &self.graph[entry.package];
+        Component {
+            name: &entry.name,
+            package,
+            world: &self.graph.types()[package.ty()],
+            digest: &entry.digest,
+        }
+    }
+
+    /// Removes a component from the graph.
+    ///
+    /// All instances of the component are also removed.
+    pub fn remove_component(&mut self, id: ComponentId) {
+        if !self.components.contains_key(&id) {
+            return;
+        }
+
+        let instances = self
+            .instances
+            .iter()
+            .filter(|(_, i)| i.component == id)
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+
+        for instance in instances {
+            self.remove_instance(instance);
+        }
+
+        let entry = self.components.remove(&id).unwrap();
+        self.graph.unregister_package(entry.package);
+    }
+
+    /// Instantiates a component in the graph.
+    pub fn instantiate(&mut self, component: ComponentId) -> Result<InstanceId> {
+        let id = self.next_instance_id;
+        self.insert_instance(id, component)?;
+        Ok(id)
+    }
+
+    /// Inserts an instance into the graph with the given identifier.
+    pub fn insert_instance(&mut self, id: InstanceId, component: ComponentId) -> Result<()> {
+        if self.instances.contains_key(&id) {
+            return Err(Error::InvalidOperation(format!(
+                "an instance with id {id} already exists"
+            )));
+        }
+
+        let node = self.graph.instantiate(self.package_id(component)?);
+        self.instances.insert(
+            id,
+            InstanceEntry {
+                component,
+                node,
+                name: None,
+            },
+        );
+        self.next_instance_id = self.next_instance_id.max(id + 1);
+        Ok(())
+    }
+
+    /// Gets an iterator over the instances in the graph and their component identifiers.
+    pub fn instances(&self) -> impl Iterator<Item = (InstanceId, ComponentId)> + '_ {
+        self.instances.iter().map(|(id, i)| (*id, i.component))
+    }
+
+    /// Gets the name of an instance in the graph.
+    pub fn instance_name(&self, id: InstanceId) -> Result<Option<&str>> {
+        self.instance(id).map(|i| i.name.as_deref())
+    }
+
+    /// Describes an instance by its name and component for reports.
+    pub fn describe_instance(&self, id: InstanceId) -> Result<String> {
+        let instance = self.instance(id)?;
+        let component = self.component(instance.component).unwrap().name();
+        Ok(match &instance.name {
+            Some(name) => format!("`{name}` (instance {id} of `{component}`)"),
+            None => format!("instance {id} of `{component}`"),
+        })
+    }
+
+    /// Sets the name of an instance in the graph, returning its previous name.
+    ///
+    /// The name is recorded in the names section of the encoded graph.
+    pub fn set_instance_name(
+        &mut self,
+        id: InstanceId,
+        name: Option<String>,
+    ) -> Result<Option<String>> {
+        if let Some(name) = &name {
+            if name.is_empty() {
+                return Err(Error::InvalidOperation(
+                    "an instance name cannot be empty".to_string(),
+                ));
+            }
+
+            if let Some((other, _)) = self
+                .instances
+                .iter()
+                .find(|(i, e)| **i != id && e.name.as_ref() == Some(name))
+            {
+                return Err(Error::InvalidOperation(format!(
+                    "instance {other} is already named `{name}`"
+                )));
+            }
+        }
+
+        let entry = self
+            .instances
+            .get_mut(&id)
+            .ok_or(Error::UnknownInstance(id))?;
+        Ok(std::mem::replace(&mut entry.name, name))
+    }
+
+    /// Removes an instance from the graph.
+    ///
+    /// All connections to and from the instance are also removed.
+    pub fn remove_instance(&mut self, id: InstanceId) {
+        if !self.instances.contains_key(&id) {
+            return;
+        }
+
+        // Removing a node does not unset the arguments it satisfies, so disconnect it first
+        let connections = self
+            .connections
+            .iter()
+            .filter(|c| c.source == id || c.target == id)
+            .copied()
+            .collect::<Vec<_>>();
+        for c in connections {
+            self.disconnect(c.source, c.target, c.target_import)
+                .expect("the instances are connected");
+        }
+
+        // Removing the node also removes any aliases of its exports
+        let entry = self.instances.remove(&id).unwrap();
+        self.graph.remove_node(entry.node);
+    }
+
+    /// Gets the aliased instance exports in the graph.
+    pub fn aliases(&self) -> Vec<Alias> {
+        let mut aliases = self
+            .graph
+            .node_ids()
+            .filter_map(|node| {
+                let (source, name) = self.graph.get_alias_source(node)?;
+                let (instance, entry) = self.instances.iter().find(|(_, i)| i.node == source)?;
+                let (export, _) = self.component(entry.component)?.export_by_name(name)?;
+                Some(Alias {
+                    instance: *instance,
+                    export,
+                    name: name.to_string(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        aliases.sort_by_key(|a| (a.instance, a.export));
+        aliases
+    }
+
+    /// Connects two instances in the graph.
+    ///
+    /// If the source is an export of an instance, the export is aliased and
+    /// the alias becomes the instantiation argument of the target instance.
+    pub fn connect(&mut self, connection: Connection) -> Result<()> {
+        let (export, import) = self.check_connection(&connection)?;
+        let export = export.map(ToString::to_string);
+        let import = import.to_string();
+
+        let source = self.instance(connection.source)?.node;
+        let target = self.instance(connection.target)?.node;
+        let argument = match &export {
+            Some(export) => self
+                .graph
+                .alias_instance_export(source, export)
+                .map_err(|e: AliasError| Error::InvalidOperation(format!("{e:#}")))?,
+            None => source,
+        };
+
+        if let Err(e) = self
+            .graph
+            .set_instantiation_argument(target, &import, argument)
+        {
+            self.remove_unused_alias(&connection, argument);
+            return Err(match e {
+                InstantiationArgumentError::ArgumentTypeMismatch { source, .. } => {
+                    Error::TypeMismatch {
+                        export,
+                        import,
+                        message: format!("{source:#}"),
+                    }
+                }
+                e => Error::InvalidOperation(format!("{e:#}")),
+            });
+        }
+
+        self.connections.push(connection);
+        Ok(())
+    }
+
+    /// Checks that a connection could be made, ignoring type compatibility.
+    ///
+    /// Returns the names of the source export (if any) and the target import.
+    fn check_connection(&self, connection: &Connection) -> Result<(Option<&str>, &str)> {
+        let source = self.instance(connection.source)?;
+        let target = self.instance(connection.target)?;
+
+        if connection.source == connection.target {
+            return Err(Error::InvalidOperation(
+                "an instance cannot be connected to itself".to_string(),
+            ));
+        }
+
+        let (import, _) = self
+            .component(target.component)
+            .unwrap()
+            .import(connection.target_import)
+            .ok_or_else(|| {
+                Error::InvalidOperation(format!(
+                    "the target import index {index} is invalid",
+                    index = connection.target_import
+                ))
+            })?;
+
+        let export = match connection.source_export {
+            Some(index) => {
+                let (export, ..) = self
+                    .component(source.component)
+                    .unwrap()
+                    .export(index)
+                    .ok_or_else(|| {
+                        Error::InvalidOperation(format!(
+                            "the source export index {index} is invalid"
+                        ))
+                    })?;
+                Some(export)
+            }
+            None => None,
+        };
+
+        if self
+            .connections
+            .iter()
+            .any(|c| c.target == connection.target && c.target_import == connection.target_import)
+        {
+            return Err(Error::InvalidOperation(format!(
+                "import `{import}` is already connected"
+            )));
+        }
+
+        if let Some(mut instances) = self.path(connection.target, connection.source) {
+            instances.push(connection.target);
+            return Err(Error::Cycle {
+                message: format!(
+                    "connecting instance {source} to instance {target} would form a cycle in \
+                     the instantiation graph",
+                    source = connection.source,
+                    target = connection.target
+                ),
+                instances,
+            });
+        }
+
+        Ok((export, import))
+    }
+
+    /// Determines if a connection could be made.
+    ///
+    /// Unlike connecting, this does not take into account resource types
+    /// unified by existing connections.
+    fn can_connect(&self, connection: &Connection) -> bool {
+        let Ok((export, import)) = self.check_connection(connection) else {
+            return false;
+        };
+
+        let source = &self.instances[&connection.source];
+        let target = self
+            .component(self.instances[&connection.target].component)
+            .unwrap();
+        let (_, import_ty) = target.import_by_name(import).unwrap();
+        let export_ty = match export {
+            Some(export) => {
+                let (_, ty) = self
+                    .component(source.component)
+                    .unwrap()
+                    .export_by_name(export)
+                    .unwrap();
+                ty
+            }
+            None => self.graph[source.node].item_kind(),
+        };
+
+        let types = self.graph.types();
+        SubtypeChecker::new(&mut HashSet::new())
+            .is_subtype(export_ty, types, import_ty, types)
+            .is_ok()
+    }
+
+    /// Gets the connections that could be made to an import of the target instance.
+    pub fn compatible_sources(
+        &self,
+        target: InstanceId,
+        target_import: u32,
+    ) -> Result<Vec<Connection>> {
+        self.instance(target)?;
+
+        let mut connections = Vec::new();
+        for (source, component) in self.instances() {
+            let exports = self.component(component).unwrap().exports().len() as u32;
+            for source_export in std::iter::once(None).chain((0..exports).map(Some)) {
+                let connection = Connection {
+                    source,
+                    source_export,
+                    target,
+                    target_import,
+                };
+
+                if self.can_connect(&connection) {
+                    connections.push(connection);
+                }
+            }
+        }
+
+        Ok(connections)
+    }
+
+    /// Connects every unconnected import of the target instance to an export of
+    /// another instance with the same name and a compatible type.
+    ///
+    /// Imports that match exports on more than one instance are left unconnected.
+    pub fn auto_connect(&mut self, target: InstanceId) -> Result<AutoConnect> {
+        let component = self.component(self.instance(target)?.component).unwrap();
+        let imports = component
+            .imports()
+            .map(|(index, name, _)| (index, name.to_string()))
+            .filter(|(index, _)| {
+                !self
+                    .connections
+                    .iter()
+                    .any(|c| c.target == target && c.target_import == *index)
+            })
+            .collect::<Vec<_>>();
+
+        let mut result = AutoConnect::default();
+        for (import, name) in imports {
+            let candidates = self
+                .instances()
+                .filter_map(|(source, component)| {
+                    let (index, ..) = self.component(component).unwrap().export_by_name(&name)?;
+                    let connection = Connection {
+                        source,
+                        source_export: Some(index),
+                        target,
+                        target_import: import,
+                    };
+
+                    self.can_connect(&connection).then_some(connection)
+                })
+                .collect::<Vec<_>>();
+
+            match candidates.as_slice() {
+                [] => result.unresolved.push(import),
+                [connection] => {
+                    if self.connect(*connection).is_ok() {
+                        result.connections.push(*connection);
+                    } else {
+                        result.unresolved.push(import);
+                    }
+                }
+                _ => result.ambiguities.push(Ambiguity { import, candidates }),
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Gets the connections that could be made from an export of the source instance.
+    ///
+    /// If `source_export` is `None`, the source instance itself is connected.
+    pub fn compatible_targets(
+        &self,
+        source: InstanceId,
+        source_export: Option<u32>,
+    ) -> Result<Vec<Connection>> {
+        self.instance(source)?;
+
+        let mut connections = Vec::new();
+        for (target, component) in self.instances() {
+            let imports = self.component(component).unwrap().imports().len() as u32;
+            for target_import in 0..imports {
+                let connection = Connection {
+                    source,
+                    source_export,
+                    target,
+                    target_import,
+                };
+
+                if self.can_connect(&connection) {
+                    connections.push(connection);
+                }
+            }
+        }
+
+        Ok(connections)
+    }
+
+    /// Finds a path of connections from one instance to another.
+    ///
+    /// Returns the instances along the path, including both ends.
+    fn path(&self, from: InstanceId, to: InstanceId) -> Option<Vec<InstanceId>> {
+        let mut visited = HashSet::new();
+        let mut stack = vec![vec![from]];
+        while let Some(path) = stack.pop() {
+            let last = *path.last().unwrap();
+            if last == to {
+                return Some(path);
+            }
+
+            if !visited.insert(last) {
+                continue;
+            }
+
+            for c in self.connections.iter().filter(|c| c.source == last) {
+                let mut next = path.clone();
+                next.push(c.target);
+                stack.push(next);
+            }
+        }
+
+        None
+    }
+
+    /// Analyzes the imports and reachability of the graph for encoding.
+    ///
+    /// An instance is reachable if it is one of the given exported instances or
+    /// if a reachable instance is connected to it, directly or indirectly.
+    pub fn analyze(&self, exports: &[InstanceId]) -> Result<Analysis> {
+        let mut reachable = HashSet::new();
+        let mut stack = Vec::new();
+        for id in exports {
+            self.instance(*id)?;
+            stack.push(*id);
+        }
+
+        while let Some(id) = stack.pop() {
+            if !reachable.insert(id) {
+                continue;
+            }
+
+            stack.extend(
+                self.connections
+                    .iter()
+                    .filter(|c| c.target == id)
+                    .map(|c| c.source),
+            );
+        }
+
+        let mut unconnected = Vec::new();
+        let mut names: HashMap<&str, Vec<InstanceId>> = HashMap::new();
+        for (id, component) in self.instances() {
+            let imports = self
+                .component(component)
+                .unwrap()
+                .imports()
+                .filter(|(index, _, _)| {
+                    !self
+                        .connections
+                        .iter()
+                        .any(|c| c.target == id && c.target_import == *index)
+                })
+                .map(|(index, name, _)| {
+                    names.entry(name).or_default().push(id);
+                    (index, name)
+                })
+                .collect::<Vec<_>>();
+
+            if !imports.is_empty() {
+                unconnected.push((id, imports));
+            }
+        }
+
+        Ok(Analysis {
+            imports: unconnected
+                .into_iter()
+                .map(|(id, imports)| {
+                    (
+                        id,
+                        imports
+                            .into_iter()
+                            .map(|(import, name)| UnconnectedImport {
+                                import,
+                                name: name.to_string(),
+                                merged_with: names[name]
+                                    .iter()
+                                    .copied()
+                                    .filter(|i| *i != id)
+                                    .collect(),
+                            })
+                            .collect(),
+                    )
+                })
+                .collect(),
+            unreachable: self
+                .instances
+                .keys()
+                .copied()
+                .filter(|id| !reachable.contains(id))
+                .collect(),
+        })
+    }
+
+    /// Gets the connections between instances in the graph.
+    pub fn connections(&self) -> &[Connection] {
+        &self.connections
+    }
+
+    /// Gets the satisfied imports of an instance and the instances satisfying them.
+    ///
+    /// The instantiation arguments in the composition graph are walked, with
+    /// aliased exports resolved to the instance they are exported from.
+    pub fn arguments(&self, id: InstanceId) -> Result<Vec<(&str, InstanceId)>> {
+        let node = self.instance(id)?.node;
+        Ok(self
+            .graph
+            .get_instantiation_arguments(node)
+            .filter_map(|(name, source)| {
+                let source = self
+                    .graph
+                    .get_alias_source(source)
+                    .map(|(source, _)| source)
+                    .unwrap_or(source);
+
+                self.instances
+                    .iter()
+                    .find(|(_, i)| i.node == source)
+                    .map(|(id, _)| (name, *id))
+            })
+            .collect())
+    }
+
+    /// Disconnects an import of a target instance from the source instance.
+    ///
+    /// If the instances are not connected via the import, this is a no-op.
+    pub fn disconnect(
+        &mut self,
+        source: InstanceId,
+        target: InstanceId,
+        target_import: u32,
+    ) -> Result<()> {
+        self.instance(source)?;
+        self.instance(target)?;
+
+        let Some(index) = self.connections.iter().position(|c| {
+            c.source == source && c.target == target && c.target_import == target_import
+        }) else {
+            return Ok(());
+        };
+
+        let connection = self.connections.remove(index);
+        let (import, _) = self
+            .component(self.instances[&target].component)
+            .unwrap()
+            .import(target_import)
+            .unwrap();
+        let import = import.to_string();
+        let source_node = self.instances[&source].node;
+        let argument = match connection.source_export {
+            Some(export) => {
+                let (export, _) = self
+                    .component(self.instances[&source].component)
+                    .unwrap()
+                    .export(export)
+                    .unwrap();
+                let export = export.to_string();
+                self.graph
+                    .alias_instance_export(source_node, &export)
+                    .map_err(|e| Error::InvalidOperation(format!("{e:#}")))?
+            }
+            None => source_node,
+        };
+
+        self.graph
+            .unset_instantiation_argument(self.instances[&target].node, &import, argument)
+            .map_err(|e| Error::InvalidOperation(format!("{e:#}")))?;
+        self.remove_unused_alias(&connection, argument);
+        Ok(())
+    }
+
+    /// Removes the alias used as the argument of a connection if no other connection uses it.
+    fn remove_unused_alias(&mut self, connection: &Connection, argument: NodeId) {
+        if connection.source_export.is_some()
+            && !self.connections.iter().any(|c| {
+                c.source == connection.source && c.source_export == connection.source_export
+            })
+        {
+            self.graph.remove_node(argument);
+        }
+    }
+
+    /// Encodes the graph as a new component.
+    ///
+    /// If `export` is given, every export of the instance is exported from the
+    /// encoded graph; each item is exported under its given name.
+    pub fn encode(
+        &self,
+        define_components: bool,
+        export: Option<InstanceId>,
+        exports: &[ExportItem],
+        validate: bool,
+    ) -> Result<Vec<u8>> {
+        // Exports and names are only applied to a copy of the graph
+        let mut graph = self.graph.clone();
+
+        for entry in self.instances.values() {
+            if let Some(name) = &entry.name {
+                graph.set_node_name(entry.node, name);
+            }
+
+            if !define_components {
+                let component = self.component(entry.component).unwrap();
+                if !is_package_name(component.package_name()) {
+                    return Err(Error::Encode(format!(
+                        "component `{name}` cannot be imported as its package name `{package}` \
+                         is not of the form `namespace:name`",
+                        name = component.name(),
+                        package = component.package_name()
+                    )));
+                }
+            }
+        }
+
+        let mut items = Vec::new();
+        if let Some(id) = export {
+            let instance = self.instance(id)?;
+            for (index, name, _) in self.component(instance.component).unwrap().exports() {
+                items.push(ExportItem {
+                    instance: id,
+                    export: Some(index),
+                    name: name.to_string(),
+                });
+            }
+        }
+
+        for item in items.iter().chain(exports) {
+            let instance = self.instance(item.instance)?;
+            let node = match item.export {
+                Some(index) => {
+                    let (name, _) = self
+                        .component(instance.component)
+                        .unwrap()
+                        .export(index)
+                        .ok_or_else(|| {
+                            Error::InvalidOperation(format!("the export index {index} is invalid"))
+                        })?;
+                    graph
+                        .alias_instance_export(instance.node, name)
+                        .map_err(|e| Error::InvalidOperation(format!("{e:#}")))?
+                }
+                None => instance.node,
+            };
+
+            // The composition graph exports each node under a single name
+            if let Some(existing) = graph[node].export_name() {
+                return Err(Error::InvalidOperation(format!(
+                    "cannot export `{name}` as the same item is already exported as `{existing}`",
+                    name = item.name
+                )));
+            }
+
+            graph
+                .export(node, item.name.clone())
+                .map_err(|e: ExportError| Error::InvalidOperation(format!("{e:#}")))?;
+        }
+
+        graph
+            .encode(EncodeOptions {
+                define_components,
+                validate,
+                processor: None,
+            })
+            .map_err(|e| Error::Encode(format!("{e:#}")))
+    }
+
+    /// Determines which connections would break if a component were replaced.
+    ///
+    /// The connections of the instances of component `from` are made again, on
+    /// a copy of the graph, to instances of component `to` by import and export
+    /// name; imports of `from` that `to` no longer has are not needed.
+    ///
+    /// Returns each connection that could not be made and why.
+    pub fn replacement_failures(
+        &self,
+        from: ComponentId,
+        to: ComponentId,
+    ) -> Result<Vec<(Connection, Error)>> {
+        let old = self.component(from).ok_or(Error::UnknownComponent(from))?;
+        let new = self.component(to).ok_or(Error::UnknownComponent(to))?;
+
+        let affected = self
+            .connections
+            .iter()
+            .copied()
+            .filter(|c| {
+                self.instances[&c.source].component == from
+                    || self.instances[&c.target].component == from
+            })
+            .collect::<Vec<_>>();
+
+        let mut state = self.clone();
+        for connection in &affected {
+            state.disconnect(
+                connection.source,
+                connection.target,
+                connection.target_import,
+            )?;
+        }
+
+        let mut replacements = HashMap::new();
+        for (id, entry) in &self.instances {
+            if entry.component == from {
+                replacements.insert(*id, state.instantiate(to)?);
+            }
+        }
+
+        let mut failures = Vec::new();
+        for connection in affected {
+            let mut replaced = connection;
+            if let Some(instance) = replacements.get(&connection.source) {
+                replaced.source = *instance;
+                if let Some(index) = connection.source_export {
+                    let (name, _) = old.export(index).unwrap();
+                    match new.export_by_name(name) {
+                        Some((index, _)) => replaced.source_export = Some(index),
+                        None => {
+                            failures.push((
+                                connection,
+                                Error::InvalidOperation(format!(
+                                    "export `{name}` is not exported by the replacement"
+                                )),
+                            ));
+                            continue;
+                        }
+                    }
+                }
+            }
+
+            if let Some(instance) = replacements.get(&connection.target) {
+                replaced.target = *instance;
+                let (name, _) = old.import(connection.target_import).unwrap();
+                match new.import_by_name(name) {
+                    Some((index, _)) => replaced.target_import = index,
+                    None => continue,
+                }
+            }
+
+            if let Err(e) = state.connect(replaced) {
+                failures.push((connection, e));
+            }
+        }
+
+        Ok(failures)
+    }
+
+    /// Finds the components defined in a graph encoded with `define_components`.
+    ///
+    /// Returns the byte range of each component definition in the encoded
+    /// graph, which attributes offsets in the encoded graph, such as those of a
+    /// trap, to components of the graph.
+    pub fn component_ranges(&self, encoded: &[u8]) -> Result<Vec<(Range<usize>, ComponentId)>> {
+        let error = |e: BinaryReaderError| Error::Parse {
+            message: format!("failed to parse the encoded graph: {e}"),
+            offset: Some(e.offset() as u64),
+        };
+
+        let mut ranges = Vec::new();
+        let mut parser = Parser::new(0);
+        let mut offset = 0;
+        loop {
+            let Chunk::Parsed { consumed, payload } =
+                parser.parse(&encoded[offset..], true).map_err(error)?
+            else {
+                unreachable!("the encoded graph is complete");
+            };
+            offset += consumed;
+
+            match payload {
+                Payload::ComponentSection {
+                    unchecked_range, ..
+                } => {
+                    // Components with identical bytes are defined in order
+                    let digest = digest(&encoded[unchecked_range.clone()]);
+                    let id = self
+                        .components
+                        .iter()
+                        .filter(|(_, e)| e.digest == digest)
+                        .map(|(id, _)| *id)
+                        .find(|id| !ranges.iter().any(|(_, r)| r == id));
+                    if let Some(id) = id {
+                        ranges.push((unchecked_range.clone(), id));
+                    }
+
+                    // Nested components and modules are skipped over
+                    offset += unchecked_range.len();
+                }
+                Payload::ModuleSection {
+                    unchecked_range, ..
+                } => {
+                    offset += unchecked_range.len();
+                }
+                Payload::End(_) => break,
+                _ => {}
+            }
+        }
+
+        Ok(ranges)
+    }
+
+    /// Converts the graph into a project.
+    pub fn to_project(
+        &self,
+        export: Option<InstanceId>,
+        exports: &[ExportItem],
+    ) -> Result<Project> {
+        if let Some(export) = export {
+            self.instance(export)?;
+        }
+
+        for item in exports {
+            self.export_item(item)?;
+        }
+
+        let mut project = Project::new();
+        project.components = self
+            .components()
+            .map(|(id, component)| ProjectComponent {
+                id,
+                name: component.name().to_string(),
+                version: component.version().map(ToString::to_string),
+                bytes: component.bytes().to_vec(),
+            })
+            .collect();
+        project.instances = self
+            .instances
+            .iter()
+            .map(|(id, i)| ProjectInstance {
+                id: *id,
+                component: i.component,
+                name: i.name.clone(),
+            })
+            .collect();
+        project.connections = self
+            .connections
+            .iter()
+            .map(|c| ProjectConnection {
+                source: c.source,
+                source_export: c.source_export,
+                target: c.target,
+                target_import: c.target_import,
+            })
+            .collect();
+        project.export = export;
+        project.exports = exports.to_vec();
+        Ok(project)
+    }
+
+    /// Creates a graph from a project.
+    pub fn from_project(project: Project) -> Result<Self> {
+        let mut state = Self::default();
+
+        for component in project.components {
+            let version = component
+                .version
+                .as_deref()
+                .map(parse_version)
+                .transpose()?;
+            state.insert_component(component.id, component.name, version, component.bytes)?;
+        }
+
+        for instance in project.instances {
+            state.insert_instance(instance.id, instance.component)?;
+            state.set_instance_name(instance.id, instance.name)?;
+        }
+
+        for c in project.connections {
+            state.connect(Connection {
+                source: c.source,
+                source_export: c.source_export,
+                target: c.target,
+                target_import: c.target_import,
+            })?;
+        }
+
+        if let Some(export) = project.export {
+            state.instance(export)?;
+        }
+
+        for item in &project.exports {
+            state.export_item(item)?;
+        }
+
+        Ok(state)
+    }
+
+    /// Checks that the instance and export of an export item are in the graph.
+    fn export_item(&self, item: &ExportItem) -> Result<()> {
+        let instance = self.instance(item.instance)?;
+        if let Some(index) = item.export {
+            self.component(instance.component)
+                .unwrap()
+                .export(index)
+                .ok_or_else(|| {
+                    Error::InvalidOperation(format!("the export index {index} is invalid"))
+                })?;
+        }
+
+        Ok(())
+    }
+
+    fn package_id(&self, id: ComponentId) -> Result<PackageId> {
+        self.components
+            .get(&id)
+            .map(|e| e.package)
+            .ok_or(Error::UnknownComponent(id))
+    }
+
+    fn instance(&self, id: InstanceId) -> Result<&InstanceEntry> {
+        self.instances.get(&id).ok_or(Error::UnknownInstance(id))
+    }
+}
+
+/// Parses the version of a component.
+pub fn parse_version(version: &str) -> Result<Version> {
+    Version::parse(version)
+        .map_err(|e| Error::InvalidOperation(format!("invalid version `{version}`: {e}")))
+}
+
+/// Computes the digest of a component's bytes.
+fn digest(bytes: &[u8]) -> String {
+    format!("sha256:{:x}", Sha256::digest(bytes))
+}
+
+/// Determines if a name is a package name of the form `namespace:name`.
+fn is_package_name(name: &str) -> bool {
+    match name.split_once(':') {
+        Some((namespace, name)) => {
+            KebabStr::new(namespace).is_some() && KebabStr::new(name).is_some()
+        }
+        None => false,
+    }
+}
+
+/// Derives the name of the package a component with the given name is registered as.
+///
+/// Names that are neither package names nor kebab-case, such as file names,
+/// are converted to kebab-case.
+fn package_name(name: &str) -> String {
+    if is_package_name(name) || KebabStr::new(name).is_some() {
+        return name.to_string();
+    }
+
+    let mut package = String::new();
+    for word in name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+    {
+        // Words must start with a letter, so words starting with a digit are appended to the last
+        if word.starts_with(|c: char| c.is_ascii_digit()) {
+            if package.is_empty() {
+                package.push_str("component");
+            }
+        } else if !package.is_empty() {
+            package.push('-');
+        }
+
+        package.push_str(&word.to_ascii_lowercase());
+    }
+
+    if package.is_empty() {
+        package.push_str("component");
+    }
+
+    package
+}
+
+/// Parses the given bytes as a WebAssembly component.
+///
+/// The bytes may be in either the binary or text format; the binary is returned.
+/// Core modules are converted into components, using the given adapter if any.
+///
+/// This only checks that the bytes are well-formed; validation is left to the composition graph.
+fn parse(bytes: Vec<u8>, adapter: Option<Adapter>) -> Result<Vec<u8>> {
+    let bytes = match wat::parse_bytes(&bytes) {
+        Ok(Cow::Borrowed(_)) => bytes,
+        Ok(Cow::Owned(bytes)) => bytes,
+        Err(e) => {
+            return Err(Error::Parse {
+                message: format!("failed to parse component: {e}"),
+                offset: None,
+            })
+        }
+    };
+
+    for (i, payload) in Parser::new(0).parse_all(&bytes).enumerate() {
+        let payload = payload.map_err(|e| Error::Parse {
+            message: format!("failed to parse component: {e}"),
+            offset: Some(e.offset() as u64),
+        })?;
+
+        // Only the outermost version matters as components may contain core modules
+        if let (
+            0,
+            Payload::Version {
+                encoding: Encoding::Module,
+                ..
+            },
+        ) = (i, payload)
+        {
+            return componentize::componentize(&bytes, adapter);
+        }
+    }
+
+    Ok(bytes)
+}
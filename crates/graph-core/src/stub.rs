@@ -0,0 +1,332 @@
+//! Module for generating stub components from WIT.
+//!
+//! A stub component imports and exports everything in a world, but its exports
+//! either trap, return default values or return errors when called.
+use crate::componentize::componentize;
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use wit_component::StringEncoding;
+use wit_parser::abi::{WasmSignature, WasmType};
+use wit_parser::{
+    Function, ManglingAndAbi, Resolve, SizeAlign, Type, TypeDefKind, WasmExport, WasmExportKind,
+    WasmImport, WorldId, WorldItem, WorldKey,
+};
+
+/// The name mangling used for the generated core module.
+pub(crate) const MANGLING: ManglingAndAbi = ManglingAndAbi::Standard32;
+
+/// Represents the behavior of the exports of a stub component.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Behavior {
+    /// Exported functions trap when called.
+    #[default]
+    Trap,
+    /// Exported functions return the default value of their result type.
+    ///
+    /// The default value is the value whose canonical ABI representation is all
+    /// zeros: `false`, `0`, empty strings and lists, `none`, the first case of
+    /// variants and enums, and so on. Resources have no default value, so
+    /// functions returning them trap.
+    Defaults,
+    /// Exported functions returning a `result` return its `err` case with the
+    /// default value of the error type; other functions trap.
+    Errors,
+}
+
+/// Generates a stub component for a world of the given WIT document.
+pub fn generate(wit: &str, world: &str, behavior: Behavior) -> Result<Vec<u8>> {
+    let mut resolve = Resolve::default();
+    let package = resolve
+        .push_str("stub.wit", wit)
+        .map_err(|e| Error::Wit(format!("{e:#}")))?;
+    let world = resolve
+        .select_world(package, Some(world))
+        .map_err(|e| Error::Wit(format!("{e:#}")))?;
+
+    encode(&resolve, world, behavior)
+}
+
+/// Encodes a stub component for the given world.
+pub fn encode(resolve: &Resolve, world: WorldId, behavior: Behavior) -> Result<Vec<u8>> {
+    let mut module = Generator::new(resolve, behavior).generate(world)?;
+    wit_component::embed_component_metadata(&mut module, resolve, world, StringEncoding::UTF8)
+        .map_err(|e| Error::Wit(format!("{e:#}")))?;
+
+    componentize(&module, None)
+}
+
+/// Generates the core module of a stub component.
+struct Generator<'a> {
+    resolve: &'a Resolve,
+    behavior: Behavior,
+    sizes: SizeAlign,
+    wat: String,
+    /// The size of the zeroed area at the start of memory that is returned for
+    /// results passed indirectly.
+    results_size: usize,
+    /// The size of the area, following the zeroed area, that is returned for
+    /// `err` results passed indirectly.
+    errors_size: usize,
+}
+
+impl<'a> Generator<'a> {
+    fn new(resolve: &'a Resolve, behavior: Behavior) -> Self {
+        let mut sizes = SizeAlign::default();
+        sizes.fill(resolve);
+
+        Self {
+            resolve,
+            behavior,
+            sizes,
+            wat: String::new(),
+            results_size: 0,
+            errors_size: 0,
+        }
+    }
+
+    fn generate(mut self, world: WorldId) -> Result<Vec<u8>> {
+        let resolve = self.resolve;
+        let world = &resolve.worlds[world];
+
+        self.wat.push_str("(module\n");
+
+        for (name, item) in &world.imports {
+            match item {
+                WorldItem::Function(func) => self.import(None, func),
+                WorldItem::Interface { id, .. } => {
+                    for func in resolve.interfaces[*id].functions.values() {
+                        self.import(Some(name), func);
+                    }
+                }
+                WorldItem::Type(_) => {}
+            }
+        }
+
+        for (name, item) in &world.exports {
+            match item {
+                WorldItem::Function(func) => self.export(None, func),
+                WorldItem::Interface { id, .. } => {
+                    let interface = &resolve.interfaces[*id];
+                    for func in interface.functions.values() {
+                        self.export(Some(name), func);
+                    }
+
+                    for ty in interface.types.values() {
+                        if let TypeDefKind::Resource = resolve.types[*ty].kind {
+                            let dtor = resolve.wasm_export_name(
+                                MANGLING,
+                                WasmExport::ResourceDtor {
+                                    interface: name,
+                                    resource: *ty,
+                                },
+                            );
+                            writeln!(self.wat, "(func (export {dtor:?}) (param i32))").unwrap();
+                        }
+                    }
+                }
+                WorldItem::Type(_) => {}
+            }
+        }
+
+        // The errors area is a result discriminant of 1 (`err`) followed by zeros
+        let errors = self.results_size.next_multiple_of(8);
+        writeln!(self.wat, "(global $errors i32 (i32.const {errors}))").unwrap();
+        if self.errors_size > 0 {
+            writeln!(self.wat, "(data (i32.const {errors}) \"\\01\")").unwrap();
+        }
+
+        // The heap used by `cabi_realloc` starts after the results areas
+        push_memory(&mut self.wat, resolve, errors + self.errors_size);
+        self.wat.push_str(")\n");
+
+        wat::parse_str(&self.wat).map_err(|e| Error::Validation {
+            message: format!("failed to generate the stub module: {e}"),
+            offset: None,
+        })
+    }
+
+    fn import(&mut self, interface: Option<&WorldKey>, func: &Function) {
+        let sig = self.resolve.wasm_signature(MANGLING.import_variant(), func);
+        let (module, name) = self
+            .resolve
+            .wasm_import_name(MANGLING, WasmImport::Func { interface, func });
+        write!(self.wat, "(import {module:?} {name:?} (func").unwrap();
+        push_types(&mut self.wat, "param", &sig.params);
+        push_types(&mut self.wat, "result", &sig.results);
+        self.wat.push_str("))\n");
+    }
+
+    fn export(&mut self, interface: Option<&WorldKey>, func: &Function) {
+        let sig = self.resolve.wasm_signature(MANGLING.export_variant(), func);
+        let name = self.resolve.wasm_export_name(
+            MANGLING,
+            WasmExport::Func {
+                interface,
+                func,
+                kind: WasmExportKind::Normal,
+            },
+        );
+        write!(self.wat, "(func (export {name:?})").unwrap();
+        push_types(&mut self.wat, "param", &sig.params);
+        push_types(&mut self.wat, "result", &sig.results);
+        self.wat.push(' ');
+        self.body(func, &sig);
+        self.wat.push_str(")\n");
+    }
+
+    fn body(&mut self, func: &Function, sig: &WasmSignature) {
+        let result = func.result.as_ref();
+        let size = result
+            .map(|ty| self.sizes.size(ty).size_wasm32())
+            .unwrap_or(0);
+
+        match self.behavior {
+            Behavior::Defaults if !result.is_some_and(|ty| contains_handle(self.resolve, ty)) => {
+                if sig.retptr {
+                    // Return a pointer to the zeroed area at the start of memory
+                    self.results_size = self.results_size.max(size);
+                    self.wat.push_str("i32.const 0");
+                    return;
+                }
+
+                for ty in &sig.results {
+                    self.wat.push_str(match ty {
+                        WasmType::I32 | WasmType::Pointer | WasmType::Length => "i32.const 0 ",
+                        WasmType::I64 | WasmType::PointerOrI64 => "i64.const 0 ",
+                        WasmType::F32 => "f32.const 0 ",
+                        WasmType::F64 => "f64.const 0 ",
+                    });
+                }
+            }
+            Behavior::Errors
+                if result
+                    .and_then(|ty| error_type(self.resolve, ty))
+                    .is_some_and(|err| {
+                        !err.is_some_and(|ty| contains_handle(self.resolve, ty))
+                    }) =>
+            {
+                if sig.retptr {
+                    // Return a pointer to the errors area
+                    self.errors_size = self.errors_size.max(size);
+                    self.wat.push_str("global.get $errors");
+                    return;
+                }
+
+                // The only flat result is the discriminant
+                self.wat.push_str("i32.const 1");
+            }
+            _ => self.wat.push_str("unreachable"),
+        }
+    }
+}
+
+/// Determines if a type contains a resource handle.
+fn contains_handle(resolve: &Resolve, ty: &Type) -> bool {
+    let Type::Id(id) = ty else {
+        return false;
+    };
+
+    let types: Vec<&Type> = match &resolve.types[*id].kind {
+        TypeDefKind::Handle(_) => return true,
+        TypeDefKind::Type(ty) | TypeDefKind::List(ty) | TypeDefKind::Option(ty) => vec![ty],
+        TypeDefKind::Record(r) => r.fields.iter().map(|f| &f.ty).collect(),
+        TypeDefKind::Tuple(t) => t.types.iter().collect(),
+        TypeDefKind::Variant(v) => v.cases.iter().filter_map(|c| c.ty.as_ref()).collect(),
+        TypeDefKind::Result(r) => r.ok.iter().chain(r.err.iter()).collect(),
+        _ => Vec::new(),
+    };
+
+    types.into_iter().any(|ty| contains_handle(resolve, ty))
+}
+
+/// Gets the error type of a `result` type.
+///
+/// Returns `None` if the type is not a `result` and `Some(None)` if the
+/// `result` has no error type.
+fn error_type<'a>(resolve: &'a Resolve, ty: &'a Type) -> Option<Option<&'a Type>> {
+    let Type::Id(id) = ty else {
+        return None;
+    };
+
+    match &resolve.types[*id].kind {
+        TypeDefKind::Type(ty) => error_type(resolve, ty),
+        TypeDefKind::Result(r) => Some(r.err.as_ref()),
+        _ => None,
+    }
+}
+
+/// Writes the memory, `cabi_realloc` and initialization function of a module.
+///
+/// Memory is allocated with a bump allocator starting at the given offset;
+/// memory before the offset is reserved for static data.
+pub(crate) fn push_memory(wat: &mut String, resolve: &Resolve, heap: usize) {
+    let heap = heap.next_multiple_of(8);
+    let pages = heap.div_ceil(65536).max(1);
+    let memory = resolve.wasm_export_name(MANGLING, WasmExport::Memory);
+    let realloc = resolve.wasm_export_name(MANGLING, WasmExport::Realloc);
+    let initialize = resolve.wasm_export_name(MANGLING, WasmExport::Initialize);
+    write!(
+        wat,
+        r#"(memory (export {memory:?}) {pages})
+(global $heap (mut i32) (i32.const {heap}))
+(func (export {realloc:?}) (param i32 i32 i32 i32) (result i32)
+  (local $ptr i32)
+  global.get $heap
+  local.get 2
+  i32.add
+  i32.const 1
+  i32.sub
+  i32.const 0
+  local.get 2
+  i32.sub
+  i32.and
+  local.tee $ptr
+  local.get 3
+  i32.add
+  global.set $heap
+  block
+    global.get $heap
+    memory.size
+    i32.const 16
+    i32.shl
+    i32.le_u
+    br_if 0
+    global.get $heap
+    i32.const 65535
+    i32.add
+    i32.const 16
+    i32.shr_u
+    memory.size
+    i32.sub
+    memory.grow
+    i32.const -1
+    i32.ne
+    br_if 0
+    unreachable
+  end
+  local.get $ptr)
+(func (export {initialize:?}))
+"#
+    )
+    .unwrap();
+}
+
+/// Writes a list of core value types to the given WAT.
+fn push_types(wat: &mut String, desc: &str, types: &[WasmType]) {
+    if types.is_empty() {
+        return;
+    }
+
+    write!(wat, " ({desc}").unwrap();
+    for ty in types {
+        wat.push_str(match ty {
+            WasmType::I32 | WasmType::Pointer | WasmType::Length => " i32",
+            WasmType::I64 | WasmType::PointerOrI64 => " i64",
+            WasmType::F32 => " f32",
+            WasmType::F64 => " f64",
+        });
+    }
+    wat.push(')');
+}
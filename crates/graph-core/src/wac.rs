@@ -0,0 +1,645 @@
+//! Module for emitting and loading the graph as a WAC document.
+use crate::{
+    error::{Error, Result},
+    state::{AddOptions, ComponentId, Connection, ExportItem, InstanceId, State},
+};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Write,
+};
+use wac_parser::{
+    Document, ExportOptions, ExportStatement, Expr, InstantiationArgument, NewExpr, PostfixExpr,
+    PrimaryExpr, Statement,
+};
+use wasmparser::names::KebabStr;
+
+/// The keywords of the WAC language that cannot be used as bare identifiers.
+const KEYWORDS: &[&str] = &[
+    "as",
+    "bool",
+    "borrow",
+    "char",
+    "constructor",
+    "enum",
+    "export",
+    "f32",
+    "f64",
+    "flags",
+    "func",
+    "import",
+    "include",
+    "interface",
+    "let",
+    "list",
+    "new",
+    "option",
+    "package",
+    "record",
+    "resource",
+    "result",
+    "s16",
+    "s32",
+    "s64",
+    "s8",
+    "static",
+    "string",
+    "targets",
+    "tuple",
+    "type",
+    "u16",
+    "u32",
+    "u64",
+    "u8",
+    "use",
+    "variant",
+    "with",
+    "world",
+];
+
+/// Emits the graph as a WAC document for the given package.
+///
+/// Components are referenced by their package names, in the namespace of the
+/// document's package unless their package names already include a namespace.
+pub fn emit(
+    state: &State,
+    package: &str,
+    export: Option<InstanceId>,
+    exports: &[ExportItem],
+) -> Result<String> {
+    let Some((namespace, _)) = package.split_once(':') else {
+        return Err(Error::InvalidOperation(format!(
+            "invalid package name `{package}`: expected `namespace:name`"
+        )));
+    };
+
+    let names = variable_names(state);
+    let mut wac = String::new();
+    writeln!(wac, "package {package};").unwrap();
+
+    for id in instantiation_order(state) {
+        let component_id = state.instances().find(|(i, _)| *i == id).unwrap().1;
+        let component = state.component(component_id).unwrap();
+        let mut package = if component.package_name().contains(':') {
+            component.package_name().to_string()
+        } else {
+            format!("{namespace}:{name}", name = component.package_name())
+        };
+        if let Some(version) = component.version() {
+            write!(package, "@{version}").unwrap();
+        }
+
+        let mut args = Vec::new();
+        let mut unconnected = false;
+        for (index, name, _) in component.imports() {
+            match state
+                .connections()
+                .iter()
+                .find(|c| c.target == id && c.target_import == index)
+            {
+                Some(c) => {
+                    let source = &names[&c.source];
+                    let expr = match c.source_export {
+                        Some(export) => {
+                            let (_, source_component) =
+                                state.instances().find(|(i, _)| *i == c.source).unwrap();
+                            let (export, _) = state
+                                .component(source_component)
+                                .unwrap()
+                                .export(export)
+                                .unwrap();
+                            access(source, export)
+                        }
+                        None => source.clone(),
+                    };
+                    args.push(format!("{name}: {expr}", name = argument_name(name)));
+                }
+                None => unconnected = true,
+            }
+        }
+
+        // Unconnected imports become imports of the composed component
+        if unconnected {
+            args.push("...".to_string());
+        }
+
+        let name = &names[&id];
+        if args.is_empty() {
+            write!(wac, "\nlet {name} = new {package} {{}};\n").unwrap();
+        } else {
+            write!(wac, "\nlet {name} = new {package} {{\n").unwrap();
+            for arg in args {
+                writeln!(wac, "    {arg},").unwrap();
+            }
+            writeln!(wac, "}};").unwrap();
+        }
+    }
+
+    let mut statements = Vec::new();
+    if let Some(export) = export {
+        let name = names.get(&export).ok_or(Error::UnknownInstance(export))?;
+        statements.push(format!("export {name}...;"));
+    }
+
+    for item in exports {
+        let name = names
+            .get(&item.instance)
+            .ok_or(Error::UnknownInstance(item.instance))?;
+        let statement = match item.export {
+            Some(index) => {
+                let component_id = state
+                    .instances()
+                    .find(|(i, _)| *i == item.instance)
+                    .unwrap()
+                    .1;
+                let (export, _) = state
+                    .component(component_id)
+                    .unwrap()
+                    .export(index)
+                    .ok_or_else(|| {
+                        Error::InvalidOperation(format!("the export index {index} is invalid"))
+                    })?;
+
+                // Exports of an access expression are named after the export by default
+                if export == item.name {
+                    format!("export {expr};", expr = access(name, export))
+                } else {
+                    format!(
+                        "export {expr} as \"{export}\";",
+                        expr = access(name, export),
+                        export = item.name
+                    )
+                }
+            }
+            None => format!("export {name} as \"{export}\";", export = item.name),
+        };
+        statements.push(statement);
+    }
+
+    if !statements.is_empty() {
+        wac.push('\n');
+        for statement in statements {
+            writeln!(wac, "{statement}").unwrap();
+        }
+    }
+
+    Ok(wac)
+}
+
+/// Gets the instances of the graph ordered so that sources precede their targets.
+fn instantiation_order(state: &State) -> Vec<InstanceId> {
+    fn visit(
+        state: &State,
+        id: InstanceId,
+        visited: &mut HashSet<InstanceId>,
+        order: &mut Vec<InstanceId>,
+    ) {
+        if !visited.insert(id) {
+            return;
+        }
+
+        for c in state.connections().iter().filter(|c| c.target == id) {
+            visit(state, c.source, visited, order);
+        }
+
+        order.push(id);
+    }
+
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+    for (id, _) in state.instances() {
+        visit(state, id, &mut visited, &mut order);
+    }
+
+    order
+}
+
+/// Gets unique variable names for the instances of the graph.
+///
+/// Variables are named after the instance or, if it has no name, the
+/// instantiated component, with a numeric suffix for each additional instance
+/// with the same name.
+fn variable_names(state: &State) -> BTreeMap<InstanceId, String> {
+    let mut used = HashSet::new();
+    state
+        .instances()
+        .map(|(id, component)| {
+            let base = variable_base(
+                state
+                    .instance_name(id)
+                    .unwrap()
+                    .unwrap_or_else(|| state.component(component).unwrap().name()),
+            );
+            let mut name = base.clone();
+            let mut n = 2;
+            while !used.insert(name.clone()) {
+                name = format!("{base}{n}");
+                n += 1;
+            }
+
+            (id, identifier(&name))
+        })
+        .collect()
+}
+
+/// Converts a component name into a kebab-cased variable name.
+fn variable_base(name: &str) -> String {
+    // Use the last segment of a name such as `ns:pkg/name@1.0.0`
+    let name = name.split('@').next().unwrap();
+    let name = name.rsplit([':', '/']).next().unwrap();
+
+    let mut base = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            base.push(c.to_ascii_lowercase());
+        } else if !base.is_empty() && !base.ends_with('-') {
+            base.push('-');
+        }
+    }
+
+    let base = base.trim_end_matches('-');
+    match KebabStr::new(base) {
+        Some(_) => base.to_string(),
+        None => "instance".to_string(),
+    }
+}
+
+/// Gets the identifier for a name, escaping it if it is a keyword.
+fn identifier(name: &str) -> String {
+    if KEYWORDS.contains(&name) {
+        format!("%{name}")
+    } else {
+        name.to_string()
+    }
+}
+
+/// Gets the name of an instantiation argument, quoting it if it is not an identifier.
+fn argument_name(name: &str) -> String {
+    if KebabStr::new(name).is_some() {
+        identifier(name)
+    } else {
+        format!("\"{name}\"")
+    }
+}
+
+/// Gets the expression for accessing an export of an instance.
+fn access(instance: &str, export: &str) -> String {
+    if KebabStr::new(export).is_some() {
+        format!("{instance}.{export}", export = identifier(export))
+    } else {
+        format!("{instance}[\"{export}\"]")
+    }
+}
+
+/// Loads a graph from a WAC document.
+///
+/// The bytes of each package instantiated by the document are looked up by
+/// name, with or without its version; components keep the package names they
+/// are instantiated by.
+///
+/// Returns the graph, the instance whose exports are spread-exported by the
+/// document, if any, and the other items the document exports.
+pub fn load(
+    source: &str,
+    packages: Vec<(String, Vec<u8>)>,
+) -> Result<(State, Option<InstanceId>, Vec<ExportItem>)> {
+    let document = Document::parse(source).map_err(|e| {
+        let span = match &e {
+            wac_parser::Error::Lexer { span, .. }
+            | wac_parser::Error::Expected { span, .. }
+            | wac_parser::Error::ExpectedEither { span, .. }
+            | wac_parser::Error::ExpectedMultiple { span, .. }
+            | wac_parser::Error::EmptyType { span, .. }
+            | wac_parser::Error::InvalidVersion { span, .. } => *span,
+        };
+        error(source, span.offset(), e.to_string())
+    })?;
+
+    let mut loader = Loader {
+        source,
+        packages: packages.into_iter().collect(),
+        state: State::default(),
+        components: HashMap::new(),
+        variables: HashMap::new(),
+        export: None,
+        exports: Vec::new(),
+    };
+
+    for statement in &document.statements {
+        match statement {
+            Statement::Import(import) => {
+                loader.variables.insert(import.id.string, Value::Import);
+            }
+            Statement::Let(statement) => {
+                let value = loader.expr(&statement.expr)?;
+
+                // Instances are named after the first variable they are assigned to
+                if let Value::Instance(id) = value {
+                    if loader.state.instance_name(id)?.is_none() {
+                        loader
+                            .state
+                            .set_instance_name(id, Some(statement.id.string.to_string()))?;
+                    }
+                }
+
+                loader.variables.insert(statement.id.string, value);
+            }
+            Statement::Export(statement) => loader.export(statement)?,
+            // Types are checked by the graph when instances are connected
+            Statement::Type(_) => {}
+        }
+    }
+
+    Ok((loader.state, loader.export, loader.exports))
+}
+
+/// Creates an error at the given offset in a WAC document.
+fn error(source: &str, offset: usize, message: String) -> Error {
+    let offset = offset.min(source.len());
+    let prefix = &source[..offset];
+    let line = prefix.matches('\n').count() + 1;
+    let column = prefix[prefix.rfind('\n').map(|i| i + 1).unwrap_or(0)..]
+        .chars()
+        .count()
+        + 1;
+
+    Error::Wac {
+        message,
+        line: line as u32,
+        column: column as u32,
+    }
+}
+
+/// Represents the value of an expression in a WAC document.
+#[derive(Debug, Clone, Copy)]
+enum Value {
+    /// An instance in the graph.
+    Instance(InstanceId),
+    /// An export of an instance in the graph.
+    Export(InstanceId, u32),
+    /// An item imported by the document.
+    ///
+    /// Imported items are left as unconnected imports in the graph.
+    Import,
+}
+
+/// Used to load a graph from a WAC document.
+struct Loader<'a> {
+    source: &'a str,
+    packages: HashMap<String, Vec<u8>>,
+    state: State,
+    components: HashMap<&'a str, ComponentId>,
+    variables: HashMap<&'a str, Value>,
+    export: Option<InstanceId>,
+    exports: Vec<ExportItem>,
+}
+
+impl<'a> Loader<'a> {
+    fn expr(&mut self, expr: &Expr<'a>) -> Result<Value> {
+        let mut value = match &expr.primary {
+            PrimaryExpr::New(new) => Value::Instance(self.new_expr(new)?),
+            PrimaryExpr::Nested(nested) => self.expr(&nested.inner)?,
+            PrimaryExpr::Ident(id) => self.variable(id.string, id.span.offset())?,
+        };
+
+        for postfix in &expr.postfix {
+            let (name, span) = match postfix {
+                PostfixExpr::Access(access) => (access.id.string, access.span),
+                PostfixExpr::NamedAccess(access) => (access.string.value, access.span),
+            };
+
+            value = match value {
+                Value::Instance(id) => {
+                    let (_, component) = self.state.instances().find(|(i, _)| *i == id).unwrap();
+                    let (index, _) = self
+                        .state
+                        .component(component)
+                        .unwrap()
+                        .export_by_name(name)
+                        .ok_or_else(|| {
+                            error(
+                                self.source,
+                                span.offset(),
+                                format!("the instance has no export named `{name}`"),
+                            )
+                        })?;
+                    Value::Export(id, index)
+                }
+                Value::Export(..) => {
+                    return Err(error(
+                        self.source,
+                        span.offset(),
+                        "accessing the exports of an exported instance is not supported"
+                            .to_string(),
+                    ))
+                }
+                Value::Import => Value::Import,
+            };
+        }
+
+        Ok(value)
+    }
+
+    fn variable(&self, name: &str, offset: usize) -> Result<Value> {
+        self.variables
+            .get(name)
+            .copied()
+            .ok_or_else(|| error(self.source, offset, format!("undefined name `{name}`")))
+    }
+
+    fn new_expr(&mut self, new: &NewExpr<'a>) -> Result<InstanceId> {
+        let component = self.component(new)?;
+        let id = self.state.instantiate(component)?;
+
+        for argument in &new.arguments {
+            match argument {
+                InstantiationArgument::Named(argument) => {
+                    let value = self.expr(&argument.expr)?;
+                    self.connect(
+                        id,
+                        argument.name.as_str(),
+                        argument.name.span().offset(),
+                        value,
+                    )?;
+                }
+                InstantiationArgument::Inferred(name) => {
+                    let value = self.variable(name.string, name.span.offset())?;
+                    self.connect(id, name.string, name.span.offset(), value)?;
+                }
+                InstantiationArgument::Spread(name) => {
+                    let Value::Instance(source) = self.variable(name.string, name.span.offset())?
+                    else {
+                        return Err(error(
+                            self.source,
+                            name.span.offset(),
+                            format!("`{name}` is not an instance", name = name.string),
+                        ));
+                    };
+
+                    // Connect every unconnected import with the same name as an export of the instance
+                    let (_, source_component) =
+                        self.state.instances().find(|(i, _)| *i == source).unwrap();
+                    let target_component = self.state.component(component).unwrap();
+                    let source_component = self.state.component(source_component).unwrap();
+                    let connections = target_component
+                        .imports()
+                        .filter_map(|(import, name, _)| {
+                            let (export, _) = source_component.export_by_name(name)?;
+                            Some(Connection {
+                                source,
+                                source_export: Some(export),
+                                target: id,
+                                target_import: import,
+                            })
+                        })
+                        .filter(|c| {
+                            !self.state.connections().iter().any(|existing| {
+                                existing.target == id && existing.target_import == c.target_import
+                            })
+                        })
+                        .collect::<Vec<_>>();
+
+                    for connection in connections {
+                        self.state.connect(connection)?;
+                    }
+                }
+                // Unconnected imports are left as imports of the graph
+                InstantiationArgument::Fill(_) => {}
+            }
+        }
+
+        Ok(id)
+    }
+
+    /// Gets the component for an instantiated package, adding it to the graph if needed.
+    fn component(&mut self, new: &NewExpr<'a>) -> Result<ComponentId> {
+        let package = &new.package;
+        if let Some(id) = self.components.get(package.string) {
+            return Ok(*id);
+        }
+
+        let bytes = self
+            .packages
+            .get(package.string)
+            .or_else(|| self.packages.get(package.name))
+            .ok_or_else(|| {
+                error(
+                    self.source,
+                    package.span.offset(),
+                    format!("unknown package `{name}`", name = package.string),
+                )
+            })?
+            .clone();
+
+        let (id, _) = self.state.add_component(
+            package.name.to_string(),
+            package.version.clone(),
+            bytes,
+            AddOptions::default(),
+        )?;
+        self.components.insert(package.string, id);
+        Ok(id)
+    }
+
+    /// Records the items exported by an export statement.
+    ///
+    /// The first instance that is spread-exported becomes the exported
+    /// instance; the exports of any other are exported as separate items.
+    fn export(&mut self, statement: &ExportStatement<'a>) -> Result<()> {
+        let value = self.expr(&statement.expr)?;
+        let offset = statement.expr.span.offset();
+        let (instance, export) = match value {
+            Value::Instance(id) => (id, None),
+            Value::Export(id, index) => (id, Some(index)),
+            Value::Import => {
+                return Err(error(
+                    self.source,
+                    offset,
+                    "exporting an imported item is not supported".to_string(),
+                ))
+            }
+        };
+
+        let (_, component) = self
+            .state
+            .instances()
+            .find(|(i, _)| *i == instance)
+            .unwrap();
+        let component = self.state.component(component).unwrap();
+        match (&statement.options, export) {
+            (ExportOptions::Spread(_), None) if self.export.is_none() => {
+                self.export = Some(instance);
+            }
+            (ExportOptions::Spread(_), None) => {
+                self.exports
+                    .extend(component.exports().map(|(index, name, _)| ExportItem {
+                        instance,
+                        export: Some(index),
+                        name: name.to_string(),
+                    }));
+            }
+            (ExportOptions::Spread(span), Some(_)) => {
+                return Err(error(
+                    self.source,
+                    span.offset(),
+                    "only the exports of an instance can be spread".to_string(),
+                ))
+            }
+            (ExportOptions::Rename(name), export) => self.exports.push(ExportItem {
+                instance,
+                export,
+                name: name.as_str().to_string(),
+            }),
+            // Exports of an access expression are named after the export by default
+            (ExportOptions::None, Some(index)) => self.exports.push(ExportItem {
+                instance,
+                export: Some(index),
+                name: component.export(index).unwrap().0.to_string(),
+            }),
+            (ExportOptions::None, None) => {
+                return Err(error(
+                    self.source,
+                    offset,
+                    "an exported instance must be given a name with `as`".to_string(),
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Connects an argument of a new expression to the target instance.
+    fn connect(
+        &mut self,
+        target: InstanceId,
+        name: &str,
+        offset: usize,
+        value: Value,
+    ) -> Result<()> {
+        let (_, component) = self.state.instances().find(|(i, _)| *i == target).unwrap();
+        let (import, _) = self
+            .state
+            .component(component)
+            .unwrap()
+            .import_by_name(name)
+            .ok_or_else(|| {
+                error(
+                    self.source,
+                    offset,
+                    format!("the component has no import named `{name}`"),
+                )
+            })?;
+
+        let (source, source_export) = match value {
+            Value::Instance(id) => (id, None),
+            Value::Export(id, export) => (id, Some(export)),
+            Value::Import => return Ok(()),
+        };
+
+        self.state.connect(Connection {
+            source,
+            source_export,
+            target,
+            target_import: import,
+        })
+    }
+}
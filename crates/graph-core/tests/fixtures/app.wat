@@ -0,0 +1,18 @@
+;; Imports `example:greeter/greeter` and exports a `run` function that calls it.
+(component
+  (import "example:greeter/greeter" (instance $greeter
+    (export "greet" (func (result u32)))
+  ))
+  (core func $greet (canon lower (func $greeter "greet")))
+  (core module $m
+    (import "greeter" "greet" (func $greet (result i32)))
+    (func (export "run") (result i32)
+      call $greet
+      i32.const 1
+      i32.add))
+  (core instance $i (instantiate $m
+    (with "greeter" (instance (export "greet" (func $greet))))
+  ))
+  (func $run (result u32) (canon lift (core func $i "run")))
+  (export "run" (func $run))
+)
@@ -0,0 +1,10 @@
+;; Exports `example:greeter/greeter` with a `greet` function returning 42.
+(component
+  (core module $m
+    (func (export "greet") (result i32)
+      i32.const 42))
+  (core instance $i (instantiate $m))
+  (func $greet (result u32) (canon lift (core func $i "greet")))
+  (instance $greeter (export "greet" (func $greet)))
+  (export "example:greeter/greeter" (instance $greeter))
+)
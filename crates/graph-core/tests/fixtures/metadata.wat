@@ -0,0 +1,8 @@
+;; An empty component with `producers`, `component-name` and `registry-metadata` sections.
+(component $metadata
+  (@producers
+    (language "Rust" "1.80.0")
+    (processed-by "wit-component" "0.229.0")
+  )
+  (@custom "registry-metadata" "{\"authors\":[\"Ada\",\"Grace\"],\"description\":\"An example component.\",\"license\":\"Apache-2.0\",\"links\":[{\"ty\":\"Repository\",\"value\":\"https://example.com/repo\"},{\"ty\":\"Homepage\",\"value\":\"https://example.com\"}]}")
+)
@@ -0,0 +1,7 @@
+;; Imports `example:greeter/greeter` and exports it again.
+(component
+  (import "example:greeter/greeter" (instance $greeter
+    (export "greet" (func (result u32)))
+  ))
+  (export "example:greeter/greeter" (instance $greeter))
+)
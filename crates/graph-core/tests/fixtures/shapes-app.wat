@@ -0,0 +1,9 @@
+;; Imports the `distance` function of `example:shapes/shapes` without using it.
+(component
+  (type $shapes (instance
+    (type $point (record (field "x" u32) (field "y" u32)))
+    (export "point" (type $p (eq $point)))
+    (export "distance" (func (param "a" $p) (param "b" $p) (result u32)))
+  ))
+  (import "example:shapes/shapes" (instance (type $shapes)))
+)
@@ -0,0 +1,10 @@
+;; Exports `example:greeter/greeter` with a `greet` function of a different type.
+(component
+  (core module $m
+    (func (export "greet") (result i64)
+      i64.const 42))
+  (core instance $i (instantiate $m))
+  (func $greet (result u64) (canon lift (core func $i "greet")))
+  (instance $greeter (export "greet" (func $greet)))
+  (export "example:greeter/greeter" (instance $greeter))
+)
@@ -0,0 +1,1115 @@
+//! Integration tests for the composition graph over the fixture components.
+use graph_core::{
+    componentize::Adapter,
+    config::Values,
+    diagram::Format,
+    diff::DifferenceKind,
+    disassemble::Options,
+    error::Error,
+    metadata::Producer,
+    model::{Change, EncodeOptions, ItemKind},
+    state::{AddOptions, Connection, Duplicates, ExportItem, InstanceId},
+    stub::Behavior,
+    Graph,
+};
+use std::{fs, path::Path};
+use wit_component::DecodedWasm;
+use wit_parser::WorldKey;
+
+/// The WIT of the interface exported by the greeter fixtures.
+const GREETER_WIT: &str = r#"
+package example:greeter;
+
+interface greeter {
+    greet: func() -> u32;
+}
+
+world stub {
+    export greeter;
+}
+"#;
+
+/// The WIT of the shapes interface, with placeholders for the changes made by a
+/// new version.
+const SHAPES_WIT: &str = r#"
+package example:shapes;
+
+interface shapes {
+    record point { x: u32, y: u32 /* field */ }
+
+    resource shape {
+        constructor(origin: point);
+        area: func() -> u32;
+        /* method */
+    }
+
+    distance: func(a: point, b: point) -> /* result */ u32;
+}
+
+world stub {
+    export shapes;
+}
+"#;
+
+/// Reads a fixture component.
+fn fixture(name: &str) -> Vec<u8> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name);
+    fs::read(&path).unwrap_or_else(|e| panic!("failed to read `{}`: {e}", path.display()))
+}
+
+/// Adds a fixture component to the graph and instantiates it.
+fn instantiate(graph: &mut Graph, name: &str, fixture_name: &str) -> InstanceId {
+    let component = graph
+        .add_component(
+            name.to_string(),
+            fixture(fixture_name),
+            AddOptions::default(),
+        )
+        .unwrap();
+    graph.instantiate_component(component.id).unwrap()
+}
+
+/// Creates a graph with a greeter instance connected to an app instance.
+///
+/// Returns the graph and the ids of the greeter and app instances.
+fn composed() -> (Graph, InstanceId, InstanceId) {
+    let mut graph = Graph::new();
+    let greeter = instantiate(&mut graph, "example:greeter", "greeter.wat");
+    let app = instantiate(&mut graph, "example:app", "app.wat");
+    graph.connect_instances(greeter, Some(0), app, 0).unwrap();
+    (graph, greeter, app)
+}
+
+/// Gets the names of the imports and exports of the world of a component.
+fn world(bytes: &[u8]) -> (Vec<String>, Vec<String>) {
+    let DecodedWasm::Component(resolve, world) = wit_component::decode(bytes).unwrap() else {
+        panic!("expected a component");
+    };
+
+    let world = &resolve.worlds[world];
+    let names = |keys: Vec<&WorldKey>| {
+        keys.into_iter()
+            .map(|key| resolve.name_world_key(key))
+            .collect()
+    };
+    (
+        names(world.imports.keys().collect()),
+        names(world.exports.keys().collect()),
+    )
+}
+
+#[test]
+fn component_records_describe_imports_and_exports() {
+    let mut graph = Graph::new();
+    let app = graph
+        .add_component(
+            "example:app".to_string(),
+            fixture("app.wat"),
+            AddOptions::default(),
+        )
+        .unwrap();
+
+    assert_eq!(app.name, "example:app");
+    assert!(app.digest.starts_with("sha256:"));
+    assert!(app.wit.contains("greet: func() -> u32;"));
+
+    assert_eq!(app.imports.len(), 1);
+    assert_eq!(app.imports[0].name, "example:greeter/greeter");
+    assert_eq!(app.imports[0].kind, ItemKind::Instance);
+    assert_eq!(app.imports[0].members.len(), 1);
+    assert_eq!(app.imports[0].members[0].name, "greet");
+    assert_eq!(app.imports[0].members[0].signature, "func() -> u32");
+
+    assert_eq!(app.exports.len(), 1);
+    assert_eq!(app.exports[0].name, "run");
+    assert_eq!(app.exports[0].kind, ItemKind::Function);
+    assert_eq!(app.exports[0].signature, "func() -> u32");
+
+    assert_eq!(graph.component(app.id).unwrap(), app);
+    assert_eq!(
+        graph.component(app.id + 1),
+        Err(Error::UnknownComponent(app.id + 1))
+    );
+}
+
+#[test]
+fn composed_graph_encodes_to_a_valid_component() {
+    let (graph, _, app) = composed();
+    let bytes = graph
+        .encode_graph(&EncodeOptions {
+            export: Some(app),
+            ..Default::default()
+        })
+        .unwrap();
+
+    let (imports, exports) = world(&bytes);
+    assert!(imports.is_empty());
+    assert_eq!(exports, ["run"]);
+}
+
+#[test]
+fn component_ranges_locate_defined_components() {
+    let (graph, _, app) = composed();
+    let bytes = graph
+        .encode_graph(&EncodeOptions {
+            export: Some(app),
+            ..Default::default()
+        })
+        .unwrap();
+
+    let ranges = graph.state().component_ranges(&bytes).unwrap();
+    assert_eq!(ranges.len(), 2);
+    for (range, id) in ranges {
+        let component = graph.state().component(id).unwrap();
+        assert_eq!(&bytes[range], component.bytes());
+    }
+}
+
+#[test]
+fn unconnected_imports_are_analyzed() {
+    let mut graph = Graph::new();
+    let app = instantiate(&mut graph, "example:app", "app.wat");
+
+    let analysis = graph
+        .analyze(&EncodeOptions {
+            export: Some(app),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(analysis.imports.len(), 1);
+    assert_eq!(analysis.imports[0].0, app);
+    assert_eq!(analysis.imports[0].1[0].name, "example:greeter/greeter");
+    assert!(analysis.unreachable.is_empty());
+
+    // The unconnected import becomes an import of the encoded graph
+    let bytes = graph
+        .encode_graph(&EncodeOptions {
+            export: Some(app),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(world(&bytes).0, ["example:greeter/greeter"]);
+}
+
+#[test]
+fn mismatched_types_cannot_be_connected() {
+    let mut graph = Graph::new();
+    let greeter = instantiate(&mut graph, "example:greeter", "greeter.wat");
+    let wide = instantiate(&mut graph, "example:wide-greeter", "wide-greeter.wat");
+    let app = instantiate(&mut graph, "example:app", "app.wat");
+
+    let sources = graph.compatible_sources(app, 0).unwrap();
+    assert_eq!(
+        sources,
+        [Connection {
+            source: greeter,
+            source_export: Some(0),
+            target: app,
+            target_import: 0,
+        }]
+    );
+
+    match graph.connect_instances(wide, Some(0), app, 0) {
+        Err(Error::TypeMismatch { import, .. }) => assert_eq!(import, "example:greeter/greeter"),
+        result => panic!("expected a type mismatch, got {result:?}"),
+    }
+    assert!(graph.state().connections().is_empty());
+}
+
+#[test]
+fn compatible_targets_exclude_connected_imports() {
+    let mut graph = Graph::new();
+    let greeter = instantiate(&mut graph, "example:greeter", "greeter.wat");
+    let wide = instantiate(&mut graph, "example:wide-greeter", "wide-greeter.wat");
+    let app = instantiate(&mut graph, "example:app", "app.wat");
+    let other = graph.instantiate_component(2).unwrap();
+
+    let target = |target| Connection {
+        source: greeter,
+        source_export: Some(0),
+        target,
+        target_import: 0,
+    };
+    assert_eq!(
+        graph.compatible_targets(greeter, Some(0)).unwrap(),
+        [target(app), target(other)]
+    );
+
+    // An import that is already connected is not a target
+    graph.connect_instances(greeter, Some(0), app, 0).unwrap();
+    assert_eq!(
+        graph.compatible_targets(greeter, Some(0)).unwrap(),
+        [target(other)]
+    );
+
+    // Neither the instance itself nor a mismatched export can be connected
+    assert!(graph.compatible_targets(greeter, None).unwrap().is_empty());
+    assert!(graph.compatible_targets(wide, Some(0)).unwrap().is_empty());
+    assert_eq!(
+        graph.compatible_targets(other + 1, None),
+        Err(Error::UnknownInstance(other + 1))
+    );
+}
+
+#[test]
+fn connections_cannot_form_cycles() {
+    let mut graph = Graph::new();
+    let first = instantiate(&mut graph, "example:relay", "relay.wat");
+    let second = graph.instantiate_component(0).unwrap();
+    graph.connect_instances(first, Some(0), second, 0).unwrap();
+
+    match graph.connect_instances(second, Some(0), first, 0) {
+        Err(Error::Cycle { instances, .. }) => assert_eq!(instances, [first, second, first]),
+        result => panic!("expected a cycle, got {result:?}"),
+    }
+    assert_eq!(graph.state().connections().len(), 1);
+    assert!(graph.compatible_sources(first, 0).unwrap().is_empty());
+}
+
+#[test]
+fn removed_sources_can_be_replaced() {
+    let (mut graph, greeter, app) = composed();
+    graph.remove_instance(greeter);
+    assert!(graph.state().connections().is_empty());
+
+    // The import is no longer satisfied, so it becomes an import of the encoded graph
+    let options = EncodeOptions {
+        export: Some(app),
+        ..Default::default()
+    };
+    let bytes = graph.encode_graph(&options).unwrap();
+    assert_eq!(world(&bytes).0, ["example:greeter/greeter"]);
+
+    let greeter = graph.instantiate_component(0).unwrap();
+    graph.connect_instances(greeter, Some(0), app, 0).unwrap();
+    let bytes = graph.encode_graph(&options).unwrap();
+    assert!(world(&bytes).0.is_empty());
+
+    // Removing the source's component also removes its instances
+    graph.remove_component(0);
+    assert!(graph.state().connections().is_empty());
+    let bytes = graph.encode_graph(&options).unwrap();
+    assert_eq!(world(&bytes).0, ["example:greeter/greeter"]);
+}
+
+#[test]
+fn auto_connect_uses_exports_of_the_same_name() {
+    let mut graph = Graph::new();
+    let greeter = instantiate(&mut graph, "example:greeter", "greeter.wat");
+    let app = instantiate(&mut graph, "example:app", "app.wat");
+
+    let result = graph.auto_connect(app).unwrap();
+    assert_eq!(
+        result.connections,
+        [Connection {
+            source: greeter,
+            source_export: Some(0),
+            target: app,
+            target_import: 0,
+        }]
+    );
+    assert!(result.ambiguities.is_empty());
+    assert!(result.unresolved.is_empty());
+}
+
+#[test]
+fn undo_and_redo_restore_the_graph() {
+    let (mut graph, greeter, app) = composed();
+    let connection = graph.state().connections()[0];
+    assert!(graph.can_undo());
+    assert!(!graph.can_redo());
+
+    assert_eq!(graph.undo().unwrap(), [Change::Disconnect(connection)]);
+    assert!(graph.state().connections().is_empty());
+
+    assert_eq!(graph.redo().unwrap(), [Change::Connect(connection)]);
+    assert_eq!(graph.state().connections(), [connection]);
+
+    graph
+        .set_instance_name(greeter, Some("greeter".to_string()))
+        .unwrap();
+    graph.remove_instance(app);
+    assert!(graph.state().connections().is_empty());
+
+    let changes = graph.undo().unwrap();
+    assert!(matches!(&changes[..], [Change::AddInstance(i), Change::Connect(_)] if i.id == app));
+    assert_eq!(graph.state().connections(), [connection]);
+
+    match &graph.undo().unwrap()[..] {
+        [Change::RenameInstance(instance)] => assert_eq!(instance.name, None),
+        changes => panic!("unexpected changes {changes:?}"),
+    }
+}
+
+#[test]
+fn undo_and_redo_restore_removed_sources() {
+    let (mut graph, greeter, app) = composed();
+    let connection = graph.state().connections()[0];
+    let options = EncodeOptions {
+        export: Some(app),
+        ..Default::default()
+    };
+
+    graph.remove_instance(greeter);
+    let changes = graph.undo().unwrap();
+    assert!(
+        matches!(&changes[..], [Change::AddInstance(i), Change::Connect(_)] if i.id == greeter)
+    );
+    assert_eq!(graph.state().connections(), [connection]);
+    assert!(world(&graph.encode_graph(&options).unwrap()).0.is_empty());
+
+    assert_eq!(graph.redo().unwrap(), [Change::RemoveInstance(greeter)]);
+    assert!(graph.state().connections().is_empty());
+    graph.undo().unwrap();
+
+    let component = graph
+        .state()
+        .instances()
+        .find(|(i, _)| *i == greeter)
+        .unwrap()
+        .1;
+    graph.remove_component(component);
+    let changes = graph.undo().unwrap();
+    assert!(matches!(
+        &changes[..],
+        [Change::AddComponent(c), Change::AddInstance(i), Change::Connect(_)]
+            if c.id == component && i.id == greeter
+    ));
+    assert_eq!(graph.state().connections(), [connection]);
+    assert!(world(&graph.encode_graph(&options).unwrap()).0.is_empty());
+
+    assert_eq!(graph.redo().unwrap(), [Change::RemoveComponent(component)]);
+    assert!(graph.state().connections().is_empty());
+    let bytes = graph.encode_graph(&options).unwrap();
+    assert_eq!(world(&bytes).0, ["example:greeter/greeter"]);
+}
+
+#[test]
+fn undone_components_are_restored_on_redo() {
+    let mut graph = Graph::new();
+    let bytes = fixture("greeter.wat");
+    let component = graph
+        .add_component(
+            "example:greeter".to_string(),
+            bytes.clone(),
+            AddOptions::default(),
+        )
+        .unwrap();
+
+    assert_eq!(
+        graph.undo().unwrap(),
+        [Change::RemoveComponent(component.id)]
+    );
+    assert!(graph.state().component(component.id).is_none());
+
+    match &graph.redo().unwrap()[..] {
+        [Change::AddComponent(added)] => assert_eq!(**added, component),
+        changes => panic!("unexpected changes {changes:?}"),
+    }
+
+    // The component can be captured again after being restored
+    graph.undo().unwrap();
+    graph.redo().unwrap();
+    assert_eq!(graph.component(component.id).unwrap(), component);
+}
+
+#[test]
+fn projects_round_trip() {
+    let (mut graph, greeter, app) = composed();
+    graph
+        .set_instance_name(greeter, Some("greeter".to_string()))
+        .unwrap();
+
+    let options = EncodeOptions {
+        export: Some(app),
+        ..Default::default()
+    };
+    let bytes = graph.save_project(&options).unwrap();
+    let mut loaded = Graph::new();
+    let snapshot = loaded.load_project(&bytes).unwrap();
+
+    assert_eq!(snapshot, graph.snapshot(Some(app)));
+    assert!(!loaded.can_undo());
+
+    // Export items are saved and restored
+    let options = EncodeOptions {
+        export: None,
+        exports: vec![
+            ExportItem {
+                instance: app,
+                export: Some(0),
+                name: "run2".to_string(),
+            },
+            ExportItem {
+                instance: greeter,
+                export: None,
+                name: "greeter".to_string(),
+            },
+        ],
+        ..Default::default()
+    };
+    let bytes = graph.save_project(&options).unwrap();
+    let snapshot = Graph::new().load_project(&bytes).unwrap();
+    assert_eq!(snapshot.export, None);
+    assert_eq!(snapshot.exports, options.exports);
+
+    // Projects saved without export items can still be loaded
+    let mut project: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    project.as_object_mut().unwrap().remove("exports");
+    let snapshot = Graph::new()
+        .load_project(&serde_json::to_vec(&project).unwrap())
+        .unwrap();
+    assert!(snapshot.exports.is_empty());
+
+    assert!(matches!(
+        graph.save_project(&EncodeOptions {
+            exports: vec![ExportItem {
+                instance: app,
+                export: Some(1),
+                name: "missing".to_string(),
+            }],
+            ..Default::default()
+        }),
+        Err(Error::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        Graph::new().load_project(b"{}"),
+        Err(Error::InvalidProject(_))
+    ));
+}
+
+#[test]
+fn wac_documents_round_trip() {
+    let (graph, greeter, app) = composed();
+    let packages = || {
+        graph
+            .state()
+            .components()
+            .map(|(_, c)| (c.package_name().to_string(), c.bytes().to_vec()))
+            .collect::<Vec<_>>()
+    };
+
+    let options = EncodeOptions {
+        export: Some(app),
+        ..Default::default()
+    };
+    let source = graph.emit_wac("example:composition", &options).unwrap();
+
+    let mut loaded = Graph::new();
+    let snapshot = loaded.load_wac(&source, packages()).unwrap();
+    assert_eq!(snapshot.components.len(), 2);
+    assert_eq!(snapshot.instances.len(), 2);
+    assert_eq!(snapshot.connections.len(), 1);
+    assert!(snapshot.exports.is_empty());
+
+    let bytes = loaded
+        .encode_graph(&EncodeOptions {
+            export: snapshot.export,
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(world(&bytes), (Vec::new(), vec!["run".to_string()]));
+
+    // Export items are recreated and components keep their package names
+    let options = EncodeOptions {
+        define_components: false,
+        export: None,
+        exports: vec![
+            ExportItem {
+                instance: app,
+                export: Some(0),
+                name: "run2".to_string(),
+            },
+            ExportItem {
+                instance: greeter,
+                export: Some(0),
+                name: "example:greeter/greeter".to_string(),
+            },
+        ],
+        validate: true,
+    };
+    let source = graph.emit_wac("example:composition", &options).unwrap();
+    assert!(source.contains("export app.run as \"run2\";"));
+
+    let mut loaded = Graph::new();
+    let snapshot = loaded.load_wac(&source, packages()).unwrap();
+    assert_eq!(snapshot.export, None);
+    assert_eq!(snapshot.exports, options.exports);
+    assert_eq!(
+        snapshot
+            .components
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect::<Vec<_>>(),
+        ["example:greeter", "example:app"]
+    );
+    assert_eq!(
+        loaded.emit_wac("example:composition", &options).unwrap(),
+        source
+    );
+
+    let bytes = loaded.encode_graph(&options).unwrap();
+    assert_eq!(
+        world(&bytes).1,
+        ["run2".to_string(), "example:greeter/greeter".to_string()]
+    );
+
+    match Graph::new().load_wac("package example:composition;\nlet", Vec::new()) {
+        Err(Error::Wac { line, .. }) => assert_eq!(line, 2),
+        result => panic!("expected a WAC error, got {result:?}"),
+    }
+
+    // Instances can only be exported with a name
+    let source =
+        "package example:composition;\n\nlet app = new example:app { ... };\nexport app;\n";
+    match Graph::new().load_wac(source, packages()) {
+        Err(Error::Wac { line, column, .. }) => assert_eq!((line, column), (4, 8)),
+        result => panic!("expected a WAC error, got {result:?}"),
+    }
+}
+
+#[test]
+fn core_modules_are_componentized_with_an_adapter() {
+    let mut graph = Graph::new();
+    let mut add = |name: &str, adapter| {
+        graph.add_component(
+            name.to_string(),
+            fixture("hello.wat"),
+            AddOptions {
+                duplicates: Duplicates::Allow,
+                adapter,
+            },
+        )
+    };
+
+    // The WASI imports cannot be satisfied without an adapter
+    assert!(matches!(
+        add("example:hello", None),
+        Err(Error::Validation { .. })
+    ));
+
+    let reactor = add("example:reactor", Some(Adapter::Reactor)).unwrap();
+    assert!(reactor
+        .imports
+        .iter()
+        .any(|i| i.name.starts_with("wasi:cli/stdout@")));
+    assert!(!reactor
+        .exports
+        .iter()
+        .any(|e| e.name.starts_with("wasi:cli/run@")));
+
+    let command = add("example:command", Some(Adapter::Command)).unwrap();
+    assert!(command
+        .exports
+        .iter()
+        .any(|e| e.name.starts_with("wasi:cli/run@")));
+}
+
+#[test]
+fn duplicate_components_follow_the_policy() {
+    let mut graph = Graph::new();
+    let first = graph
+        .add_component(
+            "example:greeter".to_string(),
+            fixture("greeter.wat"),
+            AddOptions::default(),
+        )
+        .unwrap();
+
+    let options = |duplicates| AddOptions {
+        duplicates,
+        adapter: None,
+    };
+    let aliased = graph
+        .add_component(
+            "example:other".to_string(),
+            fixture("greeter.wat"),
+            options(Duplicates::Alias),
+        )
+        .unwrap();
+    assert_eq!(aliased.id, first.id);
+
+    assert!(matches!(
+        graph.add_component(
+            "example:other".to_string(),
+            fixture("greeter.wat"),
+            options(Duplicates::Reject)
+        ),
+        Err(Error::InvalidOperation(_))
+    ));
+
+    let copy = graph
+        .add_component(
+            "example:other".to_string(),
+            fixture("greeter.wat"),
+            options(Duplicates::Allow),
+        )
+        .unwrap();
+    assert_ne!(copy.id, first.id);
+    assert_eq!(copy.digest, first.digest);
+}
+
+#[test]
+fn components_are_registered_under_derived_package_names() {
+    let mut graph = Graph::new();
+    let mut add = |name: &str, fixture_name: &str| {
+        graph
+            .add_component(
+                name.to_string(),
+                fixture(fixture_name),
+                AddOptions::default(),
+            )
+            .unwrap()
+    };
+
+    let names = [
+        ("example:greeter", "example:greeter"),
+        ("greeter", "greeter"),
+        ("My Component", "my-component"),
+        ("greeter.wasm", "greeter-wasm"),
+        ("my_component", "my-component2"),
+        ("Foo", "foo"),
+        ("2048 v2", "component2048-v2"),
+    ];
+    for (name, package_name) in names {
+        let component = add(name, "greeter.wat");
+        assert_eq!(component.name, name);
+        assert_eq!(component.package_name, package_name);
+    }
+
+    // The same name can only be used once per version
+    assert!(matches!(
+        graph.add_component(
+            "My Component".to_string(),
+            fixture("greeter.wat"),
+            AddOptions::default()
+        ),
+        Err(Error::InvalidOperation(_))
+    ));
+
+    // Components are referenced by package name in WAC documents
+    let app = graph.instantiate_component(2).unwrap();
+    let source = graph
+        .emit_wac(
+            "example:composition",
+            &EncodeOptions {
+                export: Some(app),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert!(source.contains("= new example:my-component {};"));
+}
+
+#[test]
+fn stub_components_satisfy_imports() {
+    let mut graph = Graph::new();
+    let app = instantiate(&mut graph, "example:app", "app.wat");
+    let stub = graph
+        .add_stub_component(
+            "example:stub".to_string(),
+            GREETER_WIT,
+            "stub",
+            Behavior::Defaults,
+        )
+        .unwrap();
+    assert_eq!(stub.exports[0].name, "example:greeter/greeter");
+
+    let stub = graph.instantiate_component(stub.id).unwrap();
+    graph.connect_instances(stub, Some(0), app, 0).unwrap();
+    graph
+        .encode_graph(&EncodeOptions {
+            export: Some(app),
+            ..Default::default()
+        })
+        .unwrap();
+
+    assert!(matches!(
+        graph.add_stub_component(
+            "example:stub".to_string(),
+            "package",
+            "stub",
+            Behavior::Trap
+        ),
+        Err(Error::Wit(_))
+    ));
+}
+
+#[test]
+fn deny_components_export_interfaces_used_in_the_graph() {
+    let mut graph = Graph::new();
+    let app = instantiate(&mut graph, "example:app", "app.wat");
+
+    let deny = graph
+        .add_deny_component(
+            "example:deny".to_string(),
+            &["example:greeter/*".to_string()],
+            Behavior::Trap,
+        )
+        .unwrap();
+    assert!(deny.imports.is_empty());
+    assert_eq!(deny.exports.len(), 1);
+    assert_eq!(deny.exports[0].name, "example:greeter/greeter");
+
+    let deny = graph.instantiate_component(deny.id).unwrap();
+    graph.connect_instances(deny, Some(0), app, 0).unwrap();
+
+    match graph.add_deny_component(
+        "example:deny".to_string(),
+        &["wasi:sockets/*".to_string()],
+        Behavior::Trap,
+    ) {
+        Err(Error::InvalidOperation(message)) => assert!(message.contains("wasi:sockets/*")),
+        result => panic!("expected an invalid operation, got {result:?}"),
+    }
+}
+
+#[test]
+fn config_components_export_the_requested_interfaces() {
+    let mut graph = Graph::new();
+    let environment = graph
+        .add_config_component(
+            "example:environment".to_string(),
+            &Values {
+                environment: Some(vec![("HOME".to_string(), "/".to_string())]),
+                config: None,
+            },
+        )
+        .unwrap();
+    let names = environment
+        .exports
+        .iter()
+        .map(|e| e.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["wasi:cli/environment@0.2.0"]);
+
+    let duplicate = Values {
+        environment: None,
+        config: Some(vec![
+            ("key".to_string(), "a".to_string()),
+            ("key".to_string(), "b".to_string()),
+        ]),
+    };
+    assert!(matches!(
+        graph.add_config_component("example:config".to_string(), &duplicate),
+        Err(Error::InvalidOperation(_))
+    ));
+}
+
+#[test]
+fn audits_report_reachable_host_imports() {
+    let mut graph = Graph::new();
+    let app = instantiate(&mut graph, "example:app", "app.wat");
+
+    let report = graph.audit();
+    assert_eq!(report.instances.len(), 1);
+    assert_eq!(
+        report.instances[0].capabilities[0].name,
+        "example:greeter/greeter"
+    );
+    assert_eq!(report.instances[0].capabilities[0].imported_by, [app]);
+    assert!(report.markdown.contains("- `example:greeter/greeter`"));
+
+    // Names are escaped so they cannot break the table
+    let relay = instantiate(&mut graph, "example:relay", "relay.wat");
+    graph.connect_instances(relay, Some(0), app, 0).unwrap();
+    graph
+        .set_instance_name(relay, Some("a|`b`".to_string()))
+        .unwrap();
+    let report = graph.audit();
+    assert!(report
+        .markdown
+        .contains("| `example:greeter/greeter` | `` a\\|`b` `` (instance 1 of `example:relay`) |"));
+
+    let (graph, greeter, app) = composed();
+    let report = graph.audit();
+    let audit = report.instances.iter().find(|a| a.instance == app).unwrap();
+    assert_eq!(audit.dependencies, [greeter]);
+    assert!(audit.capabilities.is_empty());
+    assert!(report
+        .markdown
+        .contains("The composition imports nothing from the host."));
+}
+
+#[test]
+fn component_records_include_metadata() {
+    let mut graph = Graph::new();
+    let component = graph
+        .add_component(
+            "example:metadata".to_string(),
+            fixture("metadata.wat"),
+            AddOptions::default(),
+        )
+        .unwrap();
+
+    let metadata = component.metadata;
+    assert_eq!(metadata.name.as_deref(), Some("metadata"));
+    assert_eq!(
+        metadata.producers,
+        [
+            Producer {
+                field: "language".to_string(),
+                name: "Rust".to_string(),
+                version: "1.80.0".to_string(),
+            },
+            Producer {
+                field: "processed-by".to_string(),
+                name: "wit-component".to_string(),
+                version: "0.229.0".to_string(),
+            },
+        ]
+    );
+
+    // The remaining metadata comes from the registry metadata
+    assert_eq!(metadata.authors.as_deref(), Some("Ada, Grace"));
+    assert_eq!(
+        metadata.description.as_deref(),
+        Some("An example component.")
+    );
+    assert_eq!(metadata.licenses.as_deref(), Some("Apache-2.0"));
+    assert_eq!(metadata.source.as_deref(), Some("https://example.com/repo"));
+    assert_eq!(metadata.homepage.as_deref(), Some("https://example.com"));
+    assert_eq!(metadata.revision, None);
+    assert_eq!(metadata.version, None);
+}
+
+#[test]
+fn records_serialize_to_json() {
+    let (graph, _, app) = composed();
+    let snapshot = graph.snapshot(Some(app));
+
+    let value = serde_json::to_value(&snapshot).unwrap();
+    assert_eq!(value["export"], app);
+    assert_eq!(value["connections"][0]["sourceExport"], 0);
+    assert_eq!(value["components"][1]["exports"][0]["kind"], "function");
+    assert_eq!(
+        serde_json::from_value::<graph_core::model::Snapshot>(value).unwrap(),
+        snapshot
+    );
+
+    let error = serde_json::to_value(Error::UnknownInstance(7)).unwrap();
+    assert_eq!(error, serde_json::json!({ "unknownInstance": 7 }));
+}
+
+#[test]
+fn diffs_classify_changes_by_the_connections_they_break() {
+    let (mut graph, greeter, _) = composed();
+    let wide = graph
+        .add_component(
+            "example:wide-greeter".to_string(),
+            fixture("wide-greeter.wat"),
+            AddOptions::default(),
+        )
+        .unwrap();
+    let (_, greeter) = graph
+        .state()
+        .instances()
+        .find(|(id, _)| *id == greeter)
+        .unwrap();
+
+    let diff = graph.diff_components(greeter, wide.id).unwrap();
+    assert!(!diff.compatible);
+    assert_eq!(diff.differences.len(), 1);
+    assert_eq!(diff.differences[0].item, "example:greeter/greeter#greet");
+    assert_eq!(diff.differences[0].kind, DifferenceKind::Changed);
+    assert!(diff.differences[0].breaking);
+    assert_eq!(diff.broken_connections.len(), 1);
+    assert_eq!(
+        diff.broken_connections[0].connection,
+        graph.state().connections()[0]
+    );
+
+    // Without connections nothing can break
+    let diff = graph.diff_components(wide.id, greeter).unwrap();
+    assert!(diff.compatible);
+    assert!(!diff.differences[0].breaking);
+}
+
+#[test]
+fn diffs_report_record_fields_and_resource_methods() {
+    let mut graph = Graph::new();
+    let old = graph
+        .add_stub_component(
+            "example:shapes".to_string(),
+            SHAPES_WIT,
+            "stub",
+            Behavior::Trap,
+        )
+        .unwrap();
+    let new = graph
+        .add_stub_component(
+            "example:new-shapes".to_string(),
+            &SHAPES_WIT
+                .replace("/* field */", ", z: u32")
+                .replace("/* method */", "scale: func(factor: u32);")
+                .replace("/* result */ u32", "u64"),
+            "stub",
+            Behavior::Trap,
+        )
+        .unwrap();
+
+    let differences = |graph: &Graph| {
+        graph
+            .diff_components(old.id, new.id)
+            .unwrap()
+            .differences
+            .into_iter()
+            .map(|d| (d.item, d.kind, d.breaking))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        differences(&graph),
+        [
+            (
+                "example:shapes/shapes#point.z".to_string(),
+                DifferenceKind::Added,
+                false
+            ),
+            (
+                "example:shapes/shapes#distance".to_string(),
+                DifferenceKind::Changed,
+                false
+            ),
+            (
+                "example:shapes/shapes#shape.scale".to_string(),
+                DifferenceKind::Added,
+                false
+            ),
+        ]
+    );
+
+    // A connection to an import of the record and function breaks, but the
+    // new method is not used by it
+    let shapes = graph.instantiate_component(old.id).unwrap();
+    let app = instantiate(&mut graph, "example:shapes-app", "shapes-app.wat");
+    graph.connect_instances(shapes, Some(0), app, 0).unwrap();
+
+    let breaking = differences(&graph)
+        .into_iter()
+        .map(|(_, _, breaking)| breaking)
+        .collect::<Vec<_>>();
+    assert_eq!(breaking, [true, true, false]);
+}
+
+#[test]
+fn world_previews_attribute_items_to_instances() {
+    let mut graph = Graph::new();
+    let app = instantiate(&mut graph, "example:app", "app.wat");
+    graph
+        .set_instance_name(app, Some("app".to_string()))
+        .unwrap();
+    let (_, component) = graph.state().instances().next().unwrap();
+    let other = graph.instantiate_component(component).unwrap();
+
+    let preview = graph
+        .preview_world(&EncodeOptions {
+            export: Some(app),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(preview.imports.len(), 1);
+    assert_eq!(preview.imports[0].name, "example:greeter/greeter");
+    assert_eq!(preview.imports[0].instances, [app, other]);
+    assert_eq!(preview.exports.len(), 1);
+    assert_eq!(preview.exports[0].name, "run");
+    assert_eq!(preview.exports[0].instances, [app]);
+    assert!(preview.wit.contains(
+        "  // imported by `app` (instance 0 of `example:app`), instance 1 of `example:app`\n  \
+         import example:greeter/greeter;"
+    ));
+    assert!(preview
+        .wit
+        .contains("  // exported by `app` (instance 0 of `example:app`)\n  export run: func"));
+}
+
+#[test]
+fn graphs_render_as_diagrams() {
+    let (mut graph, greeter, _) = composed();
+    graph
+        .set_instance_name(greeter, Some("greeter".to_string()))
+        .unwrap();
+
+    assert_eq!(
+        graph.print_graph(Format::Dot),
+        "digraph composition {\n  rankdir=LR;\n  node [shape=box];\n  \
+         i0 [label=\"greeter\\nexample:greeter\"];\n  i1 [label=\"example:app\"];\n  \
+         i0 -> i1 [label=\"example:greeter/greeter\"];\n}\n"
+    );
+    assert_eq!(
+        graph.print_graph(Format::Mermaid),
+        "flowchart LR\n  i0[\"greeter<br>example:greeter\"]\n  i1[\"example:app\"]\n  \
+         i0 -->|\"example:greeter/greeter\"| i1\n"
+    );
+}
+
+#[test]
+fn components_disassemble_to_wat() {
+    let (graph, _, app) = composed();
+    let (_, component) = graph
+        .state()
+        .instances()
+        .find(|(id, _)| *id == app)
+        .unwrap();
+    let wat = |id, options| {
+        graph
+            .component_wat(id, &EncodeOptions::default(), &options)
+            .unwrap()
+    };
+
+    let full = wat(Some(component), Options::default());
+    assert!(!full.truncated);
+    assert!(full
+        .text
+        .contains("      call $greet\n      i32.const 1\n      i32.add\n"));
+
+    let folded = wat(
+        Some(component),
+        Options {
+            fold_expressions: true,
+            ..Default::default()
+        },
+    );
+    assert!(folded.text.contains("(i32.add\n        (call $greet)"));
+
+    let skeleton = wat(
+        Some(component),
+        Options {
+            skip_function_bodies: true,
+            ..Default::default()
+        },
+    );
+    assert!(!skeleton.text.contains("i32.add"));
+
+    // The encoded graph defines both components
+    let structure = wat(
+        None,
+        Options {
+            structure_only: true,
+            ..Default::default()
+        },
+    );
+    assert_eq!(
+        structure
+            .text
+            .matches(";; the contents of the module are elided")
+            .count(),
+        2
+    );
+    assert!(!structure.text.contains("i32.add"));
+
+    let truncated = wat(
+        Some(component),
+        Options {
+            max_length: Some(100),
+            ..Default::default()
+        },
+    );
+    assert!(truncated.truncated);
+    assert!(truncated.text.len() <= 100);
+    assert!(truncated
+        .text
+        .ends_with(";; the text was truncated at 100 bytes\n"));
+}
@@ -67,6 +67,71 @@ pub mod exports {
                         }
                     }
                 }
+                /// Represents a kind of member of an instance.
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, PartialEq)]
+                pub enum MemberKind {
+                    /// The member is a function.
+                    Function,
+                    /// The member is a type.
+                    Type,
+                    /// The member is a resource.
+                    Resource,
+                }
+                impl ::core::fmt::Debug for MemberKind {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            MemberKind::Function => {
+                                f.debug_tuple("MemberKind::Function").finish()
+                            }
+                            MemberKind::Type => {
+                                f.debug_tuple("MemberKind::Type").finish()
+                            }
+                            MemberKind::Resource => {
+                                f.debug_tuple("MemberKind::Resource").finish()
+                            }
+                        }
+                    }
+                }
+                impl MemberKind {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> MemberKind {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+                        match val {
+                            0 => MemberKind::Function,
+                            1 => MemberKind::Type,
+                            2 => MemberKind::Resource,
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+                /// Represents a member of an imported or exported instance.
+                #[derive(Clone)]
+                pub struct Member {
+                    /// The member name.
+                    pub name: _rt::String,
+                    /// The member kind.
+                    pub kind: MemberKind,
+                    /// The WIT signature of the member.
+                    pub signature: _rt::String,
+                }
+                impl ::core::fmt::Debug for Member {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Member")
+                            .field("name", &self.name)
+                            .field("kind", &self.kind)
+                            .field("signature", &self.signature)
+                            .finish()
+                    }
+                }
                 /// Represents an import in a WebAssembly component.
                 #[derive(Clone)]
                 pub struct Import {
@@ -74,6 +139,10 @@ pub mod exports {
                     pub name: _rt::String,
                     /// The import kind.
                     pub kind: ItemKind,
+                    /// The WIT signature of the import.
+                    pub signature: _rt::String,
+                    /// The members of the import if it is an instance.
+                    pub members: _rt::Vec<Member>,
                 }
                 impl ::core::fmt::Debug for Import {
                     fn fmt(
@@ -83,6 +152,8 @@ pub mod exports {
                         f.debug_struct("Import")
                             .field("name", &self.name)
                             .field("kind", &self.kind)
+                            .field("signature", &self.signature)
+                            .field("members", &self.members)
                             .finish()
                     }
                 }
@@ -93,6 +164,10 @@ pub mod exports {
                     pub name: _rt::String,
                     /// The export kind.
                     pub kind: ItemKind,
+                    /// The WIT signature of the export.
+                    pub signature: _rt::String,
+                    /// The members of the export if it is an instance.
+                    pub members: _rt::Vec<Member>,
                 }
                 impl ::core::fmt::Debug for Export {
                     fn fmt(
@@ -102,195 +177,12211 @@ pub mod exports {
                         f.debug_struct("Export")
                             .field("name", &self.name)
                             .field("kind", &self.kind)
+                            .field("signature", &self.signature)
+                            .field("members", &self.members)
                             .finish()
                     }
                 }
-                /// Represents a component identifier in the graph.
-                pub type ComponentId = u32;
-                /// Represents a WebAssembly component.
+                /// Represents a tool or language that produced a component.
                 #[derive(Clone)]
-                pub struct Component {
-                    /// The id of the component in the graph/
-                    pub id: ComponentId,
-                    /// The name of the component.
+                pub struct Producer {
+                    /// The producers field, such as `language`, `processed-by` or `sdk`.
+                    pub field: _rt::String,
+                    /// The name of the tool or language.
                     pub name: _rt::String,
-                    /// The imports of the component.
-                    pub imports: _rt::Vec<Import>,
-                    /// The exports of the component.
-                    pub exports: _rt::Vec<Export>,
-                    /// The WIT definition of the component's world.
-                    pub wit: _rt::String,
+                    /// The version of the tool or language.
+                    pub version: _rt::String,
                 }
-                impl ::core::fmt::Debug for Component {
+                impl ::core::fmt::Debug for Producer {
                     fn fmt(
                         &self,
                         f: &mut ::core::fmt::Formatter<'_>,
                     ) -> ::core::fmt::Result {
-                        f.debug_struct("Component")
-                            .field("id", &self.id)
+                        f.debug_struct("Producer")
+                            .field("field", &self.field)
                             .field("name", &self.name)
-                            .field("imports", &self.imports)
-                            .field("exports", &self.exports)
-                            .field("wit", &self.wit)
+                            .field("version", &self.version)
                             .finish()
                     }
                 }
-                /// Represents an instance identifier in the graph.
-                pub type InstanceId = u32;
-                /// Represents options for encoding the graph.
-                #[repr(C)]
-                #[derive(Clone, Copy)]
-                pub struct EncodeOptions {
-                    /// Whether or not to define components in the output.
-                    pub define_components: bool,
-                    /// The instance to export from the output.
-                    pub export: Option<InstanceId>,
-                    /// Whether or not to validate the output.
-                    pub validate: bool,
+                /// Represents the metadata of a WebAssembly component.
+                ///
+                /// Metadata is read from the custom sections of the component.
+                #[derive(Clone)]
+                pub struct ComponentMetadata {
+                    /// The name of the component from its name section.
+                    pub name: Option<_rt::String>,
+                    /// The tools and languages that produced the component.
+                    pub producers: _rt::Vec<Producer>,
+                    /// The authors of the component.
+                    pub authors: Option<_rt::String>,
+                    /// The description of the component.
+                    pub description: Option<_rt::String>,
+                    /// The licenses of the component as an SPDX expression.
+                    pub licenses: Option<_rt::String>,
+                    /// The URL of the source code of the component.
+                    pub source: Option<_rt::String>,
+                    /// The URL of the homepage of the component.
+                    pub homepage: Option<_rt::String>,
+                    /// The source control revision of the component.
+                    pub revision: Option<_rt::String>,
+                    /// The version of the component.
+                    pub version: Option<_rt::String>,
                 }
-                impl ::core::fmt::Debug for EncodeOptions {
+                impl ::core::fmt::Debug for ComponentMetadata {
                     fn fmt(
                         &self,
                         f: &mut ::core::fmt::Formatter<'_>,
                     ) -> ::core::fmt::Result {
-                        f.debug_struct("EncodeOptions")
-                            .field("define-components", &self.define_components)
-                            .field("export", &self.export)
-                            .field("validate", &self.validate)
+                        f.debug_struct("ComponentMetadata")
+                            .field("name", &self.name)
+                            .field("producers", &self.producers)
+                            .field("authors", &self.authors)
+                            .field("description", &self.description)
+                            .field("licenses", &self.licenses)
+                            .field("source", &self.source)
+                            .field("homepage", &self.homepage)
+                            .field("revision", &self.revision)
+                            .field("version", &self.version)
                             .finish()
                     }
                 }
-                #[derive(Debug)]
-                #[repr(transparent)]
-                pub struct Graph {
-                    handle: _rt::Resource<Graph>,
+                /// Represents an error from parsing or validating WebAssembly.
+                #[derive(Clone)]
+                pub struct WasmError {
+                    /// The error message.
+                    pub message: _rt::String,
+                    /// The byte offset of the error, if known.
+                    pub offset: Option<u64>,
                 }
-                type _GraphRep<T> = Option<T>;
-                impl Graph {
-                    /// Creates a new resource from the specified representation.
-                    ///
-                    /// This function will create a new resource handle by moving `val` onto
-                    /// the heap and then passing that heap pointer to the component model to
-                    /// create a handle. The owned handle is then returned as `Graph`.
-                    pub fn new<T: GuestGraph>(val: T) -> Self {
-                        Self::type_guard::<T>();
-                        let val: _GraphRep<T> = Some(val);
-                        let ptr: *mut _GraphRep<T> = _rt::Box::into_raw(
-                            _rt::Box::new(val),
-                        );
-                        unsafe { Self::from_handle(T::_resource_new(ptr.cast())) }
-                    }
-                    /// Gets access to the underlying `T` which represents this resource.
-                    pub fn get<T: GuestGraph>(&self) -> &T {
-                        let ptr = unsafe { &*self.as_ptr::<T>() };
-                        ptr.as_ref().unwrap()
+                impl ::core::fmt::Debug for WasmError {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("WasmError")
+                            .field("message", &self.message)
+                            .field("offset", &self.offset)
+                            .finish()
                     }
-                    /// Gets mutable access to the underlying `T` which represents this
-                    /// resource.
-                    pub fn get_mut<T: GuestGraph>(&mut self) -> &mut T {
-                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
-                        ptr.as_mut().unwrap()
+                }
+                /// Represents a type mismatch between the source and target of a connection.
+                #[derive(Clone)]
+                pub struct TypeMismatchError {
+                    /// The name of the source export; if `none`, the source instance itself was used.
+                    pub export: Option<_rt::String>,
+                    /// The name of the target import.
+                    pub import: _rt::String,
+                    /// The error message.
+                    pub message: _rt::String,
+                }
+                impl ::core::fmt::Debug for TypeMismatchError {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("TypeMismatchError")
+                            .field("export", &self.export)
+                            .field("import", &self.import)
+                            .field("message", &self.message)
+                            .finish()
                     }
-                    /// Consumes this resource and returns the underlying `T`.
-                    pub fn into_inner<T: GuestGraph>(self) -> T {
-                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
-                        ptr.take().unwrap()
+                }
+                /// Represents an error in a source document.
+                #[derive(Clone)]
+                pub struct SourceError {
+                    /// The error message.
+                    pub message: _rt::String,
+                    /// The line of the error, starting at 1.
+                    pub line: u32,
+                    /// The column of the error, starting at 1.
+                    pub column: u32,
+                }
+                impl ::core::fmt::Debug for SourceError {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("SourceError")
+                            .field("message", &self.message)
+                            .field("line", &self.line)
+                            .field("column", &self.column)
+                            .finish()
                     }
-                    #[doc(hidden)]
-                    pub unsafe fn from_handle(handle: u32) -> Self {
-                        Self {
-                            handle: _rt::Resource::from_handle(handle),
+                }
+                /// Represents how to add a component that is identical to one already in the graph.
+                ///
+                /// Components are identical if they have the same digest.
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, PartialEq)]
+                pub enum DuplicatePolicy {
+                    /// Add the component as a separate copy.
+                    Allow,
+                    /// Fail to add the component.
+                    Reject,
+                    /// Return the existing component instead of adding a copy.
+                    Alias,
+                }
+                impl ::core::fmt::Debug for DuplicatePolicy {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            DuplicatePolicy::Allow => {
+                                f.debug_tuple("DuplicatePolicy::Allow").finish()
+                            }
+                            DuplicatePolicy::Reject => {
+                                f.debug_tuple("DuplicatePolicy::Reject").finish()
+                            }
+                            DuplicatePolicy::Alias => {
+                                f.debug_tuple("DuplicatePolicy::Alias").finish()
+                            }
                         }
                     }
+                }
+                impl DuplicatePolicy {
                     #[doc(hidden)]
-                    pub fn take_handle(&self) -> u32 {
-                        _rt::Resource::take_handle(&self.handle)
-                    }
-                    #[doc(hidden)]
-                    pub fn handle(&self) -> u32 {
-                        _rt::Resource::handle(&self.handle)
+                    pub unsafe fn _lift(val: u8) -> DuplicatePolicy {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+                        match val {
+                            0 => DuplicatePolicy::Allow,
+                            1 => DuplicatePolicy::Reject,
+                            2 => DuplicatePolicy::Alias,
+                            _ => panic!("invalid enum discriminant"),
+                        }
                     }
-                    #[doc(hidden)]
-                    fn type_guard<T: 'static>() {
-                        use core::any::TypeId;
-                        static mut LAST_TYPE: Option<TypeId> = None;
-                        unsafe {
-                            assert!(! cfg!(target_feature = "atomics"));
-                            let id = TypeId::of::<T>();
-                            match LAST_TYPE {
-                                Some(ty) => {
-                                    assert!(
-                                        ty == id, "cannot use two types with this resource type"
-                                    )
-                                }
-                                None => LAST_TYPE = Some(id),
+                }
+                /// Represents a bundled WASI preview1 adapter.
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, PartialEq)]
+                pub enum WasiAdapter {
+                    /// The adapter for modules that export functions to be called.
+                    Reactor,
+                    /// The adapter for modules with a `_start` function.
+                    Command,
+                    /// The adapter for modules that handle HTTP requests.
+                    Proxy,
+                }
+                impl ::core::fmt::Debug for WasiAdapter {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            WasiAdapter::Reactor => {
+                                f.debug_tuple("WasiAdapter::Reactor").finish()
+                            }
+                            WasiAdapter::Command => {
+                                f.debug_tuple("WasiAdapter::Command").finish()
+                            }
+                            WasiAdapter::Proxy => {
+                                f.debug_tuple("WasiAdapter::Proxy").finish()
                             }
                         }
                     }
-                    #[doc(hidden)]
-                    pub unsafe fn dtor<T: 'static>(handle: *mut u8) {
-                        Self::type_guard::<T>();
-                        let _ = _rt::Box::from_raw(handle as *mut _GraphRep<T>);
-                    }
-                    fn as_ptr<T: GuestGraph>(&self) -> *mut _GraphRep<T> {
-                        Graph::type_guard::<T>();
-                        T::_resource_rep(self.handle()).cast()
-                    }
-                }
-                /// A borrowed version of [`Graph`] which represents a borrowed value
-                /// with the lifetime `'a`.
-                #[derive(Debug)]
-                #[repr(transparent)]
-                pub struct GraphBorrow<'a> {
-                    rep: *mut u8,
-                    _marker: core::marker::PhantomData<&'a Graph>,
                 }
-                impl<'a> GraphBorrow<'a> {
+                impl WasiAdapter {
                     #[doc(hidden)]
-                    pub unsafe fn lift(rep: usize) -> Self {
-                        Self {
-                            rep: rep as *mut u8,
-                            _marker: core::marker::PhantomData,
+                    pub unsafe fn _lift(val: u8) -> WasiAdapter {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+                        match val {
+                            0 => WasiAdapter::Reactor,
+                            1 => WasiAdapter::Command,
+                            2 => WasiAdapter::Proxy,
+                            _ => panic!("invalid enum discriminant"),
                         }
                     }
-                    /// Gets access to the underlying `T` in this resource.
-                    pub fn get<T: GuestGraph>(&self) -> &T {
-                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
-                        ptr.as_ref().unwrap()
-                    }
-                    fn as_ptr<T: 'static>(&self) -> *mut _GraphRep<T> {
-                        Graph::type_guard::<T>();
-                        self.rep.cast()
+                }
+                /// Represents options for adding a component to the graph.
+                #[repr(C)]
+                #[derive(Clone, Copy)]
+                pub struct AddOptions {
+                    /// How to add a component that is identical to one already in the graph.
+                    pub duplicates: DuplicatePolicy,
+                    /// The adapter to apply when the given bytes are a core module.
+                    ///
+                    /// Core modules are converted into components; the adapter satisfies
+                    /// their imports of `wasi_snapshot_preview1`.
+                    pub adapter: Option<WasiAdapter>,
+                }
+                impl ::core::fmt::Debug for AddOptions {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("AddOptions")
+                            .field("duplicates", &self.duplicates)
+                            .field("adapter", &self.adapter)
+                            .finish()
                     }
                 }
-                unsafe impl _rt::WasmResource for Graph {
-                    #[inline]
-                    unsafe fn drop(_handle: u32) {
-                        #[cfg(not(target_arch = "wasm32"))]
-                        unreachable!();
-                        #[cfg(target_arch = "wasm32")]
-                        {
-                            #[link(
-                                wasm_import_module = "[export]wasmbuilder-app:graph/provider"
-                            )]
-                            extern "C" {
-                                #[link_name = "[resource-drop]graph"]
-                                fn drop(_: u32);
+                /// Represents the kind of a difference between two components.
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, PartialEq)]
+                pub enum DifferenceKind {
+                    /// The item was added by the second component.
+                    Added,
+                    /// The item was removed by the second component.
+                    Removed,
+                    /// The item was changed by the second component.
+                    Changed,
+                }
+                impl ::core::fmt::Debug for DifferenceKind {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            DifferenceKind::Added => {
+                                f.debug_tuple("DifferenceKind::Added").finish()
+                            }
+                            DifferenceKind::Removed => {
+                                f.debug_tuple("DifferenceKind::Removed").finish()
+                            }
+                            DifferenceKind::Changed => {
+                                f.debug_tuple("DifferenceKind::Changed").finish()
                             }
-                            drop(_handle);
                         }
                     }
                 }
-                #[doc(hidden)]
-                #[allow(non_snake_case)]
-                pub unsafe fn _export_constructor_graph_cabi<T: GuestGraph>() -> i32 {
+                impl DifferenceKind {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> DifferenceKind {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+                        match val {
+                            0 => DifferenceKind::Added,
+                            1 => DifferenceKind::Removed,
+                            2 => DifferenceKind::Changed,
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+                /// Represents a difference between the worlds of two components.
+                #[derive(Clone)]
+                pub struct Difference {
+                    /// The path of the item that differs.
+                    ///
+                    /// Members of an imported or exported instance are given as `<item>#<member>`
+                    /// and the fields of a record as `<record>.<field>`.
+                    pub item: _rt::String,
+                    /// The kind of the difference.
+                    pub kind: DifferenceKind,
+                    /// The description of the difference.
+                    pub description: _rt::String,
+                    /// Whether the difference breaks a connection in the graph.
+                    pub breaking: bool,
+                }
+                impl ::core::fmt::Debug for Difference {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Difference")
+                            .field("item", &self.item)
+                            .field("kind", &self.kind)
+                            .field("description", &self.description)
+                            .field("breaking", &self.breaking)
+                            .finish()
+                    }
+                }
+                /// Represents the format of a diagram of the graph.
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, PartialEq)]
+                pub enum GraphFormat {
+                    /// A Graphviz DOT document.
+                    Dot,
+                    /// A Mermaid flowchart.
+                    Mermaid,
+                }
+                impl ::core::fmt::Debug for GraphFormat {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            GraphFormat::Dot => {
+                                f.debug_tuple("GraphFormat::Dot").finish()
+                            }
+                            GraphFormat::Mermaid => {
+                                f.debug_tuple("GraphFormat::Mermaid").finish()
+                            }
+                        }
+                    }
+                }
+                impl GraphFormat {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> GraphFormat {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+                        match val {
+                            0 => GraphFormat::Dot,
+                            1 => GraphFormat::Mermaid,
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+                /// Represents the text format of a component.
+                #[derive(Clone)]
+                pub struct Wat {
+                    /// The text of the component.
+                    pub text: _rt::String,
+                    /// Whether the text was truncated at the maximum length.
+                    pub truncated: bool,
+                }
+                impl ::core::fmt::Debug for Wat {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Wat")
+                            .field("text", &self.text)
+                            .field("truncated", &self.truncated)
+                            .finish()
+                    }
+                }
+                /// Represents the behavior of the exports of a stub component.
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, PartialEq)]
+                pub enum StubBehavior {
+                    /// Exported functions trap when called.
+                    Trap,
+                    /// Exported functions return the default value of their result type.
+                    ///
+                    /// Functions returning resources trap.
+                    Defaults,
+                    /// Exported functions returning a `result` return an error; other functions trap.
+                    Errors,
+                }
+                impl ::core::fmt::Debug for StubBehavior {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            StubBehavior::Trap => {
+                                f.debug_tuple("StubBehavior::Trap").finish()
+                            }
+                            StubBehavior::Defaults => {
+                                f.debug_tuple("StubBehavior::Defaults").finish()
+                            }
+                            StubBehavior::Errors => {
+                                f.debug_tuple("StubBehavior::Errors").finish()
+                            }
+                        }
+                    }
+                }
+                impl StubBehavior {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> StubBehavior {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+                        match val {
+                            0 => StubBehavior::Trap,
+                            1 => StubBehavior::Defaults,
+                            2 => StubBehavior::Errors,
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+                /// Represents the values of a configuration component.
+                #[derive(Clone)]
+                pub struct ConfigValues {
+                    /// The environment variables returned by `wasi:cli/environment`.
+                    ///
+                    /// If `none`, the interface is not exported.
+                    pub environment: Option<_rt::Vec<(_rt::String, _rt::String)>>,
+                    /// The values returned by `wasi:config/store`.
+                    ///
+                    /// If `none`, the interface is not exported.
+                    pub config: Option<_rt::Vec<(_rt::String, _rt::String)>>,
+                }
+                impl ::core::fmt::Debug for ConfigValues {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("ConfigValues")
+                            .field("environment", &self.environment)
+                            .field("config", &self.config)
+                            .finish()
+                    }
+                }
+                /// Represents a component identifier in the graph.
+                pub type ComponentId = u32;
+                /// Represents a WebAssembly component.
+                #[derive(Clone)]
+                pub struct Component {
+                    /// The id of the component in the graph/
+                    pub id: ComponentId,
+                    /// The name of the component.
+                    pub name: _rt::String,
+                    /// The name of the package the component is registered as.
+                    ///
+                    /// This is the name used to reference the component in WAC documents and
+                    /// when importing it into the encoded graph.
+                    pub package_name: _rt::String,
+                    /// The version of the component, if it has one.
+                    pub version: Option<_rt::String>,
+                    /// The SHA-256 digest of the component, in the form `sha256:<hex>`.
+                    pub digest: _rt::String,
+                    /// The imports of the component.
+                    pub imports: _rt::Vec<Import>,
+                    /// The exports of the component.
+                    pub exports: _rt::Vec<Export>,
+                    /// The WIT definition of the component's world.
+                    pub wit: _rt::String,
+                    /// The metadata of the component.
+                    pub metadata: ComponentMetadata,
+                }
+                impl ::core::fmt::Debug for Component {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Component")
+                            .field("id", &self.id)
+                            .field("name", &self.name)
+                            .field("package-name", &self.package_name)
+                            .field("version", &self.version)
+                            .field("digest", &self.digest)
+                            .field("imports", &self.imports)
+                            .field("exports", &self.exports)
+                            .field("wit", &self.wit)
+                            .field("metadata", &self.metadata)
+                            .finish()
+                    }
+                }
+                /// Represents an instance identifier in the graph.
+                pub type InstanceId = u32;
+                /// Represents an instance of a component in the graph.
+                #[derive(Clone)]
+                pub struct Instance {
+                    /// The id of the instance in the graph.
+                    pub id: InstanceId,
+                    /// The id of the instantiated component.
+                    pub component: ComponentId,
+                    /// The name of the instance, if it has one.
+                    pub name: Option<_rt::String>,
+                }
+                impl ::core::fmt::Debug for Instance {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Instance")
+                            .field("id", &self.id)
+                            .field("component", &self.component)
+                            .field("name", &self.name)
+                            .finish()
+                    }
+                }
+                /// Represents a connection between two instances in the graph.
+                #[repr(C)]
+                #[derive(Clone, Copy)]
+                pub struct Connection {
+                    /// The source instance of the connection.
+                    pub source: InstanceId,
+                    /// The source export index; if `none`, the source instance itself is connected.
+                    pub source_export: Option<u32>,
+                    /// The target instance of the connection.
+                    pub target: InstanceId,
+                    /// The target import index.
+                    pub target_import: u32,
+                }
+                impl ::core::fmt::Debug for Connection {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Connection")
+                            .field("source", &self.source)
+                            .field("source-export", &self.source_export)
+                            .field("target", &self.target)
+                            .field("target-import", &self.target_import)
+                            .finish()
+                    }
+                }
+                /// Represents an alias of an instance export in the graph.
+                ///
+                /// Aliases are created for the exports used as the sources of connections.
+                #[derive(Clone)]
+                pub struct Alias {
+                    /// The instance the export is aliased from.
+                    pub instance: InstanceId,
+                    /// The index of the aliased export.
+                    pub export: u32,
+                    /// The name of the aliased export.
+                    pub name: _rt::String,
+                }
+                impl ::core::fmt::Debug for Alias {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Alias")
+                            .field("instance", &self.instance)
+                            .field("export", &self.export)
+                            .field("name", &self.name)
+                            .finish()
+                    }
+                }
+                /// Represents an import that could be connected to more than one source.
+                #[derive(Clone)]
+                pub struct Ambiguity {
+                    /// The index of the import on the target instance.
+                    pub import: u32,
+                    /// The connections that could be made to the import.
+                    pub candidates: _rt::Vec<Connection>,
+                }
+                impl ::core::fmt::Debug for Ambiguity {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Ambiguity")
+                            .field("import", &self.import)
+                            .field("candidates", &self.candidates)
+                            .finish()
+                    }
+                }
+                /// Represents the result of automatically connecting an instance.
+                #[derive(Clone)]
+                pub struct AutoConnectResult {
+                    /// The connections that were made.
+                    pub connections: _rt::Vec<Connection>,
+                    /// The imports that were not connected because more than one source matched.
+                    pub ambiguities: _rt::Vec<Ambiguity>,
+                    /// The indexes of the imports that were not connected because no source matched.
+                    pub unresolved: _rt::Vec<u32>,
+                }
+                impl ::core::fmt::Debug for AutoConnectResult {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("AutoConnectResult")
+                            .field("connections", &self.connections)
+                            .field("ambiguities", &self.ambiguities)
+                            .field("unresolved", &self.unresolved)
+                            .finish()
+                    }
+                }
+                /// Represents an import of an instance that is not connected to a source.
+                #[derive(Clone)]
+                pub struct UnconnectedImport {
+                    /// The index of the import on the instance.
+                    pub import: u32,
+                    /// The name of the import.
+                    pub name: _rt::String,
+                    /// The other instances with an unconnected import of the same name.
+                    ///
+                    /// These imports are merged into a single import of the encoded component.
+                    pub merged_with: _rt::Vec<InstanceId>,
+                }
+                impl ::core::fmt::Debug for UnconnectedImport {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("UnconnectedImport")
+                            .field("import", &self.import)
+                            .field("name", &self.name)
+                            .field("merged-with", &self.merged_with)
+                            .finish()
+                    }
+                }
+                /// Represents the unconnected imports of an instance.
+                #[derive(Clone)]
+                pub struct InstanceImports {
+                    /// The instance with the unconnected imports.
+                    pub instance: InstanceId,
+                    /// The unconnected imports of the instance.
+                    pub imports: _rt::Vec<UnconnectedImport>,
+                }
+                impl ::core::fmt::Debug for InstanceImports {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("InstanceImports")
+                            .field("instance", &self.instance)
+                            .field("imports", &self.imports)
+                            .finish()
+                    }
+                }
+                /// Represents an analysis of what the encoded component will import and export.
+                #[derive(Clone)]
+                pub struct Analysis {
+                    /// The unconnected imports of each instance; instances with none are omitted.
+                    pub imports: _rt::Vec<InstanceImports>,
+                    /// The instances that are not reachable from any exported instance.
+                    pub unreachable: _rt::Vec<InstanceId>,
+                }
+                impl ::core::fmt::Debug for Analysis {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Analysis")
+                            .field("imports", &self.imports)
+                            .field("unreachable", &self.unreachable)
+                            .finish()
+                    }
+                }
+                /// Represents a change made to the graph by undoing or redoing an operation.
+                #[derive(Clone)]
+                pub enum Change {
+                    /// A component was added to the graph.
+                    AddComponent(Component),
+                    /// A component was removed from the graph, along with its instances.
+                    RemoveComponent(ComponentId),
+                    /// An instance was added to the graph.
+                    AddInstance(Instance),
+                    /// An instance was removed from the graph, along with its connections.
+                    RemoveInstance(InstanceId),
+                    /// An instance was renamed.
+                    RenameInstance(Instance),
+                    /// Two instances were connected.
+                    Connect(Connection),
+                    /// Two instances were disconnected.
+                    Disconnect(Connection),
+                }
+                impl ::core::fmt::Debug for Change {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            Change::AddComponent(e) => {
+                                f.debug_tuple("Change::AddComponent").field(e).finish()
+                            }
+                            Change::RemoveComponent(e) => {
+                                f.debug_tuple("Change::RemoveComponent").field(e).finish()
+                            }
+                            Change::AddInstance(e) => {
+                                f.debug_tuple("Change::AddInstance").field(e).finish()
+                            }
+                            Change::RemoveInstance(e) => {
+                                f.debug_tuple("Change::RemoveInstance").field(e).finish()
+                            }
+                            Change::RenameInstance(e) => {
+                                f.debug_tuple("Change::RenameInstance").field(e).finish()
+                            }
+                            Change::Connect(e) => {
+                                f.debug_tuple("Change::Connect").field(e).finish()
+                            }
+                            Change::Disconnect(e) => {
+                                f.debug_tuple("Change::Disconnect").field(e).finish()
+                            }
+                        }
+                    }
+                }
+                /// Represents a cycle in the instantiation graph.
+                #[derive(Clone)]
+                pub struct CycleError {
+                    /// The instances forming the cycle, in instantiation order.
+                    pub instances: _rt::Vec<InstanceId>,
+                    /// The error message.
+                    pub message: _rt::String,
+                }
+                impl ::core::fmt::Debug for CycleError {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("CycleError")
+                            .field("instances", &self.instances)
+                            .field("message", &self.message)
+                            .finish()
+                    }
+                }
+                /// Represents an error from the graph.
+                #[derive(Clone)]
+                pub enum GraphError {
+                    /// The component could not be parsed.
+                    Parse(WasmError),
+                    /// The component failed validation.
+                    Validation(WasmError),
+                    /// The source of a connection is not compatible with the target import.
+                    TypeMismatch(TypeMismatchError),
+                    /// A connection would form a cycle in the instantiation graph.
+                    Cycle(CycleError),
+                    /// The component does not exist in the graph.
+                    UnknownComponent(ComponentId),
+                    /// The instance does not exist in the graph.
+                    UnknownInstance(InstanceId),
+                    /// The graph could not be encoded.
+                    Encode(_rt::String),
+                    /// The operation is not valid for the current graph.
+                    InvalidOperation(_rt::String),
+                    /// The project file is not valid.
+                    InvalidProject(_rt::String),
+                    /// The WAC document is not valid.
+                    Wac(SourceError),
+                    /// The WIT document is not valid.
+                    Wit(_rt::String),
+                }
+                impl ::core::fmt::Debug for GraphError {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            GraphError::Parse(e) => {
+                                f.debug_tuple("GraphError::Parse").field(e).finish()
+                            }
+                            GraphError::Validation(e) => {
+                                f.debug_tuple("GraphError::Validation").field(e).finish()
+                            }
+                            GraphError::TypeMismatch(e) => {
+                                f.debug_tuple("GraphError::TypeMismatch").field(e).finish()
+                            }
+                            GraphError::Cycle(e) => {
+                                f.debug_tuple("GraphError::Cycle").field(e).finish()
+                            }
+                            GraphError::UnknownComponent(e) => {
+                                f.debug_tuple("GraphError::UnknownComponent")
+                                    .field(e)
+                                    .finish()
+                            }
+                            GraphError::UnknownInstance(e) => {
+                                f.debug_tuple("GraphError::UnknownInstance")
+                                    .field(e)
+                                    .finish()
+                            }
+                            GraphError::Encode(e) => {
+                                f.debug_tuple("GraphError::Encode").field(e).finish()
+                            }
+                            GraphError::InvalidOperation(e) => {
+                                f.debug_tuple("GraphError::InvalidOperation")
+                                    .field(e)
+                                    .finish()
+                            }
+                            GraphError::InvalidProject(e) => {
+                                f.debug_tuple("GraphError::InvalidProject")
+                                    .field(e)
+                                    .finish()
+                            }
+                            GraphError::Wac(e) => {
+                                f.debug_tuple("GraphError::Wac").field(e).finish()
+                            }
+                            GraphError::Wit(e) => {
+                                f.debug_tuple("GraphError::Wit").field(e).finish()
+                            }
+                        }
+                    }
+                }
+                impl ::core::fmt::Display for GraphError {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        write!(f, "{:?}", self)
+                    }
+                }
+                impl std::error::Error for GraphError {}
+                /// Represents a host import reachable from an instance.
+                #[derive(Clone)]
+                pub struct Capability {
+                    /// The name of the import of the encoded graph.
+                    pub name: _rt::String,
+                    /// The instances in the dependency chain that import it.
+                    pub imported_by: _rt::Vec<InstanceId>,
+                }
+                impl ::core::fmt::Debug for Capability {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Capability")
+                            .field("name", &self.name)
+                            .field("imported-by", &self.imported_by)
+                            .finish()
+                    }
+                }
+                /// Represents the capabilities reachable from an instance.
+                #[derive(Clone)]
+                pub struct InstanceAudit {
+                    /// The audited instance.
+                    pub instance: InstanceId,
+                    /// The instances the audited instance depends on, directly or indirectly.
+                    pub dependencies: _rt::Vec<InstanceId>,
+                    /// The capabilities reachable from the instance, sorted by name.
+                    pub capabilities: _rt::Vec<Capability>,
+                }
+                impl ::core::fmt::Debug for InstanceAudit {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("InstanceAudit")
+                            .field("instance", &self.instance)
+                            .field("dependencies", &self.dependencies)
+                            .field("capabilities", &self.capabilities)
+                            .finish()
+                    }
+                }
+                /// Represents an audit of the host capabilities reachable in the graph.
+                #[derive(Clone)]
+                pub struct AuditReport {
+                    /// The audit of each instance in the graph.
+                    pub instances: _rt::Vec<InstanceAudit>,
+                    /// The audit formatted as a Markdown report.
+                    pub markdown: _rt::String,
+                }
+                impl ::core::fmt::Debug for AuditReport {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("AuditReport")
+                            .field("instances", &self.instances)
+                            .field("markdown", &self.markdown)
+                            .finish()
+                    }
+                }
+                /// Represents a connection that the second component cannot be used in.
+                #[derive(Clone)]
+                pub struct BrokenConnection {
+                    /// The connection of an instance of the first component.
+                    pub connection: Connection,
+                    /// The reason the connection cannot be made.
+                    pub message: _rt::String,
+                }
+                impl ::core::fmt::Debug for BrokenConnection {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("BrokenConnection")
+                            .field("connection", &self.connection)
+                            .field("message", &self.message)
+                            .finish()
+                    }
+                }
+                /// Represents the differences between the worlds of two components.
+                #[derive(Clone)]
+                pub struct ComponentDiff {
+                    /// The differences, imports first.
+                    pub differences: _rt::Vec<Difference>,
+                    /// The connections that would break if the first component were replaced.
+                    pub broken_connections: _rt::Vec<BrokenConnection>,
+                    /// Whether the second component can replace the first in the graph.
+                    pub compatible: bool,
+                }
+                impl ::core::fmt::Debug for ComponentDiff {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("ComponentDiff")
+                            .field("differences", &self.differences)
+                            .field("broken-connections", &self.broken_connections)
+                            .field("compatible", &self.compatible)
+                            .finish()
+                    }
+                }
+                /// Represents an import or export of the encoded graph.
+                #[derive(Clone)]
+                pub struct PreviewItem {
+                    /// The name of the item.
+                    pub name: _rt::String,
+                    /// The instances the item comes from.
+                    ///
+                    /// An import comes from every instance with an unconnected import of the same name;
+                    /// an export comes from the instance it is exported from.
+                    pub instances: _rt::Vec<InstanceId>,
+                }
+                impl ::core::fmt::Debug for PreviewItem {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("PreviewItem")
+                            .field("name", &self.name)
+                            .field("instances", &self.instances)
+                            .finish()
+                    }
+                }
+                /// Represents a preview of the world of the encoded graph.
+                #[derive(Clone)]
+                pub struct WorldPreview {
+                    /// The WIT of the world, with a comment naming the instances of each item.
+                    pub wit: _rt::String,
+                    /// The imports of the world.
+                    pub imports: _rt::Vec<PreviewItem>,
+                    /// The exports of the world.
+                    pub exports: _rt::Vec<PreviewItem>,
+                }
+                impl ::core::fmt::Debug for WorldPreview {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("WorldPreview")
+                            .field("wit", &self.wit)
+                            .field("imports", &self.imports)
+                            .field("exports", &self.exports)
+                            .finish()
+                    }
+                }
+                /// Represents an item to export from the output.
+                #[derive(Clone)]
+                pub struct ExportItem {
+                    /// The instance to export from.
+                    pub instance: InstanceId,
+                    /// The index of the instance export; if `none`, the instance itself is exported.
+                    pub export: Option<u32>,
+                    /// The name to export the item as.
+                    pub name: _rt::String,
+                }
+                impl ::core::fmt::Debug for ExportItem {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("ExportItem")
+                            .field("instance", &self.instance)
+                            .field("export", &self.export)
+                            .field("name", &self.name)
+                            .finish()
+                    }
+                }
+                /// Represents the contents of a loaded project file.
+                #[derive(Clone)]
+                pub struct Project {
+                    /// The components in the graph.
+                    pub components: _rt::Vec<Component>,
+                    /// The instances in the graph.
+                    pub instances: _rt::Vec<Instance>,
+                    /// The connections between instances in the graph.
+                    pub connections: _rt::Vec<Connection>,
+                    /// The instance to export from the graph.
+                    pub export: Option<InstanceId>,
+                    /// The additional items to export from the graph.
+                    pub exports: _rt::Vec<ExportItem>,
+                }
+                impl ::core::fmt::Debug for Project {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Project")
+                            .field("components", &self.components)
+                            .field("instances", &self.instances)
+                            .field("connections", &self.connections)
+                            .field("export", &self.export)
+                            .field("exports", &self.exports)
+                            .finish()
+                    }
+                }
+                /// Represents options for encoding the graph.
+                #[derive(Clone)]
+                pub struct EncodeOptions {
+                    /// Whether or not to define components in the output.
+                    pub define_components: bool,
+                    /// The instance to export from the output.
+                    pub export: Option<InstanceId>,
+                    /// The additional items to export from the output.
+                    pub exports: _rt::Vec<ExportItem>,
+                    /// Whether or not to validate the output.
+                    pub validate: bool,
+                }
+                impl ::core::fmt::Debug for EncodeOptions {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("EncodeOptions")
+                            .field("define-components", &self.define_components)
+                            .field("export", &self.export)
+                            .field("exports", &self.exports)
+                            .field("validate", &self.validate)
+                            .finish()
+                    }
+                }
+                /// Represents options for disassembling a component.
+                #[derive(Clone)]
+                pub struct WatOptions {
+                    /// Whether to print instructions in folded form.
+                    pub fold_expressions: bool,
+                    /// Whether to skip function bodies and the contents of data and element segments.
+                    pub skip_function_bodies: bool,
+                    /// Whether to print only the component-level structure.
+                    ///
+                    /// The contents of core modules are elided.
+                    pub structure_only: bool,
+                    /// The maximum length of the text in bytes; defaults to 256 KiB.
+                    ///
+                    /// The length includes the note appended to truncated text.
+                    pub max_length: Option<u32>,
+                    /// The options to encode the graph with when the encoded output is disassembled.
+                    pub encode: EncodeOptions,
+                }
+                impl ::core::fmt::Debug for WatOptions {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("WatOptions")
+                            .field("fold-expressions", &self.fold_expressions)
+                            .field("skip-function-bodies", &self.skip_function_bodies)
+                            .field("structure-only", &self.structure_only)
+                            .field("max-length", &self.max_length)
+                            .field("encode", &self.encode)
+                            .finish()
+                    }
+                }
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct Graph {
+                    handle: _rt::Resource<Graph>,
+                }
+                type _GraphRep<T> = Option<T>;
+                impl Graph {
+                    /// Creates a new resource from the specified representation.
+                    ///
+                    /// This function will create a new resource handle by moving `val` onto
+                    /// the heap and then passing that heap pointer to the component model to
+                    /// create a handle. The owned handle is then returned as `Graph`.
+                    pub fn new<T: GuestGraph>(val: T) -> Self {
+                        Self::type_guard::<T>();
+                        let val: _GraphRep<T> = Some(val);
+                        let ptr: *mut _GraphRep<T> = _rt::Box::into_raw(
+                            _rt::Box::new(val),
+                        );
+                        unsafe { Self::from_handle(T::_resource_new(ptr.cast())) }
+                    }
+                    /// Gets access to the underlying `T` which represents this resource.
+                    pub fn get<T: GuestGraph>(&self) -> &T {
+                        let ptr = unsafe { &*self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+                    /// Gets mutable access to the underlying `T` which represents this
+                    /// resource.
+                    pub fn get_mut<T: GuestGraph>(&mut self) -> &mut T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_mut().unwrap()
+                    }
+                    /// Consumes this resource and returns the underlying `T`.
+                    pub fn into_inner<T: GuestGraph>(self) -> T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.take().unwrap()
+                    }
+                    #[doc(hidden)]
+                    pub unsafe fn from_handle(handle: u32) -> Self {
+                        Self {
+                            handle: _rt::Resource::from_handle(handle),
+                        }
+                    }
+                    #[doc(hidden)]
+                    pub fn take_handle(&self) -> u32 {
+                        _rt::Resource::take_handle(&self.handle)
+                    }
+                    #[doc(hidden)]
+                    pub fn handle(&self) -> u32 {
+                        _rt::Resource::handle(&self.handle)
+                    }
+                    #[doc(hidden)]
+                    fn type_guard<T: 'static>() {
+                        use core::any::TypeId;
+                        static mut LAST_TYPE: Option<TypeId> = None;
+                        unsafe {
+                            assert!(! cfg!(target_feature = "atomics"));
+                            let id = TypeId::of::<T>();
+                            match LAST_TYPE {
+                                Some(ty) => {
+                                    assert!(
+                                        ty == id, "cannot use two types with this resource type"
+                                    )
+                                }
+                                None => LAST_TYPE = Some(id),
+                            }
+                        }
+                    }
+                    #[doc(hidden)]
+                    pub unsafe fn dtor<T: 'static>(handle: *mut u8) {
+                        Self::type_guard::<T>();
+                        let _ = _rt::Box::from_raw(handle as *mut _GraphRep<T>);
+                    }
+                    fn as_ptr<T: GuestGraph>(&self) -> *mut _GraphRep<T> {
+                        Graph::type_guard::<T>();
+                        T::_resource_rep(self.handle()).cast()
+                    }
+                }
+                /// A borrowed version of [`Graph`] which represents a borrowed value
+                /// with the lifetime `'a`.
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct GraphBorrow<'a> {
+                    rep: *mut u8,
+                    _marker: core::marker::PhantomData<&'a Graph>,
+                }
+                impl<'a> GraphBorrow<'a> {
+                    #[doc(hidden)]
+                    pub unsafe fn lift(rep: usize) -> Self {
+                        Self {
+                            rep: rep as *mut u8,
+                            _marker: core::marker::PhantomData,
+                        }
+                    }
+                    /// Gets access to the underlying `T` in this resource.
+                    pub fn get<T: GuestGraph>(&self) -> &T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+                    fn as_ptr<T: 'static>(&self) -> *mut _GraphRep<T> {
+                        Graph::type_guard::<T>();
+                        self.rep.cast()
+                    }
+                }
+                unsafe impl _rt::WasmResource for Graph {
+                    #[inline]
+                    unsafe fn drop(_handle: u32) {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unreachable!();
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(
+                                wasm_import_module = "[export]wasmbuilder-app:graph/provider"
+                            )]
+                            extern "C" {
+                                #[link_name = "[resource-drop]graph"]
+                                fn drop(_: u32);
+                            }
+                            drop(_handle);
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_constructor_graph_cabi<T: GuestGraph>() -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = Graph::new(T::new());
+                    (result0).take_handle() as i32
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_add_component_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let len1 = arg4;
+                    let result2 = T::add_component(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        _rt::Vec::from_raw_parts(arg3.cast(), len1, len1),
+                        AddOptions {
+                            duplicates: DuplicatePolicy::_lift(arg5 as u8),
+                            adapter: match arg6 {
+                                0 => None,
+                                1 => {
+                                    let e = WasiAdapter::_lift(arg7 as u8);
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                        },
+                    );
+                    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result2 {
+                        Ok(e) => {
+                            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+                            let Component {
+                                id: id4,
+                                name: name4,
+                                package_name: package_name4,
+                                version: version4,
+                                digest: digest4,
+                                imports: imports4,
+                                exports: exports4,
+                                wit: wit4,
+                                metadata: metadata4,
+                            } = e;
+                            *ptr3.add(8).cast::<i32>() = _rt::as_i32(id4);
+                            let vec5 = (name4.into_bytes()).into_boxed_slice();
+                            let ptr5 = vec5.as_ptr().cast::<u8>();
+                            let len5 = vec5.len();
+                            ::core::mem::forget(vec5);
+                            *ptr3.add(16).cast::<usize>() = len5;
+                            *ptr3.add(12).cast::<*mut u8>() = ptr5.cast_mut();
+                            let vec6 = (package_name4.into_bytes()).into_boxed_slice();
+                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                            let len6 = vec6.len();
+                            ::core::mem::forget(vec6);
+                            *ptr3.add(24).cast::<usize>() = len6;
+                            *ptr3.add(20).cast::<*mut u8>() = ptr6.cast_mut();
+                            match version4 {
+                                Some(e) => {
+                                    *ptr3.add(28).cast::<u8>() = (1i32) as u8;
+                                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr3.add(36).cast::<usize>() = len7;
+                                    *ptr3.add(32).cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                                None => {
+                                    *ptr3.add(28).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            let vec8 = (digest4.into_bytes()).into_boxed_slice();
+                            let ptr8 = vec8.as_ptr().cast::<u8>();
+                            let len8 = vec8.len();
+                            ::core::mem::forget(vec8);
+                            *ptr3.add(44).cast::<usize>() = len8;
+                            *ptr3.add(40).cast::<*mut u8>() = ptr8.cast_mut();
+                            let vec16 = imports4;
+                            let len16 = vec16.len();
+                            let layout16 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec16.len() * 28,
+                                4,
+                            );
+                            let result16 = if layout16.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout16).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout16);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec16.into_iter().enumerate() {
+                                let base = result16.add(i * 28);
+                                {
+                                    let Import {
+                                        name: name9,
+                                        kind: kind9,
+                                        signature: signature9,
+                                        members: members9,
+                                    } = e;
+                                    let vec10 = (name9.into_bytes()).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *base.add(4).cast::<usize>() = len10;
+                                    *base.add(0).cast::<*mut u8>() = ptr10.cast_mut();
+                                    *base.add(8).cast::<u8>() = (kind9.clone() as i32) as u8;
+                                    let vec11 = (signature9.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *base.add(16).cast::<usize>() = len11;
+                                    *base.add(12).cast::<*mut u8>() = ptr11.cast_mut();
+                                    let vec15 = members9;
+                                    let len15 = vec15.len();
+                                    let layout15 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec15.len() * 20,
+                                        4,
+                                    );
+                                    let result15 = if layout15.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout15).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout15);
+                                        }
+                                        ptr
+                                    } else {
+                                        { ::core::ptr::null_mut() }
+                                    };
+                                    for (i, e) in vec15.into_iter().enumerate() {
+                                        let base = result15.add(i * 20);
+                                        {
+                                            let Member {
+                                                name: name12,
+                                                kind: kind12,
+                                                signature: signature12,
+                                            } = e;
+                                            let vec13 = (name12.into_bytes()).into_boxed_slice();
+                                            let ptr13 = vec13.as_ptr().cast::<u8>();
+                                            let len13 = vec13.len();
+                                            ::core::mem::forget(vec13);
+                                            *base.add(4).cast::<usize>() = len13;
+                                            *base.add(0).cast::<*mut u8>() = ptr13.cast_mut();
+                                            *base.add(8).cast::<u8>() = (kind12.clone() as i32) as u8;
+                                            let vec14 = (signature12.into_bytes()).into_boxed_slice();
+                                            let ptr14 = vec14.as_ptr().cast::<u8>();
+                                            let len14 = vec14.len();
+                                            ::core::mem::forget(vec14);
+                                            *base.add(16).cast::<usize>() = len14;
+                                            *base.add(12).cast::<*mut u8>() = ptr14.cast_mut();
+                                        }
+                                    }
+                                    *base.add(24).cast::<usize>() = len15;
+                                    *base.add(20).cast::<*mut u8>() = result15;
+                                }
+                            }
+                            *ptr3.add(52).cast::<usize>() = len16;
+                            *ptr3.add(48).cast::<*mut u8>() = result16;
+                            let vec24 = exports4;
+                            let len24 = vec24.len();
+                            let layout24 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec24.len() * 28,
+                                4,
+                            );
+                            let result24 = if layout24.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout24).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout24);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec24.into_iter().enumerate() {
+                                let base = result24.add(i * 28);
+                                {
+                                    let Export {
+                                        name: name17,
+                                        kind: kind17,
+                                        signature: signature17,
+                                        members: members17,
+                                    } = e;
+                                    let vec18 = (name17.into_bytes()).into_boxed_slice();
+                                    let ptr18 = vec18.as_ptr().cast::<u8>();
+                                    let len18 = vec18.len();
+                                    ::core::mem::forget(vec18);
+                                    *base.add(4).cast::<usize>() = len18;
+                                    *base.add(0).cast::<*mut u8>() = ptr18.cast_mut();
+                                    *base.add(8).cast::<u8>() = (kind17.clone() as i32) as u8;
+                                    let vec19 = (signature17.into_bytes()).into_boxed_slice();
+                                    let ptr19 = vec19.as_ptr().cast::<u8>();
+                                    let len19 = vec19.len();
+                                    ::core::mem::forget(vec19);
+                                    *base.add(16).cast::<usize>() = len19;
+                                    *base.add(12).cast::<*mut u8>() = ptr19.cast_mut();
+                                    let vec23 = members17;
+                                    let len23 = vec23.len();
+                                    let layout23 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec23.len() * 20,
+                                        4,
+                                    );
+                                    let result23 = if layout23.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout23).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout23);
+                                        }
+                                        ptr
+                                    } else {
+                                        { ::core::ptr::null_mut() }
+                                    };
+                                    for (i, e) in vec23.into_iter().enumerate() {
+                                        let base = result23.add(i * 20);
+                                        {
+                                            let Member {
+                                                name: name20,
+                                                kind: kind20,
+                                                signature: signature20,
+                                            } = e;
+                                            let vec21 = (name20.into_bytes()).into_boxed_slice();
+                                            let ptr21 = vec21.as_ptr().cast::<u8>();
+                                            let len21 = vec21.len();
+                                            ::core::mem::forget(vec21);
+                                            *base.add(4).cast::<usize>() = len21;
+                                            *base.add(0).cast::<*mut u8>() = ptr21.cast_mut();
+                                            *base.add(8).cast::<u8>() = (kind20.clone() as i32) as u8;
+                                            let vec22 = (signature20.into_bytes()).into_boxed_slice();
+                                            let ptr22 = vec22.as_ptr().cast::<u8>();
+                                            let len22 = vec22.len();
+                                            ::core::mem::forget(vec22);
+                                            *base.add(16).cast::<usize>() = len22;
+                                            *base.add(12).cast::<*mut u8>() = ptr22.cast_mut();
+                                        }
+                                    }
+                                    *base.add(24).cast::<usize>() = len23;
+                                    *base.add(20).cast::<*mut u8>() = result23;
+                                }
+                            }
+                            *ptr3.add(60).cast::<usize>() = len24;
+                            *ptr3.add(56).cast::<*mut u8>() = result24;
+                            let vec25 = (wit4.into_bytes()).into_boxed_slice();
+                            let ptr25 = vec25.as_ptr().cast::<u8>();
+                            let len25 = vec25.len();
+                            ::core::mem::forget(vec25);
+                            *ptr3.add(68).cast::<usize>() = len25;
+                            *ptr3.add(64).cast::<*mut u8>() = ptr25.cast_mut();
+                            let ComponentMetadata {
+                                name: name26,
+                                producers: producers26,
+                                authors: authors26,
+                                description: description26,
+                                licenses: licenses26,
+                                source: source26,
+                                homepage: homepage26,
+                                revision: revision26,
+                                version: version26,
+                            } = metadata4;
+                            match name26 {
+                                Some(e) => {
+                                    *ptr3.add(72).cast::<u8>() = (1i32) as u8;
+                                    let vec27 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                                    let len27 = vec27.len();
+                                    ::core::mem::forget(vec27);
+                                    *ptr3.add(80).cast::<usize>() = len27;
+                                    *ptr3.add(76).cast::<*mut u8>() = ptr27.cast_mut();
+                                }
+                                None => {
+                                    *ptr3.add(72).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            let vec32 = producers26;
+                            let len32 = vec32.len();
+                            let layout32 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec32.len() * 24,
+                                4,
+                            );
+                            let result32 = if layout32.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout32).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout32);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec32.into_iter().enumerate() {
+                                let base = result32.add(i * 24);
+                                {
+                                    let Producer {
+                                        field: field28,
+                                        name: name28,
+                                        version: version28,
+                                    } = e;
+                                    let vec29 = (field28.into_bytes()).into_boxed_slice();
+                                    let ptr29 = vec29.as_ptr().cast::<u8>();
+                                    let len29 = vec29.len();
+                                    ::core::mem::forget(vec29);
+                                    *base.add(4).cast::<usize>() = len29;
+                                    *base.add(0).cast::<*mut u8>() = ptr29.cast_mut();
+                                    let vec30 = (name28.into_bytes()).into_boxed_slice();
+                                    let ptr30 = vec30.as_ptr().cast::<u8>();
+                                    let len30 = vec30.len();
+                                    ::core::mem::forget(vec30);
+                                    *base.add(12).cast::<usize>() = len30;
+                                    *base.add(8).cast::<*mut u8>() = ptr30.cast_mut();
+                                    let vec31 = (version28.into_bytes()).into_boxed_slice();
+                                    let ptr31 = vec31.as_ptr().cast::<u8>();
+                                    let len31 = vec31.len();
+                                    ::core::mem::forget(vec31);
+                                    *base.add(20).cast::<usize>() = len31;
+                                    *base.add(16).cast::<*mut u8>() = ptr31.cast_mut();
+                                }
+                            }
+                            *ptr3.add(88).cast::<usize>() = len32;
+                            *ptr3.add(84).cast::<*mut u8>() = result32;
+                            match authors26 {
+                                Some(e) => {
+                                    *ptr3.add(92).cast::<u8>() = (1i32) as u8;
+                                    let vec33 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr33 = vec33.as_ptr().cast::<u8>();
+                                    let len33 = vec33.len();
+                                    ::core::mem::forget(vec33);
+                                    *ptr3.add(100).cast::<usize>() = len33;
+                                    *ptr3.add(96).cast::<*mut u8>() = ptr33.cast_mut();
+                                }
+                                None => {
+                                    *ptr3.add(92).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match description26 {
+                                Some(e) => {
+                                    *ptr3.add(104).cast::<u8>() = (1i32) as u8;
+                                    let vec34 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr34 = vec34.as_ptr().cast::<u8>();
+                                    let len34 = vec34.len();
+                                    ::core::mem::forget(vec34);
+                                    *ptr3.add(112).cast::<usize>() = len34;
+                                    *ptr3.add(108).cast::<*mut u8>() = ptr34.cast_mut();
+                                }
+                                None => {
+                                    *ptr3.add(104).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match licenses26 {
+                                Some(e) => {
+                                    *ptr3.add(116).cast::<u8>() = (1i32) as u8;
+                                    let vec35 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr35 = vec35.as_ptr().cast::<u8>();
+                                    let len35 = vec35.len();
+                                    ::core::mem::forget(vec35);
+                                    *ptr3.add(124).cast::<usize>() = len35;
+                                    *ptr3.add(120).cast::<*mut u8>() = ptr35.cast_mut();
+                                }
+                                None => {
+                                    *ptr3.add(116).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match source26 {
+                                Some(e) => {
+                                    *ptr3.add(128).cast::<u8>() = (1i32) as u8;
+                                    let vec36 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr36 = vec36.as_ptr().cast::<u8>();
+                                    let len36 = vec36.len();
+                                    ::core::mem::forget(vec36);
+                                    *ptr3.add(136).cast::<usize>() = len36;
+                                    *ptr3.add(132).cast::<*mut u8>() = ptr36.cast_mut();
+                                }
+                                None => {
+                                    *ptr3.add(128).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match homepage26 {
+                                Some(e) => {
+                                    *ptr3.add(140).cast::<u8>() = (1i32) as u8;
+                                    let vec37 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr37 = vec37.as_ptr().cast::<u8>();
+                                    let len37 = vec37.len();
+                                    ::core::mem::forget(vec37);
+                                    *ptr3.add(148).cast::<usize>() = len37;
+                                    *ptr3.add(144).cast::<*mut u8>() = ptr37.cast_mut();
+                                }
+                                None => {
+                                    *ptr3.add(140).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match revision26 {
+                                Some(e) => {
+                                    *ptr3.add(152).cast::<u8>() = (1i32) as u8;
+                                    let vec38 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr38 = vec38.as_ptr().cast::<u8>();
+                                    let len38 = vec38.len();
+                                    ::core::mem::forget(vec38);
+                                    *ptr3.add(160).cast::<usize>() = len38;
+                                    *ptr3.add(156).cast::<*mut u8>() = ptr38.cast_mut();
+                                }
+                                None => {
+                                    *ptr3.add(152).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match version26 {
+                                Some(e) => {
+                                    *ptr3.add(164).cast::<u8>() = (1i32) as u8;
+                                    let vec39 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr39 = vec39.as_ptr().cast::<u8>();
+                                    let len39 = vec39.len();
+                                    ::core::mem::forget(vec39);
+                                    *ptr3.add(172).cast::<usize>() = len39;
+                                    *ptr3.add(168).cast::<*mut u8>() = ptr39.cast_mut();
+                                }
+                                None => {
+                                    *ptr3.add(164).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                        Err(e) => {
+                            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message40, offset: offset40 } = e;
+                                    let vec41 = (message40.into_bytes()).into_boxed_slice();
+                                    let ptr41 = vec41.as_ptr().cast::<u8>();
+                                    let len41 = vec41.len();
+                                    ::core::mem::forget(vec41);
+                                    *ptr3.add(20).cast::<usize>() = len41;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr41.cast_mut();
+                                    match offset40 {
+                                        Some(e) => {
+                                            *ptr3.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr3.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr3.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message42, offset: offset42 } = e;
+                                    let vec43 = (message42.into_bytes()).into_boxed_slice();
+                                    let ptr43 = vec43.as_ptr().cast::<u8>();
+                                    let len43 = vec43.len();
+                                    ::core::mem::forget(vec43);
+                                    *ptr3.add(20).cast::<usize>() = len43;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr43.cast_mut();
+                                    match offset42 {
+                                        Some(e) => {
+                                            *ptr3.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr3.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr3.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export44,
+                                        import: import44,
+                                        message: message44,
+                                    } = e;
+                                    match export44 {
+                                        Some(e) => {
+                                            *ptr3.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec45 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr45 = vec45.as_ptr().cast::<u8>();
+                                            let len45 = vec45.len();
+                                            ::core::mem::forget(vec45);
+                                            *ptr3.add(24).cast::<usize>() = len45;
+                                            *ptr3.add(20).cast::<*mut u8>() = ptr45.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr3.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec46 = (import44.into_bytes()).into_boxed_slice();
+                                    let ptr46 = vec46.as_ptr().cast::<u8>();
+                                    let len46 = vec46.len();
+                                    ::core::mem::forget(vec46);
+                                    *ptr3.add(32).cast::<usize>() = len46;
+                                    *ptr3.add(28).cast::<*mut u8>() = ptr46.cast_mut();
+                                    let vec47 = (message44.into_bytes()).into_boxed_slice();
+                                    let ptr47 = vec47.as_ptr().cast::<u8>();
+                                    let len47 = vec47.len();
+                                    ::core::mem::forget(vec47);
+                                    *ptr3.add(40).cast::<usize>() = len47;
+                                    *ptr3.add(36).cast::<*mut u8>() = ptr47.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances48,
+                                        message: message48,
+                                    } = e;
+                                    let vec49 = (instances48).into_boxed_slice();
+                                    let ptr49 = vec49.as_ptr().cast::<u8>();
+                                    let len49 = vec49.len();
+                                    ::core::mem::forget(vec49);
+                                    *ptr3.add(20).cast::<usize>() = len49;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr49.cast_mut();
+                                    let vec50 = (message48.into_bytes()).into_boxed_slice();
+                                    let ptr50 = vec50.as_ptr().cast::<u8>();
+                                    let len50 = vec50.len();
+                                    ::core::mem::forget(vec50);
+                                    *ptr3.add(28).cast::<usize>() = len50;
+                                    *ptr3.add(24).cast::<*mut u8>() = ptr50.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr3.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr3.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec51 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr51 = vec51.as_ptr().cast::<u8>();
+                                    let len51 = vec51.len();
+                                    ::core::mem::forget(vec51);
+                                    *ptr3.add(20).cast::<usize>() = len51;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr51.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec52 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr52 = vec52.as_ptr().cast::<u8>();
+                                    let len52 = vec52.len();
+                                    ::core::mem::forget(vec52);
+                                    *ptr3.add(20).cast::<usize>() = len52;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr52.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec53 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr53 = vec53.as_ptr().cast::<u8>();
+                                    let len53 = vec53.len();
+                                    ::core::mem::forget(vec53);
+                                    *ptr3.add(20).cast::<usize>() = len53;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr53.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message54,
+                                        line: line54,
+                                        column: column54,
+                                    } = e;
+                                    let vec55 = (message54.into_bytes()).into_boxed_slice();
+                                    let ptr55 = vec55.as_ptr().cast::<u8>();
+                                    let len55 = vec55.len();
+                                    ::core::mem::forget(vec55);
+                                    *ptr3.add(20).cast::<usize>() = len55;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr55.cast_mut();
+                                    *ptr3.add(24).cast::<i32>() = _rt::as_i32(line54);
+                                    *ptr3.add(28).cast::<i32>() = _rt::as_i32(column54);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec56 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr56 = vec56.as_ptr().cast::<u8>();
+                                    let len56 = vec56.len();
+                                    ::core::mem::forget(vec56);
+                                    *ptr3.add(20).cast::<usize>() = len56;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr56.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr3
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_add_component<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(12).cast::<*mut u8>();
+                            let l2 = *arg0.add(16).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = *arg0.add(20).cast::<*mut u8>();
+                            let l4 = *arg0.add(24).cast::<usize>();
+                            _rt::cabi_dealloc(l3, l4, 1);
+                            let l5 = i32::from(*arg0.add(28).cast::<u8>());
+                            match l5 {
+                                0 => {}
+                                _ => {
+                                    let l6 = *arg0.add(32).cast::<*mut u8>();
+                                    let l7 = *arg0.add(36).cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                            }
+                            let l8 = *arg0.add(40).cast::<*mut u8>();
+                            let l9 = *arg0.add(44).cast::<usize>();
+                            _rt::cabi_dealloc(l8, l9, 1);
+                            let l10 = *arg0.add(48).cast::<*mut u8>();
+                            let l11 = *arg0.add(52).cast::<usize>();
+                            let base23 = l10;
+                            let len23 = l11;
+                            for i in 0..len23 {
+                                let base = base23.add(i * 28);
+                                {
+                                    let l12 = *base.add(0).cast::<*mut u8>();
+                                    let l13 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                    let l14 = *base.add(12).cast::<*mut u8>();
+                                    let l15 = *base.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l14, l15, 1);
+                                    let l16 = *base.add(20).cast::<*mut u8>();
+                                    let l17 = *base.add(24).cast::<usize>();
+                                    let base22 = l16;
+                                    let len22 = l17;
+                                    for i in 0..len22 {
+                                        let base = base22.add(i * 20);
+                                        {
+                                            let l18 = *base.add(0).cast::<*mut u8>();
+                                            let l19 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l18, l19, 1);
+                                            let l20 = *base.add(12).cast::<*mut u8>();
+                                            let l21 = *base.add(16).cast::<usize>();
+                                            _rt::cabi_dealloc(l20, l21, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base22, len22 * 20, 4);
+                                }
+                            }
+                            _rt::cabi_dealloc(base23, len23 * 28, 4);
+                            let l24 = *arg0.add(56).cast::<*mut u8>();
+                            let l25 = *arg0.add(60).cast::<usize>();
+                            let base37 = l24;
+                            let len37 = l25;
+                            for i in 0..len37 {
+                                let base = base37.add(i * 28);
+                                {
+                                    let l26 = *base.add(0).cast::<*mut u8>();
+                                    let l27 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l26, l27, 1);
+                                    let l28 = *base.add(12).cast::<*mut u8>();
+                                    let l29 = *base.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l28, l29, 1);
+                                    let l30 = *base.add(20).cast::<*mut u8>();
+                                    let l31 = *base.add(24).cast::<usize>();
+                                    let base36 = l30;
+                                    let len36 = l31;
+                                    for i in 0..len36 {
+                                        let base = base36.add(i * 20);
+                                        {
+                                            let l32 = *base.add(0).cast::<*mut u8>();
+                                            let l33 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l32, l33, 1);
+                                            let l34 = *base.add(12).cast::<*mut u8>();
+                                            let l35 = *base.add(16).cast::<usize>();
+                                            _rt::cabi_dealloc(l34, l35, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base36, len36 * 20, 4);
+                                }
+                            }
+                            _rt::cabi_dealloc(base37, len37 * 28, 4);
+                            let l38 = *arg0.add(64).cast::<*mut u8>();
+                            let l39 = *arg0.add(68).cast::<usize>();
+                            _rt::cabi_dealloc(l38, l39, 1);
+                            let l40 = i32::from(*arg0.add(72).cast::<u8>());
+                            match l40 {
+                                0 => {}
+                                _ => {
+                                    let l41 = *arg0.add(76).cast::<*mut u8>();
+                                    let l42 = *arg0.add(80).cast::<usize>();
+                                    _rt::cabi_dealloc(l41, l42, 1);
+                                }
+                            }
+                            let l43 = *arg0.add(84).cast::<*mut u8>();
+                            let l44 = *arg0.add(88).cast::<usize>();
+                            let base51 = l43;
+                            let len51 = l44;
+                            for i in 0..len51 {
+                                let base = base51.add(i * 24);
+                                {
+                                    let l45 = *base.add(0).cast::<*mut u8>();
+                                    let l46 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l45, l46, 1);
+                                    let l47 = *base.add(8).cast::<*mut u8>();
+                                    let l48 = *base.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l47, l48, 1);
+                                    let l49 = *base.add(16).cast::<*mut u8>();
+                                    let l50 = *base.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l49, l50, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base51, len51 * 24, 4);
+                            let l52 = i32::from(*arg0.add(92).cast::<u8>());
+                            match l52 {
+                                0 => {}
+                                _ => {
+                                    let l53 = *arg0.add(96).cast::<*mut u8>();
+                                    let l54 = *arg0.add(100).cast::<usize>();
+                                    _rt::cabi_dealloc(l53, l54, 1);
+                                }
+                            }
+                            let l55 = i32::from(*arg0.add(104).cast::<u8>());
+                            match l55 {
+                                0 => {}
+                                _ => {
+                                    let l56 = *arg0.add(108).cast::<*mut u8>();
+                                    let l57 = *arg0.add(112).cast::<usize>();
+                                    _rt::cabi_dealloc(l56, l57, 1);
+                                }
+                            }
+                            let l58 = i32::from(*arg0.add(116).cast::<u8>());
+                            match l58 {
+                                0 => {}
+                                _ => {
+                                    let l59 = *arg0.add(120).cast::<*mut u8>();
+                                    let l60 = *arg0.add(124).cast::<usize>();
+                                    _rt::cabi_dealloc(l59, l60, 1);
+                                }
+                            }
+                            let l61 = i32::from(*arg0.add(128).cast::<u8>());
+                            match l61 {
+                                0 => {}
+                                _ => {
+                                    let l62 = *arg0.add(132).cast::<*mut u8>();
+                                    let l63 = *arg0.add(136).cast::<usize>();
+                                    _rt::cabi_dealloc(l62, l63, 1);
+                                }
+                            }
+                            let l64 = i32::from(*arg0.add(140).cast::<u8>());
+                            match l64 {
+                                0 => {}
+                                _ => {
+                                    let l65 = *arg0.add(144).cast::<*mut u8>();
+                                    let l66 = *arg0.add(148).cast::<usize>();
+                                    _rt::cabi_dealloc(l65, l66, 1);
+                                }
+                            }
+                            let l67 = i32::from(*arg0.add(152).cast::<u8>());
+                            match l67 {
+                                0 => {}
+                                _ => {
+                                    let l68 = *arg0.add(156).cast::<*mut u8>();
+                                    let l69 = *arg0.add(160).cast::<usize>();
+                                    _rt::cabi_dealloc(l68, l69, 1);
+                                }
+                            }
+                            let l70 = i32::from(*arg0.add(164).cast::<u8>());
+                            match l70 {
+                                0 => {}
+                                _ => {
+                                    let l71 = *arg0.add(168).cast::<*mut u8>();
+                                    let l72 = *arg0.add(172).cast::<usize>();
+                                    _rt::cabi_dealloc(l71, l72, 1);
+                                }
+                            }
+                        }
+                        _ => {
+                            let l73 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l73 {
+                                0 => {
+                                    let l74 = *arg0.add(16).cast::<*mut u8>();
+                                    let l75 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l74, l75, 1);
+                                }
+                                1 => {
+                                    let l76 = *arg0.add(16).cast::<*mut u8>();
+                                    let l77 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l76, l77, 1);
+                                }
+                                2 => {
+                                    let l78 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l78 {
+                                        0 => {}
+                                        _ => {
+                                            let l79 = *arg0.add(20).cast::<*mut u8>();
+                                            let l80 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l79, l80, 1);
+                                        }
+                                    }
+                                    let l81 = *arg0.add(28).cast::<*mut u8>();
+                                    let l82 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l81, l82, 1);
+                                    let l83 = *arg0.add(36).cast::<*mut u8>();
+                                    let l84 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l83, l84, 1);
+                                }
+                                3 => {
+                                    let l85 = *arg0.add(16).cast::<*mut u8>();
+                                    let l86 = *arg0.add(20).cast::<usize>();
+                                    let base87 = l85;
+                                    let len87 = l86;
+                                    _rt::cabi_dealloc(base87, len87 * 4, 4);
+                                    let l88 = *arg0.add(24).cast::<*mut u8>();
+                                    let l89 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l88, l89, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l90 = *arg0.add(16).cast::<*mut u8>();
+                                    let l91 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l90, l91, 1);
+                                }
+                                7 => {
+                                    let l92 = *arg0.add(16).cast::<*mut u8>();
+                                    let l93 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l92, l93, 1);
+                                }
+                                8 => {
+                                    let l94 = *arg0.add(16).cast::<*mut u8>();
+                                    let l95 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l94, l95, 1);
+                                }
+                                9 => {
+                                    let l96 = *arg0.add(16).cast::<*mut u8>();
+                                    let l97 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l96, l97, 1);
+                                }
+                                _ => {
+                                    let l98 = *arg0.add(16).cast::<*mut u8>();
+                                    let l99 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l98, l99, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_register_package_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i32,
+                    arg4: *mut u8,
+                    arg5: usize,
+                    arg6: *mut u8,
+                    arg7: usize,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let len2 = arg7;
+                    let result3 = T::register_package(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        match arg3 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let len1 = arg5;
+                                    let bytes1 = _rt::Vec::from_raw_parts(
+                                        arg4.cast(),
+                                        len1,
+                                        len1,
+                                    );
+                                    _rt::string_lift(bytes1)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        _rt::Vec::from_raw_parts(arg6.cast(), len2, len2),
+                        AddOptions {
+                            duplicates: DuplicatePolicy::_lift(arg8 as u8),
+                            adapter: match arg9 {
+                                0 => None,
+                                1 => {
+                                    let e = WasiAdapter::_lift(arg10 as u8);
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                        },
+                    );
+                    let ptr4 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result3 {
+                        Ok(e) => {
+                            *ptr4.add(0).cast::<u8>() = (0i32) as u8;
+                            let Component {
+                                id: id5,
+                                name: name5,
+                                package_name: package_name5,
+                                version: version5,
+                                digest: digest5,
+                                imports: imports5,
+                                exports: exports5,
+                                wit: wit5,
+                                metadata: metadata5,
+                            } = e;
+                            *ptr4.add(8).cast::<i32>() = _rt::as_i32(id5);
+                            let vec6 = (name5.into_bytes()).into_boxed_slice();
+                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                            let len6 = vec6.len();
+                            ::core::mem::forget(vec6);
+                            *ptr4.add(16).cast::<usize>() = len6;
+                            *ptr4.add(12).cast::<*mut u8>() = ptr6.cast_mut();
+                            let vec7 = (package_name5.into_bytes()).into_boxed_slice();
+                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                            let len7 = vec7.len();
+                            ::core::mem::forget(vec7);
+                            *ptr4.add(24).cast::<usize>() = len7;
+                            *ptr4.add(20).cast::<*mut u8>() = ptr7.cast_mut();
+                            match version5 {
+                                Some(e) => {
+                                    *ptr4.add(28).cast::<u8>() = (1i32) as u8;
+                                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr4.add(36).cast::<usize>() = len8;
+                                    *ptr4.add(32).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(28).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            let vec9 = (digest5.into_bytes()).into_boxed_slice();
+                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                            let len9 = vec9.len();
+                            ::core::mem::forget(vec9);
+                            *ptr4.add(44).cast::<usize>() = len9;
+                            *ptr4.add(40).cast::<*mut u8>() = ptr9.cast_mut();
+                            let vec17 = imports5;
+                            let len17 = vec17.len();
+                            let layout17 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec17.len() * 28,
+                                4,
+                            );
+                            let result17 = if layout17.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout17).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout17);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec17.into_iter().enumerate() {
+                                let base = result17.add(i * 28);
+                                {
+                                    let Import {
+                                        name: name10,
+                                        kind: kind10,
+                                        signature: signature10,
+                                        members: members10,
+                                    } = e;
+                                    let vec11 = (name10.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *base.add(4).cast::<usize>() = len11;
+                                    *base.add(0).cast::<*mut u8>() = ptr11.cast_mut();
+                                    *base.add(8).cast::<u8>() = (kind10.clone() as i32) as u8;
+                                    let vec12 = (signature10.into_bytes()).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *base.add(16).cast::<usize>() = len12;
+                                    *base.add(12).cast::<*mut u8>() = ptr12.cast_mut();
+                                    let vec16 = members10;
+                                    let len16 = vec16.len();
+                                    let layout16 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec16.len() * 20,
+                                        4,
+                                    );
+                                    let result16 = if layout16.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout16).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout16);
+                                        }
+                                        ptr
+                                    } else {
+                                        { ::core::ptr::null_mut() }
+                                    };
+                                    for (i, e) in vec16.into_iter().enumerate() {
+                                        let base = result16.add(i * 20);
+                                        {
+                                            let Member {
+                                                name: name13,
+                                                kind: kind13,
+                                                signature: signature13,
+                                            } = e;
+                                            let vec14 = (name13.into_bytes()).into_boxed_slice();
+                                            let ptr14 = vec14.as_ptr().cast::<u8>();
+                                            let len14 = vec14.len();
+                                            ::core::mem::forget(vec14);
+                                            *base.add(4).cast::<usize>() = len14;
+                                            *base.add(0).cast::<*mut u8>() = ptr14.cast_mut();
+                                            *base.add(8).cast::<u8>() = (kind13.clone() as i32) as u8;
+                                            let vec15 = (signature13.into_bytes()).into_boxed_slice();
+                                            let ptr15 = vec15.as_ptr().cast::<u8>();
+                                            let len15 = vec15.len();
+                                            ::core::mem::forget(vec15);
+                                            *base.add(16).cast::<usize>() = len15;
+                                            *base.add(12).cast::<*mut u8>() = ptr15.cast_mut();
+                                        }
+                                    }
+                                    *base.add(24).cast::<usize>() = len16;
+                                    *base.add(20).cast::<*mut u8>() = result16;
+                                }
+                            }
+                            *ptr4.add(52).cast::<usize>() = len17;
+                            *ptr4.add(48).cast::<*mut u8>() = result17;
+                            let vec25 = exports5;
+                            let len25 = vec25.len();
+                            let layout25 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec25.len() * 28,
+                                4,
+                            );
+                            let result25 = if layout25.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout25).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout25);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec25.into_iter().enumerate() {
+                                let base = result25.add(i * 28);
+                                {
+                                    let Export {
+                                        name: name18,
+                                        kind: kind18,
+                                        signature: signature18,
+                                        members: members18,
+                                    } = e;
+                                    let vec19 = (name18.into_bytes()).into_boxed_slice();
+                                    let ptr19 = vec19.as_ptr().cast::<u8>();
+                                    let len19 = vec19.len();
+                                    ::core::mem::forget(vec19);
+                                    *base.add(4).cast::<usize>() = len19;
+                                    *base.add(0).cast::<*mut u8>() = ptr19.cast_mut();
+                                    *base.add(8).cast::<u8>() = (kind18.clone() as i32) as u8;
+                                    let vec20 = (signature18.into_bytes()).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *base.add(16).cast::<usize>() = len20;
+                                    *base.add(12).cast::<*mut u8>() = ptr20.cast_mut();
+                                    let vec24 = members18;
+                                    let len24 = vec24.len();
+                                    let layout24 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec24.len() * 20,
+                                        4,
+                                    );
+                                    let result24 = if layout24.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout24).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout24);
+                                        }
+                                        ptr
+                                    } else {
+                                        { ::core::ptr::null_mut() }
+                                    };
+                                    for (i, e) in vec24.into_iter().enumerate() {
+                                        let base = result24.add(i * 20);
+                                        {
+                                            let Member {
+                                                name: name21,
+                                                kind: kind21,
+                                                signature: signature21,
+                                            } = e;
+                                            let vec22 = (name21.into_bytes()).into_boxed_slice();
+                                            let ptr22 = vec22.as_ptr().cast::<u8>();
+                                            let len22 = vec22.len();
+                                            ::core::mem::forget(vec22);
+                                            *base.add(4).cast::<usize>() = len22;
+                                            *base.add(0).cast::<*mut u8>() = ptr22.cast_mut();
+                                            *base.add(8).cast::<u8>() = (kind21.clone() as i32) as u8;
+                                            let vec23 = (signature21.into_bytes()).into_boxed_slice();
+                                            let ptr23 = vec23.as_ptr().cast::<u8>();
+                                            let len23 = vec23.len();
+                                            ::core::mem::forget(vec23);
+                                            *base.add(16).cast::<usize>() = len23;
+                                            *base.add(12).cast::<*mut u8>() = ptr23.cast_mut();
+                                        }
+                                    }
+                                    *base.add(24).cast::<usize>() = len24;
+                                    *base.add(20).cast::<*mut u8>() = result24;
+                                }
+                            }
+                            *ptr4.add(60).cast::<usize>() = len25;
+                            *ptr4.add(56).cast::<*mut u8>() = result25;
+                            let vec26 = (wit5.into_bytes()).into_boxed_slice();
+                            let ptr26 = vec26.as_ptr().cast::<u8>();
+                            let len26 = vec26.len();
+                            ::core::mem::forget(vec26);
+                            *ptr4.add(68).cast::<usize>() = len26;
+                            *ptr4.add(64).cast::<*mut u8>() = ptr26.cast_mut();
+                            let ComponentMetadata {
+                                name: name27,
+                                producers: producers27,
+                                authors: authors27,
+                                description: description27,
+                                licenses: licenses27,
+                                source: source27,
+                                homepage: homepage27,
+                                revision: revision27,
+                                version: version27,
+                            } = metadata5;
+                            match name27 {
+                                Some(e) => {
+                                    *ptr4.add(72).cast::<u8>() = (1i32) as u8;
+                                    let vec28 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr28 = vec28.as_ptr().cast::<u8>();
+                                    let len28 = vec28.len();
+                                    ::core::mem::forget(vec28);
+                                    *ptr4.add(80).cast::<usize>() = len28;
+                                    *ptr4.add(76).cast::<*mut u8>() = ptr28.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(72).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            let vec33 = producers27;
+                            let len33 = vec33.len();
+                            let layout33 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec33.len() * 24,
+                                4,
+                            );
+                            let result33 = if layout33.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout33).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout33);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec33.into_iter().enumerate() {
+                                let base = result33.add(i * 24);
+                                {
+                                    let Producer {
+                                        field: field29,
+                                        name: name29,
+                                        version: version29,
+                                    } = e;
+                                    let vec30 = (field29.into_bytes()).into_boxed_slice();
+                                    let ptr30 = vec30.as_ptr().cast::<u8>();
+                                    let len30 = vec30.len();
+                                    ::core::mem::forget(vec30);
+                                    *base.add(4).cast::<usize>() = len30;
+                                    *base.add(0).cast::<*mut u8>() = ptr30.cast_mut();
+                                    let vec31 = (name29.into_bytes()).into_boxed_slice();
+                                    let ptr31 = vec31.as_ptr().cast::<u8>();
+                                    let len31 = vec31.len();
+                                    ::core::mem::forget(vec31);
+                                    *base.add(12).cast::<usize>() = len31;
+                                    *base.add(8).cast::<*mut u8>() = ptr31.cast_mut();
+                                    let vec32 = (version29.into_bytes()).into_boxed_slice();
+                                    let ptr32 = vec32.as_ptr().cast::<u8>();
+                                    let len32 = vec32.len();
+                                    ::core::mem::forget(vec32);
+                                    *base.add(20).cast::<usize>() = len32;
+                                    *base.add(16).cast::<*mut u8>() = ptr32.cast_mut();
+                                }
+                            }
+                            *ptr4.add(88).cast::<usize>() = len33;
+                            *ptr4.add(84).cast::<*mut u8>() = result33;
+                            match authors27 {
+                                Some(e) => {
+                                    *ptr4.add(92).cast::<u8>() = (1i32) as u8;
+                                    let vec34 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr34 = vec34.as_ptr().cast::<u8>();
+                                    let len34 = vec34.len();
+                                    ::core::mem::forget(vec34);
+                                    *ptr4.add(100).cast::<usize>() = len34;
+                                    *ptr4.add(96).cast::<*mut u8>() = ptr34.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(92).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match description27 {
+                                Some(e) => {
+                                    *ptr4.add(104).cast::<u8>() = (1i32) as u8;
+                                    let vec35 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr35 = vec35.as_ptr().cast::<u8>();
+                                    let len35 = vec35.len();
+                                    ::core::mem::forget(vec35);
+                                    *ptr4.add(112).cast::<usize>() = len35;
+                                    *ptr4.add(108).cast::<*mut u8>() = ptr35.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(104).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match licenses27 {
+                                Some(e) => {
+                                    *ptr4.add(116).cast::<u8>() = (1i32) as u8;
+                                    let vec36 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr36 = vec36.as_ptr().cast::<u8>();
+                                    let len36 = vec36.len();
+                                    ::core::mem::forget(vec36);
+                                    *ptr4.add(124).cast::<usize>() = len36;
+                                    *ptr4.add(120).cast::<*mut u8>() = ptr36.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(116).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match source27 {
+                                Some(e) => {
+                                    *ptr4.add(128).cast::<u8>() = (1i32) as u8;
+                                    let vec37 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr37 = vec37.as_ptr().cast::<u8>();
+                                    let len37 = vec37.len();
+                                    ::core::mem::forget(vec37);
+                                    *ptr4.add(136).cast::<usize>() = len37;
+                                    *ptr4.add(132).cast::<*mut u8>() = ptr37.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(128).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match homepage27 {
+                                Some(e) => {
+                                    *ptr4.add(140).cast::<u8>() = (1i32) as u8;
+                                    let vec38 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr38 = vec38.as_ptr().cast::<u8>();
+                                    let len38 = vec38.len();
+                                    ::core::mem::forget(vec38);
+                                    *ptr4.add(148).cast::<usize>() = len38;
+                                    *ptr4.add(144).cast::<*mut u8>() = ptr38.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(140).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match revision27 {
+                                Some(e) => {
+                                    *ptr4.add(152).cast::<u8>() = (1i32) as u8;
+                                    let vec39 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr39 = vec39.as_ptr().cast::<u8>();
+                                    let len39 = vec39.len();
+                                    ::core::mem::forget(vec39);
+                                    *ptr4.add(160).cast::<usize>() = len39;
+                                    *ptr4.add(156).cast::<*mut u8>() = ptr39.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(152).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match version27 {
+                                Some(e) => {
+                                    *ptr4.add(164).cast::<u8>() = (1i32) as u8;
+                                    let vec40 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr40 = vec40.as_ptr().cast::<u8>();
+                                    let len40 = vec40.len();
+                                    ::core::mem::forget(vec40);
+                                    *ptr4.add(172).cast::<usize>() = len40;
+                                    *ptr4.add(168).cast::<*mut u8>() = ptr40.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(164).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                        Err(e) => {
+                            *ptr4.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message41, offset: offset41 } = e;
+                                    let vec42 = (message41.into_bytes()).into_boxed_slice();
+                                    let ptr42 = vec42.as_ptr().cast::<u8>();
+                                    let len42 = vec42.len();
+                                    ::core::mem::forget(vec42);
+                                    *ptr4.add(20).cast::<usize>() = len42;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr42.cast_mut();
+                                    match offset41 {
+                                        Some(e) => {
+                                            *ptr4.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr4.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr4.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message43, offset: offset43 } = e;
+                                    let vec44 = (message43.into_bytes()).into_boxed_slice();
+                                    let ptr44 = vec44.as_ptr().cast::<u8>();
+                                    let len44 = vec44.len();
+                                    ::core::mem::forget(vec44);
+                                    *ptr4.add(20).cast::<usize>() = len44;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr44.cast_mut();
+                                    match offset43 {
+                                        Some(e) => {
+                                            *ptr4.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr4.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr4.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export45,
+                                        import: import45,
+                                        message: message45,
+                                    } = e;
+                                    match export45 {
+                                        Some(e) => {
+                                            *ptr4.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec46 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr46 = vec46.as_ptr().cast::<u8>();
+                                            let len46 = vec46.len();
+                                            ::core::mem::forget(vec46);
+                                            *ptr4.add(24).cast::<usize>() = len46;
+                                            *ptr4.add(20).cast::<*mut u8>() = ptr46.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr4.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec47 = (import45.into_bytes()).into_boxed_slice();
+                                    let ptr47 = vec47.as_ptr().cast::<u8>();
+                                    let len47 = vec47.len();
+                                    ::core::mem::forget(vec47);
+                                    *ptr4.add(32).cast::<usize>() = len47;
+                                    *ptr4.add(28).cast::<*mut u8>() = ptr47.cast_mut();
+                                    let vec48 = (message45.into_bytes()).into_boxed_slice();
+                                    let ptr48 = vec48.as_ptr().cast::<u8>();
+                                    let len48 = vec48.len();
+                                    ::core::mem::forget(vec48);
+                                    *ptr4.add(40).cast::<usize>() = len48;
+                                    *ptr4.add(36).cast::<*mut u8>() = ptr48.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances49,
+                                        message: message49,
+                                    } = e;
+                                    let vec50 = (instances49).into_boxed_slice();
+                                    let ptr50 = vec50.as_ptr().cast::<u8>();
+                                    let len50 = vec50.len();
+                                    ::core::mem::forget(vec50);
+                                    *ptr4.add(20).cast::<usize>() = len50;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr50.cast_mut();
+                                    let vec51 = (message49.into_bytes()).into_boxed_slice();
+                                    let ptr51 = vec51.as_ptr().cast::<u8>();
+                                    let len51 = vec51.len();
+                                    ::core::mem::forget(vec51);
+                                    *ptr4.add(28).cast::<usize>() = len51;
+                                    *ptr4.add(24).cast::<*mut u8>() = ptr51.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr4.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr4.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec52 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr52 = vec52.as_ptr().cast::<u8>();
+                                    let len52 = vec52.len();
+                                    ::core::mem::forget(vec52);
+                                    *ptr4.add(20).cast::<usize>() = len52;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr52.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec53 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr53 = vec53.as_ptr().cast::<u8>();
+                                    let len53 = vec53.len();
+                                    ::core::mem::forget(vec53);
+                                    *ptr4.add(20).cast::<usize>() = len53;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr53.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec54 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr54 = vec54.as_ptr().cast::<u8>();
+                                    let len54 = vec54.len();
+                                    ::core::mem::forget(vec54);
+                                    *ptr4.add(20).cast::<usize>() = len54;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr54.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message55,
+                                        line: line55,
+                                        column: column55,
+                                    } = e;
+                                    let vec56 = (message55.into_bytes()).into_boxed_slice();
+                                    let ptr56 = vec56.as_ptr().cast::<u8>();
+                                    let len56 = vec56.len();
+                                    ::core::mem::forget(vec56);
+                                    *ptr4.add(20).cast::<usize>() = len56;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr56.cast_mut();
+                                    *ptr4.add(24).cast::<i32>() = _rt::as_i32(line55);
+                                    *ptr4.add(28).cast::<i32>() = _rt::as_i32(column55);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec57 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr57 = vec57.as_ptr().cast::<u8>();
+                                    let len57 = vec57.len();
+                                    ::core::mem::forget(vec57);
+                                    *ptr4.add(20).cast::<usize>() = len57;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr57.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr4
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_register_package<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(12).cast::<*mut u8>();
+                            let l2 = *arg0.add(16).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = *arg0.add(20).cast::<*mut u8>();
+                            let l4 = *arg0.add(24).cast::<usize>();
+                            _rt::cabi_dealloc(l3, l4, 1);
+                            let l5 = i32::from(*arg0.add(28).cast::<u8>());
+                            match l5 {
+                                0 => {}
+                                _ => {
+                                    let l6 = *arg0.add(32).cast::<*mut u8>();
+                                    let l7 = *arg0.add(36).cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                            }
+                            let l8 = *arg0.add(40).cast::<*mut u8>();
+                            let l9 = *arg0.add(44).cast::<usize>();
+                            _rt::cabi_dealloc(l8, l9, 1);
+                            let l10 = *arg0.add(48).cast::<*mut u8>();
+                            let l11 = *arg0.add(52).cast::<usize>();
+                            let base23 = l10;
+                            let len23 = l11;
+                            for i in 0..len23 {
+                                let base = base23.add(i * 28);
+                                {
+                                    let l12 = *base.add(0).cast::<*mut u8>();
+                                    let l13 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                    let l14 = *base.add(12).cast::<*mut u8>();
+                                    let l15 = *base.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l14, l15, 1);
+                                    let l16 = *base.add(20).cast::<*mut u8>();
+                                    let l17 = *base.add(24).cast::<usize>();
+                                    let base22 = l16;
+                                    let len22 = l17;
+                                    for i in 0..len22 {
+                                        let base = base22.add(i * 20);
+                                        {
+                                            let l18 = *base.add(0).cast::<*mut u8>();
+                                            let l19 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l18, l19, 1);
+                                            let l20 = *base.add(12).cast::<*mut u8>();
+                                            let l21 = *base.add(16).cast::<usize>();
+                                            _rt::cabi_dealloc(l20, l21, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base22, len22 * 20, 4);
+                                }
+                            }
+                            _rt::cabi_dealloc(base23, len23 * 28, 4);
+                            let l24 = *arg0.add(56).cast::<*mut u8>();
+                            let l25 = *arg0.add(60).cast::<usize>();
+                            let base37 = l24;
+                            let len37 = l25;
+                            for i in 0..len37 {
+                                let base = base37.add(i * 28);
+                                {
+                                    let l26 = *base.add(0).cast::<*mut u8>();
+                                    let l27 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l26, l27, 1);
+                                    let l28 = *base.add(12).cast::<*mut u8>();
+                                    let l29 = *base.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l28, l29, 1);
+                                    let l30 = *base.add(20).cast::<*mut u8>();
+                                    let l31 = *base.add(24).cast::<usize>();
+                                    let base36 = l30;
+                                    let len36 = l31;
+                                    for i in 0..len36 {
+                                        let base = base36.add(i * 20);
+                                        {
+                                            let l32 = *base.add(0).cast::<*mut u8>();
+                                            let l33 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l32, l33, 1);
+                                            let l34 = *base.add(12).cast::<*mut u8>();
+                                            let l35 = *base.add(16).cast::<usize>();
+                                            _rt::cabi_dealloc(l34, l35, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base36, len36 * 20, 4);
+                                }
+                            }
+                            _rt::cabi_dealloc(base37, len37 * 28, 4);
+                            let l38 = *arg0.add(64).cast::<*mut u8>();
+                            let l39 = *arg0.add(68).cast::<usize>();
+                            _rt::cabi_dealloc(l38, l39, 1);
+                            let l40 = i32::from(*arg0.add(72).cast::<u8>());
+                            match l40 {
+                                0 => {}
+                                _ => {
+                                    let l41 = *arg0.add(76).cast::<*mut u8>();
+                                    let l42 = *arg0.add(80).cast::<usize>();
+                                    _rt::cabi_dealloc(l41, l42, 1);
+                                }
+                            }
+                            let l43 = *arg0.add(84).cast::<*mut u8>();
+                            let l44 = *arg0.add(88).cast::<usize>();
+                            let base51 = l43;
+                            let len51 = l44;
+                            for i in 0..len51 {
+                                let base = base51.add(i * 24);
+                                {
+                                    let l45 = *base.add(0).cast::<*mut u8>();
+                                    let l46 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l45, l46, 1);
+                                    let l47 = *base.add(8).cast::<*mut u8>();
+                                    let l48 = *base.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l47, l48, 1);
+                                    let l49 = *base.add(16).cast::<*mut u8>();
+                                    let l50 = *base.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l49, l50, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base51, len51 * 24, 4);
+                            let l52 = i32::from(*arg0.add(92).cast::<u8>());
+                            match l52 {
+                                0 => {}
+                                _ => {
+                                    let l53 = *arg0.add(96).cast::<*mut u8>();
+                                    let l54 = *arg0.add(100).cast::<usize>();
+                                    _rt::cabi_dealloc(l53, l54, 1);
+                                }
+                            }
+                            let l55 = i32::from(*arg0.add(104).cast::<u8>());
+                            match l55 {
+                                0 => {}
+                                _ => {
+                                    let l56 = *arg0.add(108).cast::<*mut u8>();
+                                    let l57 = *arg0.add(112).cast::<usize>();
+                                    _rt::cabi_dealloc(l56, l57, 1);
+                                }
+                            }
+                            let l58 = i32::from(*arg0.add(116).cast::<u8>());
+                            match l58 {
+                                0 => {}
+                                _ => {
+                                    let l59 = *arg0.add(120).cast::<*mut u8>();
+                                    let l60 = *arg0.add(124).cast::<usize>();
+                                    _rt::cabi_dealloc(l59, l60, 1);
+                                }
+                            }
+                            let l61 = i32::from(*arg0.add(128).cast::<u8>());
+                            match l61 {
+                                0 => {}
+                                _ => {
+                                    let l62 = *arg0.add(132).cast::<*mut u8>();
+                                    let l63 = *arg0.add(136).cast::<usize>();
+                                    _rt::cabi_dealloc(l62, l63, 1);
+                                }
+                            }
+                            let l64 = i32::from(*arg0.add(140).cast::<u8>());
+                            match l64 {
+                                0 => {}
+                                _ => {
+                                    let l65 = *arg0.add(144).cast::<*mut u8>();
+                                    let l66 = *arg0.add(148).cast::<usize>();
+                                    _rt::cabi_dealloc(l65, l66, 1);
+                                }
+                            }
+                            let l67 = i32::from(*arg0.add(152).cast::<u8>());
+                            match l67 {
+                                0 => {}
+                                _ => {
+                                    let l68 = *arg0.add(156).cast::<*mut u8>();
+                                    let l69 = *arg0.add(160).cast::<usize>();
+                                    _rt::cabi_dealloc(l68, l69, 1);
+                                }
+                            }
+                            let l70 = i32::from(*arg0.add(164).cast::<u8>());
+                            match l70 {
+                                0 => {}
+                                _ => {
+                                    let l71 = *arg0.add(168).cast::<*mut u8>();
+                                    let l72 = *arg0.add(172).cast::<usize>();
+                                    _rt::cabi_dealloc(l71, l72, 1);
+                                }
+                            }
+                        }
+                        _ => {
+                            let l73 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l73 {
+                                0 => {
+                                    let l74 = *arg0.add(16).cast::<*mut u8>();
+                                    let l75 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l74, l75, 1);
+                                }
+                                1 => {
+                                    let l76 = *arg0.add(16).cast::<*mut u8>();
+                                    let l77 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l76, l77, 1);
+                                }
+                                2 => {
+                                    let l78 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l78 {
+                                        0 => {}
+                                        _ => {
+                                            let l79 = *arg0.add(20).cast::<*mut u8>();
+                                            let l80 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l79, l80, 1);
+                                        }
+                                    }
+                                    let l81 = *arg0.add(28).cast::<*mut u8>();
+                                    let l82 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l81, l82, 1);
+                                    let l83 = *arg0.add(36).cast::<*mut u8>();
+                                    let l84 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l83, l84, 1);
+                                }
+                                3 => {
+                                    let l85 = *arg0.add(16).cast::<*mut u8>();
+                                    let l86 = *arg0.add(20).cast::<usize>();
+                                    let base87 = l85;
+                                    let len87 = l86;
+                                    _rt::cabi_dealloc(base87, len87 * 4, 4);
+                                    let l88 = *arg0.add(24).cast::<*mut u8>();
+                                    let l89 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l88, l89, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l90 = *arg0.add(16).cast::<*mut u8>();
+                                    let l91 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l90, l91, 1);
+                                }
+                                7 => {
+                                    let l92 = *arg0.add(16).cast::<*mut u8>();
+                                    let l93 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l92, l93, 1);
+                                }
+                                8 => {
+                                    let l94 = *arg0.add(16).cast::<*mut u8>();
+                                    let l95 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l94, l95, 1);
+                                }
+                                9 => {
+                                    let l96 = *arg0.add(16).cast::<*mut u8>();
+                                    let l97 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l96, l97, 1);
+                                }
+                                _ => {
+                                    let l98 = *arg0.add(16).cast::<*mut u8>();
+                                    let l99 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l98, l99, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_add_stub_component_cabi<
+                    T: GuestGraph,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                    arg5: *mut u8,
+                    arg6: usize,
+                    arg7: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let len1 = arg4;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+                    let len2 = arg6;
+                    let bytes2 = _rt::Vec::from_raw_parts(arg5.cast(), len2, len2);
+                    let result3 = T::add_stub_component(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        _rt::string_lift(bytes1),
+                        _rt::string_lift(bytes2),
+                        StubBehavior::_lift(arg7 as u8),
+                    );
+                    let ptr4 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result3 {
+                        Ok(e) => {
+                            *ptr4.add(0).cast::<u8>() = (0i32) as u8;
+                            let Component {
+                                id: id5,
+                                name: name5,
+                                package_name: package_name5,
+                                version: version5,
+                                digest: digest5,
+                                imports: imports5,
+                                exports: exports5,
+                                wit: wit5,
+                                metadata: metadata5,
+                            } = e;
+                            *ptr4.add(8).cast::<i32>() = _rt::as_i32(id5);
+                            let vec6 = (name5.into_bytes()).into_boxed_slice();
+                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                            let len6 = vec6.len();
+                            ::core::mem::forget(vec6);
+                            *ptr4.add(16).cast::<usize>() = len6;
+                            *ptr4.add(12).cast::<*mut u8>() = ptr6.cast_mut();
+                            let vec7 = (package_name5.into_bytes()).into_boxed_slice();
+                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                            let len7 = vec7.len();
+                            ::core::mem::forget(vec7);
+                            *ptr4.add(24).cast::<usize>() = len7;
+                            *ptr4.add(20).cast::<*mut u8>() = ptr7.cast_mut();
+                            match version5 {
+                                Some(e) => {
+                                    *ptr4.add(28).cast::<u8>() = (1i32) as u8;
+                                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr4.add(36).cast::<usize>() = len8;
+                                    *ptr4.add(32).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(28).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            let vec9 = (digest5.into_bytes()).into_boxed_slice();
+                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                            let len9 = vec9.len();
+                            ::core::mem::forget(vec9);
+                            *ptr4.add(44).cast::<usize>() = len9;
+                            *ptr4.add(40).cast::<*mut u8>() = ptr9.cast_mut();
+                            let vec17 = imports5;
+                            let len17 = vec17.len();
+                            let layout17 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec17.len() * 28,
+                                4,
+                            );
+                            let result17 = if layout17.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout17).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout17);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec17.into_iter().enumerate() {
+                                let base = result17.add(i * 28);
+                                {
+                                    let Import {
+                                        name: name10,
+                                        kind: kind10,
+                                        signature: signature10,
+                                        members: members10,
+                                    } = e;
+                                    let vec11 = (name10.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *base.add(4).cast::<usize>() = len11;
+                                    *base.add(0).cast::<*mut u8>() = ptr11.cast_mut();
+                                    *base.add(8).cast::<u8>() = (kind10.clone() as i32) as u8;
+                                    let vec12 = (signature10.into_bytes()).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *base.add(16).cast::<usize>() = len12;
+                                    *base.add(12).cast::<*mut u8>() = ptr12.cast_mut();
+                                    let vec16 = members10;
+                                    let len16 = vec16.len();
+                                    let layout16 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec16.len() * 20,
+                                        4,
+                                    );
+                                    let result16 = if layout16.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout16).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout16);
+                                        }
+                                        ptr
+                                    } else {
+                                        { ::core::ptr::null_mut() }
+                                    };
+                                    for (i, e) in vec16.into_iter().enumerate() {
+                                        let base = result16.add(i * 20);
+                                        {
+                                            let Member {
+                                                name: name13,
+                                                kind: kind13,
+                                                signature: signature13,
+                                            } = e;
+                                            let vec14 = (name13.into_bytes()).into_boxed_slice();
+                                            let ptr14 = vec14.as_ptr().cast::<u8>();
+                                            let len14 = vec14.len();
+                                            ::core::mem::forget(vec14);
+                                            *base.add(4).cast::<usize>() = len14;
+                                            *base.add(0).cast::<*mut u8>() = ptr14.cast_mut();
+                                            *base.add(8).cast::<u8>() = (kind13.clone() as i32) as u8;
+                                            let vec15 = (signature13.into_bytes()).into_boxed_slice();
+                                            let ptr15 = vec15.as_ptr().cast::<u8>();
+                                            let len15 = vec15.len();
+                                            ::core::mem::forget(vec15);
+                                            *base.add(16).cast::<usize>() = len15;
+                                            *base.add(12).cast::<*mut u8>() = ptr15.cast_mut();
+                                        }
+                                    }
+                                    *base.add(24).cast::<usize>() = len16;
+                                    *base.add(20).cast::<*mut u8>() = result16;
+                                }
+                            }
+                            *ptr4.add(52).cast::<usize>() = len17;
+                            *ptr4.add(48).cast::<*mut u8>() = result17;
+                            let vec25 = exports5;
+                            let len25 = vec25.len();
+                            let layout25 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec25.len() * 28,
+                                4,
+                            );
+                            let result25 = if layout25.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout25).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout25);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec25.into_iter().enumerate() {
+                                let base = result25.add(i * 28);
+                                {
+                                    let Export {
+                                        name: name18,
+                                        kind: kind18,
+                                        signature: signature18,
+                                        members: members18,
+                                    } = e;
+                                    let vec19 = (name18.into_bytes()).into_boxed_slice();
+                                    let ptr19 = vec19.as_ptr().cast::<u8>();
+                                    let len19 = vec19.len();
+                                    ::core::mem::forget(vec19);
+                                    *base.add(4).cast::<usize>() = len19;
+                                    *base.add(0).cast::<*mut u8>() = ptr19.cast_mut();
+                                    *base.add(8).cast::<u8>() = (kind18.clone() as i32) as u8;
+                                    let vec20 = (signature18.into_bytes()).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *base.add(16).cast::<usize>() = len20;
+                                    *base.add(12).cast::<*mut u8>() = ptr20.cast_mut();
+                                    let vec24 = members18;
+                                    let len24 = vec24.len();
+                                    let layout24 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec24.len() * 20,
+                                        4,
+                                    );
+                                    let result24 = if layout24.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout24).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout24);
+                                        }
+                                        ptr
+                                    } else {
+                                        { ::core::ptr::null_mut() }
+                                    };
+                                    for (i, e) in vec24.into_iter().enumerate() {
+                                        let base = result24.add(i * 20);
+                                        {
+                                            let Member {
+                                                name: name21,
+                                                kind: kind21,
+                                                signature: signature21,
+                                            } = e;
+                                            let vec22 = (name21.into_bytes()).into_boxed_slice();
+                                            let ptr22 = vec22.as_ptr().cast::<u8>();
+                                            let len22 = vec22.len();
+                                            ::core::mem::forget(vec22);
+                                            *base.add(4).cast::<usize>() = len22;
+                                            *base.add(0).cast::<*mut u8>() = ptr22.cast_mut();
+                                            *base.add(8).cast::<u8>() = (kind21.clone() as i32) as u8;
+                                            let vec23 = (signature21.into_bytes()).into_boxed_slice();
+                                            let ptr23 = vec23.as_ptr().cast::<u8>();
+                                            let len23 = vec23.len();
+                                            ::core::mem::forget(vec23);
+                                            *base.add(16).cast::<usize>() = len23;
+                                            *base.add(12).cast::<*mut u8>() = ptr23.cast_mut();
+                                        }
+                                    }
+                                    *base.add(24).cast::<usize>() = len24;
+                                    *base.add(20).cast::<*mut u8>() = result24;
+                                }
+                            }
+                            *ptr4.add(60).cast::<usize>() = len25;
+                            *ptr4.add(56).cast::<*mut u8>() = result25;
+                            let vec26 = (wit5.into_bytes()).into_boxed_slice();
+                            let ptr26 = vec26.as_ptr().cast::<u8>();
+                            let len26 = vec26.len();
+                            ::core::mem::forget(vec26);
+                            *ptr4.add(68).cast::<usize>() = len26;
+                            *ptr4.add(64).cast::<*mut u8>() = ptr26.cast_mut();
+                            let ComponentMetadata {
+                                name: name27,
+                                producers: producers27,
+                                authors: authors27,
+                                description: description27,
+                                licenses: licenses27,
+                                source: source27,
+                                homepage: homepage27,
+                                revision: revision27,
+                                version: version27,
+                            } = metadata5;
+                            match name27 {
+                                Some(e) => {
+                                    *ptr4.add(72).cast::<u8>() = (1i32) as u8;
+                                    let vec28 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr28 = vec28.as_ptr().cast::<u8>();
+                                    let len28 = vec28.len();
+                                    ::core::mem::forget(vec28);
+                                    *ptr4.add(80).cast::<usize>() = len28;
+                                    *ptr4.add(76).cast::<*mut u8>() = ptr28.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(72).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            let vec33 = producers27;
+                            let len33 = vec33.len();
+                            let layout33 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec33.len() * 24,
+                                4,
+                            );
+                            let result33 = if layout33.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout33).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout33);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec33.into_iter().enumerate() {
+                                let base = result33.add(i * 24);
+                                {
+                                    let Producer {
+                                        field: field29,
+                                        name: name29,
+                                        version: version29,
+                                    } = e;
+                                    let vec30 = (field29.into_bytes()).into_boxed_slice();
+                                    let ptr30 = vec30.as_ptr().cast::<u8>();
+                                    let len30 = vec30.len();
+                                    ::core::mem::forget(vec30);
+                                    *base.add(4).cast::<usize>() = len30;
+                                    *base.add(0).cast::<*mut u8>() = ptr30.cast_mut();
+                                    let vec31 = (name29.into_bytes()).into_boxed_slice();
+                                    let ptr31 = vec31.as_ptr().cast::<u8>();
+                                    let len31 = vec31.len();
+                                    ::core::mem::forget(vec31);
+                                    *base.add(12).cast::<usize>() = len31;
+                                    *base.add(8).cast::<*mut u8>() = ptr31.cast_mut();
+                                    let vec32 = (version29.into_bytes()).into_boxed_slice();
+                                    let ptr32 = vec32.as_ptr().cast::<u8>();
+                                    let len32 = vec32.len();
+                                    ::core::mem::forget(vec32);
+                                    *base.add(20).cast::<usize>() = len32;
+                                    *base.add(16).cast::<*mut u8>() = ptr32.cast_mut();
+                                }
+                            }
+                            *ptr4.add(88).cast::<usize>() = len33;
+                            *ptr4.add(84).cast::<*mut u8>() = result33;
+                            match authors27 {
+                                Some(e) => {
+                                    *ptr4.add(92).cast::<u8>() = (1i32) as u8;
+                                    let vec34 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr34 = vec34.as_ptr().cast::<u8>();
+                                    let len34 = vec34.len();
+                                    ::core::mem::forget(vec34);
+                                    *ptr4.add(100).cast::<usize>() = len34;
+                                    *ptr4.add(96).cast::<*mut u8>() = ptr34.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(92).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match description27 {
+                                Some(e) => {
+                                    *ptr4.add(104).cast::<u8>() = (1i32) as u8;
+                                    let vec35 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr35 = vec35.as_ptr().cast::<u8>();
+                                    let len35 = vec35.len();
+                                    ::core::mem::forget(vec35);
+                                    *ptr4.add(112).cast::<usize>() = len35;
+                                    *ptr4.add(108).cast::<*mut u8>() = ptr35.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(104).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match licenses27 {
+                                Some(e) => {
+                                    *ptr4.add(116).cast::<u8>() = (1i32) as u8;
+                                    let vec36 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr36 = vec36.as_ptr().cast::<u8>();
+                                    let len36 = vec36.len();
+                                    ::core::mem::forget(vec36);
+                                    *ptr4.add(124).cast::<usize>() = len36;
+                                    *ptr4.add(120).cast::<*mut u8>() = ptr36.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(116).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match source27 {
+                                Some(e) => {
+                                    *ptr4.add(128).cast::<u8>() = (1i32) as u8;
+                                    let vec37 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr37 = vec37.as_ptr().cast::<u8>();
+                                    let len37 = vec37.len();
+                                    ::core::mem::forget(vec37);
+                                    *ptr4.add(136).cast::<usize>() = len37;
+                                    *ptr4.add(132).cast::<*mut u8>() = ptr37.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(128).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match homepage27 {
+                                Some(e) => {
+                                    *ptr4.add(140).cast::<u8>() = (1i32) as u8;
+                                    let vec38 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr38 = vec38.as_ptr().cast::<u8>();
+                                    let len38 = vec38.len();
+                                    ::core::mem::forget(vec38);
+                                    *ptr4.add(148).cast::<usize>() = len38;
+                                    *ptr4.add(144).cast::<*mut u8>() = ptr38.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(140).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match revision27 {
+                                Some(e) => {
+                                    *ptr4.add(152).cast::<u8>() = (1i32) as u8;
+                                    let vec39 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr39 = vec39.as_ptr().cast::<u8>();
+                                    let len39 = vec39.len();
+                                    ::core::mem::forget(vec39);
+                                    *ptr4.add(160).cast::<usize>() = len39;
+                                    *ptr4.add(156).cast::<*mut u8>() = ptr39.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(152).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match version27 {
+                                Some(e) => {
+                                    *ptr4.add(164).cast::<u8>() = (1i32) as u8;
+                                    let vec40 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr40 = vec40.as_ptr().cast::<u8>();
+                                    let len40 = vec40.len();
+                                    ::core::mem::forget(vec40);
+                                    *ptr4.add(172).cast::<usize>() = len40;
+                                    *ptr4.add(168).cast::<*mut u8>() = ptr40.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(164).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                        Err(e) => {
+                            *ptr4.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message41, offset: offset41 } = e;
+                                    let vec42 = (message41.into_bytes()).into_boxed_slice();
+                                    let ptr42 = vec42.as_ptr().cast::<u8>();
+                                    let len42 = vec42.len();
+                                    ::core::mem::forget(vec42);
+                                    *ptr4.add(20).cast::<usize>() = len42;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr42.cast_mut();
+                                    match offset41 {
+                                        Some(e) => {
+                                            *ptr4.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr4.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr4.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message43, offset: offset43 } = e;
+                                    let vec44 = (message43.into_bytes()).into_boxed_slice();
+                                    let ptr44 = vec44.as_ptr().cast::<u8>();
+                                    let len44 = vec44.len();
+                                    ::core::mem::forget(vec44);
+                                    *ptr4.add(20).cast::<usize>() = len44;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr44.cast_mut();
+                                    match offset43 {
+                                        Some(e) => {
+                                            *ptr4.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr4.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr4.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export45,
+                                        import: import45,
+                                        message: message45,
+                                    } = e;
+                                    match export45 {
+                                        Some(e) => {
+                                            *ptr4.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec46 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr46 = vec46.as_ptr().cast::<u8>();
+                                            let len46 = vec46.len();
+                                            ::core::mem::forget(vec46);
+                                            *ptr4.add(24).cast::<usize>() = len46;
+                                            *ptr4.add(20).cast::<*mut u8>() = ptr46.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr4.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec47 = (import45.into_bytes()).into_boxed_slice();
+                                    let ptr47 = vec47.as_ptr().cast::<u8>();
+                                    let len47 = vec47.len();
+                                    ::core::mem::forget(vec47);
+                                    *ptr4.add(32).cast::<usize>() = len47;
+                                    *ptr4.add(28).cast::<*mut u8>() = ptr47.cast_mut();
+                                    let vec48 = (message45.into_bytes()).into_boxed_slice();
+                                    let ptr48 = vec48.as_ptr().cast::<u8>();
+                                    let len48 = vec48.len();
+                                    ::core::mem::forget(vec48);
+                                    *ptr4.add(40).cast::<usize>() = len48;
+                                    *ptr4.add(36).cast::<*mut u8>() = ptr48.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances49,
+                                        message: message49,
+                                    } = e;
+                                    let vec50 = (instances49).into_boxed_slice();
+                                    let ptr50 = vec50.as_ptr().cast::<u8>();
+                                    let len50 = vec50.len();
+                                    ::core::mem::forget(vec50);
+                                    *ptr4.add(20).cast::<usize>() = len50;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr50.cast_mut();
+                                    let vec51 = (message49.into_bytes()).into_boxed_slice();
+                                    let ptr51 = vec51.as_ptr().cast::<u8>();
+                                    let len51 = vec51.len();
+                                    ::core::mem::forget(vec51);
+                                    *ptr4.add(28).cast::<usize>() = len51;
+                                    *ptr4.add(24).cast::<*mut u8>() = ptr51.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr4.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr4.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec52 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr52 = vec52.as_ptr().cast::<u8>();
+                                    let len52 = vec52.len();
+                                    ::core::mem::forget(vec52);
+                                    *ptr4.add(20).cast::<usize>() = len52;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr52.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec53 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr53 = vec53.as_ptr().cast::<u8>();
+                                    let len53 = vec53.len();
+                                    ::core::mem::forget(vec53);
+                                    *ptr4.add(20).cast::<usize>() = len53;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr53.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec54 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr54 = vec54.as_ptr().cast::<u8>();
+                                    let len54 = vec54.len();
+                                    ::core::mem::forget(vec54);
+                                    *ptr4.add(20).cast::<usize>() = len54;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr54.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message55,
+                                        line: line55,
+                                        column: column55,
+                                    } = e;
+                                    let vec56 = (message55.into_bytes()).into_boxed_slice();
+                                    let ptr56 = vec56.as_ptr().cast::<u8>();
+                                    let len56 = vec56.len();
+                                    ::core::mem::forget(vec56);
+                                    *ptr4.add(20).cast::<usize>() = len56;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr56.cast_mut();
+                                    *ptr4.add(24).cast::<i32>() = _rt::as_i32(line55);
+                                    *ptr4.add(28).cast::<i32>() = _rt::as_i32(column55);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec57 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr57 = vec57.as_ptr().cast::<u8>();
+                                    let len57 = vec57.len();
+                                    ::core::mem::forget(vec57);
+                                    *ptr4.add(20).cast::<usize>() = len57;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr57.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr4
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_add_stub_component<
+                    T: GuestGraph,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(12).cast::<*mut u8>();
+                            let l2 = *arg0.add(16).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = *arg0.add(20).cast::<*mut u8>();
+                            let l4 = *arg0.add(24).cast::<usize>();
+                            _rt::cabi_dealloc(l3, l4, 1);
+                            let l5 = i32::from(*arg0.add(28).cast::<u8>());
+                            match l5 {
+                                0 => {}
+                                _ => {
+                                    let l6 = *arg0.add(32).cast::<*mut u8>();
+                                    let l7 = *arg0.add(36).cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                            }
+                            let l8 = *arg0.add(40).cast::<*mut u8>();
+                            let l9 = *arg0.add(44).cast::<usize>();
+                            _rt::cabi_dealloc(l8, l9, 1);
+                            let l10 = *arg0.add(48).cast::<*mut u8>();
+                            let l11 = *arg0.add(52).cast::<usize>();
+                            let base23 = l10;
+                            let len23 = l11;
+                            for i in 0..len23 {
+                                let base = base23.add(i * 28);
+                                {
+                                    let l12 = *base.add(0).cast::<*mut u8>();
+                                    let l13 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                    let l14 = *base.add(12).cast::<*mut u8>();
+                                    let l15 = *base.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l14, l15, 1);
+                                    let l16 = *base.add(20).cast::<*mut u8>();
+                                    let l17 = *base.add(24).cast::<usize>();
+                                    let base22 = l16;
+                                    let len22 = l17;
+                                    for i in 0..len22 {
+                                        let base = base22.add(i * 20);
+                                        {
+                                            let l18 = *base.add(0).cast::<*mut u8>();
+                                            let l19 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l18, l19, 1);
+                                            let l20 = *base.add(12).cast::<*mut u8>();
+                                            let l21 = *base.add(16).cast::<usize>();
+                                            _rt::cabi_dealloc(l20, l21, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base22, len22 * 20, 4);
+                                }
+                            }
+                            _rt::cabi_dealloc(base23, len23 * 28, 4);
+                            let l24 = *arg0.add(56).cast::<*mut u8>();
+                            let l25 = *arg0.add(60).cast::<usize>();
+                            let base37 = l24;
+                            let len37 = l25;
+                            for i in 0..len37 {
+                                let base = base37.add(i * 28);
+                                {
+                                    let l26 = *base.add(0).cast::<*mut u8>();
+                                    let l27 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l26, l27, 1);
+                                    let l28 = *base.add(12).cast::<*mut u8>();
+                                    let l29 = *base.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l28, l29, 1);
+                                    let l30 = *base.add(20).cast::<*mut u8>();
+                                    let l31 = *base.add(24).cast::<usize>();
+                                    let base36 = l30;
+                                    let len36 = l31;
+                                    for i in 0..len36 {
+                                        let base = base36.add(i * 20);
+                                        {
+                                            let l32 = *base.add(0).cast::<*mut u8>();
+                                            let l33 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l32, l33, 1);
+                                            let l34 = *base.add(12).cast::<*mut u8>();
+                                            let l35 = *base.add(16).cast::<usize>();
+                                            _rt::cabi_dealloc(l34, l35, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base36, len36 * 20, 4);
+                                }
+                            }
+                            _rt::cabi_dealloc(base37, len37 * 28, 4);
+                            let l38 = *arg0.add(64).cast::<*mut u8>();
+                            let l39 = *arg0.add(68).cast::<usize>();
+                            _rt::cabi_dealloc(l38, l39, 1);
+                            let l40 = i32::from(*arg0.add(72).cast::<u8>());
+                            match l40 {
+                                0 => {}
+                                _ => {
+                                    let l41 = *arg0.add(76).cast::<*mut u8>();
+                                    let l42 = *arg0.add(80).cast::<usize>();
+                                    _rt::cabi_dealloc(l41, l42, 1);
+                                }
+                            }
+                            let l43 = *arg0.add(84).cast::<*mut u8>();
+                            let l44 = *arg0.add(88).cast::<usize>();
+                            let base51 = l43;
+                            let len51 = l44;
+                            for i in 0..len51 {
+                                let base = base51.add(i * 24);
+                                {
+                                    let l45 = *base.add(0).cast::<*mut u8>();
+                                    let l46 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l45, l46, 1);
+                                    let l47 = *base.add(8).cast::<*mut u8>();
+                                    let l48 = *base.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l47, l48, 1);
+                                    let l49 = *base.add(16).cast::<*mut u8>();
+                                    let l50 = *base.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l49, l50, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base51, len51 * 24, 4);
+                            let l52 = i32::from(*arg0.add(92).cast::<u8>());
+                            match l52 {
+                                0 => {}
+                                _ => {
+                                    let l53 = *arg0.add(96).cast::<*mut u8>();
+                                    let l54 = *arg0.add(100).cast::<usize>();
+                                    _rt::cabi_dealloc(l53, l54, 1);
+                                }
+                            }
+                            let l55 = i32::from(*arg0.add(104).cast::<u8>());
+                            match l55 {
+                                0 => {}
+                                _ => {
+                                    let l56 = *arg0.add(108).cast::<*mut u8>();
+                                    let l57 = *arg0.add(112).cast::<usize>();
+                                    _rt::cabi_dealloc(l56, l57, 1);
+                                }
+                            }
+                            let l58 = i32::from(*arg0.add(116).cast::<u8>());
+                            match l58 {
+                                0 => {}
+                                _ => {
+                                    let l59 = *arg0.add(120).cast::<*mut u8>();
+                                    let l60 = *arg0.add(124).cast::<usize>();
+                                    _rt::cabi_dealloc(l59, l60, 1);
+                                }
+                            }
+                            let l61 = i32::from(*arg0.add(128).cast::<u8>());
+                            match l61 {
+                                0 => {}
+                                _ => {
+                                    let l62 = *arg0.add(132).cast::<*mut u8>();
+                                    let l63 = *arg0.add(136).cast::<usize>();
+                                    _rt::cabi_dealloc(l62, l63, 1);
+                                }
+                            }
+                            let l64 = i32::from(*arg0.add(140).cast::<u8>());
+                            match l64 {
+                                0 => {}
+                                _ => {
+                                    let l65 = *arg0.add(144).cast::<*mut u8>();
+                                    let l66 = *arg0.add(148).cast::<usize>();
+                                    _rt::cabi_dealloc(l65, l66, 1);
+                                }
+                            }
+                            let l67 = i32::from(*arg0.add(152).cast::<u8>());
+                            match l67 {
+                                0 => {}
+                                _ => {
+                                    let l68 = *arg0.add(156).cast::<*mut u8>();
+                                    let l69 = *arg0.add(160).cast::<usize>();
+                                    _rt::cabi_dealloc(l68, l69, 1);
+                                }
+                            }
+                            let l70 = i32::from(*arg0.add(164).cast::<u8>());
+                            match l70 {
+                                0 => {}
+                                _ => {
+                                    let l71 = *arg0.add(168).cast::<*mut u8>();
+                                    let l72 = *arg0.add(172).cast::<usize>();
+                                    _rt::cabi_dealloc(l71, l72, 1);
+                                }
+                            }
+                        }
+                        _ => {
+                            let l73 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l73 {
+                                0 => {
+                                    let l74 = *arg0.add(16).cast::<*mut u8>();
+                                    let l75 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l74, l75, 1);
+                                }
+                                1 => {
+                                    let l76 = *arg0.add(16).cast::<*mut u8>();
+                                    let l77 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l76, l77, 1);
+                                }
+                                2 => {
+                                    let l78 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l78 {
+                                        0 => {}
+                                        _ => {
+                                            let l79 = *arg0.add(20).cast::<*mut u8>();
+                                            let l80 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l79, l80, 1);
+                                        }
+                                    }
+                                    let l81 = *arg0.add(28).cast::<*mut u8>();
+                                    let l82 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l81, l82, 1);
+                                    let l83 = *arg0.add(36).cast::<*mut u8>();
+                                    let l84 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l83, l84, 1);
+                                }
+                                3 => {
+                                    let l85 = *arg0.add(16).cast::<*mut u8>();
+                                    let l86 = *arg0.add(20).cast::<usize>();
+                                    let base87 = l85;
+                                    let len87 = l86;
+                                    _rt::cabi_dealloc(base87, len87 * 4, 4);
+                                    let l88 = *arg0.add(24).cast::<*mut u8>();
+                                    let l89 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l88, l89, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l90 = *arg0.add(16).cast::<*mut u8>();
+                                    let l91 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l90, l91, 1);
+                                }
+                                7 => {
+                                    let l92 = *arg0.add(16).cast::<*mut u8>();
+                                    let l93 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l92, l93, 1);
+                                }
+                                8 => {
+                                    let l94 = *arg0.add(16).cast::<*mut u8>();
+                                    let l95 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l94, l95, 1);
+                                }
+                                9 => {
+                                    let l96 = *arg0.add(16).cast::<*mut u8>();
+                                    let l97 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l96, l97, 1);
+                                }
+                                _ => {
+                                    let l98 = *arg0.add(16).cast::<*mut u8>();
+                                    let l99 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l98, l99, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_add_config_component_cabi<
+                    T: GuestGraph,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i32,
+                    arg4: *mut u8,
+                    arg5: usize,
+                    arg6: i32,
+                    arg7: *mut u8,
+                    arg8: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result15 = T::add_config_component(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        ConfigValues {
+                            environment: match arg3 {
+                                0 => None,
+                                1 => {
+                                    let e = {
+                                        let base7 = arg4;
+                                        let len7 = arg5;
+                                        let mut result7 = _rt::Vec::with_capacity(len7);
+                                        for i in 0..len7 {
+                                            let base = base7.add(i * 16);
+                                            let e7 = {
+                                                let l1 = *base.add(0).cast::<*mut u8>();
+                                                let l2 = *base.add(4).cast::<usize>();
+                                                let len3 = l2;
+                                                let bytes3 = _rt::Vec::from_raw_parts(
+                                                    l1.cast(),
+                                                    len3,
+                                                    len3,
+                                                );
+                                                let l4 = *base.add(8).cast::<*mut u8>();
+                                                let l5 = *base.add(12).cast::<usize>();
+                                                let len6 = l5;
+                                                let bytes6 = _rt::Vec::from_raw_parts(
+                                                    l4.cast(),
+                                                    len6,
+                                                    len6,
+                                                );
+                                                (_rt::string_lift(bytes3), _rt::string_lift(bytes6))
+                                            };
+                                            result7.push(e7);
+                                        }
+                                        _rt::cabi_dealloc(base7, len7 * 16, 4);
+                                        result7
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            config: match arg6 {
+                                0 => None,
+                                1 => {
+                                    let e = {
+                                        let base14 = arg7;
+                                        let len14 = arg8;
+                                        let mut result14 = _rt::Vec::with_capacity(len14);
+                                        for i in 0..len14 {
+                                            let base = base14.add(i * 16);
+                                            let e14 = {
+                                                let l8 = *base.add(0).cast::<*mut u8>();
+                                                let l9 = *base.add(4).cast::<usize>();
+                                                let len10 = l9;
+                                                let bytes10 = _rt::Vec::from_raw_parts(
+                                                    l8.cast(),
+                                                    len10,
+                                                    len10,
+                                                );
+                                                let l11 = *base.add(8).cast::<*mut u8>();
+                                                let l12 = *base.add(12).cast::<usize>();
+                                                let len13 = l12;
+                                                let bytes13 = _rt::Vec::from_raw_parts(
+                                                    l11.cast(),
+                                                    len13,
+                                                    len13,
+                                                );
+                                                (_rt::string_lift(bytes10), _rt::string_lift(bytes13))
+                                            };
+                                            result14.push(e14);
+                                        }
+                                        _rt::cabi_dealloc(base14, len14 * 16, 4);
+                                        result14
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                        },
+                    );
+                    let ptr16 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result15 {
+                        Ok(e) => {
+                            *ptr16.add(0).cast::<u8>() = (0i32) as u8;
+                            let Component {
+                                id: id17,
+                                name: name17,
+                                package_name: package_name17,
+                                version: version17,
+                                digest: digest17,
+                                imports: imports17,
+                                exports: exports17,
+                                wit: wit17,
+                                metadata: metadata17,
+                            } = e;
+                            *ptr16.add(8).cast::<i32>() = _rt::as_i32(id17);
+                            let vec18 = (name17.into_bytes()).into_boxed_slice();
+                            let ptr18 = vec18.as_ptr().cast::<u8>();
+                            let len18 = vec18.len();
+                            ::core::mem::forget(vec18);
+                            *ptr16.add(16).cast::<usize>() = len18;
+                            *ptr16.add(12).cast::<*mut u8>() = ptr18.cast_mut();
+                            let vec19 = (package_name17.into_bytes()).into_boxed_slice();
+                            let ptr19 = vec19.as_ptr().cast::<u8>();
+                            let len19 = vec19.len();
+                            ::core::mem::forget(vec19);
+                            *ptr16.add(24).cast::<usize>() = len19;
+                            *ptr16.add(20).cast::<*mut u8>() = ptr19.cast_mut();
+                            match version17 {
+                                Some(e) => {
+                                    *ptr16.add(28).cast::<u8>() = (1i32) as u8;
+                                    let vec20 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *ptr16.add(36).cast::<usize>() = len20;
+                                    *ptr16.add(32).cast::<*mut u8>() = ptr20.cast_mut();
+                                }
+                                None => {
+                                    *ptr16.add(28).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            let vec21 = (digest17.into_bytes()).into_boxed_slice();
+                            let ptr21 = vec21.as_ptr().cast::<u8>();
+                            let len21 = vec21.len();
+                            ::core::mem::forget(vec21);
+                            *ptr16.add(44).cast::<usize>() = len21;
+                            *ptr16.add(40).cast::<*mut u8>() = ptr21.cast_mut();
+                            let vec29 = imports17;
+                            let len29 = vec29.len();
+                            let layout29 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec29.len() * 28,
+                                4,
+                            );
+                            let result29 = if layout29.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout29).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout29);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec29.into_iter().enumerate() {
+                                let base = result29.add(i * 28);
+                                {
+                                    let Import {
+                                        name: name22,
+                                        kind: kind22,
+                                        signature: signature22,
+                                        members: members22,
+                                    } = e;
+                                    let vec23 = (name22.into_bytes()).into_boxed_slice();
+                                    let ptr23 = vec23.as_ptr().cast::<u8>();
+                                    let len23 = vec23.len();
+                                    ::core::mem::forget(vec23);
+                                    *base.add(4).cast::<usize>() = len23;
+                                    *base.add(0).cast::<*mut u8>() = ptr23.cast_mut();
+                                    *base.add(8).cast::<u8>() = (kind22.clone() as i32) as u8;
+                                    let vec24 = (signature22.into_bytes()).into_boxed_slice();
+                                    let ptr24 = vec24.as_ptr().cast::<u8>();
+                                    let len24 = vec24.len();
+                                    ::core::mem::forget(vec24);
+                                    *base.add(16).cast::<usize>() = len24;
+                                    *base.add(12).cast::<*mut u8>() = ptr24.cast_mut();
+                                    let vec28 = members22;
+                                    let len28 = vec28.len();
+                                    let layout28 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec28.len() * 20,
+                                        4,
+                                    );
+                                    let result28 = if layout28.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout28).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout28);
+                                        }
+                                        ptr
+                                    } else {
+                                        { ::core::ptr::null_mut() }
+                                    };
+                                    for (i, e) in vec28.into_iter().enumerate() {
+                                        let base = result28.add(i * 20);
+                                        {
+                                            let Member {
+                                                name: name25,
+                                                kind: kind25,
+                                                signature: signature25,
+                                            } = e;
+                                            let vec26 = (name25.into_bytes()).into_boxed_slice();
+                                            let ptr26 = vec26.as_ptr().cast::<u8>();
+                                            let len26 = vec26.len();
+                                            ::core::mem::forget(vec26);
+                                            *base.add(4).cast::<usize>() = len26;
+                                            *base.add(0).cast::<*mut u8>() = ptr26.cast_mut();
+                                            *base.add(8).cast::<u8>() = (kind25.clone() as i32) as u8;
+                                            let vec27 = (signature25.into_bytes()).into_boxed_slice();
+                                            let ptr27 = vec27.as_ptr().cast::<u8>();
+                                            let len27 = vec27.len();
+                                            ::core::mem::forget(vec27);
+                                            *base.add(16).cast::<usize>() = len27;
+                                            *base.add(12).cast::<*mut u8>() = ptr27.cast_mut();
+                                        }
+                                    }
+                                    *base.add(24).cast::<usize>() = len28;
+                                    *base.add(20).cast::<*mut u8>() = result28;
+                                }
+                            }
+                            *ptr16.add(52).cast::<usize>() = len29;
+                            *ptr16.add(48).cast::<*mut u8>() = result29;
+                            let vec37 = exports17;
+                            let len37 = vec37.len();
+                            let layout37 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec37.len() * 28,
+                                4,
+                            );
+                            let result37 = if layout37.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout37).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout37);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec37.into_iter().enumerate() {
+                                let base = result37.add(i * 28);
+                                {
+                                    let Export {
+                                        name: name30,
+                                        kind: kind30,
+                                        signature: signature30,
+                                        members: members30,
+                                    } = e;
+                                    let vec31 = (name30.into_bytes()).into_boxed_slice();
+                                    let ptr31 = vec31.as_ptr().cast::<u8>();
+                                    let len31 = vec31.len();
+                                    ::core::mem::forget(vec31);
+                                    *base.add(4).cast::<usize>() = len31;
+                                    *base.add(0).cast::<*mut u8>() = ptr31.cast_mut();
+                                    *base.add(8).cast::<u8>() = (kind30.clone() as i32) as u8;
+                                    let vec32 = (signature30.into_bytes()).into_boxed_slice();
+                                    let ptr32 = vec32.as_ptr().cast::<u8>();
+                                    let len32 = vec32.len();
+                                    ::core::mem::forget(vec32);
+                                    *base.add(16).cast::<usize>() = len32;
+                                    *base.add(12).cast::<*mut u8>() = ptr32.cast_mut();
+                                    let vec36 = members30;
+                                    let len36 = vec36.len();
+                                    let layout36 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec36.len() * 20,
+                                        4,
+                                    );
+                                    let result36 = if layout36.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout36).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout36);
+                                        }
+                                        ptr
+                                    } else {
+                                        { ::core::ptr::null_mut() }
+                                    };
+                                    for (i, e) in vec36.into_iter().enumerate() {
+                                        let base = result36.add(i * 20);
+                                        {
+                                            let Member {
+                                                name: name33,
+                                                kind: kind33,
+                                                signature: signature33,
+                                            } = e;
+                                            let vec34 = (name33.into_bytes()).into_boxed_slice();
+                                            let ptr34 = vec34.as_ptr().cast::<u8>();
+                                            let len34 = vec34.len();
+                                            ::core::mem::forget(vec34);
+                                            *base.add(4).cast::<usize>() = len34;
+                                            *base.add(0).cast::<*mut u8>() = ptr34.cast_mut();
+                                            *base.add(8).cast::<u8>() = (kind33.clone() as i32) as u8;
+                                            let vec35 = (signature33.into_bytes()).into_boxed_slice();
+                                            let ptr35 = vec35.as_ptr().cast::<u8>();
+                                            let len35 = vec35.len();
+                                            ::core::mem::forget(vec35);
+                                            *base.add(16).cast::<usize>() = len35;
+                                            *base.add(12).cast::<*mut u8>() = ptr35.cast_mut();
+                                        }
+                                    }
+                                    *base.add(24).cast::<usize>() = len36;
+                                    *base.add(20).cast::<*mut u8>() = result36;
+                                }
+                            }
+                            *ptr16.add(60).cast::<usize>() = len37;
+                            *ptr16.add(56).cast::<*mut u8>() = result37;
+                            let vec38 = (wit17.into_bytes()).into_boxed_slice();
+                            let ptr38 = vec38.as_ptr().cast::<u8>();
+                            let len38 = vec38.len();
+                            ::core::mem::forget(vec38);
+                            *ptr16.add(68).cast::<usize>() = len38;
+                            *ptr16.add(64).cast::<*mut u8>() = ptr38.cast_mut();
+                            let ComponentMetadata {
+                                name: name39,
+                                producers: producers39,
+                                authors: authors39,
+                                description: description39,
+                                licenses: licenses39,
+                                source: source39,
+                                homepage: homepage39,
+                                revision: revision39,
+                                version: version39,
+                            } = metadata17;
+                            match name39 {
+                                Some(e) => {
+                                    *ptr16.add(72).cast::<u8>() = (1i32) as u8;
+                                    let vec40 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr40 = vec40.as_ptr().cast::<u8>();
+                                    let len40 = vec40.len();
+                                    ::core::mem::forget(vec40);
+                                    *ptr16.add(80).cast::<usize>() = len40;
+                                    *ptr16.add(76).cast::<*mut u8>() = ptr40.cast_mut();
+                                }
+                                None => {
+                                    *ptr16.add(72).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            let vec45 = producers39;
+                            let len45 = vec45.len();
+                            let layout45 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec45.len() * 24,
+                                4,
+                            );
+                            let result45 = if layout45.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout45).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout45);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec45.into_iter().enumerate() {
+                                let base = result45.add(i * 24);
+                                {
+                                    let Producer {
+                                        field: field41,
+                                        name: name41,
+                                        version: version41,
+                                    } = e;
+                                    let vec42 = (field41.into_bytes()).into_boxed_slice();
+                                    let ptr42 = vec42.as_ptr().cast::<u8>();
+                                    let len42 = vec42.len();
+                                    ::core::mem::forget(vec42);
+                                    *base.add(4).cast::<usize>() = len42;
+                                    *base.add(0).cast::<*mut u8>() = ptr42.cast_mut();
+                                    let vec43 = (name41.into_bytes()).into_boxed_slice();
+                                    let ptr43 = vec43.as_ptr().cast::<u8>();
+                                    let len43 = vec43.len();
+                                    ::core::mem::forget(vec43);
+                                    *base.add(12).cast::<usize>() = len43;
+                                    *base.add(8).cast::<*mut u8>() = ptr43.cast_mut();
+                                    let vec44 = (version41.into_bytes()).into_boxed_slice();
+                                    let ptr44 = vec44.as_ptr().cast::<u8>();
+                                    let len44 = vec44.len();
+                                    ::core::mem::forget(vec44);
+                                    *base.add(20).cast::<usize>() = len44;
+                                    *base.add(16).cast::<*mut u8>() = ptr44.cast_mut();
+                                }
+                            }
+                            *ptr16.add(88).cast::<usize>() = len45;
+                            *ptr16.add(84).cast::<*mut u8>() = result45;
+                            match authors39 {
+                                Some(e) => {
+                                    *ptr16.add(92).cast::<u8>() = (1i32) as u8;
+                                    let vec46 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr46 = vec46.as_ptr().cast::<u8>();
+                                    let len46 = vec46.len();
+                                    ::core::mem::forget(vec46);
+                                    *ptr16.add(100).cast::<usize>() = len46;
+                                    *ptr16.add(96).cast::<*mut u8>() = ptr46.cast_mut();
+                                }
+                                None => {
+                                    *ptr16.add(92).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match description39 {
+                                Some(e) => {
+                                    *ptr16.add(104).cast::<u8>() = (1i32) as u8;
+                                    let vec47 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr47 = vec47.as_ptr().cast::<u8>();
+                                    let len47 = vec47.len();
+                                    ::core::mem::forget(vec47);
+                                    *ptr16.add(112).cast::<usize>() = len47;
+                                    *ptr16.add(108).cast::<*mut u8>() = ptr47.cast_mut();
+                                }
+                                None => {
+                                    *ptr16.add(104).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match licenses39 {
+                                Some(e) => {
+                                    *ptr16.add(116).cast::<u8>() = (1i32) as u8;
+                                    let vec48 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr48 = vec48.as_ptr().cast::<u8>();
+                                    let len48 = vec48.len();
+                                    ::core::mem::forget(vec48);
+                                    *ptr16.add(124).cast::<usize>() = len48;
+                                    *ptr16.add(120).cast::<*mut u8>() = ptr48.cast_mut();
+                                }
+                                None => {
+                                    *ptr16.add(116).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match source39 {
+                                Some(e) => {
+                                    *ptr16.add(128).cast::<u8>() = (1i32) as u8;
+                                    let vec49 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr49 = vec49.as_ptr().cast::<u8>();
+                                    let len49 = vec49.len();
+                                    ::core::mem::forget(vec49);
+                                    *ptr16.add(136).cast::<usize>() = len49;
+                                    *ptr16.add(132).cast::<*mut u8>() = ptr49.cast_mut();
+                                }
+                                None => {
+                                    *ptr16.add(128).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match homepage39 {
+                                Some(e) => {
+                                    *ptr16.add(140).cast::<u8>() = (1i32) as u8;
+                                    let vec50 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr50 = vec50.as_ptr().cast::<u8>();
+                                    let len50 = vec50.len();
+                                    ::core::mem::forget(vec50);
+                                    *ptr16.add(148).cast::<usize>() = len50;
+                                    *ptr16.add(144).cast::<*mut u8>() = ptr50.cast_mut();
+                                }
+                                None => {
+                                    *ptr16.add(140).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match revision39 {
+                                Some(e) => {
+                                    *ptr16.add(152).cast::<u8>() = (1i32) as u8;
+                                    let vec51 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr51 = vec51.as_ptr().cast::<u8>();
+                                    let len51 = vec51.len();
+                                    ::core::mem::forget(vec51);
+                                    *ptr16.add(160).cast::<usize>() = len51;
+                                    *ptr16.add(156).cast::<*mut u8>() = ptr51.cast_mut();
+                                }
+                                None => {
+                                    *ptr16.add(152).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match version39 {
+                                Some(e) => {
+                                    *ptr16.add(164).cast::<u8>() = (1i32) as u8;
+                                    let vec52 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr52 = vec52.as_ptr().cast::<u8>();
+                                    let len52 = vec52.len();
+                                    ::core::mem::forget(vec52);
+                                    *ptr16.add(172).cast::<usize>() = len52;
+                                    *ptr16.add(168).cast::<*mut u8>() = ptr52.cast_mut();
+                                }
+                                None => {
+                                    *ptr16.add(164).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                        Err(e) => {
+                            *ptr16.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr16.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message53, offset: offset53 } = e;
+                                    let vec54 = (message53.into_bytes()).into_boxed_slice();
+                                    let ptr54 = vec54.as_ptr().cast::<u8>();
+                                    let len54 = vec54.len();
+                                    ::core::mem::forget(vec54);
+                                    *ptr16.add(20).cast::<usize>() = len54;
+                                    *ptr16.add(16).cast::<*mut u8>() = ptr54.cast_mut();
+                                    match offset53 {
+                                        Some(e) => {
+                                            *ptr16.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr16.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr16.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr16.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message55, offset: offset55 } = e;
+                                    let vec56 = (message55.into_bytes()).into_boxed_slice();
+                                    let ptr56 = vec56.as_ptr().cast::<u8>();
+                                    let len56 = vec56.len();
+                                    ::core::mem::forget(vec56);
+                                    *ptr16.add(20).cast::<usize>() = len56;
+                                    *ptr16.add(16).cast::<*mut u8>() = ptr56.cast_mut();
+                                    match offset55 {
+                                        Some(e) => {
+                                            *ptr16.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr16.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr16.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr16.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export57,
+                                        import: import57,
+                                        message: message57,
+                                    } = e;
+                                    match export57 {
+                                        Some(e) => {
+                                            *ptr16.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec58 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr58 = vec58.as_ptr().cast::<u8>();
+                                            let len58 = vec58.len();
+                                            ::core::mem::forget(vec58);
+                                            *ptr16.add(24).cast::<usize>() = len58;
+                                            *ptr16.add(20).cast::<*mut u8>() = ptr58.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr16.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec59 = (import57.into_bytes()).into_boxed_slice();
+                                    let ptr59 = vec59.as_ptr().cast::<u8>();
+                                    let len59 = vec59.len();
+                                    ::core::mem::forget(vec59);
+                                    *ptr16.add(32).cast::<usize>() = len59;
+                                    *ptr16.add(28).cast::<*mut u8>() = ptr59.cast_mut();
+                                    let vec60 = (message57.into_bytes()).into_boxed_slice();
+                                    let ptr60 = vec60.as_ptr().cast::<u8>();
+                                    let len60 = vec60.len();
+                                    ::core::mem::forget(vec60);
+                                    *ptr16.add(40).cast::<usize>() = len60;
+                                    *ptr16.add(36).cast::<*mut u8>() = ptr60.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr16.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances61,
+                                        message: message61,
+                                    } = e;
+                                    let vec62 = (instances61).into_boxed_slice();
+                                    let ptr62 = vec62.as_ptr().cast::<u8>();
+                                    let len62 = vec62.len();
+                                    ::core::mem::forget(vec62);
+                                    *ptr16.add(20).cast::<usize>() = len62;
+                                    *ptr16.add(16).cast::<*mut u8>() = ptr62.cast_mut();
+                                    let vec63 = (message61.into_bytes()).into_boxed_slice();
+                                    let ptr63 = vec63.as_ptr().cast::<u8>();
+                                    let len63 = vec63.len();
+                                    ::core::mem::forget(vec63);
+                                    *ptr16.add(28).cast::<usize>() = len63;
+                                    *ptr16.add(24).cast::<*mut u8>() = ptr63.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr16.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr16.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr16.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr16.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr16.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec64 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr64 = vec64.as_ptr().cast::<u8>();
+                                    let len64 = vec64.len();
+                                    ::core::mem::forget(vec64);
+                                    *ptr16.add(20).cast::<usize>() = len64;
+                                    *ptr16.add(16).cast::<*mut u8>() = ptr64.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr16.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec65 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr65 = vec65.as_ptr().cast::<u8>();
+                                    let len65 = vec65.len();
+                                    ::core::mem::forget(vec65);
+                                    *ptr16.add(20).cast::<usize>() = len65;
+                                    *ptr16.add(16).cast::<*mut u8>() = ptr65.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr16.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec66 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr66 = vec66.as_ptr().cast::<u8>();
+                                    let len66 = vec66.len();
+                                    ::core::mem::forget(vec66);
+                                    *ptr16.add(20).cast::<usize>() = len66;
+                                    *ptr16.add(16).cast::<*mut u8>() = ptr66.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr16.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message67,
+                                        line: line67,
+                                        column: column67,
+                                    } = e;
+                                    let vec68 = (message67.into_bytes()).into_boxed_slice();
+                                    let ptr68 = vec68.as_ptr().cast::<u8>();
+                                    let len68 = vec68.len();
+                                    ::core::mem::forget(vec68);
+                                    *ptr16.add(20).cast::<usize>() = len68;
+                                    *ptr16.add(16).cast::<*mut u8>() = ptr68.cast_mut();
+                                    *ptr16.add(24).cast::<i32>() = _rt::as_i32(line67);
+                                    *ptr16.add(28).cast::<i32>() = _rt::as_i32(column67);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr16.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec69 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr69 = vec69.as_ptr().cast::<u8>();
+                                    let len69 = vec69.len();
+                                    ::core::mem::forget(vec69);
+                                    *ptr16.add(20).cast::<usize>() = len69;
+                                    *ptr16.add(16).cast::<*mut u8>() = ptr69.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr16
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_add_config_component<
+                    T: GuestGraph,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(12).cast::<*mut u8>();
+                            let l2 = *arg0.add(16).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = *arg0.add(20).cast::<*mut u8>();
+                            let l4 = *arg0.add(24).cast::<usize>();
+                            _rt::cabi_dealloc(l3, l4, 1);
+                            let l5 = i32::from(*arg0.add(28).cast::<u8>());
+                            match l5 {
+                                0 => {}
+                                _ => {
+                                    let l6 = *arg0.add(32).cast::<*mut u8>();
+                                    let l7 = *arg0.add(36).cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                            }
+                            let l8 = *arg0.add(40).cast::<*mut u8>();
+                            let l9 = *arg0.add(44).cast::<usize>();
+                            _rt::cabi_dealloc(l8, l9, 1);
+                            let l10 = *arg0.add(48).cast::<*mut u8>();
+                            let l11 = *arg0.add(52).cast::<usize>();
+                            let base23 = l10;
+                            let len23 = l11;
+                            for i in 0..len23 {
+                                let base = base23.add(i * 28);
+                                {
+                                    let l12 = *base.add(0).cast::<*mut u8>();
+                                    let l13 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                    let l14 = *base.add(12).cast::<*mut u8>();
+                                    let l15 = *base.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l14, l15, 1);
+                                    let l16 = *base.add(20).cast::<*mut u8>();
+                                    let l17 = *base.add(24).cast::<usize>();
+                                    let base22 = l16;
+                                    let len22 = l17;
+                                    for i in 0..len22 {
+                                        let base = base22.add(i * 20);
+                                        {
+                                            let l18 = *base.add(0).cast::<*mut u8>();
+                                            let l19 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l18, l19, 1);
+                                            let l20 = *base.add(12).cast::<*mut u8>();
+                                            let l21 = *base.add(16).cast::<usize>();
+                                            _rt::cabi_dealloc(l20, l21, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base22, len22 * 20, 4);
+                                }
+                            }
+                            _rt::cabi_dealloc(base23, len23 * 28, 4);
+                            let l24 = *arg0.add(56).cast::<*mut u8>();
+                            let l25 = *arg0.add(60).cast::<usize>();
+                            let base37 = l24;
+                            let len37 = l25;
+                            for i in 0..len37 {
+                                let base = base37.add(i * 28);
+                                {
+                                    let l26 = *base.add(0).cast::<*mut u8>();
+                                    let l27 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l26, l27, 1);
+                                    let l28 = *base.add(12).cast::<*mut u8>();
+                                    let l29 = *base.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l28, l29, 1);
+                                    let l30 = *base.add(20).cast::<*mut u8>();
+                                    let l31 = *base.add(24).cast::<usize>();
+                                    let base36 = l30;
+                                    let len36 = l31;
+                                    for i in 0..len36 {
+                                        let base = base36.add(i * 20);
+                                        {
+                                            let l32 = *base.add(0).cast::<*mut u8>();
+                                            let l33 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l32, l33, 1);
+                                            let l34 = *base.add(12).cast::<*mut u8>();
+                                            let l35 = *base.add(16).cast::<usize>();
+                                            _rt::cabi_dealloc(l34, l35, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base36, len36 * 20, 4);
+                                }
+                            }
+                            _rt::cabi_dealloc(base37, len37 * 28, 4);
+                            let l38 = *arg0.add(64).cast::<*mut u8>();
+                            let l39 = *arg0.add(68).cast::<usize>();
+                            _rt::cabi_dealloc(l38, l39, 1);
+                            let l40 = i32::from(*arg0.add(72).cast::<u8>());
+                            match l40 {
+                                0 => {}
+                                _ => {
+                                    let l41 = *arg0.add(76).cast::<*mut u8>();
+                                    let l42 = *arg0.add(80).cast::<usize>();
+                                    _rt::cabi_dealloc(l41, l42, 1);
+                                }
+                            }
+                            let l43 = *arg0.add(84).cast::<*mut u8>();
+                            let l44 = *arg0.add(88).cast::<usize>();
+                            let base51 = l43;
+                            let len51 = l44;
+                            for i in 0..len51 {
+                                let base = base51.add(i * 24);
+                                {
+                                    let l45 = *base.add(0).cast::<*mut u8>();
+                                    let l46 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l45, l46, 1);
+                                    let l47 = *base.add(8).cast::<*mut u8>();
+                                    let l48 = *base.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l47, l48, 1);
+                                    let l49 = *base.add(16).cast::<*mut u8>();
+                                    let l50 = *base.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l49, l50, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base51, len51 * 24, 4);
+                            let l52 = i32::from(*arg0.add(92).cast::<u8>());
+                            match l52 {
+                                0 => {}
+                                _ => {
+                                    let l53 = *arg0.add(96).cast::<*mut u8>();
+                                    let l54 = *arg0.add(100).cast::<usize>();
+                                    _rt::cabi_dealloc(l53, l54, 1);
+                                }
+                            }
+                            let l55 = i32::from(*arg0.add(104).cast::<u8>());
+                            match l55 {
+                                0 => {}
+                                _ => {
+                                    let l56 = *arg0.add(108).cast::<*mut u8>();
+                                    let l57 = *arg0.add(112).cast::<usize>();
+                                    _rt::cabi_dealloc(l56, l57, 1);
+                                }
+                            }
+                            let l58 = i32::from(*arg0.add(116).cast::<u8>());
+                            match l58 {
+                                0 => {}
+                                _ => {
+                                    let l59 = *arg0.add(120).cast::<*mut u8>();
+                                    let l60 = *arg0.add(124).cast::<usize>();
+                                    _rt::cabi_dealloc(l59, l60, 1);
+                                }
+                            }
+                            let l61 = i32::from(*arg0.add(128).cast::<u8>());
+                            match l61 {
+                                0 => {}
+                                _ => {
+                                    let l62 = *arg0.add(132).cast::<*mut u8>();
+                                    let l63 = *arg0.add(136).cast::<usize>();
+                                    _rt::cabi_dealloc(l62, l63, 1);
+                                }
+                            }
+                            let l64 = i32::from(*arg0.add(140).cast::<u8>());
+                            match l64 {
+                                0 => {}
+                                _ => {
+                                    let l65 = *arg0.add(144).cast::<*mut u8>();
+                                    let l66 = *arg0.add(148).cast::<usize>();
+                                    _rt::cabi_dealloc(l65, l66, 1);
+                                }
+                            }
+                            let l67 = i32::from(*arg0.add(152).cast::<u8>());
+                            match l67 {
+                                0 => {}
+                                _ => {
+                                    let l68 = *arg0.add(156).cast::<*mut u8>();
+                                    let l69 = *arg0.add(160).cast::<usize>();
+                                    _rt::cabi_dealloc(l68, l69, 1);
+                                }
+                            }
+                            let l70 = i32::from(*arg0.add(164).cast::<u8>());
+                            match l70 {
+                                0 => {}
+                                _ => {
+                                    let l71 = *arg0.add(168).cast::<*mut u8>();
+                                    let l72 = *arg0.add(172).cast::<usize>();
+                                    _rt::cabi_dealloc(l71, l72, 1);
+                                }
+                            }
+                        }
+                        _ => {
+                            let l73 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l73 {
+                                0 => {
+                                    let l74 = *arg0.add(16).cast::<*mut u8>();
+                                    let l75 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l74, l75, 1);
+                                }
+                                1 => {
+                                    let l76 = *arg0.add(16).cast::<*mut u8>();
+                                    let l77 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l76, l77, 1);
+                                }
+                                2 => {
+                                    let l78 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l78 {
+                                        0 => {}
+                                        _ => {
+                                            let l79 = *arg0.add(20).cast::<*mut u8>();
+                                            let l80 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l79, l80, 1);
+                                        }
+                                    }
+                                    let l81 = *arg0.add(28).cast::<*mut u8>();
+                                    let l82 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l81, l82, 1);
+                                    let l83 = *arg0.add(36).cast::<*mut u8>();
+                                    let l84 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l83, l84, 1);
+                                }
+                                3 => {
+                                    let l85 = *arg0.add(16).cast::<*mut u8>();
+                                    let l86 = *arg0.add(20).cast::<usize>();
+                                    let base87 = l85;
+                                    let len87 = l86;
+                                    _rt::cabi_dealloc(base87, len87 * 4, 4);
+                                    let l88 = *arg0.add(24).cast::<*mut u8>();
+                                    let l89 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l88, l89, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l90 = *arg0.add(16).cast::<*mut u8>();
+                                    let l91 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l90, l91, 1);
+                                }
+                                7 => {
+                                    let l92 = *arg0.add(16).cast::<*mut u8>();
+                                    let l93 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l92, l93, 1);
+                                }
+                                8 => {
+                                    let l94 = *arg0.add(16).cast::<*mut u8>();
+                                    let l95 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l94, l95, 1);
+                                }
+                                9 => {
+                                    let l96 = *arg0.add(16).cast::<*mut u8>();
+                                    let l97 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l96, l97, 1);
+                                }
+                                _ => {
+                                    let l98 = *arg0.add(16).cast::<*mut u8>();
+                                    let l99 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l98, l99, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_add_deny_component_cabi<
+                    T: GuestGraph,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                    arg5: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let base4 = arg3;
+                    let len4 = arg4;
+                    let mut result4 = _rt::Vec::with_capacity(len4);
+                    for i in 0..len4 {
+                        let base = base4.add(i * 8);
+                        let e4 = {
+                            let l1 = *base.add(0).cast::<*mut u8>();
+                            let l2 = *base.add(4).cast::<usize>();
+                            let len3 = l2;
+                            let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                            _rt::string_lift(bytes3)
+                        };
+                        result4.push(e4);
+                    }
+                    _rt::cabi_dealloc(base4, len4 * 8, 4);
+                    let result5 = T::add_deny_component(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        result4,
+                        StubBehavior::_lift(arg5 as u8),
+                    );
+                    let ptr6 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result5 {
+                        Ok(e) => {
+                            *ptr6.add(0).cast::<u8>() = (0i32) as u8;
+                            let Component {
+                                id: id7,
+                                name: name7,
+                                package_name: package_name7,
+                                version: version7,
+                                digest: digest7,
+                                imports: imports7,
+                                exports: exports7,
+                                wit: wit7,
+                                metadata: metadata7,
+                            } = e;
+                            *ptr6.add(8).cast::<i32>() = _rt::as_i32(id7);
+                            let vec8 = (name7.into_bytes()).into_boxed_slice();
+                            let ptr8 = vec8.as_ptr().cast::<u8>();
+                            let len8 = vec8.len();
+                            ::core::mem::forget(vec8);
+                            *ptr6.add(16).cast::<usize>() = len8;
+                            *ptr6.add(12).cast::<*mut u8>() = ptr8.cast_mut();
+                            let vec9 = (package_name7.into_bytes()).into_boxed_slice();
+                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                            let len9 = vec9.len();
+                            ::core::mem::forget(vec9);
+                            *ptr6.add(24).cast::<usize>() = len9;
+                            *ptr6.add(20).cast::<*mut u8>() = ptr9.cast_mut();
+                            match version7 {
+                                Some(e) => {
+                                    *ptr6.add(28).cast::<u8>() = (1i32) as u8;
+                                    let vec10 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr6.add(36).cast::<usize>() = len10;
+                                    *ptr6.add(32).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                None => {
+                                    *ptr6.add(28).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            let vec11 = (digest7.into_bytes()).into_boxed_slice();
+                            let ptr11 = vec11.as_ptr().cast::<u8>();
+                            let len11 = vec11.len();
+                            ::core::mem::forget(vec11);
+                            *ptr6.add(44).cast::<usize>() = len11;
+                            *ptr6.add(40).cast::<*mut u8>() = ptr11.cast_mut();
+                            let vec19 = imports7;
+                            let len19 = vec19.len();
+                            let layout19 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec19.len() * 28,
+                                4,
+                            );
+                            let result19 = if layout19.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout19).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout19);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec19.into_iter().enumerate() {
+                                let base = result19.add(i * 28);
+                                {
+                                    let Import {
+                                        name: name12,
+                                        kind: kind12,
+                                        signature: signature12,
+                                        members: members12,
+                                    } = e;
+                                    let vec13 = (name12.into_bytes()).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *base.add(4).cast::<usize>() = len13;
+                                    *base.add(0).cast::<*mut u8>() = ptr13.cast_mut();
+                                    *base.add(8).cast::<u8>() = (kind12.clone() as i32) as u8;
+                                    let vec14 = (signature12.into_bytes()).into_boxed_slice();
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    ::core::mem::forget(vec14);
+                                    *base.add(16).cast::<usize>() = len14;
+                                    *base.add(12).cast::<*mut u8>() = ptr14.cast_mut();
+                                    let vec18 = members12;
+                                    let len18 = vec18.len();
+                                    let layout18 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec18.len() * 20,
+                                        4,
+                                    );
+                                    let result18 = if layout18.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout18).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout18);
+                                        }
+                                        ptr
+                                    } else {
+                                        { ::core::ptr::null_mut() }
+                                    };
+                                    for (i, e) in vec18.into_iter().enumerate() {
+                                        let base = result18.add(i * 20);
+                                        {
+                                            let Member {
+                                                name: name15,
+                                                kind: kind15,
+                                                signature: signature15,
+                                            } = e;
+                                            let vec16 = (name15.into_bytes()).into_boxed_slice();
+                                            let ptr16 = vec16.as_ptr().cast::<u8>();
+                                            let len16 = vec16.len();
+                                            ::core::mem::forget(vec16);
+                                            *base.add(4).cast::<usize>() = len16;
+                                            *base.add(0).cast::<*mut u8>() = ptr16.cast_mut();
+                                            *base.add(8).cast::<u8>() = (kind15.clone() as i32) as u8;
+                                            let vec17 = (signature15.into_bytes()).into_boxed_slice();
+                                            let ptr17 = vec17.as_ptr().cast::<u8>();
+                                            let len17 = vec17.len();
+                                            ::core::mem::forget(vec17);
+                                            *base.add(16).cast::<usize>() = len17;
+                                            *base.add(12).cast::<*mut u8>() = ptr17.cast_mut();
+                                        }
+                                    }
+                                    *base.add(24).cast::<usize>() = len18;
+                                    *base.add(20).cast::<*mut u8>() = result18;
+                                }
+                            }
+                            *ptr6.add(52).cast::<usize>() = len19;
+                            *ptr6.add(48).cast::<*mut u8>() = result19;
+                            let vec27 = exports7;
+                            let len27 = vec27.len();
+                            let layout27 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec27.len() * 28,
+                                4,
+                            );
+                            let result27 = if layout27.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout27).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout27);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec27.into_iter().enumerate() {
+                                let base = result27.add(i * 28);
+                                {
+                                    let Export {
+                                        name: name20,
+                                        kind: kind20,
+                                        signature: signature20,
+                                        members: members20,
+                                    } = e;
+                                    let vec21 = (name20.into_bytes()).into_boxed_slice();
+                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                    let len21 = vec21.len();
+                                    ::core::mem::forget(vec21);
+                                    *base.add(4).cast::<usize>() = len21;
+                                    *base.add(0).cast::<*mut u8>() = ptr21.cast_mut();
+                                    *base.add(8).cast::<u8>() = (kind20.clone() as i32) as u8;
+                                    let vec22 = (signature20.into_bytes()).into_boxed_slice();
+                                    let ptr22 = vec22.as_ptr().cast::<u8>();
+                                    let len22 = vec22.len();
+                                    ::core::mem::forget(vec22);
+                                    *base.add(16).cast::<usize>() = len22;
+                                    *base.add(12).cast::<*mut u8>() = ptr22.cast_mut();
+                                    let vec26 = members20;
+                                    let len26 = vec26.len();
+                                    let layout26 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec26.len() * 20,
+                                        4,
+                                    );
+                                    let result26 = if layout26.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout26).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout26);
+                                        }
+                                        ptr
+                                    } else {
+                                        { ::core::ptr::null_mut() }
+                                    };
+                                    for (i, e) in vec26.into_iter().enumerate() {
+                                        let base = result26.add(i * 20);
+                                        {
+                                            let Member {
+                                                name: name23,
+                                                kind: kind23,
+                                                signature: signature23,
+                                            } = e;
+                                            let vec24 = (name23.into_bytes()).into_boxed_slice();
+                                            let ptr24 = vec24.as_ptr().cast::<u8>();
+                                            let len24 = vec24.len();
+                                            ::core::mem::forget(vec24);
+                                            *base.add(4).cast::<usize>() = len24;
+                                            *base.add(0).cast::<*mut u8>() = ptr24.cast_mut();
+                                            *base.add(8).cast::<u8>() = (kind23.clone() as i32) as u8;
+                                            let vec25 = (signature23.into_bytes()).into_boxed_slice();
+                                            let ptr25 = vec25.as_ptr().cast::<u8>();
+                                            let len25 = vec25.len();
+                                            ::core::mem::forget(vec25);
+                                            *base.add(16).cast::<usize>() = len25;
+                                            *base.add(12).cast::<*mut u8>() = ptr25.cast_mut();
+                                        }
+                                    }
+                                    *base.add(24).cast::<usize>() = len26;
+                                    *base.add(20).cast::<*mut u8>() = result26;
+                                }
+                            }
+                            *ptr6.add(60).cast::<usize>() = len27;
+                            *ptr6.add(56).cast::<*mut u8>() = result27;
+                            let vec28 = (wit7.into_bytes()).into_boxed_slice();
+                            let ptr28 = vec28.as_ptr().cast::<u8>();
+                            let len28 = vec28.len();
+                            ::core::mem::forget(vec28);
+                            *ptr6.add(68).cast::<usize>() = len28;
+                            *ptr6.add(64).cast::<*mut u8>() = ptr28.cast_mut();
+                            let ComponentMetadata {
+                                name: name29,
+                                producers: producers29,
+                                authors: authors29,
+                                description: description29,
+                                licenses: licenses29,
+                                source: source29,
+                                homepage: homepage29,
+                                revision: revision29,
+                                version: version29,
+                            } = metadata7;
+                            match name29 {
+                                Some(e) => {
+                                    *ptr6.add(72).cast::<u8>() = (1i32) as u8;
+                                    let vec30 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr30 = vec30.as_ptr().cast::<u8>();
+                                    let len30 = vec30.len();
+                                    ::core::mem::forget(vec30);
+                                    *ptr6.add(80).cast::<usize>() = len30;
+                                    *ptr6.add(76).cast::<*mut u8>() = ptr30.cast_mut();
+                                }
+                                None => {
+                                    *ptr6.add(72).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            let vec35 = producers29;
+                            let len35 = vec35.len();
+                            let layout35 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec35.len() * 24,
+                                4,
+                            );
+                            let result35 = if layout35.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout35).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout35);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec35.into_iter().enumerate() {
+                                let base = result35.add(i * 24);
+                                {
+                                    let Producer {
+                                        field: field31,
+                                        name: name31,
+                                        version: version31,
+                                    } = e;
+                                    let vec32 = (field31.into_bytes()).into_boxed_slice();
+                                    let ptr32 = vec32.as_ptr().cast::<u8>();
+                                    let len32 = vec32.len();
+                                    ::core::mem::forget(vec32);
+                                    *base.add(4).cast::<usize>() = len32;
+                                    *base.add(0).cast::<*mut u8>() = ptr32.cast_mut();
+                                    let vec33 = (name31.into_bytes()).into_boxed_slice();
+                                    let ptr33 = vec33.as_ptr().cast::<u8>();
+                                    let len33 = vec33.len();
+                                    ::core::mem::forget(vec33);
+                                    *base.add(12).cast::<usize>() = len33;
+                                    *base.add(8).cast::<*mut u8>() = ptr33.cast_mut();
+                                    let vec34 = (version31.into_bytes()).into_boxed_slice();
+                                    let ptr34 = vec34.as_ptr().cast::<u8>();
+                                    let len34 = vec34.len();
+                                    ::core::mem::forget(vec34);
+                                    *base.add(20).cast::<usize>() = len34;
+                                    *base.add(16).cast::<*mut u8>() = ptr34.cast_mut();
+                                }
+                            }
+                            *ptr6.add(88).cast::<usize>() = len35;
+                            *ptr6.add(84).cast::<*mut u8>() = result35;
+                            match authors29 {
+                                Some(e) => {
+                                    *ptr6.add(92).cast::<u8>() = (1i32) as u8;
+                                    let vec36 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr36 = vec36.as_ptr().cast::<u8>();
+                                    let len36 = vec36.len();
+                                    ::core::mem::forget(vec36);
+                                    *ptr6.add(100).cast::<usize>() = len36;
+                                    *ptr6.add(96).cast::<*mut u8>() = ptr36.cast_mut();
+                                }
+                                None => {
+                                    *ptr6.add(92).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match description29 {
+                                Some(e) => {
+                                    *ptr6.add(104).cast::<u8>() = (1i32) as u8;
+                                    let vec37 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr37 = vec37.as_ptr().cast::<u8>();
+                                    let len37 = vec37.len();
+                                    ::core::mem::forget(vec37);
+                                    *ptr6.add(112).cast::<usize>() = len37;
+                                    *ptr6.add(108).cast::<*mut u8>() = ptr37.cast_mut();
+                                }
+                                None => {
+                                    *ptr6.add(104).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match licenses29 {
+                                Some(e) => {
+                                    *ptr6.add(116).cast::<u8>() = (1i32) as u8;
+                                    let vec38 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr38 = vec38.as_ptr().cast::<u8>();
+                                    let len38 = vec38.len();
+                                    ::core::mem::forget(vec38);
+                                    *ptr6.add(124).cast::<usize>() = len38;
+                                    *ptr6.add(120).cast::<*mut u8>() = ptr38.cast_mut();
+                                }
+                                None => {
+                                    *ptr6.add(116).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match source29 {
+                                Some(e) => {
+                                    *ptr6.add(128).cast::<u8>() = (1i32) as u8;
+                                    let vec39 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr39 = vec39.as_ptr().cast::<u8>();
+                                    let len39 = vec39.len();
+                                    ::core::mem::forget(vec39);
+                                    *ptr6.add(136).cast::<usize>() = len39;
+                                    *ptr6.add(132).cast::<*mut u8>() = ptr39.cast_mut();
+                                }
+                                None => {
+                                    *ptr6.add(128).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match homepage29 {
+                                Some(e) => {
+                                    *ptr6.add(140).cast::<u8>() = (1i32) as u8;
+                                    let vec40 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr40 = vec40.as_ptr().cast::<u8>();
+                                    let len40 = vec40.len();
+                                    ::core::mem::forget(vec40);
+                                    *ptr6.add(148).cast::<usize>() = len40;
+                                    *ptr6.add(144).cast::<*mut u8>() = ptr40.cast_mut();
+                                }
+                                None => {
+                                    *ptr6.add(140).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match revision29 {
+                                Some(e) => {
+                                    *ptr6.add(152).cast::<u8>() = (1i32) as u8;
+                                    let vec41 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr41 = vec41.as_ptr().cast::<u8>();
+                                    let len41 = vec41.len();
+                                    ::core::mem::forget(vec41);
+                                    *ptr6.add(160).cast::<usize>() = len41;
+                                    *ptr6.add(156).cast::<*mut u8>() = ptr41.cast_mut();
+                                }
+                                None => {
+                                    *ptr6.add(152).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match version29 {
+                                Some(e) => {
+                                    *ptr6.add(164).cast::<u8>() = (1i32) as u8;
+                                    let vec42 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr42 = vec42.as_ptr().cast::<u8>();
+                                    let len42 = vec42.len();
+                                    ::core::mem::forget(vec42);
+                                    *ptr6.add(172).cast::<usize>() = len42;
+                                    *ptr6.add(168).cast::<*mut u8>() = ptr42.cast_mut();
+                                }
+                                None => {
+                                    *ptr6.add(164).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                        Err(e) => {
+                            *ptr6.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message43, offset: offset43 } = e;
+                                    let vec44 = (message43.into_bytes()).into_boxed_slice();
+                                    let ptr44 = vec44.as_ptr().cast::<u8>();
+                                    let len44 = vec44.len();
+                                    ::core::mem::forget(vec44);
+                                    *ptr6.add(20).cast::<usize>() = len44;
+                                    *ptr6.add(16).cast::<*mut u8>() = ptr44.cast_mut();
+                                    match offset43 {
+                                        Some(e) => {
+                                            *ptr6.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr6.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr6.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message45, offset: offset45 } = e;
+                                    let vec46 = (message45.into_bytes()).into_boxed_slice();
+                                    let ptr46 = vec46.as_ptr().cast::<u8>();
+                                    let len46 = vec46.len();
+                                    ::core::mem::forget(vec46);
+                                    *ptr6.add(20).cast::<usize>() = len46;
+                                    *ptr6.add(16).cast::<*mut u8>() = ptr46.cast_mut();
+                                    match offset45 {
+                                        Some(e) => {
+                                            *ptr6.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr6.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr6.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export47,
+                                        import: import47,
+                                        message: message47,
+                                    } = e;
+                                    match export47 {
+                                        Some(e) => {
+                                            *ptr6.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec48 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr48 = vec48.as_ptr().cast::<u8>();
+                                            let len48 = vec48.len();
+                                            ::core::mem::forget(vec48);
+                                            *ptr6.add(24).cast::<usize>() = len48;
+                                            *ptr6.add(20).cast::<*mut u8>() = ptr48.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr6.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec49 = (import47.into_bytes()).into_boxed_slice();
+                                    let ptr49 = vec49.as_ptr().cast::<u8>();
+                                    let len49 = vec49.len();
+                                    ::core::mem::forget(vec49);
+                                    *ptr6.add(32).cast::<usize>() = len49;
+                                    *ptr6.add(28).cast::<*mut u8>() = ptr49.cast_mut();
+                                    let vec50 = (message47.into_bytes()).into_boxed_slice();
+                                    let ptr50 = vec50.as_ptr().cast::<u8>();
+                                    let len50 = vec50.len();
+                                    ::core::mem::forget(vec50);
+                                    *ptr6.add(40).cast::<usize>() = len50;
+                                    *ptr6.add(36).cast::<*mut u8>() = ptr50.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances51,
+                                        message: message51,
+                                    } = e;
+                                    let vec52 = (instances51).into_boxed_slice();
+                                    let ptr52 = vec52.as_ptr().cast::<u8>();
+                                    let len52 = vec52.len();
+                                    ::core::mem::forget(vec52);
+                                    *ptr6.add(20).cast::<usize>() = len52;
+                                    *ptr6.add(16).cast::<*mut u8>() = ptr52.cast_mut();
+                                    let vec53 = (message51.into_bytes()).into_boxed_slice();
+                                    let ptr53 = vec53.as_ptr().cast::<u8>();
+                                    let len53 = vec53.len();
+                                    ::core::mem::forget(vec53);
+                                    *ptr6.add(28).cast::<usize>() = len53;
+                                    *ptr6.add(24).cast::<*mut u8>() = ptr53.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr6.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr6.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec54 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr54 = vec54.as_ptr().cast::<u8>();
+                                    let len54 = vec54.len();
+                                    ::core::mem::forget(vec54);
+                                    *ptr6.add(20).cast::<usize>() = len54;
+                                    *ptr6.add(16).cast::<*mut u8>() = ptr54.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec55 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr55 = vec55.as_ptr().cast::<u8>();
+                                    let len55 = vec55.len();
+                                    ::core::mem::forget(vec55);
+                                    *ptr6.add(20).cast::<usize>() = len55;
+                                    *ptr6.add(16).cast::<*mut u8>() = ptr55.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec56 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr56 = vec56.as_ptr().cast::<u8>();
+                                    let len56 = vec56.len();
+                                    ::core::mem::forget(vec56);
+                                    *ptr6.add(20).cast::<usize>() = len56;
+                                    *ptr6.add(16).cast::<*mut u8>() = ptr56.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message57,
+                                        line: line57,
+                                        column: column57,
+                                    } = e;
+                                    let vec58 = (message57.into_bytes()).into_boxed_slice();
+                                    let ptr58 = vec58.as_ptr().cast::<u8>();
+                                    let len58 = vec58.len();
+                                    ::core::mem::forget(vec58);
+                                    *ptr6.add(20).cast::<usize>() = len58;
+                                    *ptr6.add(16).cast::<*mut u8>() = ptr58.cast_mut();
+                                    *ptr6.add(24).cast::<i32>() = _rt::as_i32(line57);
+                                    *ptr6.add(28).cast::<i32>() = _rt::as_i32(column57);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec59 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr59 = vec59.as_ptr().cast::<u8>();
+                                    let len59 = vec59.len();
+                                    ::core::mem::forget(vec59);
+                                    *ptr6.add(20).cast::<usize>() = len59;
+                                    *ptr6.add(16).cast::<*mut u8>() = ptr59.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr6
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_add_deny_component<
+                    T: GuestGraph,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(12).cast::<*mut u8>();
+                            let l2 = *arg0.add(16).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = *arg0.add(20).cast::<*mut u8>();
+                            let l4 = *arg0.add(24).cast::<usize>();
+                            _rt::cabi_dealloc(l3, l4, 1);
+                            let l5 = i32::from(*arg0.add(28).cast::<u8>());
+                            match l5 {
+                                0 => {}
+                                _ => {
+                                    let l6 = *arg0.add(32).cast::<*mut u8>();
+                                    let l7 = *arg0.add(36).cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                            }
+                            let l8 = *arg0.add(40).cast::<*mut u8>();
+                            let l9 = *arg0.add(44).cast::<usize>();
+                            _rt::cabi_dealloc(l8, l9, 1);
+                            let l10 = *arg0.add(48).cast::<*mut u8>();
+                            let l11 = *arg0.add(52).cast::<usize>();
+                            let base23 = l10;
+                            let len23 = l11;
+                            for i in 0..len23 {
+                                let base = base23.add(i * 28);
+                                {
+                                    let l12 = *base.add(0).cast::<*mut u8>();
+                                    let l13 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                    let l14 = *base.add(12).cast::<*mut u8>();
+                                    let l15 = *base.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l14, l15, 1);
+                                    let l16 = *base.add(20).cast::<*mut u8>();
+                                    let l17 = *base.add(24).cast::<usize>();
+                                    let base22 = l16;
+                                    let len22 = l17;
+                                    for i in 0..len22 {
+                                        let base = base22.add(i * 20);
+                                        {
+                                            let l18 = *base.add(0).cast::<*mut u8>();
+                                            let l19 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l18, l19, 1);
+                                            let l20 = *base.add(12).cast::<*mut u8>();
+                                            let l21 = *base.add(16).cast::<usize>();
+                                            _rt::cabi_dealloc(l20, l21, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base22, len22 * 20, 4);
+                                }
+                            }
+                            _rt::cabi_dealloc(base23, len23 * 28, 4);
+                            let l24 = *arg0.add(56).cast::<*mut u8>();
+                            let l25 = *arg0.add(60).cast::<usize>();
+                            let base37 = l24;
+                            let len37 = l25;
+                            for i in 0..len37 {
+                                let base = base37.add(i * 28);
+                                {
+                                    let l26 = *base.add(0).cast::<*mut u8>();
+                                    let l27 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l26, l27, 1);
+                                    let l28 = *base.add(12).cast::<*mut u8>();
+                                    let l29 = *base.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l28, l29, 1);
+                                    let l30 = *base.add(20).cast::<*mut u8>();
+                                    let l31 = *base.add(24).cast::<usize>();
+                                    let base36 = l30;
+                                    let len36 = l31;
+                                    for i in 0..len36 {
+                                        let base = base36.add(i * 20);
+                                        {
+                                            let l32 = *base.add(0).cast::<*mut u8>();
+                                            let l33 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l32, l33, 1);
+                                            let l34 = *base.add(12).cast::<*mut u8>();
+                                            let l35 = *base.add(16).cast::<usize>();
+                                            _rt::cabi_dealloc(l34, l35, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base36, len36 * 20, 4);
+                                }
+                            }
+                            _rt::cabi_dealloc(base37, len37 * 28, 4);
+                            let l38 = *arg0.add(64).cast::<*mut u8>();
+                            let l39 = *arg0.add(68).cast::<usize>();
+                            _rt::cabi_dealloc(l38, l39, 1);
+                            let l40 = i32::from(*arg0.add(72).cast::<u8>());
+                            match l40 {
+                                0 => {}
+                                _ => {
+                                    let l41 = *arg0.add(76).cast::<*mut u8>();
+                                    let l42 = *arg0.add(80).cast::<usize>();
+                                    _rt::cabi_dealloc(l41, l42, 1);
+                                }
+                            }
+                            let l43 = *arg0.add(84).cast::<*mut u8>();
+                            let l44 = *arg0.add(88).cast::<usize>();
+                            let base51 = l43;
+                            let len51 = l44;
+                            for i in 0..len51 {
+                                let base = base51.add(i * 24);
+                                {
+                                    let l45 = *base.add(0).cast::<*mut u8>();
+                                    let l46 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l45, l46, 1);
+                                    let l47 = *base.add(8).cast::<*mut u8>();
+                                    let l48 = *base.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l47, l48, 1);
+                                    let l49 = *base.add(16).cast::<*mut u8>();
+                                    let l50 = *base.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l49, l50, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base51, len51 * 24, 4);
+                            let l52 = i32::from(*arg0.add(92).cast::<u8>());
+                            match l52 {
+                                0 => {}
+                                _ => {
+                                    let l53 = *arg0.add(96).cast::<*mut u8>();
+                                    let l54 = *arg0.add(100).cast::<usize>();
+                                    _rt::cabi_dealloc(l53, l54, 1);
+                                }
+                            }
+                            let l55 = i32::from(*arg0.add(104).cast::<u8>());
+                            match l55 {
+                                0 => {}
+                                _ => {
+                                    let l56 = *arg0.add(108).cast::<*mut u8>();
+                                    let l57 = *arg0.add(112).cast::<usize>();
+                                    _rt::cabi_dealloc(l56, l57, 1);
+                                }
+                            }
+                            let l58 = i32::from(*arg0.add(116).cast::<u8>());
+                            match l58 {
+                                0 => {}
+                                _ => {
+                                    let l59 = *arg0.add(120).cast::<*mut u8>();
+                                    let l60 = *arg0.add(124).cast::<usize>();
+                                    _rt::cabi_dealloc(l59, l60, 1);
+                                }
+                            }
+                            let l61 = i32::from(*arg0.add(128).cast::<u8>());
+                            match l61 {
+                                0 => {}
+                                _ => {
+                                    let l62 = *arg0.add(132).cast::<*mut u8>();
+                                    let l63 = *arg0.add(136).cast::<usize>();
+                                    _rt::cabi_dealloc(l62, l63, 1);
+                                }
+                            }
+                            let l64 = i32::from(*arg0.add(140).cast::<u8>());
+                            match l64 {
+                                0 => {}
+                                _ => {
+                                    let l65 = *arg0.add(144).cast::<*mut u8>();
+                                    let l66 = *arg0.add(148).cast::<usize>();
+                                    _rt::cabi_dealloc(l65, l66, 1);
+                                }
+                            }
+                            let l67 = i32::from(*arg0.add(152).cast::<u8>());
+                            match l67 {
+                                0 => {}
+                                _ => {
+                                    let l68 = *arg0.add(156).cast::<*mut u8>();
+                                    let l69 = *arg0.add(160).cast::<usize>();
+                                    _rt::cabi_dealloc(l68, l69, 1);
+                                }
+                            }
+                            let l70 = i32::from(*arg0.add(164).cast::<u8>());
+                            match l70 {
+                                0 => {}
+                                _ => {
+                                    let l71 = *arg0.add(168).cast::<*mut u8>();
+                                    let l72 = *arg0.add(172).cast::<usize>();
+                                    _rt::cabi_dealloc(l71, l72, 1);
+                                }
+                            }
+                        }
+                        _ => {
+                            let l73 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l73 {
+                                0 => {
+                                    let l74 = *arg0.add(16).cast::<*mut u8>();
+                                    let l75 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l74, l75, 1);
+                                }
+                                1 => {
+                                    let l76 = *arg0.add(16).cast::<*mut u8>();
+                                    let l77 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l76, l77, 1);
+                                }
+                                2 => {
+                                    let l78 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l78 {
+                                        0 => {}
+                                        _ => {
+                                            let l79 = *arg0.add(20).cast::<*mut u8>();
+                                            let l80 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l79, l80, 1);
+                                        }
+                                    }
+                                    let l81 = *arg0.add(28).cast::<*mut u8>();
+                                    let l82 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l81, l82, 1);
+                                    let l83 = *arg0.add(36).cast::<*mut u8>();
+                                    let l84 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l83, l84, 1);
+                                }
+                                3 => {
+                                    let l85 = *arg0.add(16).cast::<*mut u8>();
+                                    let l86 = *arg0.add(20).cast::<usize>();
+                                    let base87 = l85;
+                                    let len87 = l86;
+                                    _rt::cabi_dealloc(base87, len87 * 4, 4);
+                                    let l88 = *arg0.add(24).cast::<*mut u8>();
+                                    let l89 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l88, l89, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l90 = *arg0.add(16).cast::<*mut u8>();
+                                    let l91 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l90, l91, 1);
+                                }
+                                7 => {
+                                    let l92 = *arg0.add(16).cast::<*mut u8>();
+                                    let l93 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l92, l93, 1);
+                                }
+                                8 => {
+                                    let l94 = *arg0.add(16).cast::<*mut u8>();
+                                    let l95 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l94, l95, 1);
+                                }
+                                9 => {
+                                    let l96 = *arg0.add(16).cast::<*mut u8>();
+                                    let l97 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l96, l97, 1);
+                                }
+                                _ => {
+                                    let l98 = *arg0.add(16).cast::<*mut u8>();
+                                    let l99 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l98, l99, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_find_package_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i32,
+                    arg4: *mut u8,
+                    arg5: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result2 = T::find_package(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        match arg3 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let len1 = arg5;
+                                    let bytes1 = _rt::Vec::from_raw_parts(
+                                        arg4.cast(),
+                                        len1,
+                                        len1,
+                                    );
+                                    _rt::string_lift(bytes1)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    );
+                    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result2 {
+                        Ok(e) => {
+                            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+                            match e {
+                                Some(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (1i32) as u8;
+                                    *ptr3.add(12).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                None => {
+                                    *ptr3.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                        Err(e) => {
+                            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message4, offset: offset4 } = e;
+                                    let vec5 = (message4.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr3.add(20).cast::<usize>() = len5;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                    match offset4 {
+                                        Some(e) => {
+                                            *ptr3.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr3.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr3.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message6, offset: offset6 } = e;
+                                    let vec7 = (message6.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr3.add(20).cast::<usize>() = len7;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                    match offset6 {
+                                        Some(e) => {
+                                            *ptr3.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr3.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr3.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export8,
+                                        import: import8,
+                                        message: message8,
+                                    } = e;
+                                    match export8 {
+                                        Some(e) => {
+                                            *ptr3.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec9 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                                            let len9 = vec9.len();
+                                            ::core::mem::forget(vec9);
+                                            *ptr3.add(24).cast::<usize>() = len9;
+                                            *ptr3.add(20).cast::<*mut u8>() = ptr9.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr3.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec10 = (import8.into_bytes()).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr3.add(32).cast::<usize>() = len10;
+                                    *ptr3.add(28).cast::<*mut u8>() = ptr10.cast_mut();
+                                    let vec11 = (message8.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr3.add(40).cast::<usize>() = len11;
+                                    *ptr3.add(36).cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances12,
+                                        message: message12,
+                                    } = e;
+                                    let vec13 = (instances12).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr3.add(20).cast::<usize>() = len13;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr13.cast_mut();
+                                    let vec14 = (message12.into_bytes()).into_boxed_slice();
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    ::core::mem::forget(vec14);
+                                    *ptr3.add(28).cast::<usize>() = len14;
+                                    *ptr3.add(24).cast::<*mut u8>() = ptr14.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr3.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr3.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec15 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *ptr3.add(20).cast::<usize>() = len15;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr15.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec16 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                    let len16 = vec16.len();
+                                    ::core::mem::forget(vec16);
+                                    *ptr3.add(20).cast::<usize>() = len16;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr16.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec17 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr3.add(20).cast::<usize>() = len17;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr17.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message18,
+                                        line: line18,
+                                        column: column18,
+                                    } = e;
+                                    let vec19 = (message18.into_bytes()).into_boxed_slice();
+                                    let ptr19 = vec19.as_ptr().cast::<u8>();
+                                    let len19 = vec19.len();
+                                    ::core::mem::forget(vec19);
+                                    *ptr3.add(20).cast::<usize>() = len19;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr19.cast_mut();
+                                    *ptr3.add(24).cast::<i32>() = _rt::as_i32(line18);
+                                    *ptr3.add(28).cast::<i32>() = _rt::as_i32(column18);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec20 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *ptr3.add(20).cast::<usize>() = len20;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr20.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr3
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_find_package<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                1 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                2 => {
+                                    let l6 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l6 {
+                                        0 => {}
+                                        _ => {
+                                            let l7 = *arg0.add(20).cast::<*mut u8>();
+                                            let l8 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l7, l8, 1);
+                                        }
+                                    }
+                                    let l9 = *arg0.add(28).cast::<*mut u8>();
+                                    let l10 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l9, l10, 1);
+                                    let l11 = *arg0.add(36).cast::<*mut u8>();
+                                    let l12 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l11, l12, 1);
+                                }
+                                3 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 4, 4);
+                                    let l16 = *arg0.add(24).cast::<*mut u8>();
+                                    let l17 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l16, l17, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l18 = *arg0.add(16).cast::<*mut u8>();
+                                    let l19 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l18, l19, 1);
+                                }
+                                7 => {
+                                    let l20 = *arg0.add(16).cast::<*mut u8>();
+                                    let l21 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l20, l21, 1);
+                                }
+                                8 => {
+                                    let l22 = *arg0.add(16).cast::<*mut u8>();
+                                    let l23 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l22, l23, 1);
+                                }
+                                9 => {
+                                    let l24 = *arg0.add(16).cast::<*mut u8>();
+                                    let l25 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l24, l25, 1);
+                                }
+                                _ => {
+                                    let l26 = *arg0.add(16).cast::<*mut u8>();
+                                    let l27 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l26, l27, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_instantiate_component_cabi<
+                    T: GuestGraph,
+                >(arg0: *mut u8, arg1: i32) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::instantiate_component(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u32,
+                    );
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            *ptr1.add(8).cast::<i32>() = _rt::as_i32(e);
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message2, offset: offset2 } = e;
+                                    let vec3 = (message2.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *ptr1.add(20).cast::<usize>() = len3;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr3.cast_mut();
+                                    match offset2 {
+                                        Some(e) => {
+                                            *ptr1.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message4, offset: offset4 } = e;
+                                    let vec5 = (message4.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr1.add(20).cast::<usize>() = len5;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                    match offset4 {
+                                        Some(e) => {
+                                            *ptr1.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export6,
+                                        import: import6,
+                                        message: message6,
+                                    } = e;
+                                    match export6 {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec7 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                                            let len7 = vec7.len();
+                                            ::core::mem::forget(vec7);
+                                            *ptr1.add(24).cast::<usize>() = len7;
+                                            *ptr1.add(20).cast::<*mut u8>() = ptr7.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec8 = (import6.into_bytes()).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr1.add(32).cast::<usize>() = len8;
+                                    *ptr1.add(28).cast::<*mut u8>() = ptr8.cast_mut();
+                                    let vec9 = (message6.into_bytes()).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr1.add(40).cast::<usize>() = len9;
+                                    *ptr1.add(36).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances10,
+                                        message: message10,
+                                    } = e;
+                                    let vec11 = (instances10).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr1.add(20).cast::<usize>() = len11;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                    let vec12 = (message10.into_bytes()).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *ptr1.add(28).cast::<usize>() = len12;
+                                    *ptr1.add(24).cast::<*mut u8>() = ptr12.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec13 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr1.add(20).cast::<usize>() = len13;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr13.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec14 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    ::core::mem::forget(vec14);
+                                    *ptr1.add(20).cast::<usize>() = len14;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr14.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec15 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *ptr1.add(20).cast::<usize>() = len15;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr15.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message16,
+                                        line: line16,
+                                        column: column16,
+                                    } = e;
+                                    let vec17 = (message16.into_bytes()).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr1.add(20).cast::<usize>() = len17;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr17.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(line16);
+                                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(column16);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec18 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr18 = vec18.as_ptr().cast::<u8>();
+                                    let len18 = vec18.len();
+                                    ::core::mem::forget(vec18);
+                                    *ptr1.add(20).cast::<usize>() = len18;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr18.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_instantiate_component<
+                    T: GuestGraph,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                1 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                2 => {
+                                    let l6 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l6 {
+                                        0 => {}
+                                        _ => {
+                                            let l7 = *arg0.add(20).cast::<*mut u8>();
+                                            let l8 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l7, l8, 1);
+                                        }
+                                    }
+                                    let l9 = *arg0.add(28).cast::<*mut u8>();
+                                    let l10 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l9, l10, 1);
+                                    let l11 = *arg0.add(36).cast::<*mut u8>();
+                                    let l12 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l11, l12, 1);
+                                }
+                                3 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 4, 4);
+                                    let l16 = *arg0.add(24).cast::<*mut u8>();
+                                    let l17 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l16, l17, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l18 = *arg0.add(16).cast::<*mut u8>();
+                                    let l19 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l18, l19, 1);
+                                }
+                                7 => {
+                                    let l20 = *arg0.add(16).cast::<*mut u8>();
+                                    let l21 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l20, l21, 1);
+                                }
+                                8 => {
+                                    let l22 = *arg0.add(16).cast::<*mut u8>();
+                                    let l23 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l22, l23, 1);
+                                }
+                                9 => {
+                                    let l24 = *arg0.add(16).cast::<*mut u8>();
+                                    let l25 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l24, l25, 1);
+                                }
+                                _ => {
+                                    let l26 = *arg0.add(16).cast::<*mut u8>();
+                                    let l27 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l26, l27, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_connect_instances_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::connect_instances(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u32,
+                        match arg2 {
+                            0 => None,
+                            1 => {
+                                let e = arg3 as u32;
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        arg4 as u32,
+                        arg5 as u32,
+                    );
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(_) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message2, offset: offset2 } = e;
+                                    let vec3 = (message2.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *ptr1.add(20).cast::<usize>() = len3;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr3.cast_mut();
+                                    match offset2 {
+                                        Some(e) => {
+                                            *ptr1.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message4, offset: offset4 } = e;
+                                    let vec5 = (message4.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr1.add(20).cast::<usize>() = len5;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                    match offset4 {
+                                        Some(e) => {
+                                            *ptr1.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export6,
+                                        import: import6,
+                                        message: message6,
+                                    } = e;
+                                    match export6 {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec7 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                                            let len7 = vec7.len();
+                                            ::core::mem::forget(vec7);
+                                            *ptr1.add(24).cast::<usize>() = len7;
+                                            *ptr1.add(20).cast::<*mut u8>() = ptr7.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec8 = (import6.into_bytes()).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr1.add(32).cast::<usize>() = len8;
+                                    *ptr1.add(28).cast::<*mut u8>() = ptr8.cast_mut();
+                                    let vec9 = (message6.into_bytes()).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr1.add(40).cast::<usize>() = len9;
+                                    *ptr1.add(36).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances10,
+                                        message: message10,
+                                    } = e;
+                                    let vec11 = (instances10).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr1.add(20).cast::<usize>() = len11;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                    let vec12 = (message10.into_bytes()).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *ptr1.add(28).cast::<usize>() = len12;
+                                    *ptr1.add(24).cast::<*mut u8>() = ptr12.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec13 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr1.add(20).cast::<usize>() = len13;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr13.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec14 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    ::core::mem::forget(vec14);
+                                    *ptr1.add(20).cast::<usize>() = len14;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr14.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec15 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *ptr1.add(20).cast::<usize>() = len15;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr15.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message16,
+                                        line: line16,
+                                        column: column16,
+                                    } = e;
+                                    let vec17 = (message16.into_bytes()).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr1.add(20).cast::<usize>() = len17;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr17.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(line16);
+                                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(column16);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec18 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr18 = vec18.as_ptr().cast::<u8>();
+                                    let len18 = vec18.len();
+                                    ::core::mem::forget(vec18);
+                                    *ptr1.add(20).cast::<usize>() = len18;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr18.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_connect_instances<
+                    T: GuestGraph,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                1 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                2 => {
+                                    let l6 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l6 {
+                                        0 => {}
+                                        _ => {
+                                            let l7 = *arg0.add(20).cast::<*mut u8>();
+                                            let l8 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l7, l8, 1);
+                                        }
+                                    }
+                                    let l9 = *arg0.add(28).cast::<*mut u8>();
+                                    let l10 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l9, l10, 1);
+                                    let l11 = *arg0.add(36).cast::<*mut u8>();
+                                    let l12 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l11, l12, 1);
+                                }
+                                3 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 4, 4);
+                                    let l16 = *arg0.add(24).cast::<*mut u8>();
+                                    let l17 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l16, l17, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l18 = *arg0.add(16).cast::<*mut u8>();
+                                    let l19 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l18, l19, 1);
+                                }
+                                7 => {
+                                    let l20 = *arg0.add(16).cast::<*mut u8>();
+                                    let l21 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l20, l21, 1);
+                                }
+                                8 => {
+                                    let l22 = *arg0.add(16).cast::<*mut u8>();
+                                    let l23 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l22, l23, 1);
+                                }
+                                9 => {
+                                    let l24 = *arg0.add(16).cast::<*mut u8>();
+                                    let l25 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l24, l25, 1);
+                                }
+                                _ => {
+                                    let l26 = *arg0.add(16).cast::<*mut u8>();
+                                    let l27 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l26, l27, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_compatible_sources_cabi<
+                    T: GuestGraph,
+                >(arg0: *mut u8, arg1: i32, arg2: i32) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::compatible_sources(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u32,
+                        arg2 as u32,
+                    );
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec3 = e;
+                            let len3 = vec3.len();
+                            let layout3 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec3.len() * 20,
+                                4,
+                            );
+                            let result3 = if layout3.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout3).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout3);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec3.into_iter().enumerate() {
+                                let base = result3.add(i * 20);
+                                {
+                                    let Connection {
+                                        source: source2,
+                                        source_export: source_export2,
+                                        target: target2,
+                                        target_import: target_import2,
+                                    } = e;
+                                    *base.add(0).cast::<i32>() = _rt::as_i32(source2);
+                                    match source_export2 {
+                                        Some(e) => {
+                                            *base.add(4).cast::<u8>() = (1i32) as u8;
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *base.add(4).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    *base.add(12).cast::<i32>() = _rt::as_i32(target2);
+                                    *base.add(16).cast::<i32>() = _rt::as_i32(target_import2);
+                                }
+                            }
+                            *ptr1.add(12).cast::<usize>() = len3;
+                            *ptr1.add(8).cast::<*mut u8>() = result3;
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message4, offset: offset4 } = e;
+                                    let vec5 = (message4.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr1.add(20).cast::<usize>() = len5;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                    match offset4 {
+                                        Some(e) => {
+                                            *ptr1.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message6, offset: offset6 } = e;
+                                    let vec7 = (message6.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr1.add(20).cast::<usize>() = len7;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                    match offset6 {
+                                        Some(e) => {
+                                            *ptr1.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export8,
+                                        import: import8,
+                                        message: message8,
+                                    } = e;
+                                    match export8 {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec9 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                                            let len9 = vec9.len();
+                                            ::core::mem::forget(vec9);
+                                            *ptr1.add(24).cast::<usize>() = len9;
+                                            *ptr1.add(20).cast::<*mut u8>() = ptr9.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec10 = (import8.into_bytes()).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr1.add(32).cast::<usize>() = len10;
+                                    *ptr1.add(28).cast::<*mut u8>() = ptr10.cast_mut();
+                                    let vec11 = (message8.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr1.add(40).cast::<usize>() = len11;
+                                    *ptr1.add(36).cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances12,
+                                        message: message12,
+                                    } = e;
+                                    let vec13 = (instances12).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr1.add(20).cast::<usize>() = len13;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr13.cast_mut();
+                                    let vec14 = (message12.into_bytes()).into_boxed_slice();
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    ::core::mem::forget(vec14);
+                                    *ptr1.add(28).cast::<usize>() = len14;
+                                    *ptr1.add(24).cast::<*mut u8>() = ptr14.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec15 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *ptr1.add(20).cast::<usize>() = len15;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr15.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec16 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                    let len16 = vec16.len();
+                                    ::core::mem::forget(vec16);
+                                    *ptr1.add(20).cast::<usize>() = len16;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr16.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec17 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr1.add(20).cast::<usize>() = len17;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr17.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message18,
+                                        line: line18,
+                                        column: column18,
+                                    } = e;
+                                    let vec19 = (message18.into_bytes()).into_boxed_slice();
+                                    let ptr19 = vec19.as_ptr().cast::<u8>();
+                                    let len19 = vec19.len();
+                                    ::core::mem::forget(vec19);
+                                    *ptr1.add(20).cast::<usize>() = len19;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr19.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(line18);
+                                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(column18);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec20 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *ptr1.add(20).cast::<usize>() = len20;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr20.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_compatible_sources<
+                    T: GuestGraph,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            let base3 = l1;
+                            let len3 = l2;
+                            _rt::cabi_dealloc(base3, len3 * 20, 4);
+                        }
+                        _ => {
+                            let l4 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l4 {
+                                0 => {
+                                    let l5 = *arg0.add(16).cast::<*mut u8>();
+                                    let l6 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                }
+                                1 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l7, l8, 1);
+                                }
+                                2 => {
+                                    let l9 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l9 {
+                                        0 => {}
+                                        _ => {
+                                            let l10 = *arg0.add(20).cast::<*mut u8>();
+                                            let l11 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l10, l11, 1);
+                                        }
+                                    }
+                                    let l12 = *arg0.add(28).cast::<*mut u8>();
+                                    let l13 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                    let l14 = *arg0.add(36).cast::<*mut u8>();
+                                    let l15 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l14, l15, 1);
+                                }
+                                3 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 4, 4);
+                                    let l19 = *arg0.add(24).cast::<*mut u8>();
+                                    let l20 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l19, l20, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l21 = *arg0.add(16).cast::<*mut u8>();
+                                    let l22 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l21, l22, 1);
+                                }
+                                7 => {
+                                    let l23 = *arg0.add(16).cast::<*mut u8>();
+                                    let l24 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l23, l24, 1);
+                                }
+                                8 => {
+                                    let l25 = *arg0.add(16).cast::<*mut u8>();
+                                    let l26 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l25, l26, 1);
+                                }
+                                9 => {
+                                    let l27 = *arg0.add(16).cast::<*mut u8>();
+                                    let l28 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l27, l28, 1);
+                                }
+                                _ => {
+                                    let l29 = *arg0.add(16).cast::<*mut u8>();
+                                    let l30 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l29, l30, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_compatible_targets_cabi<
+                    T: GuestGraph,
+                >(arg0: *mut u8, arg1: i32, arg2: i32, arg3: i32) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::compatible_targets(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u32,
+                        match arg2 {
+                            0 => None,
+                            1 => {
+                                let e = arg3 as u32;
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    );
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec3 = e;
+                            let len3 = vec3.len();
+                            let layout3 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec3.len() * 20,
+                                4,
+                            );
+                            let result3 = if layout3.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout3).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout3);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec3.into_iter().enumerate() {
+                                let base = result3.add(i * 20);
+                                {
+                                    let Connection {
+                                        source: source2,
+                                        source_export: source_export2,
+                                        target: target2,
+                                        target_import: target_import2,
+                                    } = e;
+                                    *base.add(0).cast::<i32>() = _rt::as_i32(source2);
+                                    match source_export2 {
+                                        Some(e) => {
+                                            *base.add(4).cast::<u8>() = (1i32) as u8;
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *base.add(4).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    *base.add(12).cast::<i32>() = _rt::as_i32(target2);
+                                    *base.add(16).cast::<i32>() = _rt::as_i32(target_import2);
+                                }
+                            }
+                            *ptr1.add(12).cast::<usize>() = len3;
+                            *ptr1.add(8).cast::<*mut u8>() = result3;
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message4, offset: offset4 } = e;
+                                    let vec5 = (message4.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr1.add(20).cast::<usize>() = len5;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                    match offset4 {
+                                        Some(e) => {
+                                            *ptr1.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message6, offset: offset6 } = e;
+                                    let vec7 = (message6.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr1.add(20).cast::<usize>() = len7;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                    match offset6 {
+                                        Some(e) => {
+                                            *ptr1.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export8,
+                                        import: import8,
+                                        message: message8,
+                                    } = e;
+                                    match export8 {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec9 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                                            let len9 = vec9.len();
+                                            ::core::mem::forget(vec9);
+                                            *ptr1.add(24).cast::<usize>() = len9;
+                                            *ptr1.add(20).cast::<*mut u8>() = ptr9.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec10 = (import8.into_bytes()).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr1.add(32).cast::<usize>() = len10;
+                                    *ptr1.add(28).cast::<*mut u8>() = ptr10.cast_mut();
+                                    let vec11 = (message8.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr1.add(40).cast::<usize>() = len11;
+                                    *ptr1.add(36).cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances12,
+                                        message: message12,
+                                    } = e;
+                                    let vec13 = (instances12).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr1.add(20).cast::<usize>() = len13;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr13.cast_mut();
+                                    let vec14 = (message12.into_bytes()).into_boxed_slice();
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    ::core::mem::forget(vec14);
+                                    *ptr1.add(28).cast::<usize>() = len14;
+                                    *ptr1.add(24).cast::<*mut u8>() = ptr14.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec15 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *ptr1.add(20).cast::<usize>() = len15;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr15.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec16 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                    let len16 = vec16.len();
+                                    ::core::mem::forget(vec16);
+                                    *ptr1.add(20).cast::<usize>() = len16;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr16.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec17 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr1.add(20).cast::<usize>() = len17;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr17.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message18,
+                                        line: line18,
+                                        column: column18,
+                                    } = e;
+                                    let vec19 = (message18.into_bytes()).into_boxed_slice();
+                                    let ptr19 = vec19.as_ptr().cast::<u8>();
+                                    let len19 = vec19.len();
+                                    ::core::mem::forget(vec19);
+                                    *ptr1.add(20).cast::<usize>() = len19;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr19.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(line18);
+                                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(column18);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec20 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *ptr1.add(20).cast::<usize>() = len20;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr20.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_compatible_targets<
+                    T: GuestGraph,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            let base3 = l1;
+                            let len3 = l2;
+                            _rt::cabi_dealloc(base3, len3 * 20, 4);
+                        }
+                        _ => {
+                            let l4 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l4 {
+                                0 => {
+                                    let l5 = *arg0.add(16).cast::<*mut u8>();
+                                    let l6 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                }
+                                1 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l7, l8, 1);
+                                }
+                                2 => {
+                                    let l9 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l9 {
+                                        0 => {}
+                                        _ => {
+                                            let l10 = *arg0.add(20).cast::<*mut u8>();
+                                            let l11 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l10, l11, 1);
+                                        }
+                                    }
+                                    let l12 = *arg0.add(28).cast::<*mut u8>();
+                                    let l13 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                    let l14 = *arg0.add(36).cast::<*mut u8>();
+                                    let l15 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l14, l15, 1);
+                                }
+                                3 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 4, 4);
+                                    let l19 = *arg0.add(24).cast::<*mut u8>();
+                                    let l20 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l19, l20, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l21 = *arg0.add(16).cast::<*mut u8>();
+                                    let l22 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l21, l22, 1);
+                                }
+                                7 => {
+                                    let l23 = *arg0.add(16).cast::<*mut u8>();
+                                    let l24 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l23, l24, 1);
+                                }
+                                8 => {
+                                    let l25 = *arg0.add(16).cast::<*mut u8>();
+                                    let l26 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l25, l26, 1);
+                                }
+                                9 => {
+                                    let l27 = *arg0.add(16).cast::<*mut u8>();
+                                    let l28 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l27, l28, 1);
+                                }
+                                _ => {
+                                    let l29 = *arg0.add(16).cast::<*mut u8>();
+                                    let l30 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l29, l30, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_auto_connect_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::auto_connect(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u32,
+                    );
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            let AutoConnectResult {
+                                connections: connections2,
+                                ambiguities: ambiguities2,
+                                unresolved: unresolved2,
+                            } = e;
+                            let vec4 = connections2;
+                            let len4 = vec4.len();
+                            let layout4 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec4.len() * 20,
+                                4,
+                            );
+                            let result4 = if layout4.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout4);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec4.into_iter().enumerate() {
+                                let base = result4.add(i * 20);
+                                {
+                                    let Connection {
+                                        source: source3,
+                                        source_export: source_export3,
+                                        target: target3,
+                                        target_import: target_import3,
+                                    } = e;
+                                    *base.add(0).cast::<i32>() = _rt::as_i32(source3);
+                                    match source_export3 {
+                                        Some(e) => {
+                                            *base.add(4).cast::<u8>() = (1i32) as u8;
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *base.add(4).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    *base.add(12).cast::<i32>() = _rt::as_i32(target3);
+                                    *base.add(16).cast::<i32>() = _rt::as_i32(target_import3);
+                                }
+                            }
+                            *ptr1.add(12).cast::<usize>() = len4;
+                            *ptr1.add(8).cast::<*mut u8>() = result4;
+                            let vec8 = ambiguities2;
+                            let len8 = vec8.len();
+                            let layout8 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec8.len() * 12,
+                                4,
+                            );
+                            let result8 = if layout8.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout8).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout8);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec8.into_iter().enumerate() {
+                                let base = result8.add(i * 12);
+                                {
+                                    let Ambiguity {
+                                        import: import5,
+                                        candidates: candidates5,
+                                    } = e;
+                                    *base.add(0).cast::<i32>() = _rt::as_i32(import5);
+                                    let vec7 = candidates5;
+                                    let len7 = vec7.len();
+                                    let layout7 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec7.len() * 20,
+                                        4,
+                                    );
+                                    let result7 = if layout7.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout7);
+                                        }
+                                        ptr
+                                    } else {
+                                        { ::core::ptr::null_mut() }
+                                    };
+                                    for (i, e) in vec7.into_iter().enumerate() {
+                                        let base = result7.add(i * 20);
+                                        {
+                                            let Connection {
+                                                source: source6,
+                                                source_export: source_export6,
+                                                target: target6,
+                                                target_import: target_import6,
+                                            } = e;
+                                            *base.add(0).cast::<i32>() = _rt::as_i32(source6);
+                                            match source_export6 {
+                                                Some(e) => {
+                                                    *base.add(4).cast::<u8>() = (1i32) as u8;
+                                                    *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                None => {
+                                                    *base.add(4).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            *base.add(12).cast::<i32>() = _rt::as_i32(target6);
+                                            *base.add(16).cast::<i32>() = _rt::as_i32(target_import6);
+                                        }
+                                    }
+                                    *base.add(8).cast::<usize>() = len7;
+                                    *base.add(4).cast::<*mut u8>() = result7;
+                                }
+                            }
+                            *ptr1.add(20).cast::<usize>() = len8;
+                            *ptr1.add(16).cast::<*mut u8>() = result8;
+                            let vec9 = (unresolved2).into_boxed_slice();
+                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                            let len9 = vec9.len();
+                            ::core::mem::forget(vec9);
+                            *ptr1.add(28).cast::<usize>() = len9;
+                            *ptr1.add(24).cast::<*mut u8>() = ptr9.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message10, offset: offset10 } = e;
+                                    let vec11 = (message10.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr1.add(20).cast::<usize>() = len11;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                    match offset10 {
+                                        Some(e) => {
+                                            *ptr1.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message12, offset: offset12 } = e;
+                                    let vec13 = (message12.into_bytes()).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr1.add(20).cast::<usize>() = len13;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr13.cast_mut();
+                                    match offset12 {
+                                        Some(e) => {
+                                            *ptr1.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export14,
+                                        import: import14,
+                                        message: message14,
+                                    } = e;
+                                    match export14 {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec15 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr15 = vec15.as_ptr().cast::<u8>();
+                                            let len15 = vec15.len();
+                                            ::core::mem::forget(vec15);
+                                            *ptr1.add(24).cast::<usize>() = len15;
+                                            *ptr1.add(20).cast::<*mut u8>() = ptr15.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec16 = (import14.into_bytes()).into_boxed_slice();
+                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                    let len16 = vec16.len();
+                                    ::core::mem::forget(vec16);
+                                    *ptr1.add(32).cast::<usize>() = len16;
+                                    *ptr1.add(28).cast::<*mut u8>() = ptr16.cast_mut();
+                                    let vec17 = (message14.into_bytes()).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr1.add(40).cast::<usize>() = len17;
+                                    *ptr1.add(36).cast::<*mut u8>() = ptr17.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances18,
+                                        message: message18,
+                                    } = e;
+                                    let vec19 = (instances18).into_boxed_slice();
+                                    let ptr19 = vec19.as_ptr().cast::<u8>();
+                                    let len19 = vec19.len();
+                                    ::core::mem::forget(vec19);
+                                    *ptr1.add(20).cast::<usize>() = len19;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr19.cast_mut();
+                                    let vec20 = (message18.into_bytes()).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *ptr1.add(28).cast::<usize>() = len20;
+                                    *ptr1.add(24).cast::<*mut u8>() = ptr20.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec21 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                    let len21 = vec21.len();
+                                    ::core::mem::forget(vec21);
+                                    *ptr1.add(20).cast::<usize>() = len21;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr21.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec22 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr22 = vec22.as_ptr().cast::<u8>();
+                                    let len22 = vec22.len();
+                                    ::core::mem::forget(vec22);
+                                    *ptr1.add(20).cast::<usize>() = len22;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr22.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec23 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr23 = vec23.as_ptr().cast::<u8>();
+                                    let len23 = vec23.len();
+                                    ::core::mem::forget(vec23);
+                                    *ptr1.add(20).cast::<usize>() = len23;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr23.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message24,
+                                        line: line24,
+                                        column: column24,
+                                    } = e;
+                                    let vec25 = (message24.into_bytes()).into_boxed_slice();
+                                    let ptr25 = vec25.as_ptr().cast::<u8>();
+                                    let len25 = vec25.len();
+                                    ::core::mem::forget(vec25);
+                                    *ptr1.add(20).cast::<usize>() = len25;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr25.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(line24);
+                                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(column24);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec26 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr26 = vec26.as_ptr().cast::<u8>();
+                                    let len26 = vec26.len();
+                                    ::core::mem::forget(vec26);
+                                    *ptr1.add(20).cast::<usize>() = len26;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr26.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_auto_connect<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            let base3 = l1;
+                            let len3 = l2;
+                            _rt::cabi_dealloc(base3, len3 * 20, 4);
+                            let l4 = *arg0.add(16).cast::<*mut u8>();
+                            let l5 = *arg0.add(20).cast::<usize>();
+                            let base9 = l4;
+                            let len9 = l5;
+                            for i in 0..len9 {
+                                let base = base9.add(i * 12);
+                                {
+                                    let l6 = *base.add(4).cast::<*mut u8>();
+                                    let l7 = *base.add(8).cast::<usize>();
+                                    let base8 = l6;
+                                    let len8 = l7;
+                                    _rt::cabi_dealloc(base8, len8 * 20, 4);
+                                }
+                            }
+                            _rt::cabi_dealloc(base9, len9 * 12, 4);
+                            let l10 = *arg0.add(24).cast::<*mut u8>();
+                            let l11 = *arg0.add(28).cast::<usize>();
+                            let base12 = l10;
+                            let len12 = l11;
+                            _rt::cabi_dealloc(base12, len12 * 4, 4);
+                        }
+                        _ => {
+                            let l13 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l13 {
+                                0 => {
+                                    let l14 = *arg0.add(16).cast::<*mut u8>();
+                                    let l15 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l14, l15, 1);
+                                }
+                                1 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l16, l17, 1);
+                                }
+                                2 => {
+                                    let l18 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l18 {
+                                        0 => {}
+                                        _ => {
+                                            let l19 = *arg0.add(20).cast::<*mut u8>();
+                                            let l20 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l19, l20, 1);
+                                        }
+                                    }
+                                    let l21 = *arg0.add(28).cast::<*mut u8>();
+                                    let l22 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l21, l22, 1);
+                                    let l23 = *arg0.add(36).cast::<*mut u8>();
+                                    let l24 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l23, l24, 1);
+                                }
+                                3 => {
+                                    let l25 = *arg0.add(16).cast::<*mut u8>();
+                                    let l26 = *arg0.add(20).cast::<usize>();
+                                    let base27 = l25;
+                                    let len27 = l26;
+                                    _rt::cabi_dealloc(base27, len27 * 4, 4);
+                                    let l28 = *arg0.add(24).cast::<*mut u8>();
+                                    let l29 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l28, l29, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l30 = *arg0.add(16).cast::<*mut u8>();
+                                    let l31 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l30, l31, 1);
+                                }
+                                7 => {
+                                    let l32 = *arg0.add(16).cast::<*mut u8>();
+                                    let l33 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l32, l33, 1);
+                                }
+                                8 => {
+                                    let l34 = *arg0.add(16).cast::<*mut u8>();
+                                    let l35 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l34, l35, 1);
+                                }
+                                9 => {
+                                    let l36 = *arg0.add(16).cast::<*mut u8>();
+                                    let l37 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l36, l37, 1);
+                                }
+                                _ => {
+                                    let l38 = *arg0.add(16).cast::<*mut u8>();
+                                    let l39 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l38, l39, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_set_instance_name_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: *mut u8,
+                    arg4: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result1 = T::set_instance_name(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u32,
+                        match arg2 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let len0 = arg4;
+                                    let bytes0 = _rt::Vec::from_raw_parts(
+                                        arg3.cast(),
+                                        len0,
+                                        len0,
+                                    );
+                                    _rt::string_lift(bytes0)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(_) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message3, offset: offset3 } = e;
+                                    let vec4 = (message3.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *ptr2.add(20).cast::<usize>() = len4;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr4.cast_mut();
+                                    match offset3 {
+                                        Some(e) => {
+                                            *ptr2.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr2.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr2.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message5, offset: offset5 } = e;
+                                    let vec6 = (message5.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr2.add(20).cast::<usize>() = len6;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr6.cast_mut();
+                                    match offset5 {
+                                        Some(e) => {
+                                            *ptr2.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr2.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr2.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export7,
+                                        import: import7,
+                                        message: message7,
+                                    } = e;
+                                    match export7 {
+                                        Some(e) => {
+                                            *ptr2.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec8 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr8 = vec8.as_ptr().cast::<u8>();
+                                            let len8 = vec8.len();
+                                            ::core::mem::forget(vec8);
+                                            *ptr2.add(24).cast::<usize>() = len8;
+                                            *ptr2.add(20).cast::<*mut u8>() = ptr8.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr2.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec9 = (import7.into_bytes()).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr2.add(32).cast::<usize>() = len9;
+                                    *ptr2.add(28).cast::<*mut u8>() = ptr9.cast_mut();
+                                    let vec10 = (message7.into_bytes()).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr2.add(40).cast::<usize>() = len10;
+                                    *ptr2.add(36).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances11,
+                                        message: message11,
+                                    } = e;
+                                    let vec12 = (instances11).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *ptr2.add(20).cast::<usize>() = len12;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr12.cast_mut();
+                                    let vec13 = (message11.into_bytes()).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr2.add(28).cast::<usize>() = len13;
+                                    *ptr2.add(24).cast::<*mut u8>() = ptr13.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr2.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr2.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec14 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    ::core::mem::forget(vec14);
+                                    *ptr2.add(20).cast::<usize>() = len14;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr14.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec15 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *ptr2.add(20).cast::<usize>() = len15;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr15.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec16 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                    let len16 = vec16.len();
+                                    ::core::mem::forget(vec16);
+                                    *ptr2.add(20).cast::<usize>() = len16;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr16.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message17,
+                                        line: line17,
+                                        column: column17,
+                                    } = e;
+                                    let vec18 = (message17.into_bytes()).into_boxed_slice();
+                                    let ptr18 = vec18.as_ptr().cast::<u8>();
+                                    let len18 = vec18.len();
+                                    ::core::mem::forget(vec18);
+                                    *ptr2.add(20).cast::<usize>() = len18;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr18.cast_mut();
+                                    *ptr2.add(24).cast::<i32>() = _rt::as_i32(line17);
+                                    *ptr2.add(28).cast::<i32>() = _rt::as_i32(column17);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec19 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr19 = vec19.as_ptr().cast::<u8>();
+                                    let len19 = vec19.len();
+                                    ::core::mem::forget(vec19);
+                                    *ptr2.add(20).cast::<usize>() = len19;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr19.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_set_instance_name<
+                    T: GuestGraph,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                1 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                2 => {
+                                    let l6 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l6 {
+                                        0 => {}
+                                        _ => {
+                                            let l7 = *arg0.add(20).cast::<*mut u8>();
+                                            let l8 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l7, l8, 1);
+                                        }
+                                    }
+                                    let l9 = *arg0.add(28).cast::<*mut u8>();
+                                    let l10 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l9, l10, 1);
+                                    let l11 = *arg0.add(36).cast::<*mut u8>();
+                                    let l12 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l11, l12, 1);
+                                }
+                                3 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 4, 4);
+                                    let l16 = *arg0.add(24).cast::<*mut u8>();
+                                    let l17 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l16, l17, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l18 = *arg0.add(16).cast::<*mut u8>();
+                                    let l19 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l18, l19, 1);
+                                }
+                                7 => {
+                                    let l20 = *arg0.add(16).cast::<*mut u8>();
+                                    let l21 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l20, l21, 1);
+                                }
+                                8 => {
+                                    let l22 = *arg0.add(16).cast::<*mut u8>();
+                                    let l23 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l22, l23, 1);
+                                }
+                                9 => {
+                                    let l24 = *arg0.add(16).cast::<*mut u8>();
+                                    let l25 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l24, l25, 1);
+                                }
+                                _ => {
+                                    let l26 = *arg0.add(16).cast::<*mut u8>();
+                                    let l27 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l26, l27, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_aliases_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::aliases(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                    );
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec4 = result0;
+                    let len4 = vec4.len();
+                    let layout4 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec4.len() * 16,
+                        4,
+                    );
+                    let result4 = if layout4.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout4);
+                        }
+                        ptr
+                    } else {
+                        { ::core::ptr::null_mut() }
+                    };
+                    for (i, e) in vec4.into_iter().enumerate() {
+                        let base = result4.add(i * 16);
+                        {
+                            let Alias {
+                                instance: instance2,
+                                export: export2,
+                                name: name2,
+                            } = e;
+                            *base.add(0).cast::<i32>() = _rt::as_i32(instance2);
+                            *base.add(4).cast::<i32>() = _rt::as_i32(export2);
+                            let vec3 = (name2.into_bytes()).into_boxed_slice();
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            ::core::mem::forget(vec3);
+                            *base.add(12).cast::<usize>() = len3;
+                            *base.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+                        }
+                    }
+                    *ptr1.add(4).cast::<usize>() = len4;
+                    *ptr1.add(0).cast::<*mut u8>() = result4;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_aliases<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0.add(4).cast::<usize>();
+                    let base4 = l0;
+                    let len4 = l1;
+                    for i in 0..len4 {
+                        let base = base4.add(i * 16);
+                        {
+                            let l2 = *base.add(8).cast::<*mut u8>();
+                            let l3 = *base.add(12).cast::<usize>();
+                            _rt::cabi_dealloc(l2, l3, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(base4, len4 * 16, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_remove_component_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::remove_component(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u32,
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_remove_instance_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::remove_instance(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u32,
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_disconnect_instances_cabi<
+                    T: GuestGraph,
+                >(arg0: *mut u8, arg1: i32, arg2: i32, arg3: i32) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::disconnect_instances(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u32,
+                        arg2 as u32,
+                        arg3 as u32,
+                    );
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(_) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message2, offset: offset2 } = e;
+                                    let vec3 = (message2.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *ptr1.add(20).cast::<usize>() = len3;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr3.cast_mut();
+                                    match offset2 {
+                                        Some(e) => {
+                                            *ptr1.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message4, offset: offset4 } = e;
+                                    let vec5 = (message4.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr1.add(20).cast::<usize>() = len5;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                    match offset4 {
+                                        Some(e) => {
+                                            *ptr1.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export6,
+                                        import: import6,
+                                        message: message6,
+                                    } = e;
+                                    match export6 {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec7 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                                            let len7 = vec7.len();
+                                            ::core::mem::forget(vec7);
+                                            *ptr1.add(24).cast::<usize>() = len7;
+                                            *ptr1.add(20).cast::<*mut u8>() = ptr7.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec8 = (import6.into_bytes()).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr1.add(32).cast::<usize>() = len8;
+                                    *ptr1.add(28).cast::<*mut u8>() = ptr8.cast_mut();
+                                    let vec9 = (message6.into_bytes()).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr1.add(40).cast::<usize>() = len9;
+                                    *ptr1.add(36).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances10,
+                                        message: message10,
+                                    } = e;
+                                    let vec11 = (instances10).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr1.add(20).cast::<usize>() = len11;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                    let vec12 = (message10.into_bytes()).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *ptr1.add(28).cast::<usize>() = len12;
+                                    *ptr1.add(24).cast::<*mut u8>() = ptr12.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec13 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr1.add(20).cast::<usize>() = len13;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr13.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec14 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    ::core::mem::forget(vec14);
+                                    *ptr1.add(20).cast::<usize>() = len14;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr14.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec15 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *ptr1.add(20).cast::<usize>() = len15;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr15.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message16,
+                                        line: line16,
+                                        column: column16,
+                                    } = e;
+                                    let vec17 = (message16.into_bytes()).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr1.add(20).cast::<usize>() = len17;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr17.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(line16);
+                                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(column16);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec18 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr18 = vec18.as_ptr().cast::<u8>();
+                                    let len18 = vec18.len();
+                                    ::core::mem::forget(vec18);
+                                    *ptr1.add(20).cast::<usize>() = len18;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr18.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_disconnect_instances<
+                    T: GuestGraph,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                1 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                2 => {
+                                    let l6 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l6 {
+                                        0 => {}
+                                        _ => {
+                                            let l7 = *arg0.add(20).cast::<*mut u8>();
+                                            let l8 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l7, l8, 1);
+                                        }
+                                    }
+                                    let l9 = *arg0.add(28).cast::<*mut u8>();
+                                    let l10 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l9, l10, 1);
+                                    let l11 = *arg0.add(36).cast::<*mut u8>();
+                                    let l12 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l11, l12, 1);
+                                }
+                                3 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 4, 4);
+                                    let l16 = *arg0.add(24).cast::<*mut u8>();
+                                    let l17 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l16, l17, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l18 = *arg0.add(16).cast::<*mut u8>();
+                                    let l19 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l18, l19, 1);
+                                }
+                                7 => {
+                                    let l20 = *arg0.add(16).cast::<*mut u8>();
+                                    let l21 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l20, l21, 1);
+                                }
+                                8 => {
+                                    let l22 = *arg0.add(16).cast::<*mut u8>();
+                                    let l23 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l22, l23, 1);
+                                }
+                                9 => {
+                                    let l24 = *arg0.add(16).cast::<*mut u8>();
+                                    let l25 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l24, l25, 1);
+                                }
+                                _ => {
+                                    let l26 = *arg0.add(16).cast::<*mut u8>();
+                                    let l27 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l26, l27, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_undo_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::undo(GraphBorrow::lift(arg0 as u32 as usize).get());
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec44 = e;
+                            let len44 = vec44.len();
+                            let layout44 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec44.len() * 172,
+                                4,
+                            );
+                            let result44 = if layout44.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout44).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout44);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec44.into_iter().enumerate() {
+                                let base = result44.add(i * 172);
+                                {
+                                    match e {
+                                        Change::AddComponent(e) => {
+                                            *base.add(0).cast::<u8>() = (0i32) as u8;
+                                            let Component {
+                                                id: id2,
+                                                name: name2,
+                                                package_name: package_name2,
+                                                version: version2,
+                                                digest: digest2,
+                                                imports: imports2,
+                                                exports: exports2,
+                                                wit: wit2,
+                                                metadata: metadata2,
+                                            } = e;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(id2);
+                                            let vec3 = (name2.into_bytes()).into_boxed_slice();
+                                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                                            let len3 = vec3.len();
+                                            ::core::mem::forget(vec3);
+                                            *base.add(12).cast::<usize>() = len3;
+                                            *base.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+                                            let vec4 = (package_name2.into_bytes()).into_boxed_slice();
+                                            let ptr4 = vec4.as_ptr().cast::<u8>();
+                                            let len4 = vec4.len();
+                                            ::core::mem::forget(vec4);
+                                            *base.add(20).cast::<usize>() = len4;
+                                            *base.add(16).cast::<*mut u8>() = ptr4.cast_mut();
+                                            match version2 {
+                                                Some(e) => {
+                                                    *base.add(24).cast::<u8>() = (1i32) as u8;
+                                                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                                    let len5 = vec5.len();
+                                                    ::core::mem::forget(vec5);
+                                                    *base.add(32).cast::<usize>() = len5;
+                                                    *base.add(28).cast::<*mut u8>() = ptr5.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(24).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            let vec6 = (digest2.into_bytes()).into_boxed_slice();
+                                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                                            let len6 = vec6.len();
+                                            ::core::mem::forget(vec6);
+                                            *base.add(40).cast::<usize>() = len6;
+                                            *base.add(36).cast::<*mut u8>() = ptr6.cast_mut();
+                                            let vec14 = imports2;
+                                            let len14 = vec14.len();
+                                            let layout14 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec14.len() * 28,
+                                                4,
+                                            );
+                                            let result14 = if layout14.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout14).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout14);
+                                                }
+                                                ptr
+                                            } else {
+                                                { ::core::ptr::null_mut() }
+                                            };
+                                            for (i, e) in vec14.into_iter().enumerate() {
+                                                let base = result14.add(i * 28);
+                                                {
+                                                    let Import {
+                                                        name: name7,
+                                                        kind: kind7,
+                                                        signature: signature7,
+                                                        members: members7,
+                                                    } = e;
+                                                    let vec8 = (name7.into_bytes()).into_boxed_slice();
+                                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                                    let len8 = vec8.len();
+                                                    ::core::mem::forget(vec8);
+                                                    *base.add(4).cast::<usize>() = len8;
+                                                    *base.add(0).cast::<*mut u8>() = ptr8.cast_mut();
+                                                    *base.add(8).cast::<u8>() = (kind7.clone() as i32) as u8;
+                                                    let vec9 = (signature7.into_bytes()).into_boxed_slice();
+                                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                                    let len9 = vec9.len();
+                                                    ::core::mem::forget(vec9);
+                                                    *base.add(16).cast::<usize>() = len9;
+                                                    *base.add(12).cast::<*mut u8>() = ptr9.cast_mut();
+                                                    let vec13 = members7;
+                                                    let len13 = vec13.len();
+                                                    let layout13 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                        vec13.len() * 20,
+                                                        4,
+                                                    );
+                                                    let result13 = if layout13.size() != 0 {
+                                                        let ptr = _rt::alloc::alloc(layout13).cast::<u8>();
+                                                        if ptr.is_null() {
+                                                            _rt::alloc::handle_alloc_error(layout13);
+                                                        }
+                                                        ptr
+                                                    } else {
+                                                        { ::core::ptr::null_mut() }
+                                                    };
+                                                    for (i, e) in vec13.into_iter().enumerate() {
+                                                        let base = result13.add(i * 20);
+                                                        {
+                                                            let Member {
+                                                                name: name10,
+                                                                kind: kind10,
+                                                                signature: signature10,
+                                                            } = e;
+                                                            let vec11 = (name10.into_bytes()).into_boxed_slice();
+                                                            let ptr11 = vec11.as_ptr().cast::<u8>();
+                                                            let len11 = vec11.len();
+                                                            ::core::mem::forget(vec11);
+                                                            *base.add(4).cast::<usize>() = len11;
+                                                            *base.add(0).cast::<*mut u8>() = ptr11.cast_mut();
+                                                            *base.add(8).cast::<u8>() = (kind10.clone() as i32) as u8;
+                                                            let vec12 = (signature10.into_bytes()).into_boxed_slice();
+                                                            let ptr12 = vec12.as_ptr().cast::<u8>();
+                                                            let len12 = vec12.len();
+                                                            ::core::mem::forget(vec12);
+                                                            *base.add(16).cast::<usize>() = len12;
+                                                            *base.add(12).cast::<*mut u8>() = ptr12.cast_mut();
+                                                        }
+                                                    }
+                                                    *base.add(24).cast::<usize>() = len13;
+                                                    *base.add(20).cast::<*mut u8>() = result13;
+                                                }
+                                            }
+                                            *base.add(48).cast::<usize>() = len14;
+                                            *base.add(44).cast::<*mut u8>() = result14;
+                                            let vec22 = exports2;
+                                            let len22 = vec22.len();
+                                            let layout22 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec22.len() * 28,
+                                                4,
+                                            );
+                                            let result22 = if layout22.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout22).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout22);
+                                                }
+                                                ptr
+                                            } else {
+                                                { ::core::ptr::null_mut() }
+                                            };
+                                            for (i, e) in vec22.into_iter().enumerate() {
+                                                let base = result22.add(i * 28);
+                                                {
+                                                    let Export {
+                                                        name: name15,
+                                                        kind: kind15,
+                                                        signature: signature15,
+                                                        members: members15,
+                                                    } = e;
+                                                    let vec16 = (name15.into_bytes()).into_boxed_slice();
+                                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                                    let len16 = vec16.len();
+                                                    ::core::mem::forget(vec16);
+                                                    *base.add(4).cast::<usize>() = len16;
+                                                    *base.add(0).cast::<*mut u8>() = ptr16.cast_mut();
+                                                    *base.add(8).cast::<u8>() = (kind15.clone() as i32) as u8;
+                                                    let vec17 = (signature15.into_bytes()).into_boxed_slice();
+                                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                                    let len17 = vec17.len();
+                                                    ::core::mem::forget(vec17);
+                                                    *base.add(16).cast::<usize>() = len17;
+                                                    *base.add(12).cast::<*mut u8>() = ptr17.cast_mut();
+                                                    let vec21 = members15;
+                                                    let len21 = vec21.len();
+                                                    let layout21 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                        vec21.len() * 20,
+                                                        4,
+                                                    );
+                                                    let result21 = if layout21.size() != 0 {
+                                                        let ptr = _rt::alloc::alloc(layout21).cast::<u8>();
+                                                        if ptr.is_null() {
+                                                            _rt::alloc::handle_alloc_error(layout21);
+                                                        }
+                                                        ptr
+                                                    } else {
+                                                        { ::core::ptr::null_mut() }
+                                                    };
+                                                    for (i, e) in vec21.into_iter().enumerate() {
+                                                        let base = result21.add(i * 20);
+                                                        {
+                                                            let Member {
+                                                                name: name18,
+                                                                kind: kind18,
+                                                                signature: signature18,
+                                                            } = e;
+                                                            let vec19 = (name18.into_bytes()).into_boxed_slice();
+                                                            let ptr19 = vec19.as_ptr().cast::<u8>();
+                                                            let len19 = vec19.len();
+                                                            ::core::mem::forget(vec19);
+                                                            *base.add(4).cast::<usize>() = len19;
+                                                            *base.add(0).cast::<*mut u8>() = ptr19.cast_mut();
+                                                            *base.add(8).cast::<u8>() = (kind18.clone() as i32) as u8;
+                                                            let vec20 = (signature18.into_bytes()).into_boxed_slice();
+                                                            let ptr20 = vec20.as_ptr().cast::<u8>();
+                                                            let len20 = vec20.len();
+                                                            ::core::mem::forget(vec20);
+                                                            *base.add(16).cast::<usize>() = len20;
+                                                            *base.add(12).cast::<*mut u8>() = ptr20.cast_mut();
+                                                        }
+                                                    }
+                                                    *base.add(24).cast::<usize>() = len21;
+                                                    *base.add(20).cast::<*mut u8>() = result21;
+                                                }
+                                            }
+                                            *base.add(56).cast::<usize>() = len22;
+                                            *base.add(52).cast::<*mut u8>() = result22;
+                                            let vec23 = (wit2.into_bytes()).into_boxed_slice();
+                                            let ptr23 = vec23.as_ptr().cast::<u8>();
+                                            let len23 = vec23.len();
+                                            ::core::mem::forget(vec23);
+                                            *base.add(64).cast::<usize>() = len23;
+                                            *base.add(60).cast::<*mut u8>() = ptr23.cast_mut();
+                                            let ComponentMetadata {
+                                                name: name24,
+                                                producers: producers24,
+                                                authors: authors24,
+                                                description: description24,
+                                                licenses: licenses24,
+                                                source: source24,
+                                                homepage: homepage24,
+                                                revision: revision24,
+                                                version: version24,
+                                            } = metadata2;
+                                            match name24 {
+                                                Some(e) => {
+                                                    *base.add(68).cast::<u8>() = (1i32) as u8;
+                                                    let vec25 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr25 = vec25.as_ptr().cast::<u8>();
+                                                    let len25 = vec25.len();
+                                                    ::core::mem::forget(vec25);
+                                                    *base.add(76).cast::<usize>() = len25;
+                                                    *base.add(72).cast::<*mut u8>() = ptr25.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(68).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            let vec30 = producers24;
+                                            let len30 = vec30.len();
+                                            let layout30 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec30.len() * 24,
+                                                4,
+                                            );
+                                            let result30 = if layout30.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout30).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout30);
+                                                }
+                                                ptr
+                                            } else {
+                                                { ::core::ptr::null_mut() }
+                                            };
+                                            for (i, e) in vec30.into_iter().enumerate() {
+                                                let base = result30.add(i * 24);
+                                                {
+                                                    let Producer {
+                                                        field: field26,
+                                                        name: name26,
+                                                        version: version26,
+                                                    } = e;
+                                                    let vec27 = (field26.into_bytes()).into_boxed_slice();
+                                                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                                                    let len27 = vec27.len();
+                                                    ::core::mem::forget(vec27);
+                                                    *base.add(4).cast::<usize>() = len27;
+                                                    *base.add(0).cast::<*mut u8>() = ptr27.cast_mut();
+                                                    let vec28 = (name26.into_bytes()).into_boxed_slice();
+                                                    let ptr28 = vec28.as_ptr().cast::<u8>();
+                                                    let len28 = vec28.len();
+                                                    ::core::mem::forget(vec28);
+                                                    *base.add(12).cast::<usize>() = len28;
+                                                    *base.add(8).cast::<*mut u8>() = ptr28.cast_mut();
+                                                    let vec29 = (version26.into_bytes()).into_boxed_slice();
+                                                    let ptr29 = vec29.as_ptr().cast::<u8>();
+                                                    let len29 = vec29.len();
+                                                    ::core::mem::forget(vec29);
+                                                    *base.add(20).cast::<usize>() = len29;
+                                                    *base.add(16).cast::<*mut u8>() = ptr29.cast_mut();
+                                                }
+                                            }
+                                            *base.add(84).cast::<usize>() = len30;
+                                            *base.add(80).cast::<*mut u8>() = result30;
+                                            match authors24 {
+                                                Some(e) => {
+                                                    *base.add(88).cast::<u8>() = (1i32) as u8;
+                                                    let vec31 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr31 = vec31.as_ptr().cast::<u8>();
+                                                    let len31 = vec31.len();
+                                                    ::core::mem::forget(vec31);
+                                                    *base.add(96).cast::<usize>() = len31;
+                                                    *base.add(92).cast::<*mut u8>() = ptr31.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(88).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match description24 {
+                                                Some(e) => {
+                                                    *base.add(100).cast::<u8>() = (1i32) as u8;
+                                                    let vec32 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr32 = vec32.as_ptr().cast::<u8>();
+                                                    let len32 = vec32.len();
+                                                    ::core::mem::forget(vec32);
+                                                    *base.add(108).cast::<usize>() = len32;
+                                                    *base.add(104).cast::<*mut u8>() = ptr32.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(100).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match licenses24 {
+                                                Some(e) => {
+                                                    *base.add(112).cast::<u8>() = (1i32) as u8;
+                                                    let vec33 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr33 = vec33.as_ptr().cast::<u8>();
+                                                    let len33 = vec33.len();
+                                                    ::core::mem::forget(vec33);
+                                                    *base.add(120).cast::<usize>() = len33;
+                                                    *base.add(116).cast::<*mut u8>() = ptr33.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(112).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match source24 {
+                                                Some(e) => {
+                                                    *base.add(124).cast::<u8>() = (1i32) as u8;
+                                                    let vec34 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr34 = vec34.as_ptr().cast::<u8>();
+                                                    let len34 = vec34.len();
+                                                    ::core::mem::forget(vec34);
+                                                    *base.add(132).cast::<usize>() = len34;
+                                                    *base.add(128).cast::<*mut u8>() = ptr34.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(124).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match homepage24 {
+                                                Some(e) => {
+                                                    *base.add(136).cast::<u8>() = (1i32) as u8;
+                                                    let vec35 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr35 = vec35.as_ptr().cast::<u8>();
+                                                    let len35 = vec35.len();
+                                                    ::core::mem::forget(vec35);
+                                                    *base.add(144).cast::<usize>() = len35;
+                                                    *base.add(140).cast::<*mut u8>() = ptr35.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(136).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match revision24 {
+                                                Some(e) => {
+                                                    *base.add(148).cast::<u8>() = (1i32) as u8;
+                                                    let vec36 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr36 = vec36.as_ptr().cast::<u8>();
+                                                    let len36 = vec36.len();
+                                                    ::core::mem::forget(vec36);
+                                                    *base.add(156).cast::<usize>() = len36;
+                                                    *base.add(152).cast::<*mut u8>() = ptr36.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(148).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match version24 {
+                                                Some(e) => {
+                                                    *base.add(160).cast::<u8>() = (1i32) as u8;
+                                                    let vec37 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr37 = vec37.as_ptr().cast::<u8>();
+                                                    let len37 = vec37.len();
+                                                    ::core::mem::forget(vec37);
+                                                    *base.add(168).cast::<usize>() = len37;
+                                                    *base.add(164).cast::<*mut u8>() = ptr37.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(160).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                        Change::RemoveComponent(e) => {
+                                            *base.add(0).cast::<u8>() = (1i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        Change::AddInstance(e) => {
+                                            *base.add(0).cast::<u8>() = (2i32) as u8;
+                                            let Instance {
+                                                id: id38,
+                                                component: component38,
+                                                name: name38,
+                                            } = e;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(id38);
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(component38);
+                                            match name38 {
+                                                Some(e) => {
+                                                    *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                    let vec39 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr39 = vec39.as_ptr().cast::<u8>();
+                                                    let len39 = vec39.len();
+                                                    ::core::mem::forget(vec39);
+                                                    *base.add(20).cast::<usize>() = len39;
+                                                    *base.add(16).cast::<*mut u8>() = ptr39.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                        Change::RemoveInstance(e) => {
+                                            *base.add(0).cast::<u8>() = (3i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        Change::RenameInstance(e) => {
+                                            *base.add(0).cast::<u8>() = (4i32) as u8;
+                                            let Instance {
+                                                id: id40,
+                                                component: component40,
+                                                name: name40,
+                                            } = e;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(id40);
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(component40);
+                                            match name40 {
+                                                Some(e) => {
+                                                    *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                    let vec41 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr41 = vec41.as_ptr().cast::<u8>();
+                                                    let len41 = vec41.len();
+                                                    ::core::mem::forget(vec41);
+                                                    *base.add(20).cast::<usize>() = len41;
+                                                    *base.add(16).cast::<*mut u8>() = ptr41.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                        Change::Connect(e) => {
+                                            *base.add(0).cast::<u8>() = (5i32) as u8;
+                                            let Connection {
+                                                source: source42,
+                                                source_export: source_export42,
+                                                target: target42,
+                                                target_import: target_import42,
+                                            } = e;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(source42);
+                                            match source_export42 {
+                                                Some(e) => {
+                                                    *base.add(8).cast::<u8>() = (1i32) as u8;
+                                                    *base.add(12).cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                None => {
+                                                    *base.add(8).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            *base.add(16).cast::<i32>() = _rt::as_i32(target42);
+                                            *base.add(20).cast::<i32>() = _rt::as_i32(target_import42);
+                                        }
+                                        Change::Disconnect(e) => {
+                                            *base.add(0).cast::<u8>() = (6i32) as u8;
+                                            let Connection {
+                                                source: source43,
+                                                source_export: source_export43,
+                                                target: target43,
+                                                target_import: target_import43,
+                                            } = e;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(source43);
+                                            match source_export43 {
+                                                Some(e) => {
+                                                    *base.add(8).cast::<u8>() = (1i32) as u8;
+                                                    *base.add(12).cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                None => {
+                                                    *base.add(8).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            *base.add(16).cast::<i32>() = _rt::as_i32(target43);
+                                            *base.add(20).cast::<i32>() = _rt::as_i32(target_import43);
+                                        }
+                                    }
+                                }
+                            }
+                            *ptr1.add(12).cast::<usize>() = len44;
+                            *ptr1.add(8).cast::<*mut u8>() = result44;
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message45, offset: offset45 } = e;
+                                    let vec46 = (message45.into_bytes()).into_boxed_slice();
+                                    let ptr46 = vec46.as_ptr().cast::<u8>();
+                                    let len46 = vec46.len();
+                                    ::core::mem::forget(vec46);
+                                    *ptr1.add(20).cast::<usize>() = len46;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr46.cast_mut();
+                                    match offset45 {
+                                        Some(e) => {
+                                            *ptr1.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message47, offset: offset47 } = e;
+                                    let vec48 = (message47.into_bytes()).into_boxed_slice();
+                                    let ptr48 = vec48.as_ptr().cast::<u8>();
+                                    let len48 = vec48.len();
+                                    ::core::mem::forget(vec48);
+                                    *ptr1.add(20).cast::<usize>() = len48;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr48.cast_mut();
+                                    match offset47 {
+                                        Some(e) => {
+                                            *ptr1.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export49,
+                                        import: import49,
+                                        message: message49,
+                                    } = e;
+                                    match export49 {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec50 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr50 = vec50.as_ptr().cast::<u8>();
+                                            let len50 = vec50.len();
+                                            ::core::mem::forget(vec50);
+                                            *ptr1.add(24).cast::<usize>() = len50;
+                                            *ptr1.add(20).cast::<*mut u8>() = ptr50.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec51 = (import49.into_bytes()).into_boxed_slice();
+                                    let ptr51 = vec51.as_ptr().cast::<u8>();
+                                    let len51 = vec51.len();
+                                    ::core::mem::forget(vec51);
+                                    *ptr1.add(32).cast::<usize>() = len51;
+                                    *ptr1.add(28).cast::<*mut u8>() = ptr51.cast_mut();
+                                    let vec52 = (message49.into_bytes()).into_boxed_slice();
+                                    let ptr52 = vec52.as_ptr().cast::<u8>();
+                                    let len52 = vec52.len();
+                                    ::core::mem::forget(vec52);
+                                    *ptr1.add(40).cast::<usize>() = len52;
+                                    *ptr1.add(36).cast::<*mut u8>() = ptr52.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances53,
+                                        message: message53,
+                                    } = e;
+                                    let vec54 = (instances53).into_boxed_slice();
+                                    let ptr54 = vec54.as_ptr().cast::<u8>();
+                                    let len54 = vec54.len();
+                                    ::core::mem::forget(vec54);
+                                    *ptr1.add(20).cast::<usize>() = len54;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr54.cast_mut();
+                                    let vec55 = (message53.into_bytes()).into_boxed_slice();
+                                    let ptr55 = vec55.as_ptr().cast::<u8>();
+                                    let len55 = vec55.len();
+                                    ::core::mem::forget(vec55);
+                                    *ptr1.add(28).cast::<usize>() = len55;
+                                    *ptr1.add(24).cast::<*mut u8>() = ptr55.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec56 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr56 = vec56.as_ptr().cast::<u8>();
+                                    let len56 = vec56.len();
+                                    ::core::mem::forget(vec56);
+                                    *ptr1.add(20).cast::<usize>() = len56;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr56.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec57 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr57 = vec57.as_ptr().cast::<u8>();
+                                    let len57 = vec57.len();
+                                    ::core::mem::forget(vec57);
+                                    *ptr1.add(20).cast::<usize>() = len57;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr57.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec58 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr58 = vec58.as_ptr().cast::<u8>();
+                                    let len58 = vec58.len();
+                                    ::core::mem::forget(vec58);
+                                    *ptr1.add(20).cast::<usize>() = len58;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr58.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message59,
+                                        line: line59,
+                                        column: column59,
+                                    } = e;
+                                    let vec60 = (message59.into_bytes()).into_boxed_slice();
+                                    let ptr60 = vec60.as_ptr().cast::<u8>();
+                                    let len60 = vec60.len();
+                                    ::core::mem::forget(vec60);
+                                    *ptr1.add(20).cast::<usize>() = len60;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr60.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(line59);
+                                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(column59);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec61 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr61 = vec61.as_ptr().cast::<u8>();
+                                    let len61 = vec61.len();
+                                    ::core::mem::forget(vec61);
+                                    *ptr1.add(20).cast::<usize>() = len61;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr61.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_undo<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            let base82 = l1;
+                            let len82 = l2;
+                            for i in 0..len82 {
+                                let base = base82.add(i * 172);
+                                {
+                                    let l3 = i32::from(*base.add(0).cast::<u8>());
+                                    match l3 {
+                                        0 => {
+                                            let l4 = *base.add(8).cast::<*mut u8>();
+                                            let l5 = *base.add(12).cast::<usize>();
+                                            _rt::cabi_dealloc(l4, l5, 1);
+                                            let l6 = *base.add(16).cast::<*mut u8>();
+                                            let l7 = *base.add(20).cast::<usize>();
+                                            _rt::cabi_dealloc(l6, l7, 1);
+                                            let l8 = i32::from(*base.add(24).cast::<u8>());
+                                            match l8 {
+                                                0 => {}
+                                                _ => {
+                                                    let l9 = *base.add(28).cast::<*mut u8>();
+                                                    let l10 = *base.add(32).cast::<usize>();
+                                                    _rt::cabi_dealloc(l9, l10, 1);
+                                                }
+                                            }
+                                            let l11 = *base.add(36).cast::<*mut u8>();
+                                            let l12 = *base.add(40).cast::<usize>();
+                                            _rt::cabi_dealloc(l11, l12, 1);
+                                            let l13 = *base.add(44).cast::<*mut u8>();
+                                            let l14 = *base.add(48).cast::<usize>();
+                                            let base26 = l13;
+                                            let len26 = l14;
+                                            for i in 0..len26 {
+                                                let base = base26.add(i * 28);
+                                                {
+                                                    let l15 = *base.add(0).cast::<*mut u8>();
+                                                    let l16 = *base.add(4).cast::<usize>();
+                                                    _rt::cabi_dealloc(l15, l16, 1);
+                                                    let l17 = *base.add(12).cast::<*mut u8>();
+                                                    let l18 = *base.add(16).cast::<usize>();
+                                                    _rt::cabi_dealloc(l17, l18, 1);
+                                                    let l19 = *base.add(20).cast::<*mut u8>();
+                                                    let l20 = *base.add(24).cast::<usize>();
+                                                    let base25 = l19;
+                                                    let len25 = l20;
+                                                    for i in 0..len25 {
+                                                        let base = base25.add(i * 20);
+                                                        {
+                                                            let l21 = *base.add(0).cast::<*mut u8>();
+                                                            let l22 = *base.add(4).cast::<usize>();
+                                                            _rt::cabi_dealloc(l21, l22, 1);
+                                                            let l23 = *base.add(12).cast::<*mut u8>();
+                                                            let l24 = *base.add(16).cast::<usize>();
+                                                            _rt::cabi_dealloc(l23, l24, 1);
+                                                        }
+                                                    }
+                                                    _rt::cabi_dealloc(base25, len25 * 20, 4);
+                                                }
+                                            }
+                                            _rt::cabi_dealloc(base26, len26 * 28, 4);
+                                            let l27 = *base.add(52).cast::<*mut u8>();
+                                            let l28 = *base.add(56).cast::<usize>();
+                                            let base40 = l27;
+                                            let len40 = l28;
+                                            for i in 0..len40 {
+                                                let base = base40.add(i * 28);
+                                                {
+                                                    let l29 = *base.add(0).cast::<*mut u8>();
+                                                    let l30 = *base.add(4).cast::<usize>();
+                                                    _rt::cabi_dealloc(l29, l30, 1);
+                                                    let l31 = *base.add(12).cast::<*mut u8>();
+                                                    let l32 = *base.add(16).cast::<usize>();
+                                                    _rt::cabi_dealloc(l31, l32, 1);
+                                                    let l33 = *base.add(20).cast::<*mut u8>();
+                                                    let l34 = *base.add(24).cast::<usize>();
+                                                    let base39 = l33;
+                                                    let len39 = l34;
+                                                    for i in 0..len39 {
+                                                        let base = base39.add(i * 20);
+                                                        {
+                                                            let l35 = *base.add(0).cast::<*mut u8>();
+                                                            let l36 = *base.add(4).cast::<usize>();
+                                                            _rt::cabi_dealloc(l35, l36, 1);
+                                                            let l37 = *base.add(12).cast::<*mut u8>();
+                                                            let l38 = *base.add(16).cast::<usize>();
+                                                            _rt::cabi_dealloc(l37, l38, 1);
+                                                        }
+                                                    }
+                                                    _rt::cabi_dealloc(base39, len39 * 20, 4);
+                                                }
+                                            }
+                                            _rt::cabi_dealloc(base40, len40 * 28, 4);
+                                            let l41 = *base.add(60).cast::<*mut u8>();
+                                            let l42 = *base.add(64).cast::<usize>();
+                                            _rt::cabi_dealloc(l41, l42, 1);
+                                            let l43 = i32::from(*base.add(68).cast::<u8>());
+                                            match l43 {
+                                                0 => {}
+                                                _ => {
+                                                    let l44 = *base.add(72).cast::<*mut u8>();
+                                                    let l45 = *base.add(76).cast::<usize>();
+                                                    _rt::cabi_dealloc(l44, l45, 1);
+                                                }
+                                            }
+                                            let l46 = *base.add(80).cast::<*mut u8>();
+                                            let l47 = *base.add(84).cast::<usize>();
+                                            let base54 = l46;
+                                            let len54 = l47;
+                                            for i in 0..len54 {
+                                                let base = base54.add(i * 24);
+                                                {
+                                                    let l48 = *base.add(0).cast::<*mut u8>();
+                                                    let l49 = *base.add(4).cast::<usize>();
+                                                    _rt::cabi_dealloc(l48, l49, 1);
+                                                    let l50 = *base.add(8).cast::<*mut u8>();
+                                                    let l51 = *base.add(12).cast::<usize>();
+                                                    _rt::cabi_dealloc(l50, l51, 1);
+                                                    let l52 = *base.add(16).cast::<*mut u8>();
+                                                    let l53 = *base.add(20).cast::<usize>();
+                                                    _rt::cabi_dealloc(l52, l53, 1);
+                                                }
+                                            }
+                                            _rt::cabi_dealloc(base54, len54 * 24, 4);
+                                            let l55 = i32::from(*base.add(88).cast::<u8>());
+                                            match l55 {
+                                                0 => {}
+                                                _ => {
+                                                    let l56 = *base.add(92).cast::<*mut u8>();
+                                                    let l57 = *base.add(96).cast::<usize>();
+                                                    _rt::cabi_dealloc(l56, l57, 1);
+                                                }
+                                            }
+                                            let l58 = i32::from(*base.add(100).cast::<u8>());
+                                            match l58 {
+                                                0 => {}
+                                                _ => {
+                                                    let l59 = *base.add(104).cast::<*mut u8>();
+                                                    let l60 = *base.add(108).cast::<usize>();
+                                                    _rt::cabi_dealloc(l59, l60, 1);
+                                                }
+                                            }
+                                            let l61 = i32::from(*base.add(112).cast::<u8>());
+                                            match l61 {
+                                                0 => {}
+                                                _ => {
+                                                    let l62 = *base.add(116).cast::<*mut u8>();
+                                                    let l63 = *base.add(120).cast::<usize>();
+                                                    _rt::cabi_dealloc(l62, l63, 1);
+                                                }
+                                            }
+                                            let l64 = i32::from(*base.add(124).cast::<u8>());
+                                            match l64 {
+                                                0 => {}
+                                                _ => {
+                                                    let l65 = *base.add(128).cast::<*mut u8>();
+                                                    let l66 = *base.add(132).cast::<usize>();
+                                                    _rt::cabi_dealloc(l65, l66, 1);
+                                                }
+                                            }
+                                            let l67 = i32::from(*base.add(136).cast::<u8>());
+                                            match l67 {
+                                                0 => {}
+                                                _ => {
+                                                    let l68 = *base.add(140).cast::<*mut u8>();
+                                                    let l69 = *base.add(144).cast::<usize>();
+                                                    _rt::cabi_dealloc(l68, l69, 1);
+                                                }
+                                            }
+                                            let l70 = i32::from(*base.add(148).cast::<u8>());
+                                            match l70 {
+                                                0 => {}
+                                                _ => {
+                                                    let l71 = *base.add(152).cast::<*mut u8>();
+                                                    let l72 = *base.add(156).cast::<usize>();
+                                                    _rt::cabi_dealloc(l71, l72, 1);
+                                                }
+                                            }
+                                            let l73 = i32::from(*base.add(160).cast::<u8>());
+                                            match l73 {
+                                                0 => {}
+                                                _ => {
+                                                    let l74 = *base.add(164).cast::<*mut u8>();
+                                                    let l75 = *base.add(168).cast::<usize>();
+                                                    _rt::cabi_dealloc(l74, l75, 1);
+                                                }
+                                            }
+                                        }
+                                        1 => {}
+                                        2 => {
+                                            let l76 = i32::from(*base.add(12).cast::<u8>());
+                                            match l76 {
+                                                0 => {}
+                                                _ => {
+                                                    let l77 = *base.add(16).cast::<*mut u8>();
+                                                    let l78 = *base.add(20).cast::<usize>();
+                                                    _rt::cabi_dealloc(l77, l78, 1);
+                                                }
+                                            }
+                                        }
+                                        3 => {}
+                                        4 => {
+                                            let l79 = i32::from(*base.add(12).cast::<u8>());
+                                            match l79 {
+                                                0 => {}
+                                                _ => {
+                                                    let l80 = *base.add(16).cast::<*mut u8>();
+                                                    let l81 = *base.add(20).cast::<usize>();
+                                                    _rt::cabi_dealloc(l80, l81, 1);
+                                                }
+                                            }
+                                        }
+                                        5 => {}
+                                        _ => {}
+                                    }
+                                }
+                            }
+                            _rt::cabi_dealloc(base82, len82 * 172, 4);
+                        }
+                        _ => {
+                            let l83 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l83 {
+                                0 => {
+                                    let l84 = *arg0.add(16).cast::<*mut u8>();
+                                    let l85 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l84, l85, 1);
+                                }
+                                1 => {
+                                    let l86 = *arg0.add(16).cast::<*mut u8>();
+                                    let l87 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l86, l87, 1);
+                                }
+                                2 => {
+                                    let l88 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l88 {
+                                        0 => {}
+                                        _ => {
+                                            let l89 = *arg0.add(20).cast::<*mut u8>();
+                                            let l90 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l89, l90, 1);
+                                        }
+                                    }
+                                    let l91 = *arg0.add(28).cast::<*mut u8>();
+                                    let l92 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l91, l92, 1);
+                                    let l93 = *arg0.add(36).cast::<*mut u8>();
+                                    let l94 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l93, l94, 1);
+                                }
+                                3 => {
+                                    let l95 = *arg0.add(16).cast::<*mut u8>();
+                                    let l96 = *arg0.add(20).cast::<usize>();
+                                    let base97 = l95;
+                                    let len97 = l96;
+                                    _rt::cabi_dealloc(base97, len97 * 4, 4);
+                                    let l98 = *arg0.add(24).cast::<*mut u8>();
+                                    let l99 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l98, l99, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l100 = *arg0.add(16).cast::<*mut u8>();
+                                    let l101 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l100, l101, 1);
+                                }
+                                7 => {
+                                    let l102 = *arg0.add(16).cast::<*mut u8>();
+                                    let l103 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l102, l103, 1);
+                                }
+                                8 => {
+                                    let l104 = *arg0.add(16).cast::<*mut u8>();
+                                    let l105 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l104, l105, 1);
+                                }
+                                9 => {
+                                    let l106 = *arg0.add(16).cast::<*mut u8>();
+                                    let l107 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l106, l107, 1);
+                                }
+                                _ => {
+                                    let l108 = *arg0.add(16).cast::<*mut u8>();
+                                    let l109 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l108, l109, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_redo_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::redo(GraphBorrow::lift(arg0 as u32 as usize).get());
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec44 = e;
+                            let len44 = vec44.len();
+                            let layout44 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec44.len() * 172,
+                                4,
+                            );
+                            let result44 = if layout44.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout44).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout44);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec44.into_iter().enumerate() {
+                                let base = result44.add(i * 172);
+                                {
+                                    match e {
+                                        Change::AddComponent(e) => {
+                                            *base.add(0).cast::<u8>() = (0i32) as u8;
+                                            let Component {
+                                                id: id2,
+                                                name: name2,
+                                                package_name: package_name2,
+                                                version: version2,
+                                                digest: digest2,
+                                                imports: imports2,
+                                                exports: exports2,
+                                                wit: wit2,
+                                                metadata: metadata2,
+                                            } = e;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(id2);
+                                            let vec3 = (name2.into_bytes()).into_boxed_slice();
+                                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                                            let len3 = vec3.len();
+                                            ::core::mem::forget(vec3);
+                                            *base.add(12).cast::<usize>() = len3;
+                                            *base.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+                                            let vec4 = (package_name2.into_bytes()).into_boxed_slice();
+                                            let ptr4 = vec4.as_ptr().cast::<u8>();
+                                            let len4 = vec4.len();
+                                            ::core::mem::forget(vec4);
+                                            *base.add(20).cast::<usize>() = len4;
+                                            *base.add(16).cast::<*mut u8>() = ptr4.cast_mut();
+                                            match version2 {
+                                                Some(e) => {
+                                                    *base.add(24).cast::<u8>() = (1i32) as u8;
+                                                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                                    let len5 = vec5.len();
+                                                    ::core::mem::forget(vec5);
+                                                    *base.add(32).cast::<usize>() = len5;
+                                                    *base.add(28).cast::<*mut u8>() = ptr5.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(24).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            let vec6 = (digest2.into_bytes()).into_boxed_slice();
+                                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                                            let len6 = vec6.len();
+                                            ::core::mem::forget(vec6);
+                                            *base.add(40).cast::<usize>() = len6;
+                                            *base.add(36).cast::<*mut u8>() = ptr6.cast_mut();
+                                            let vec14 = imports2;
+                                            let len14 = vec14.len();
+                                            let layout14 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec14.len() * 28,
+                                                4,
+                                            );
+                                            let result14 = if layout14.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout14).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout14);
+                                                }
+                                                ptr
+                                            } else {
+                                                { ::core::ptr::null_mut() }
+                                            };
+                                            for (i, e) in vec14.into_iter().enumerate() {
+                                                let base = result14.add(i * 28);
+                                                {
+                                                    let Import {
+                                                        name: name7,
+                                                        kind: kind7,
+                                                        signature: signature7,
+                                                        members: members7,
+                                                    } = e;
+                                                    let vec8 = (name7.into_bytes()).into_boxed_slice();
+                                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                                    let len8 = vec8.len();
+                                                    ::core::mem::forget(vec8);
+                                                    *base.add(4).cast::<usize>() = len8;
+                                                    *base.add(0).cast::<*mut u8>() = ptr8.cast_mut();
+                                                    *base.add(8).cast::<u8>() = (kind7.clone() as i32) as u8;
+                                                    let vec9 = (signature7.into_bytes()).into_boxed_slice();
+                                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                                    let len9 = vec9.len();
+                                                    ::core::mem::forget(vec9);
+                                                    *base.add(16).cast::<usize>() = len9;
+                                                    *base.add(12).cast::<*mut u8>() = ptr9.cast_mut();
+                                                    let vec13 = members7;
+                                                    let len13 = vec13.len();
+                                                    let layout13 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                        vec13.len() * 20,
+                                                        4,
+                                                    );
+                                                    let result13 = if layout13.size() != 0 {
+                                                        let ptr = _rt::alloc::alloc(layout13).cast::<u8>();
+                                                        if ptr.is_null() {
+                                                            _rt::alloc::handle_alloc_error(layout13);
+                                                        }
+                                                        ptr
+                                                    } else {
+                                                        { ::core::ptr::null_mut() }
+                                                    };
+                                                    for (i, e) in vec13.into_iter().enumerate() {
+                                                        let base = result13.add(i * 20);
+                                                        {
+                                                            let Member {
+                                                                name: name10,
+                                                                kind: kind10,
+                                                                signature: signature10,
+                                                            } = e;
+                                                            let vec11 = (name10.into_bytes()).into_boxed_slice();
+                                                            let ptr11 = vec11.as_ptr().cast::<u8>();
+                                                            let len11 = vec11.len();
+                                                            ::core::mem::forget(vec11);
+                                                            *base.add(4).cast::<usize>() = len11;
+                                                            *base.add(0).cast::<*mut u8>() = ptr11.cast_mut();
+                                                            *base.add(8).cast::<u8>() = (kind10.clone() as i32) as u8;
+                                                            let vec12 = (signature10.into_bytes()).into_boxed_slice();
+                                                            let ptr12 = vec12.as_ptr().cast::<u8>();
+                                                            let len12 = vec12.len();
+                                                            ::core::mem::forget(vec12);
+                                                            *base.add(16).cast::<usize>() = len12;
+                                                            *base.add(12).cast::<*mut u8>() = ptr12.cast_mut();
+                                                        }
+                                                    }
+                                                    *base.add(24).cast::<usize>() = len13;
+                                                    *base.add(20).cast::<*mut u8>() = result13;
+                                                }
+                                            }
+                                            *base.add(48).cast::<usize>() = len14;
+                                            *base.add(44).cast::<*mut u8>() = result14;
+                                            let vec22 = exports2;
+                                            let len22 = vec22.len();
+                                            let layout22 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec22.len() * 28,
+                                                4,
+                                            );
+                                            let result22 = if layout22.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout22).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout22);
+                                                }
+                                                ptr
+                                            } else {
+                                                { ::core::ptr::null_mut() }
+                                            };
+                                            for (i, e) in vec22.into_iter().enumerate() {
+                                                let base = result22.add(i * 28);
+                                                {
+                                                    let Export {
+                                                        name: name15,
+                                                        kind: kind15,
+                                                        signature: signature15,
+                                                        members: members15,
+                                                    } = e;
+                                                    let vec16 = (name15.into_bytes()).into_boxed_slice();
+                                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                                    let len16 = vec16.len();
+                                                    ::core::mem::forget(vec16);
+                                                    *base.add(4).cast::<usize>() = len16;
+                                                    *base.add(0).cast::<*mut u8>() = ptr16.cast_mut();
+                                                    *base.add(8).cast::<u8>() = (kind15.clone() as i32) as u8;
+                                                    let vec17 = (signature15.into_bytes()).into_boxed_slice();
+                                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                                    let len17 = vec17.len();
+                                                    ::core::mem::forget(vec17);
+                                                    *base.add(16).cast::<usize>() = len17;
+                                                    *base.add(12).cast::<*mut u8>() = ptr17.cast_mut();
+                                                    let vec21 = members15;
+                                                    let len21 = vec21.len();
+                                                    let layout21 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                        vec21.len() * 20,
+                                                        4,
+                                                    );
+                                                    let result21 = if layout21.size() != 0 {
+                                                        let ptr = _rt::alloc::alloc(layout21).cast::<u8>();
+                                                        if ptr.is_null() {
+                                                            _rt::alloc::handle_alloc_error(layout21);
+                                                        }
+                                                        ptr
+                                                    } else {
+                                                        { ::core::ptr::null_mut() }
+                                                    };
+                                                    for (i, e) in vec21.into_iter().enumerate() {
+                                                        let base = result21.add(i * 20);
+                                                        {
+                                                            let Member {
+                                                                name: name18,
+                                                                kind: kind18,
+                                                                signature: signature18,
+                                                            } = e;
+                                                            let vec19 = (name18.into_bytes()).into_boxed_slice();
+                                                            let ptr19 = vec19.as_ptr().cast::<u8>();
+                                                            let len19 = vec19.len();
+                                                            ::core::mem::forget(vec19);
+                                                            *base.add(4).cast::<usize>() = len19;
+                                                            *base.add(0).cast::<*mut u8>() = ptr19.cast_mut();
+                                                            *base.add(8).cast::<u8>() = (kind18.clone() as i32) as u8;
+                                                            let vec20 = (signature18.into_bytes()).into_boxed_slice();
+                                                            let ptr20 = vec20.as_ptr().cast::<u8>();
+                                                            let len20 = vec20.len();
+                                                            ::core::mem::forget(vec20);
+                                                            *base.add(16).cast::<usize>() = len20;
+                                                            *base.add(12).cast::<*mut u8>() = ptr20.cast_mut();
+                                                        }
+                                                    }
+                                                    *base.add(24).cast::<usize>() = len21;
+                                                    *base.add(20).cast::<*mut u8>() = result21;
+                                                }
+                                            }
+                                            *base.add(56).cast::<usize>() = len22;
+                                            *base.add(52).cast::<*mut u8>() = result22;
+                                            let vec23 = (wit2.into_bytes()).into_boxed_slice();
+                                            let ptr23 = vec23.as_ptr().cast::<u8>();
+                                            let len23 = vec23.len();
+                                            ::core::mem::forget(vec23);
+                                            *base.add(64).cast::<usize>() = len23;
+                                            *base.add(60).cast::<*mut u8>() = ptr23.cast_mut();
+                                            let ComponentMetadata {
+                                                name: name24,
+                                                producers: producers24,
+                                                authors: authors24,
+                                                description: description24,
+                                                licenses: licenses24,
+                                                source: source24,
+                                                homepage: homepage24,
+                                                revision: revision24,
+                                                version: version24,
+                                            } = metadata2;
+                                            match name24 {
+                                                Some(e) => {
+                                                    *base.add(68).cast::<u8>() = (1i32) as u8;
+                                                    let vec25 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr25 = vec25.as_ptr().cast::<u8>();
+                                                    let len25 = vec25.len();
+                                                    ::core::mem::forget(vec25);
+                                                    *base.add(76).cast::<usize>() = len25;
+                                                    *base.add(72).cast::<*mut u8>() = ptr25.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(68).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            let vec30 = producers24;
+                                            let len30 = vec30.len();
+                                            let layout30 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec30.len() * 24,
+                                                4,
+                                            );
+                                            let result30 = if layout30.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout30).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout30);
+                                                }
+                                                ptr
+                                            } else {
+                                                { ::core::ptr::null_mut() }
+                                            };
+                                            for (i, e) in vec30.into_iter().enumerate() {
+                                                let base = result30.add(i * 24);
+                                                {
+                                                    let Producer {
+                                                        field: field26,
+                                                        name: name26,
+                                                        version: version26,
+                                                    } = e;
+                                                    let vec27 = (field26.into_bytes()).into_boxed_slice();
+                                                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                                                    let len27 = vec27.len();
+                                                    ::core::mem::forget(vec27);
+                                                    *base.add(4).cast::<usize>() = len27;
+                                                    *base.add(0).cast::<*mut u8>() = ptr27.cast_mut();
+                                                    let vec28 = (name26.into_bytes()).into_boxed_slice();
+                                                    let ptr28 = vec28.as_ptr().cast::<u8>();
+                                                    let len28 = vec28.len();
+                                                    ::core::mem::forget(vec28);
+                                                    *base.add(12).cast::<usize>() = len28;
+                                                    *base.add(8).cast::<*mut u8>() = ptr28.cast_mut();
+                                                    let vec29 = (version26.into_bytes()).into_boxed_slice();
+                                                    let ptr29 = vec29.as_ptr().cast::<u8>();
+                                                    let len29 = vec29.len();
+                                                    ::core::mem::forget(vec29);
+                                                    *base.add(20).cast::<usize>() = len29;
+                                                    *base.add(16).cast::<*mut u8>() = ptr29.cast_mut();
+                                                }
+                                            }
+                                            *base.add(84).cast::<usize>() = len30;
+                                            *base.add(80).cast::<*mut u8>() = result30;
+                                            match authors24 {
+                                                Some(e) => {
+                                                    *base.add(88).cast::<u8>() = (1i32) as u8;
+                                                    let vec31 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr31 = vec31.as_ptr().cast::<u8>();
+                                                    let len31 = vec31.len();
+                                                    ::core::mem::forget(vec31);
+                                                    *base.add(96).cast::<usize>() = len31;
+                                                    *base.add(92).cast::<*mut u8>() = ptr31.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(88).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match description24 {
+                                                Some(e) => {
+                                                    *base.add(100).cast::<u8>() = (1i32) as u8;
+                                                    let vec32 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr32 = vec32.as_ptr().cast::<u8>();
+                                                    let len32 = vec32.len();
+                                                    ::core::mem::forget(vec32);
+                                                    *base.add(108).cast::<usize>() = len32;
+                                                    *base.add(104).cast::<*mut u8>() = ptr32.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(100).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match licenses24 {
+                                                Some(e) => {
+                                                    *base.add(112).cast::<u8>() = (1i32) as u8;
+                                                    let vec33 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr33 = vec33.as_ptr().cast::<u8>();
+                                                    let len33 = vec33.len();
+                                                    ::core::mem::forget(vec33);
+                                                    *base.add(120).cast::<usize>() = len33;
+                                                    *base.add(116).cast::<*mut u8>() = ptr33.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(112).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match source24 {
+                                                Some(e) => {
+                                                    *base.add(124).cast::<u8>() = (1i32) as u8;
+                                                    let vec34 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr34 = vec34.as_ptr().cast::<u8>();
+                                                    let len34 = vec34.len();
+                                                    ::core::mem::forget(vec34);
+                                                    *base.add(132).cast::<usize>() = len34;
+                                                    *base.add(128).cast::<*mut u8>() = ptr34.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(124).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match homepage24 {
+                                                Some(e) => {
+                                                    *base.add(136).cast::<u8>() = (1i32) as u8;
+                                                    let vec35 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr35 = vec35.as_ptr().cast::<u8>();
+                                                    let len35 = vec35.len();
+                                                    ::core::mem::forget(vec35);
+                                                    *base.add(144).cast::<usize>() = len35;
+                                                    *base.add(140).cast::<*mut u8>() = ptr35.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(136).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match revision24 {
+                                                Some(e) => {
+                                                    *base.add(148).cast::<u8>() = (1i32) as u8;
+                                                    let vec36 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr36 = vec36.as_ptr().cast::<u8>();
+                                                    let len36 = vec36.len();
+                                                    ::core::mem::forget(vec36);
+                                                    *base.add(156).cast::<usize>() = len36;
+                                                    *base.add(152).cast::<*mut u8>() = ptr36.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(148).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match version24 {
+                                                Some(e) => {
+                                                    *base.add(160).cast::<u8>() = (1i32) as u8;
+                                                    let vec37 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr37 = vec37.as_ptr().cast::<u8>();
+                                                    let len37 = vec37.len();
+                                                    ::core::mem::forget(vec37);
+                                                    *base.add(168).cast::<usize>() = len37;
+                                                    *base.add(164).cast::<*mut u8>() = ptr37.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(160).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                        Change::RemoveComponent(e) => {
+                                            *base.add(0).cast::<u8>() = (1i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        Change::AddInstance(e) => {
+                                            *base.add(0).cast::<u8>() = (2i32) as u8;
+                                            let Instance {
+                                                id: id38,
+                                                component: component38,
+                                                name: name38,
+                                            } = e;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(id38);
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(component38);
+                                            match name38 {
+                                                Some(e) => {
+                                                    *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                    let vec39 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr39 = vec39.as_ptr().cast::<u8>();
+                                                    let len39 = vec39.len();
+                                                    ::core::mem::forget(vec39);
+                                                    *base.add(20).cast::<usize>() = len39;
+                                                    *base.add(16).cast::<*mut u8>() = ptr39.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                        Change::RemoveInstance(e) => {
+                                            *base.add(0).cast::<u8>() = (3i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        Change::RenameInstance(e) => {
+                                            *base.add(0).cast::<u8>() = (4i32) as u8;
+                                            let Instance {
+                                                id: id40,
+                                                component: component40,
+                                                name: name40,
+                                            } = e;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(id40);
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(component40);
+                                            match name40 {
+                                                Some(e) => {
+                                                    *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                    let vec41 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr41 = vec41.as_ptr().cast::<u8>();
+                                                    let len41 = vec41.len();
+                                                    ::core::mem::forget(vec41);
+                                                    *base.add(20).cast::<usize>() = len41;
+                                                    *base.add(16).cast::<*mut u8>() = ptr41.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                        Change::Connect(e) => {
+                                            *base.add(0).cast::<u8>() = (5i32) as u8;
+                                            let Connection {
+                                                source: source42,
+                                                source_export: source_export42,
+                                                target: target42,
+                                                target_import: target_import42,
+                                            } = e;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(source42);
+                                            match source_export42 {
+                                                Some(e) => {
+                                                    *base.add(8).cast::<u8>() = (1i32) as u8;
+                                                    *base.add(12).cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                None => {
+                                                    *base.add(8).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            *base.add(16).cast::<i32>() = _rt::as_i32(target42);
+                                            *base.add(20).cast::<i32>() = _rt::as_i32(target_import42);
+                                        }
+                                        Change::Disconnect(e) => {
+                                            *base.add(0).cast::<u8>() = (6i32) as u8;
+                                            let Connection {
+                                                source: source43,
+                                                source_export: source_export43,
+                                                target: target43,
+                                                target_import: target_import43,
+                                            } = e;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(source43);
+                                            match source_export43 {
+                                                Some(e) => {
+                                                    *base.add(8).cast::<u8>() = (1i32) as u8;
+                                                    *base.add(12).cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                None => {
+                                                    *base.add(8).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            *base.add(16).cast::<i32>() = _rt::as_i32(target43);
+                                            *base.add(20).cast::<i32>() = _rt::as_i32(target_import43);
+                                        }
+                                    }
+                                }
+                            }
+                            *ptr1.add(12).cast::<usize>() = len44;
+                            *ptr1.add(8).cast::<*mut u8>() = result44;
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message45, offset: offset45 } = e;
+                                    let vec46 = (message45.into_bytes()).into_boxed_slice();
+                                    let ptr46 = vec46.as_ptr().cast::<u8>();
+                                    let len46 = vec46.len();
+                                    ::core::mem::forget(vec46);
+                                    *ptr1.add(20).cast::<usize>() = len46;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr46.cast_mut();
+                                    match offset45 {
+                                        Some(e) => {
+                                            *ptr1.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message47, offset: offset47 } = e;
+                                    let vec48 = (message47.into_bytes()).into_boxed_slice();
+                                    let ptr48 = vec48.as_ptr().cast::<u8>();
+                                    let len48 = vec48.len();
+                                    ::core::mem::forget(vec48);
+                                    *ptr1.add(20).cast::<usize>() = len48;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr48.cast_mut();
+                                    match offset47 {
+                                        Some(e) => {
+                                            *ptr1.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export49,
+                                        import: import49,
+                                        message: message49,
+                                    } = e;
+                                    match export49 {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec50 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr50 = vec50.as_ptr().cast::<u8>();
+                                            let len50 = vec50.len();
+                                            ::core::mem::forget(vec50);
+                                            *ptr1.add(24).cast::<usize>() = len50;
+                                            *ptr1.add(20).cast::<*mut u8>() = ptr50.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec51 = (import49.into_bytes()).into_boxed_slice();
+                                    let ptr51 = vec51.as_ptr().cast::<u8>();
+                                    let len51 = vec51.len();
+                                    ::core::mem::forget(vec51);
+                                    *ptr1.add(32).cast::<usize>() = len51;
+                                    *ptr1.add(28).cast::<*mut u8>() = ptr51.cast_mut();
+                                    let vec52 = (message49.into_bytes()).into_boxed_slice();
+                                    let ptr52 = vec52.as_ptr().cast::<u8>();
+                                    let len52 = vec52.len();
+                                    ::core::mem::forget(vec52);
+                                    *ptr1.add(40).cast::<usize>() = len52;
+                                    *ptr1.add(36).cast::<*mut u8>() = ptr52.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances53,
+                                        message: message53,
+                                    } = e;
+                                    let vec54 = (instances53).into_boxed_slice();
+                                    let ptr54 = vec54.as_ptr().cast::<u8>();
+                                    let len54 = vec54.len();
+                                    ::core::mem::forget(vec54);
+                                    *ptr1.add(20).cast::<usize>() = len54;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr54.cast_mut();
+                                    let vec55 = (message53.into_bytes()).into_boxed_slice();
+                                    let ptr55 = vec55.as_ptr().cast::<u8>();
+                                    let len55 = vec55.len();
+                                    ::core::mem::forget(vec55);
+                                    *ptr1.add(28).cast::<usize>() = len55;
+                                    *ptr1.add(24).cast::<*mut u8>() = ptr55.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec56 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr56 = vec56.as_ptr().cast::<u8>();
+                                    let len56 = vec56.len();
+                                    ::core::mem::forget(vec56);
+                                    *ptr1.add(20).cast::<usize>() = len56;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr56.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec57 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr57 = vec57.as_ptr().cast::<u8>();
+                                    let len57 = vec57.len();
+                                    ::core::mem::forget(vec57);
+                                    *ptr1.add(20).cast::<usize>() = len57;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr57.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec58 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr58 = vec58.as_ptr().cast::<u8>();
+                                    let len58 = vec58.len();
+                                    ::core::mem::forget(vec58);
+                                    *ptr1.add(20).cast::<usize>() = len58;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr58.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message59,
+                                        line: line59,
+                                        column: column59,
+                                    } = e;
+                                    let vec60 = (message59.into_bytes()).into_boxed_slice();
+                                    let ptr60 = vec60.as_ptr().cast::<u8>();
+                                    let len60 = vec60.len();
+                                    ::core::mem::forget(vec60);
+                                    *ptr1.add(20).cast::<usize>() = len60;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr60.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(line59);
+                                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(column59);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec61 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr61 = vec61.as_ptr().cast::<u8>();
+                                    let len61 = vec61.len();
+                                    ::core::mem::forget(vec61);
+                                    *ptr1.add(20).cast::<usize>() = len61;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr61.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_redo<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            let base82 = l1;
+                            let len82 = l2;
+                            for i in 0..len82 {
+                                let base = base82.add(i * 172);
+                                {
+                                    let l3 = i32::from(*base.add(0).cast::<u8>());
+                                    match l3 {
+                                        0 => {
+                                            let l4 = *base.add(8).cast::<*mut u8>();
+                                            let l5 = *base.add(12).cast::<usize>();
+                                            _rt::cabi_dealloc(l4, l5, 1);
+                                            let l6 = *base.add(16).cast::<*mut u8>();
+                                            let l7 = *base.add(20).cast::<usize>();
+                                            _rt::cabi_dealloc(l6, l7, 1);
+                                            let l8 = i32::from(*base.add(24).cast::<u8>());
+                                            match l8 {
+                                                0 => {}
+                                                _ => {
+                                                    let l9 = *base.add(28).cast::<*mut u8>();
+                                                    let l10 = *base.add(32).cast::<usize>();
+                                                    _rt::cabi_dealloc(l9, l10, 1);
+                                                }
+                                            }
+                                            let l11 = *base.add(36).cast::<*mut u8>();
+                                            let l12 = *base.add(40).cast::<usize>();
+                                            _rt::cabi_dealloc(l11, l12, 1);
+                                            let l13 = *base.add(44).cast::<*mut u8>();
+                                            let l14 = *base.add(48).cast::<usize>();
+                                            let base26 = l13;
+                                            let len26 = l14;
+                                            for i in 0..len26 {
+                                                let base = base26.add(i * 28);
+                                                {
+                                                    let l15 = *base.add(0).cast::<*mut u8>();
+                                                    let l16 = *base.add(4).cast::<usize>();
+                                                    _rt::cabi_dealloc(l15, l16, 1);
+                                                    let l17 = *base.add(12).cast::<*mut u8>();
+                                                    let l18 = *base.add(16).cast::<usize>();
+                                                    _rt::cabi_dealloc(l17, l18, 1);
+                                                    let l19 = *base.add(20).cast::<*mut u8>();
+                                                    let l20 = *base.add(24).cast::<usize>();
+                                                    let base25 = l19;
+                                                    let len25 = l20;
+                                                    for i in 0..len25 {
+                                                        let base = base25.add(i * 20);
+                                                        {
+                                                            let l21 = *base.add(0).cast::<*mut u8>();
+                                                            let l22 = *base.add(4).cast::<usize>();
+                                                            _rt::cabi_dealloc(l21, l22, 1);
+                                                            let l23 = *base.add(12).cast::<*mut u8>();
+                                                            let l24 = *base.add(16).cast::<usize>();
+                                                            _rt::cabi_dealloc(l23, l24, 1);
+                                                        }
+                                                    }
+                                                    _rt::cabi_dealloc(base25, len25 * 20, 4);
+                                                }
+                                            }
+                                            _rt::cabi_dealloc(base26, len26 * 28, 4);
+                                            let l27 = *base.add(52).cast::<*mut u8>();
+                                            let l28 = *base.add(56).cast::<usize>();
+                                            let base40 = l27;
+                                            let len40 = l28;
+                                            for i in 0..len40 {
+                                                let base = base40.add(i * 28);
+                                                {
+                                                    let l29 = *base.add(0).cast::<*mut u8>();
+                                                    let l30 = *base.add(4).cast::<usize>();
+                                                    _rt::cabi_dealloc(l29, l30, 1);
+                                                    let l31 = *base.add(12).cast::<*mut u8>();
+                                                    let l32 = *base.add(16).cast::<usize>();
+                                                    _rt::cabi_dealloc(l31, l32, 1);
+                                                    let l33 = *base.add(20).cast::<*mut u8>();
+                                                    let l34 = *base.add(24).cast::<usize>();
+                                                    let base39 = l33;
+                                                    let len39 = l34;
+                                                    for i in 0..len39 {
+                                                        let base = base39.add(i * 20);
+                                                        {
+                                                            let l35 = *base.add(0).cast::<*mut u8>();
+                                                            let l36 = *base.add(4).cast::<usize>();
+                                                            _rt::cabi_dealloc(l35, l36, 1);
+                                                            let l37 = *base.add(12).cast::<*mut u8>();
+                                                            let l38 = *base.add(16).cast::<usize>();
+                                                            _rt::cabi_dealloc(l37, l38, 1);
+                                                        }
+                                                    }
+                                                    _rt::cabi_dealloc(base39, len39 * 20, 4);
+                                                }
+                                            }
+                                            _rt::cabi_dealloc(base40, len40 * 28, 4);
+                                            let l41 = *base.add(60).cast::<*mut u8>();
+                                            let l42 = *base.add(64).cast::<usize>();
+                                            _rt::cabi_dealloc(l41, l42, 1);
+                                            let l43 = i32::from(*base.add(68).cast::<u8>());
+                                            match l43 {
+                                                0 => {}
+                                                _ => {
+                                                    let l44 = *base.add(72).cast::<*mut u8>();
+                                                    let l45 = *base.add(76).cast::<usize>();
+                                                    _rt::cabi_dealloc(l44, l45, 1);
+                                                }
+                                            }
+                                            let l46 = *base.add(80).cast::<*mut u8>();
+                                            let l47 = *base.add(84).cast::<usize>();
+                                            let base54 = l46;
+                                            let len54 = l47;
+                                            for i in 0..len54 {
+                                                let base = base54.add(i * 24);
+                                                {
+                                                    let l48 = *base.add(0).cast::<*mut u8>();
+                                                    let l49 = *base.add(4).cast::<usize>();
+                                                    _rt::cabi_dealloc(l48, l49, 1);
+                                                    let l50 = *base.add(8).cast::<*mut u8>();
+                                                    let l51 = *base.add(12).cast::<usize>();
+                                                    _rt::cabi_dealloc(l50, l51, 1);
+                                                    let l52 = *base.add(16).cast::<*mut u8>();
+                                                    let l53 = *base.add(20).cast::<usize>();
+                                                    _rt::cabi_dealloc(l52, l53, 1);
+                                                }
+                                            }
+                                            _rt::cabi_dealloc(base54, len54 * 24, 4);
+                                            let l55 = i32::from(*base.add(88).cast::<u8>());
+                                            match l55 {
+                                                0 => {}
+                                                _ => {
+                                                    let l56 = *base.add(92).cast::<*mut u8>();
+                                                    let l57 = *base.add(96).cast::<usize>();
+                                                    _rt::cabi_dealloc(l56, l57, 1);
+                                                }
+                                            }
+                                            let l58 = i32::from(*base.add(100).cast::<u8>());
+                                            match l58 {
+                                                0 => {}
+                                                _ => {
+                                                    let l59 = *base.add(104).cast::<*mut u8>();
+                                                    let l60 = *base.add(108).cast::<usize>();
+                                                    _rt::cabi_dealloc(l59, l60, 1);
+                                                }
+                                            }
+                                            let l61 = i32::from(*base.add(112).cast::<u8>());
+                                            match l61 {
+                                                0 => {}
+                                                _ => {
+                                                    let l62 = *base.add(116).cast::<*mut u8>();
+                                                    let l63 = *base.add(120).cast::<usize>();
+                                                    _rt::cabi_dealloc(l62, l63, 1);
+                                                }
+                                            }
+                                            let l64 = i32::from(*base.add(124).cast::<u8>());
+                                            match l64 {
+                                                0 => {}
+                                                _ => {
+                                                    let l65 = *base.add(128).cast::<*mut u8>();
+                                                    let l66 = *base.add(132).cast::<usize>();
+                                                    _rt::cabi_dealloc(l65, l66, 1);
+                                                }
+                                            }
+                                            let l67 = i32::from(*base.add(136).cast::<u8>());
+                                            match l67 {
+                                                0 => {}
+                                                _ => {
+                                                    let l68 = *base.add(140).cast::<*mut u8>();
+                                                    let l69 = *base.add(144).cast::<usize>();
+                                                    _rt::cabi_dealloc(l68, l69, 1);
+                                                }
+                                            }
+                                            let l70 = i32::from(*base.add(148).cast::<u8>());
+                                            match l70 {
+                                                0 => {}
+                                                _ => {
+                                                    let l71 = *base.add(152).cast::<*mut u8>();
+                                                    let l72 = *base.add(156).cast::<usize>();
+                                                    _rt::cabi_dealloc(l71, l72, 1);
+                                                }
+                                            }
+                                            let l73 = i32::from(*base.add(160).cast::<u8>());
+                                            match l73 {
+                                                0 => {}
+                                                _ => {
+                                                    let l74 = *base.add(164).cast::<*mut u8>();
+                                                    let l75 = *base.add(168).cast::<usize>();
+                                                    _rt::cabi_dealloc(l74, l75, 1);
+                                                }
+                                            }
+                                        }
+                                        1 => {}
+                                        2 => {
+                                            let l76 = i32::from(*base.add(12).cast::<u8>());
+                                            match l76 {
+                                                0 => {}
+                                                _ => {
+                                                    let l77 = *base.add(16).cast::<*mut u8>();
+                                                    let l78 = *base.add(20).cast::<usize>();
+                                                    _rt::cabi_dealloc(l77, l78, 1);
+                                                }
+                                            }
+                                        }
+                                        3 => {}
+                                        4 => {
+                                            let l79 = i32::from(*base.add(12).cast::<u8>());
+                                            match l79 {
+                                                0 => {}
+                                                _ => {
+                                                    let l80 = *base.add(16).cast::<*mut u8>();
+                                                    let l81 = *base.add(20).cast::<usize>();
+                                                    _rt::cabi_dealloc(l80, l81, 1);
+                                                }
+                                            }
+                                        }
+                                        5 => {}
+                                        _ => {}
+                                    }
+                                }
+                            }
+                            _rt::cabi_dealloc(base82, len82 * 172, 4);
+                        }
+                        _ => {
+                            let l83 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l83 {
+                                0 => {
+                                    let l84 = *arg0.add(16).cast::<*mut u8>();
+                                    let l85 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l84, l85, 1);
+                                }
+                                1 => {
+                                    let l86 = *arg0.add(16).cast::<*mut u8>();
+                                    let l87 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l86, l87, 1);
+                                }
+                                2 => {
+                                    let l88 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l88 {
+                                        0 => {}
+                                        _ => {
+                                            let l89 = *arg0.add(20).cast::<*mut u8>();
+                                            let l90 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l89, l90, 1);
+                                        }
+                                    }
+                                    let l91 = *arg0.add(28).cast::<*mut u8>();
+                                    let l92 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l91, l92, 1);
+                                    let l93 = *arg0.add(36).cast::<*mut u8>();
+                                    let l94 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l93, l94, 1);
+                                }
+                                3 => {
+                                    let l95 = *arg0.add(16).cast::<*mut u8>();
+                                    let l96 = *arg0.add(20).cast::<usize>();
+                                    let base97 = l95;
+                                    let len97 = l96;
+                                    _rt::cabi_dealloc(base97, len97 * 4, 4);
+                                    let l98 = *arg0.add(24).cast::<*mut u8>();
+                                    let l99 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l98, l99, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l100 = *arg0.add(16).cast::<*mut u8>();
+                                    let l101 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l100, l101, 1);
+                                }
+                                7 => {
+                                    let l102 = *arg0.add(16).cast::<*mut u8>();
+                                    let l103 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l102, l103, 1);
+                                }
+                                8 => {
+                                    let l104 = *arg0.add(16).cast::<*mut u8>();
+                                    let l105 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l104, l105, 1);
+                                }
+                                9 => {
+                                    let l106 = *arg0.add(16).cast::<*mut u8>();
+                                    let l107 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l106, l107, 1);
+                                }
+                                _ => {
+                                    let l108 = *arg0.add(16).cast::<*mut u8>();
+                                    let l109 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l108, l109, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_can_undo_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::can_undo(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                    );
+                    match result0 {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_can_redo_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::can_redo(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                    );
+                    match result0 {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_print_graph_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::print_graph(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        GraphFormat::_lift(arg1 as u8),
+                    );
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec2 = (result0.into_bytes()).into_boxed_slice();
+                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                    let len2 = vec2.len();
+                    ::core::mem::forget(vec2);
+                    *ptr1.add(4).cast::<usize>() = len2;
+                    *ptr1.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_print_graph<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l0, l1, 1);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_analyze_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: *mut u8,
+                    arg5: usize,
+                    arg6: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base6 = arg4;
+                    let len6 = arg5;
+                    let mut result6 = _rt::Vec::with_capacity(len6);
+                    for i in 0..len6 {
+                        let base = base6.add(i * 20);
+                        let e6 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = i32::from(*base.add(4).cast::<u8>());
+                            let l3 = *base.add(12).cast::<*mut u8>();
+                            let l4 = *base.add(16).cast::<usize>();
+                            let len5 = l4;
+                            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+                            ExportItem {
+                                instance: l0 as u32,
+                                export: match l1 {
+                                    0 => None,
+                                    1 => {
+                                        let e = {
+                                            let l2 = *base.add(8).cast::<i32>();
+                                            l2 as u32
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                name: _rt::string_lift(bytes5),
+                            }
+                        };
+                        result6.push(e6);
+                    }
+                    _rt::cabi_dealloc(base6, len6 * 20, 4);
+                    let result7 = T::analyze(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        EncodeOptions {
+                            define_components: _rt::bool_lift(arg1 as u8),
+                            export: match arg2 {
+                                0 => None,
+                                1 => {
+                                    let e = arg3 as u32;
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            exports: result6,
+                            validate: _rt::bool_lift(arg6 as u8),
+                        },
+                    );
+                    let ptr8 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result7 {
+                        Ok(e) => {
+                            *ptr8.add(0).cast::<u8>() = (0i32) as u8;
+                            let Analysis {
+                                imports: imports9,
+                                unreachable: unreachable9,
+                            } = e;
+                            let vec15 = imports9;
+                            let len15 = vec15.len();
+                            let layout15 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec15.len() * 12,
+                                4,
+                            );
+                            let result15 = if layout15.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout15).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout15);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec15.into_iter().enumerate() {
+                                let base = result15.add(i * 12);
+                                {
+                                    let InstanceImports {
+                                        instance: instance10,
+                                        imports: imports10,
+                                    } = e;
+                                    *base.add(0).cast::<i32>() = _rt::as_i32(instance10);
+                                    let vec14 = imports10;
+                                    let len14 = vec14.len();
+                                    let layout14 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec14.len() * 20,
+                                        4,
+                                    );
+                                    let result14 = if layout14.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout14).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout14);
+                                        }
+                                        ptr
+                                    } else {
+                                        { ::core::ptr::null_mut() }
+                                    };
+                                    for (i, e) in vec14.into_iter().enumerate() {
+                                        let base = result14.add(i * 20);
+                                        {
+                                            let UnconnectedImport {
+                                                import: import11,
+                                                name: name11,
+                                                merged_with: merged_with11,
+                                            } = e;
+                                            *base.add(0).cast::<i32>() = _rt::as_i32(import11);
+                                            let vec12 = (name11.into_bytes()).into_boxed_slice();
+                                            let ptr12 = vec12.as_ptr().cast::<u8>();
+                                            let len12 = vec12.len();
+                                            ::core::mem::forget(vec12);
+                                            *base.add(8).cast::<usize>() = len12;
+                                            *base.add(4).cast::<*mut u8>() = ptr12.cast_mut();
+                                            let vec13 = (merged_with11).into_boxed_slice();
+                                            let ptr13 = vec13.as_ptr().cast::<u8>();
+                                            let len13 = vec13.len();
+                                            ::core::mem::forget(vec13);
+                                            *base.add(16).cast::<usize>() = len13;
+                                            *base.add(12).cast::<*mut u8>() = ptr13.cast_mut();
+                                        }
+                                    }
+                                    *base.add(8).cast::<usize>() = len14;
+                                    *base.add(4).cast::<*mut u8>() = result14;
+                                }
+                            }
+                            *ptr8.add(12).cast::<usize>() = len15;
+                            *ptr8.add(8).cast::<*mut u8>() = result15;
+                            let vec16 = (unreachable9).into_boxed_slice();
+                            let ptr16 = vec16.as_ptr().cast::<u8>();
+                            let len16 = vec16.len();
+                            ::core::mem::forget(vec16);
+                            *ptr8.add(20).cast::<usize>() = len16;
+                            *ptr8.add(16).cast::<*mut u8>() = ptr16.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr8.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message17, offset: offset17 } = e;
+                                    let vec18 = (message17.into_bytes()).into_boxed_slice();
+                                    let ptr18 = vec18.as_ptr().cast::<u8>();
+                                    let len18 = vec18.len();
+                                    ::core::mem::forget(vec18);
+                                    *ptr8.add(20).cast::<usize>() = len18;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr18.cast_mut();
+                                    match offset17 {
+                                        Some(e) => {
+                                            *ptr8.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr8.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr8.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message19, offset: offset19 } = e;
+                                    let vec20 = (message19.into_bytes()).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *ptr8.add(20).cast::<usize>() = len20;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr20.cast_mut();
+                                    match offset19 {
+                                        Some(e) => {
+                                            *ptr8.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr8.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr8.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export21,
+                                        import: import21,
+                                        message: message21,
+                                    } = e;
+                                    match export21 {
+                                        Some(e) => {
+                                            *ptr8.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec22 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr22 = vec22.as_ptr().cast::<u8>();
+                                            let len22 = vec22.len();
+                                            ::core::mem::forget(vec22);
+                                            *ptr8.add(24).cast::<usize>() = len22;
+                                            *ptr8.add(20).cast::<*mut u8>() = ptr22.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr8.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec23 = (import21.into_bytes()).into_boxed_slice();
+                                    let ptr23 = vec23.as_ptr().cast::<u8>();
+                                    let len23 = vec23.len();
+                                    ::core::mem::forget(vec23);
+                                    *ptr8.add(32).cast::<usize>() = len23;
+                                    *ptr8.add(28).cast::<*mut u8>() = ptr23.cast_mut();
+                                    let vec24 = (message21.into_bytes()).into_boxed_slice();
+                                    let ptr24 = vec24.as_ptr().cast::<u8>();
+                                    let len24 = vec24.len();
+                                    ::core::mem::forget(vec24);
+                                    *ptr8.add(40).cast::<usize>() = len24;
+                                    *ptr8.add(36).cast::<*mut u8>() = ptr24.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances25,
+                                        message: message25,
+                                    } = e;
+                                    let vec26 = (instances25).into_boxed_slice();
+                                    let ptr26 = vec26.as_ptr().cast::<u8>();
+                                    let len26 = vec26.len();
+                                    ::core::mem::forget(vec26);
+                                    *ptr8.add(20).cast::<usize>() = len26;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr26.cast_mut();
+                                    let vec27 = (message25.into_bytes()).into_boxed_slice();
+                                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                                    let len27 = vec27.len();
+                                    ::core::mem::forget(vec27);
+                                    *ptr8.add(28).cast::<usize>() = len27;
+                                    *ptr8.add(24).cast::<*mut u8>() = ptr27.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr8.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr8.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec28 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr28 = vec28.as_ptr().cast::<u8>();
+                                    let len28 = vec28.len();
+                                    ::core::mem::forget(vec28);
+                                    *ptr8.add(20).cast::<usize>() = len28;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr28.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec29 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr29 = vec29.as_ptr().cast::<u8>();
+                                    let len29 = vec29.len();
+                                    ::core::mem::forget(vec29);
+                                    *ptr8.add(20).cast::<usize>() = len29;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr29.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec30 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr30 = vec30.as_ptr().cast::<u8>();
+                                    let len30 = vec30.len();
+                                    ::core::mem::forget(vec30);
+                                    *ptr8.add(20).cast::<usize>() = len30;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr30.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message31,
+                                        line: line31,
+                                        column: column31,
+                                    } = e;
+                                    let vec32 = (message31.into_bytes()).into_boxed_slice();
+                                    let ptr32 = vec32.as_ptr().cast::<u8>();
+                                    let len32 = vec32.len();
+                                    ::core::mem::forget(vec32);
+                                    *ptr8.add(20).cast::<usize>() = len32;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr32.cast_mut();
+                                    *ptr8.add(24).cast::<i32>() = _rt::as_i32(line31);
+                                    *ptr8.add(28).cast::<i32>() = _rt::as_i32(column31);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec33 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr33 = vec33.as_ptr().cast::<u8>();
+                                    let len33 = vec33.len();
+                                    ::core::mem::forget(vec33);
+                                    *ptr8.add(20).cast::<usize>() = len33;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr33.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr8
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_analyze<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            let base11 = l1;
+                            let len11 = l2;
+                            for i in 0..len11 {
+                                let base = base11.add(i * 12);
+                                {
+                                    let l3 = *base.add(4).cast::<*mut u8>();
+                                    let l4 = *base.add(8).cast::<usize>();
+                                    let base10 = l3;
+                                    let len10 = l4;
+                                    for i in 0..len10 {
+                                        let base = base10.add(i * 20);
+                                        {
+                                            let l5 = *base.add(4).cast::<*mut u8>();
+                                            let l6 = *base.add(8).cast::<usize>();
+                                            _rt::cabi_dealloc(l5, l6, 1);
+                                            let l7 = *base.add(12).cast::<*mut u8>();
+                                            let l8 = *base.add(16).cast::<usize>();
+                                            let base9 = l7;
+                                            let len9 = l8;
+                                            _rt::cabi_dealloc(base9, len9 * 4, 4);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base10, len10 * 20, 4);
+                                }
+                            }
+                            _rt::cabi_dealloc(base11, len11 * 12, 4);
+                            let l12 = *arg0.add(16).cast::<*mut u8>();
+                            let l13 = *arg0.add(20).cast::<usize>();
+                            let base14 = l12;
+                            let len14 = l13;
+                            _rt::cabi_dealloc(base14, len14 * 4, 4);
+                        }
+                        _ => {
+                            let l15 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l15 {
+                                0 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l16, l17, 1);
+                                }
+                                1 => {
+                                    let l18 = *arg0.add(16).cast::<*mut u8>();
+                                    let l19 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l18, l19, 1);
+                                }
+                                2 => {
+                                    let l20 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l20 {
+                                        0 => {}
+                                        _ => {
+                                            let l21 = *arg0.add(20).cast::<*mut u8>();
+                                            let l22 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l21, l22, 1);
+                                        }
+                                    }
+                                    let l23 = *arg0.add(28).cast::<*mut u8>();
+                                    let l24 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l23, l24, 1);
+                                    let l25 = *arg0.add(36).cast::<*mut u8>();
+                                    let l26 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l25, l26, 1);
+                                }
+                                3 => {
+                                    let l27 = *arg0.add(16).cast::<*mut u8>();
+                                    let l28 = *arg0.add(20).cast::<usize>();
+                                    let base29 = l27;
+                                    let len29 = l28;
+                                    _rt::cabi_dealloc(base29, len29 * 4, 4);
+                                    let l30 = *arg0.add(24).cast::<*mut u8>();
+                                    let l31 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l30, l31, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l32 = *arg0.add(16).cast::<*mut u8>();
+                                    let l33 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l32, l33, 1);
+                                }
+                                7 => {
+                                    let l34 = *arg0.add(16).cast::<*mut u8>();
+                                    let l35 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l34, l35, 1);
+                                }
+                                8 => {
+                                    let l36 = *arg0.add(16).cast::<*mut u8>();
+                                    let l37 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l36, l37, 1);
+                                }
+                                9 => {
+                                    let l38 = *arg0.add(16).cast::<*mut u8>();
+                                    let l39 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l38, l39, 1);
+                                }
+                                _ => {
+                                    let l40 = *arg0.add(16).cast::<*mut u8>();
+                                    let l41 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l40, l41, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_audit_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::audit(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                    );
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let AuditReport { instances: instances2, markdown: markdown2 } = result0;
+                    let vec9 = instances2;
+                    let len9 = vec9.len();
+                    let layout9 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec9.len() * 20,
+                        4,
+                    );
+                    let result9 = if layout9.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout9).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout9);
+                        }
+                        ptr
+                    } else {
+                        { ::core::ptr::null_mut() }
+                    };
+                    for (i, e) in vec9.into_iter().enumerate() {
+                        let base = result9.add(i * 20);
+                        {
+                            let InstanceAudit {
+                                instance: instance3,
+                                dependencies: dependencies3,
+                                capabilities: capabilities3,
+                            } = e;
+                            *base.add(0).cast::<i32>() = _rt::as_i32(instance3);
+                            let vec4 = (dependencies3).into_boxed_slice();
+                            let ptr4 = vec4.as_ptr().cast::<u8>();
+                            let len4 = vec4.len();
+                            ::core::mem::forget(vec4);
+                            *base.add(8).cast::<usize>() = len4;
+                            *base.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+                            let vec8 = capabilities3;
+                            let len8 = vec8.len();
+                            let layout8 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec8.len() * 16,
+                                4,
+                            );
+                            let result8 = if layout8.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout8).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout8);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec8.into_iter().enumerate() {
+                                let base = result8.add(i * 16);
+                                {
+                                    let Capability { name: name5, imported_by: imported_by5 } = e;
+                                    let vec6 = (name5.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *base.add(4).cast::<usize>() = len6;
+                                    *base.add(0).cast::<*mut u8>() = ptr6.cast_mut();
+                                    let vec7 = (imported_by5).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *base.add(12).cast::<usize>() = len7;
+                                    *base.add(8).cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                            }
+                            *base.add(16).cast::<usize>() = len8;
+                            *base.add(12).cast::<*mut u8>() = result8;
+                        }
+                    }
+                    *ptr1.add(4).cast::<usize>() = len9;
+                    *ptr1.add(0).cast::<*mut u8>() = result9;
+                    let vec10 = (markdown2.into_bytes()).into_boxed_slice();
+                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                    let len10 = vec10.len();
+                    ::core::mem::forget(vec10);
+                    *ptr1.add(12).cast::<usize>() = len10;
+                    *ptr1.add(8).cast::<*mut u8>() = ptr10.cast_mut();
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_audit<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0.add(4).cast::<usize>();
+                    let base13 = l0;
+                    let len13 = l1;
+                    for i in 0..len13 {
+                        let base = base13.add(i * 20);
+                        {
+                            let l2 = *base.add(4).cast::<*mut u8>();
+                            let l3 = *base.add(8).cast::<usize>();
+                            let base4 = l2;
+                            let len4 = l3;
+                            _rt::cabi_dealloc(base4, len4 * 4, 4);
+                            let l5 = *base.add(12).cast::<*mut u8>();
+                            let l6 = *base.add(16).cast::<usize>();
+                            let base12 = l5;
+                            let len12 = l6;
+                            for i in 0..len12 {
+                                let base = base12.add(i * 16);
+                                {
+                                    let l7 = *base.add(0).cast::<*mut u8>();
+                                    let l8 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l7, l8, 1);
+                                    let l9 = *base.add(8).cast::<*mut u8>();
+                                    let l10 = *base.add(12).cast::<usize>();
+                                    let base11 = l9;
+                                    let len11 = l10;
+                                    _rt::cabi_dealloc(base11, len11 * 4, 4);
+                                }
+                            }
+                            _rt::cabi_dealloc(base12, len12 * 16, 4);
+                        }
+                    }
+                    _rt::cabi_dealloc(base13, len13 * 20, 4);
+                    let l14 = *arg0.add(8).cast::<*mut u8>();
+                    let l15 = *arg0.add(12).cast::<usize>();
+                    _rt::cabi_dealloc(l14, l15, 1);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_diff_components_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                    arg2: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::diff_components(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u32,
+                        arg2 as u32,
+                    );
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            let ComponentDiff {
+                                differences: differences2,
+                                broken_connections: broken_connections2,
+                                compatible: compatible2,
+                            } = e;
+                            let vec6 = differences2;
+                            let len6 = vec6.len();
+                            let layout6 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec6.len() * 24,
+                                4,
+                            );
+                            let result6 = if layout6.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout6).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout6);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec6.into_iter().enumerate() {
+                                let base = result6.add(i * 24);
+                                {
+                                    let Difference {
+                                        item: item3,
+                                        kind: kind3,
+                                        description: description3,
+                                        breaking: breaking3,
+                                    } = e;
+                                    let vec4 = (item3.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *base.add(4).cast::<usize>() = len4;
+                                    *base.add(0).cast::<*mut u8>() = ptr4.cast_mut();
+                                    *base.add(8).cast::<u8>() = (kind3.clone() as i32) as u8;
+                                    let vec5 = (description3.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *base.add(16).cast::<usize>() = len5;
+                                    *base.add(12).cast::<*mut u8>() = ptr5.cast_mut();
+                                    *base.add(20).cast::<u8>() = (match breaking3 {
+                                        true => 1,
+                                        false => 0,
+                                    }) as u8;
+                                }
+                            }
+                            *ptr1.add(12).cast::<usize>() = len6;
+                            *ptr1.add(8).cast::<*mut u8>() = result6;
+                            let vec10 = broken_connections2;
+                            let len10 = vec10.len();
+                            let layout10 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec10.len() * 28,
+                                4,
+                            );
+                            let result10 = if layout10.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout10).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout10);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec10.into_iter().enumerate() {
+                                let base = result10.add(i * 28);
+                                {
+                                    let BrokenConnection {
+                                        connection: connection7,
+                                        message: message7,
+                                    } = e;
+                                    let Connection {
+                                        source: source8,
+                                        source_export: source_export8,
+                                        target: target8,
+                                        target_import: target_import8,
+                                    } = connection7;
+                                    *base.add(0).cast::<i32>() = _rt::as_i32(source8);
+                                    match source_export8 {
+                                        Some(e) => {
+                                            *base.add(4).cast::<u8>() = (1i32) as u8;
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *base.add(4).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    *base.add(12).cast::<i32>() = _rt::as_i32(target8);
+                                    *base.add(16).cast::<i32>() = _rt::as_i32(target_import8);
+                                    let vec9 = (message7.into_bytes()).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *base.add(24).cast::<usize>() = len9;
+                                    *base.add(20).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                            }
+                            *ptr1.add(20).cast::<usize>() = len10;
+                            *ptr1.add(16).cast::<*mut u8>() = result10;
+                            *ptr1.add(24).cast::<u8>() = (match compatible2 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message11, offset: offset11 } = e;
+                                    let vec12 = (message11.into_bytes()).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *ptr1.add(20).cast::<usize>() = len12;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr12.cast_mut();
+                                    match offset11 {
+                                        Some(e) => {
+                                            *ptr1.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message13, offset: offset13 } = e;
+                                    let vec14 = (message13.into_bytes()).into_boxed_slice();
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    ::core::mem::forget(vec14);
+                                    *ptr1.add(20).cast::<usize>() = len14;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr14.cast_mut();
+                                    match offset13 {
+                                        Some(e) => {
+                                            *ptr1.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export15,
+                                        import: import15,
+                                        message: message15,
+                                    } = e;
+                                    match export15 {
+                                        Some(e) => {
+                                            *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec16 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr16 = vec16.as_ptr().cast::<u8>();
+                                            let len16 = vec16.len();
+                                            ::core::mem::forget(vec16);
+                                            *ptr1.add(24).cast::<usize>() = len16;
+                                            *ptr1.add(20).cast::<*mut u8>() = ptr16.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec17 = (import15.into_bytes()).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr1.add(32).cast::<usize>() = len17;
+                                    *ptr1.add(28).cast::<*mut u8>() = ptr17.cast_mut();
+                                    let vec18 = (message15.into_bytes()).into_boxed_slice();
+                                    let ptr18 = vec18.as_ptr().cast::<u8>();
+                                    let len18 = vec18.len();
+                                    ::core::mem::forget(vec18);
+                                    *ptr1.add(40).cast::<usize>() = len18;
+                                    *ptr1.add(36).cast::<*mut u8>() = ptr18.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances19,
+                                        message: message19,
+                                    } = e;
+                                    let vec20 = (instances19).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *ptr1.add(20).cast::<usize>() = len20;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr20.cast_mut();
+                                    let vec21 = (message19.into_bytes()).into_boxed_slice();
+                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                    let len21 = vec21.len();
+                                    ::core::mem::forget(vec21);
+                                    *ptr1.add(28).cast::<usize>() = len21;
+                                    *ptr1.add(24).cast::<*mut u8>() = ptr21.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec22 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr22 = vec22.as_ptr().cast::<u8>();
+                                    let len22 = vec22.len();
+                                    ::core::mem::forget(vec22);
+                                    *ptr1.add(20).cast::<usize>() = len22;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr22.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec23 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr23 = vec23.as_ptr().cast::<u8>();
+                                    let len23 = vec23.len();
+                                    ::core::mem::forget(vec23);
+                                    *ptr1.add(20).cast::<usize>() = len23;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr23.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec24 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr24 = vec24.as_ptr().cast::<u8>();
+                                    let len24 = vec24.len();
+                                    ::core::mem::forget(vec24);
+                                    *ptr1.add(20).cast::<usize>() = len24;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr24.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message25,
+                                        line: line25,
+                                        column: column25,
+                                    } = e;
+                                    let vec26 = (message25.into_bytes()).into_boxed_slice();
+                                    let ptr26 = vec26.as_ptr().cast::<u8>();
+                                    let len26 = vec26.len();
+                                    ::core::mem::forget(vec26);
+                                    *ptr1.add(20).cast::<usize>() = len26;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr26.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(line25);
+                                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(column25);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec27 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                                    let len27 = vec27.len();
+                                    ::core::mem::forget(vec27);
+                                    *ptr1.add(20).cast::<usize>() = len27;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr27.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_diff_components<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            let base7 = l1;
+                            let len7 = l2;
+                            for i in 0..len7 {
+                                let base = base7.add(i * 24);
+                                {
+                                    let l3 = *base.add(0).cast::<*mut u8>();
+                                    let l4 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l3, l4, 1);
+                                    let l5 = *base.add(12).cast::<*mut u8>();
+                                    let l6 = *base.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base7, len7 * 24, 4);
+                            let l8 = *arg0.add(16).cast::<*mut u8>();
+                            let l9 = *arg0.add(20).cast::<usize>();
+                            let base12 = l8;
+                            let len12 = l9;
+                            for i in 0..len12 {
+                                let base = base12.add(i * 28);
+                                {
+                                    let l10 = *base.add(20).cast::<*mut u8>();
+                                    let l11 = *base.add(24).cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base12, len12 * 28, 4);
+                        }
+                        _ => {
+                            let l13 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l13 {
+                                0 => {
+                                    let l14 = *arg0.add(16).cast::<*mut u8>();
+                                    let l15 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l14, l15, 1);
+                                }
+                                1 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l16, l17, 1);
+                                }
+                                2 => {
+                                    let l18 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l18 {
+                                        0 => {}
+                                        _ => {
+                                            let l19 = *arg0.add(20).cast::<*mut u8>();
+                                            let l20 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l19, l20, 1);
+                                        }
+                                    }
+                                    let l21 = *arg0.add(28).cast::<*mut u8>();
+                                    let l22 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l21, l22, 1);
+                                    let l23 = *arg0.add(36).cast::<*mut u8>();
+                                    let l24 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l23, l24, 1);
+                                }
+                                3 => {
+                                    let l25 = *arg0.add(16).cast::<*mut u8>();
+                                    let l26 = *arg0.add(20).cast::<usize>();
+                                    let base27 = l25;
+                                    let len27 = l26;
+                                    _rt::cabi_dealloc(base27, len27 * 4, 4);
+                                    let l28 = *arg0.add(24).cast::<*mut u8>();
+                                    let l29 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l28, l29, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l30 = *arg0.add(16).cast::<*mut u8>();
+                                    let l31 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l30, l31, 1);
+                                }
+                                7 => {
+                                    let l32 = *arg0.add(16).cast::<*mut u8>();
+                                    let l33 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l32, l33, 1);
+                                }
+                                8 => {
+                                    let l34 = *arg0.add(16).cast::<*mut u8>();
+                                    let l35 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l34, l35, 1);
+                                }
+                                9 => {
+                                    let l36 = *arg0.add(16).cast::<*mut u8>();
+                                    let l37 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l36, l37, 1);
+                                }
+                                _ => {
+                                    let l38 = *arg0.add(16).cast::<*mut u8>();
+                                    let l39 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l38, l39, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_preview_world_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: *mut u8,
+                    arg5: usize,
+                    arg6: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base6 = arg4;
+                    let len6 = arg5;
+                    let mut result6 = _rt::Vec::with_capacity(len6);
+                    for i in 0..len6 {
+                        let base = base6.add(i * 20);
+                        let e6 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = i32::from(*base.add(4).cast::<u8>());
+                            let l3 = *base.add(12).cast::<*mut u8>();
+                            let l4 = *base.add(16).cast::<usize>();
+                            let len5 = l4;
+                            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+                            ExportItem {
+                                instance: l0 as u32,
+                                export: match l1 {
+                                    0 => None,
+                                    1 => {
+                                        let e = {
+                                            let l2 = *base.add(8).cast::<i32>();
+                                            l2 as u32
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                name: _rt::string_lift(bytes5),
+                            }
+                        };
+                        result6.push(e6);
+                    }
+                    _rt::cabi_dealloc(base6, len6 * 20, 4);
+                    let result7 = T::preview_world(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        EncodeOptions {
+                            define_components: _rt::bool_lift(arg1 as u8),
+                            export: match arg2 {
+                                0 => None,
+                                1 => {
+                                    let e = arg3 as u32;
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            exports: result6,
+                            validate: _rt::bool_lift(arg6 as u8),
+                        },
+                    );
+                    let ptr8 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result7 {
+                        Ok(e) => {
+                            *ptr8.add(0).cast::<u8>() = (0i32) as u8;
+                            let WorldPreview {
+                                wit: wit9,
+                                imports: imports9,
+                                exports: exports9,
+                            } = e;
+                            let vec10 = (wit9.into_bytes()).into_boxed_slice();
+                            let ptr10 = vec10.as_ptr().cast::<u8>();
+                            let len10 = vec10.len();
+                            ::core::mem::forget(vec10);
+                            *ptr8.add(12).cast::<usize>() = len10;
+                            *ptr8.add(8).cast::<*mut u8>() = ptr10.cast_mut();
+                            let vec14 = imports9;
+                            let len14 = vec14.len();
+                            let layout14 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec14.len() * 16,
+                                4,
+                            );
+                            let result14 = if layout14.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout14).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout14);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec14.into_iter().enumerate() {
+                                let base = result14.add(i * 16);
+                                {
+                                    let PreviewItem { name: name11, instances: instances11 } = e;
+                                    let vec12 = (name11.into_bytes()).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *base.add(4).cast::<usize>() = len12;
+                                    *base.add(0).cast::<*mut u8>() = ptr12.cast_mut();
+                                    let vec13 = (instances11).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *base.add(12).cast::<usize>() = len13;
+                                    *base.add(8).cast::<*mut u8>() = ptr13.cast_mut();
+                                }
+                            }
+                            *ptr8.add(20).cast::<usize>() = len14;
+                            *ptr8.add(16).cast::<*mut u8>() = result14;
+                            let vec18 = exports9;
+                            let len18 = vec18.len();
+                            let layout18 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec18.len() * 16,
+                                4,
+                            );
+                            let result18 = if layout18.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout18).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout18);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec18.into_iter().enumerate() {
+                                let base = result18.add(i * 16);
+                                {
+                                    let PreviewItem { name: name15, instances: instances15 } = e;
+                                    let vec16 = (name15.into_bytes()).into_boxed_slice();
+                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                    let len16 = vec16.len();
+                                    ::core::mem::forget(vec16);
+                                    *base.add(4).cast::<usize>() = len16;
+                                    *base.add(0).cast::<*mut u8>() = ptr16.cast_mut();
+                                    let vec17 = (instances15).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *base.add(12).cast::<usize>() = len17;
+                                    *base.add(8).cast::<*mut u8>() = ptr17.cast_mut();
+                                }
+                            }
+                            *ptr8.add(28).cast::<usize>() = len18;
+                            *ptr8.add(24).cast::<*mut u8>() = result18;
+                        }
+                        Err(e) => {
+                            *ptr8.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message19, offset: offset19 } = e;
+                                    let vec20 = (message19.into_bytes()).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *ptr8.add(20).cast::<usize>() = len20;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr20.cast_mut();
+                                    match offset19 {
+                                        Some(e) => {
+                                            *ptr8.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr8.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr8.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message21, offset: offset21 } = e;
+                                    let vec22 = (message21.into_bytes()).into_boxed_slice();
+                                    let ptr22 = vec22.as_ptr().cast::<u8>();
+                                    let len22 = vec22.len();
+                                    ::core::mem::forget(vec22);
+                                    *ptr8.add(20).cast::<usize>() = len22;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr22.cast_mut();
+                                    match offset21 {
+                                        Some(e) => {
+                                            *ptr8.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr8.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr8.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export23,
+                                        import: import23,
+                                        message: message23,
+                                    } = e;
+                                    match export23 {
+                                        Some(e) => {
+                                            *ptr8.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec24 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr24 = vec24.as_ptr().cast::<u8>();
+                                            let len24 = vec24.len();
+                                            ::core::mem::forget(vec24);
+                                            *ptr8.add(24).cast::<usize>() = len24;
+                                            *ptr8.add(20).cast::<*mut u8>() = ptr24.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr8.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec25 = (import23.into_bytes()).into_boxed_slice();
+                                    let ptr25 = vec25.as_ptr().cast::<u8>();
+                                    let len25 = vec25.len();
+                                    ::core::mem::forget(vec25);
+                                    *ptr8.add(32).cast::<usize>() = len25;
+                                    *ptr8.add(28).cast::<*mut u8>() = ptr25.cast_mut();
+                                    let vec26 = (message23.into_bytes()).into_boxed_slice();
+                                    let ptr26 = vec26.as_ptr().cast::<u8>();
+                                    let len26 = vec26.len();
+                                    ::core::mem::forget(vec26);
+                                    *ptr8.add(40).cast::<usize>() = len26;
+                                    *ptr8.add(36).cast::<*mut u8>() = ptr26.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances27,
+                                        message: message27,
+                                    } = e;
+                                    let vec28 = (instances27).into_boxed_slice();
+                                    let ptr28 = vec28.as_ptr().cast::<u8>();
+                                    let len28 = vec28.len();
+                                    ::core::mem::forget(vec28);
+                                    *ptr8.add(20).cast::<usize>() = len28;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr28.cast_mut();
+                                    let vec29 = (message27.into_bytes()).into_boxed_slice();
+                                    let ptr29 = vec29.as_ptr().cast::<u8>();
+                                    let len29 = vec29.len();
+                                    ::core::mem::forget(vec29);
+                                    *ptr8.add(28).cast::<usize>() = len29;
+                                    *ptr8.add(24).cast::<*mut u8>() = ptr29.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr8.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr8.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec30 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr30 = vec30.as_ptr().cast::<u8>();
+                                    let len30 = vec30.len();
+                                    ::core::mem::forget(vec30);
+                                    *ptr8.add(20).cast::<usize>() = len30;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr30.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec31 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr31 = vec31.as_ptr().cast::<u8>();
+                                    let len31 = vec31.len();
+                                    ::core::mem::forget(vec31);
+                                    *ptr8.add(20).cast::<usize>() = len31;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr31.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec32 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr32 = vec32.as_ptr().cast::<u8>();
+                                    let len32 = vec32.len();
+                                    ::core::mem::forget(vec32);
+                                    *ptr8.add(20).cast::<usize>() = len32;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr32.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message33,
+                                        line: line33,
+                                        column: column33,
+                                    } = e;
+                                    let vec34 = (message33.into_bytes()).into_boxed_slice();
+                                    let ptr34 = vec34.as_ptr().cast::<u8>();
+                                    let len34 = vec34.len();
+                                    ::core::mem::forget(vec34);
+                                    *ptr8.add(20).cast::<usize>() = len34;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr34.cast_mut();
+                                    *ptr8.add(24).cast::<i32>() = _rt::as_i32(line33);
+                                    *ptr8.add(28).cast::<i32>() = _rt::as_i32(column33);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec35 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr35 = vec35.as_ptr().cast::<u8>();
+                                    let len35 = vec35.len();
+                                    ::core::mem::forget(vec35);
+                                    *ptr8.add(20).cast::<usize>() = len35;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr35.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr8
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_preview_world<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = *arg0.add(16).cast::<*mut u8>();
+                            let l4 = *arg0.add(20).cast::<usize>();
+                            let base10 = l3;
+                            let len10 = l4;
+                            for i in 0..len10 {
+                                let base = base10.add(i * 16);
+                                {
+                                    let l5 = *base.add(0).cast::<*mut u8>();
+                                    let l6 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                    let l7 = *base.add(8).cast::<*mut u8>();
+                                    let l8 = *base.add(12).cast::<usize>();
+                                    let base9 = l7;
+                                    let len9 = l8;
+                                    _rt::cabi_dealloc(base9, len9 * 4, 4);
+                                }
+                            }
+                            _rt::cabi_dealloc(base10, len10 * 16, 4);
+                            let l11 = *arg0.add(24).cast::<*mut u8>();
+                            let l12 = *arg0.add(28).cast::<usize>();
+                            let base18 = l11;
+                            let len18 = l12;
+                            for i in 0..len18 {
+                                let base = base18.add(i * 16);
+                                {
+                                    let l13 = *base.add(0).cast::<*mut u8>();
+                                    let l14 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l13, l14, 1);
+                                    let l15 = *base.add(8).cast::<*mut u8>();
+                                    let l16 = *base.add(12).cast::<usize>();
+                                    let base17 = l15;
+                                    let len17 = l16;
+                                    _rt::cabi_dealloc(base17, len17 * 4, 4);
+                                }
+                            }
+                            _rt::cabi_dealloc(base18, len18 * 16, 4);
+                        }
+                        _ => {
+                            let l19 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l19 {
+                                0 => {
+                                    let l20 = *arg0.add(16).cast::<*mut u8>();
+                                    let l21 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l20, l21, 1);
+                                }
+                                1 => {
+                                    let l22 = *arg0.add(16).cast::<*mut u8>();
+                                    let l23 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l22, l23, 1);
+                                }
+                                2 => {
+                                    let l24 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l24 {
+                                        0 => {}
+                                        _ => {
+                                            let l25 = *arg0.add(20).cast::<*mut u8>();
+                                            let l26 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l25, l26, 1);
+                                        }
+                                    }
+                                    let l27 = *arg0.add(28).cast::<*mut u8>();
+                                    let l28 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l27, l28, 1);
+                                    let l29 = *arg0.add(36).cast::<*mut u8>();
+                                    let l30 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l29, l30, 1);
+                                }
+                                3 => {
+                                    let l31 = *arg0.add(16).cast::<*mut u8>();
+                                    let l32 = *arg0.add(20).cast::<usize>();
+                                    let base33 = l31;
+                                    let len33 = l32;
+                                    _rt::cabi_dealloc(base33, len33 * 4, 4);
+                                    let l34 = *arg0.add(24).cast::<*mut u8>();
+                                    let l35 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l34, l35, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l36 = *arg0.add(16).cast::<*mut u8>();
+                                    let l37 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l36, l37, 1);
+                                }
+                                7 => {
+                                    let l38 = *arg0.add(16).cast::<*mut u8>();
+                                    let l39 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l38, l39, 1);
+                                }
+                                8 => {
+                                    let l40 = *arg0.add(16).cast::<*mut u8>();
+                                    let l41 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l40, l41, 1);
+                                }
+                                9 => {
+                                    let l42 = *arg0.add(16).cast::<*mut u8>();
+                                    let l43 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l42, l43, 1);
+                                }
+                                _ => {
+                                    let l44 = *arg0.add(16).cast::<*mut u8>();
+                                    let l45 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l44, l45, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_component_wat_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: i32,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: *mut u8,
+                    arg12: usize,
+                    arg13: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base6 = arg11;
+                    let len6 = arg12;
+                    let mut result6 = _rt::Vec::with_capacity(len6);
+                    for i in 0..len6 {
+                        let base = base6.add(i * 20);
+                        let e6 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = i32::from(*base.add(4).cast::<u8>());
+                            let l3 = *base.add(12).cast::<*mut u8>();
+                            let l4 = *base.add(16).cast::<usize>();
+                            let len5 = l4;
+                            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+                            ExportItem {
+                                instance: l0 as u32,
+                                export: match l1 {
+                                    0 => None,
+                                    1 => {
+                                        let e = {
+                                            let l2 = *base.add(8).cast::<i32>();
+                                            l2 as u32
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                name: _rt::string_lift(bytes5),
+                            }
+                        };
+                        result6.push(e6);
+                    }
+                    _rt::cabi_dealloc(base6, len6 * 20, 4);
+                    let result7 = T::component_wat(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        match arg1 {
+                            0 => None,
+                            1 => {
+                                let e = arg2 as u32;
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        WatOptions {
+                            fold_expressions: _rt::bool_lift(arg3 as u8),
+                            skip_function_bodies: _rt::bool_lift(arg4 as u8),
+                            structure_only: _rt::bool_lift(arg5 as u8),
+                            max_length: match arg6 {
+                                0 => None,
+                                1 => {
+                                    let e = arg7 as u32;
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            encode: EncodeOptions {
+                                define_components: _rt::bool_lift(arg8 as u8),
+                                export: match arg9 {
+                                    0 => None,
+                                    1 => {
+                                        let e = arg10 as u32;
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                exports: result6,
+                                validate: _rt::bool_lift(arg13 as u8),
+                            },
+                        },
+                    );
+                    let ptr8 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result7 {
+                        Ok(e) => {
+                            *ptr8.add(0).cast::<u8>() = (0i32) as u8;
+                            let Wat { text: text9, truncated: truncated9 } = e;
+                            let vec10 = (text9.into_bytes()).into_boxed_slice();
+                            let ptr10 = vec10.as_ptr().cast::<u8>();
+                            let len10 = vec10.len();
+                            ::core::mem::forget(vec10);
+                            *ptr8.add(12).cast::<usize>() = len10;
+                            *ptr8.add(8).cast::<*mut u8>() = ptr10.cast_mut();
+                            *ptr8.add(16).cast::<u8>() = (match truncated9 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                        }
+                        Err(e) => {
+                            *ptr8.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message11, offset: offset11 } = e;
+                                    let vec12 = (message11.into_bytes()).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *ptr8.add(20).cast::<usize>() = len12;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr12.cast_mut();
+                                    match offset11 {
+                                        Some(e) => {
+                                            *ptr8.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr8.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr8.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message13, offset: offset13 } = e;
+                                    let vec14 = (message13.into_bytes()).into_boxed_slice();
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    ::core::mem::forget(vec14);
+                                    *ptr8.add(20).cast::<usize>() = len14;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr14.cast_mut();
+                                    match offset13 {
+                                        Some(e) => {
+                                            *ptr8.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr8.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr8.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export15,
+                                        import: import15,
+                                        message: message15,
+                                    } = e;
+                                    match export15 {
+                                        Some(e) => {
+                                            *ptr8.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec16 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr16 = vec16.as_ptr().cast::<u8>();
+                                            let len16 = vec16.len();
+                                            ::core::mem::forget(vec16);
+                                            *ptr8.add(24).cast::<usize>() = len16;
+                                            *ptr8.add(20).cast::<*mut u8>() = ptr16.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr8.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec17 = (import15.into_bytes()).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr8.add(32).cast::<usize>() = len17;
+                                    *ptr8.add(28).cast::<*mut u8>() = ptr17.cast_mut();
+                                    let vec18 = (message15.into_bytes()).into_boxed_slice();
+                                    let ptr18 = vec18.as_ptr().cast::<u8>();
+                                    let len18 = vec18.len();
+                                    ::core::mem::forget(vec18);
+                                    *ptr8.add(40).cast::<usize>() = len18;
+                                    *ptr8.add(36).cast::<*mut u8>() = ptr18.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances19,
+                                        message: message19,
+                                    } = e;
+                                    let vec20 = (instances19).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *ptr8.add(20).cast::<usize>() = len20;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr20.cast_mut();
+                                    let vec21 = (message19.into_bytes()).into_boxed_slice();
+                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                    let len21 = vec21.len();
+                                    ::core::mem::forget(vec21);
+                                    *ptr8.add(28).cast::<usize>() = len21;
+                                    *ptr8.add(24).cast::<*mut u8>() = ptr21.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr8.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr8.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec22 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr22 = vec22.as_ptr().cast::<u8>();
+                                    let len22 = vec22.len();
+                                    ::core::mem::forget(vec22);
+                                    *ptr8.add(20).cast::<usize>() = len22;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr22.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec23 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr23 = vec23.as_ptr().cast::<u8>();
+                                    let len23 = vec23.len();
+                                    ::core::mem::forget(vec23);
+                                    *ptr8.add(20).cast::<usize>() = len23;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr23.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec24 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr24 = vec24.as_ptr().cast::<u8>();
+                                    let len24 = vec24.len();
+                                    ::core::mem::forget(vec24);
+                                    *ptr8.add(20).cast::<usize>() = len24;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr24.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message25,
+                                        line: line25,
+                                        column: column25,
+                                    } = e;
+                                    let vec26 = (message25.into_bytes()).into_boxed_slice();
+                                    let ptr26 = vec26.as_ptr().cast::<u8>();
+                                    let len26 = vec26.len();
+                                    ::core::mem::forget(vec26);
+                                    *ptr8.add(20).cast::<usize>() = len26;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr26.cast_mut();
+                                    *ptr8.add(24).cast::<i32>() = _rt::as_i32(line25);
+                                    *ptr8.add(28).cast::<i32>() = _rt::as_i32(column25);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec27 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                                    let len27 = vec27.len();
+                                    ::core::mem::forget(vec27);
+                                    *ptr8.add(20).cast::<usize>() = len27;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr27.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr8
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_component_wat<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                        _ => {
+                            let l3 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l3 {
+                                0 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                1 => {
+                                    let l6 = *arg0.add(16).cast::<*mut u8>();
+                                    let l7 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                                2 => {
+                                    let l8 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l8 {
+                                        0 => {}
+                                        _ => {
+                                            let l9 = *arg0.add(20).cast::<*mut u8>();
+                                            let l10 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l9, l10, 1);
+                                        }
+                                    }
+                                    let l11 = *arg0.add(28).cast::<*mut u8>();
+                                    let l12 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l11, l12, 1);
+                                    let l13 = *arg0.add(36).cast::<*mut u8>();
+                                    let l14 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l13, l14, 1);
+                                }
+                                3 => {
+                                    let l15 = *arg0.add(16).cast::<*mut u8>();
+                                    let l16 = *arg0.add(20).cast::<usize>();
+                                    let base17 = l15;
+                                    let len17 = l16;
+                                    _rt::cabi_dealloc(base17, len17 * 4, 4);
+                                    let l18 = *arg0.add(24).cast::<*mut u8>();
+                                    let l19 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l18, l19, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l20 = *arg0.add(16).cast::<*mut u8>();
+                                    let l21 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l20, l21, 1);
+                                }
+                                7 => {
+                                    let l22 = *arg0.add(16).cast::<*mut u8>();
+                                    let l23 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l22, l23, 1);
+                                }
+                                8 => {
+                                    let l24 = *arg0.add(16).cast::<*mut u8>();
+                                    let l25 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l24, l25, 1);
+                                }
+                                9 => {
+                                    let l26 = *arg0.add(16).cast::<*mut u8>();
+                                    let l27 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l26, l27, 1);
+                                }
+                                _ => {
+                                    let l28 = *arg0.add(16).cast::<*mut u8>();
+                                    let l29 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l28, l29, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_encode_graph_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: *mut u8,
+                    arg5: usize,
+                    arg6: i32,
+                ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = Graph::new(T::new());
-                    (result0).take_handle() as i32
+                    let base6 = arg4;
+                    let len6 = arg5;
+                    let mut result6 = _rt::Vec::with_capacity(len6);
+                    for i in 0..len6 {
+                        let base = base6.add(i * 20);
+                        let e6 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = i32::from(*base.add(4).cast::<u8>());
+                            let l3 = *base.add(12).cast::<*mut u8>();
+                            let l4 = *base.add(16).cast::<usize>();
+                            let len5 = l4;
+                            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+                            ExportItem {
+                                instance: l0 as u32,
+                                export: match l1 {
+                                    0 => None,
+                                    1 => {
+                                        let e = {
+                                            let l2 = *base.add(8).cast::<i32>();
+                                            l2 as u32
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                name: _rt::string_lift(bytes5),
+                            }
+                        };
+                        result6.push(e6);
+                    }
+                    _rt::cabi_dealloc(base6, len6 * 20, 4);
+                    let result7 = T::encode_graph(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        EncodeOptions {
+                            define_components: _rt::bool_lift(arg1 as u8),
+                            export: match arg2 {
+                                0 => None,
+                                1 => {
+                                    let e = arg3 as u32;
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            exports: result6,
+                            validate: _rt::bool_lift(arg6 as u8),
+                        },
+                    );
+                    let ptr8 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result7 {
+                        Ok(e) => {
+                            *ptr8.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec9 = (e).into_boxed_slice();
+                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                            let len9 = vec9.len();
+                            ::core::mem::forget(vec9);
+                            *ptr8.add(12).cast::<usize>() = len9;
+                            *ptr8.add(8).cast::<*mut u8>() = ptr9.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr8.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message10, offset: offset10 } = e;
+                                    let vec11 = (message10.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr8.add(20).cast::<usize>() = len11;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                    match offset10 {
+                                        Some(e) => {
+                                            *ptr8.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr8.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr8.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message12, offset: offset12 } = e;
+                                    let vec13 = (message12.into_bytes()).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr8.add(20).cast::<usize>() = len13;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr13.cast_mut();
+                                    match offset12 {
+                                        Some(e) => {
+                                            *ptr8.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr8.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr8.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export14,
+                                        import: import14,
+                                        message: message14,
+                                    } = e;
+                                    match export14 {
+                                        Some(e) => {
+                                            *ptr8.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec15 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr15 = vec15.as_ptr().cast::<u8>();
+                                            let len15 = vec15.len();
+                                            ::core::mem::forget(vec15);
+                                            *ptr8.add(24).cast::<usize>() = len15;
+                                            *ptr8.add(20).cast::<*mut u8>() = ptr15.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr8.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec16 = (import14.into_bytes()).into_boxed_slice();
+                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                    let len16 = vec16.len();
+                                    ::core::mem::forget(vec16);
+                                    *ptr8.add(32).cast::<usize>() = len16;
+                                    *ptr8.add(28).cast::<*mut u8>() = ptr16.cast_mut();
+                                    let vec17 = (message14.into_bytes()).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr8.add(40).cast::<usize>() = len17;
+                                    *ptr8.add(36).cast::<*mut u8>() = ptr17.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances18,
+                                        message: message18,
+                                    } = e;
+                                    let vec19 = (instances18).into_boxed_slice();
+                                    let ptr19 = vec19.as_ptr().cast::<u8>();
+                                    let len19 = vec19.len();
+                                    ::core::mem::forget(vec19);
+                                    *ptr8.add(20).cast::<usize>() = len19;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr19.cast_mut();
+                                    let vec20 = (message18.into_bytes()).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *ptr8.add(28).cast::<usize>() = len20;
+                                    *ptr8.add(24).cast::<*mut u8>() = ptr20.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr8.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr8.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec21 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                    let len21 = vec21.len();
+                                    ::core::mem::forget(vec21);
+                                    *ptr8.add(20).cast::<usize>() = len21;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr21.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec22 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr22 = vec22.as_ptr().cast::<u8>();
+                                    let len22 = vec22.len();
+                                    ::core::mem::forget(vec22);
+                                    *ptr8.add(20).cast::<usize>() = len22;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr22.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec23 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr23 = vec23.as_ptr().cast::<u8>();
+                                    let len23 = vec23.len();
+                                    ::core::mem::forget(vec23);
+                                    *ptr8.add(20).cast::<usize>() = len23;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr23.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message24,
+                                        line: line24,
+                                        column: column24,
+                                    } = e;
+                                    let vec25 = (message24.into_bytes()).into_boxed_slice();
+                                    let ptr25 = vec25.as_ptr().cast::<u8>();
+                                    let len25 = vec25.len();
+                                    ::core::mem::forget(vec25);
+                                    *ptr8.add(20).cast::<usize>() = len25;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr25.cast_mut();
+                                    *ptr8.add(24).cast::<i32>() = _rt::as_i32(line24);
+                                    *ptr8.add(28).cast::<i32>() = _rt::as_i32(column24);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec26 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr26 = vec26.as_ptr().cast::<u8>();
+                                    let len26 = vec26.len();
+                                    ::core::mem::forget(vec26);
+                                    *ptr8.add(20).cast::<usize>() = len26;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr26.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr8
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_method_graph_add_component_cabi<T: GuestGraph>(
+                pub unsafe fn __post_return_method_graph_encode_graph<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            let base3 = l1;
+                            let len3 = l2;
+                            _rt::cabi_dealloc(base3, len3 * 1, 1);
+                        }
+                        _ => {
+                            let l4 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l4 {
+                                0 => {
+                                    let l5 = *arg0.add(16).cast::<*mut u8>();
+                                    let l6 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                }
+                                1 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l7, l8, 1);
+                                }
+                                2 => {
+                                    let l9 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l9 {
+                                        0 => {}
+                                        _ => {
+                                            let l10 = *arg0.add(20).cast::<*mut u8>();
+                                            let l11 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l10, l11, 1);
+                                        }
+                                    }
+                                    let l12 = *arg0.add(28).cast::<*mut u8>();
+                                    let l13 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                    let l14 = *arg0.add(36).cast::<*mut u8>();
+                                    let l15 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l14, l15, 1);
+                                }
+                                3 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 4, 4);
+                                    let l19 = *arg0.add(24).cast::<*mut u8>();
+                                    let l20 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l19, l20, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l21 = *arg0.add(16).cast::<*mut u8>();
+                                    let l22 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l21, l22, 1);
+                                }
+                                7 => {
+                                    let l23 = *arg0.add(16).cast::<*mut u8>();
+                                    let l24 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l23, l24, 1);
+                                }
+                                8 => {
+                                    let l25 = *arg0.add(16).cast::<*mut u8>();
+                                    let l26 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l25, l26, 1);
+                                }
+                                9 => {
+                                    let l27 = *arg0.add(16).cast::<*mut u8>();
+                                    let l28 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l27, l28, 1);
+                                }
+                                _ => {
+                                    let l29 = *arg0.add(16).cast::<*mut u8>();
+                                    let l30 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l29, l30, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_emit_wac_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: *mut u8,
+                    arg7: usize,
+                    arg8: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let base7 = arg6;
+                    let len7 = arg7;
+                    let mut result7 = _rt::Vec::with_capacity(len7);
+                    for i in 0..len7 {
+                        let base = base7.add(i * 20);
+                        let e7 = {
+                            let l1 = *base.add(0).cast::<i32>();
+                            let l2 = i32::from(*base.add(4).cast::<u8>());
+                            let l4 = *base.add(12).cast::<*mut u8>();
+                            let l5 = *base.add(16).cast::<usize>();
+                            let len6 = l5;
+                            let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+                            ExportItem {
+                                instance: l1 as u32,
+                                export: match l2 {
+                                    0 => None,
+                                    1 => {
+                                        let e = {
+                                            let l3 = *base.add(8).cast::<i32>();
+                                            l3 as u32
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                name: _rt::string_lift(bytes6),
+                            }
+                        };
+                        result7.push(e7);
+                    }
+                    _rt::cabi_dealloc(base7, len7 * 20, 4);
+                    let result8 = T::emit_wac(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        EncodeOptions {
+                            define_components: _rt::bool_lift(arg3 as u8),
+                            export: match arg4 {
+                                0 => None,
+                                1 => {
+                                    let e = arg5 as u32;
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            exports: result7,
+                            validate: _rt::bool_lift(arg8 as u8),
+                        },
+                    );
+                    let ptr9 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result8 {
+                        Ok(e) => {
+                            *ptr9.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec10 = (e.into_bytes()).into_boxed_slice();
+                            let ptr10 = vec10.as_ptr().cast::<u8>();
+                            let len10 = vec10.len();
+                            ::core::mem::forget(vec10);
+                            *ptr9.add(12).cast::<usize>() = len10;
+                            *ptr9.add(8).cast::<*mut u8>() = ptr10.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr9.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message11, offset: offset11 } = e;
+                                    let vec12 = (message11.into_bytes()).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *ptr9.add(20).cast::<usize>() = len12;
+                                    *ptr9.add(16).cast::<*mut u8>() = ptr12.cast_mut();
+                                    match offset11 {
+                                        Some(e) => {
+                                            *ptr9.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr9.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr9.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message13, offset: offset13 } = e;
+                                    let vec14 = (message13.into_bytes()).into_boxed_slice();
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    ::core::mem::forget(vec14);
+                                    *ptr9.add(20).cast::<usize>() = len14;
+                                    *ptr9.add(16).cast::<*mut u8>() = ptr14.cast_mut();
+                                    match offset13 {
+                                        Some(e) => {
+                                            *ptr9.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr9.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr9.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export15,
+                                        import: import15,
+                                        message: message15,
+                                    } = e;
+                                    match export15 {
+                                        Some(e) => {
+                                            *ptr9.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec16 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr16 = vec16.as_ptr().cast::<u8>();
+                                            let len16 = vec16.len();
+                                            ::core::mem::forget(vec16);
+                                            *ptr9.add(24).cast::<usize>() = len16;
+                                            *ptr9.add(20).cast::<*mut u8>() = ptr16.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr9.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec17 = (import15.into_bytes()).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr9.add(32).cast::<usize>() = len17;
+                                    *ptr9.add(28).cast::<*mut u8>() = ptr17.cast_mut();
+                                    let vec18 = (message15.into_bytes()).into_boxed_slice();
+                                    let ptr18 = vec18.as_ptr().cast::<u8>();
+                                    let len18 = vec18.len();
+                                    ::core::mem::forget(vec18);
+                                    *ptr9.add(40).cast::<usize>() = len18;
+                                    *ptr9.add(36).cast::<*mut u8>() = ptr18.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances19,
+                                        message: message19,
+                                    } = e;
+                                    let vec20 = (instances19).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *ptr9.add(20).cast::<usize>() = len20;
+                                    *ptr9.add(16).cast::<*mut u8>() = ptr20.cast_mut();
+                                    let vec21 = (message19.into_bytes()).into_boxed_slice();
+                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                    let len21 = vec21.len();
+                                    ::core::mem::forget(vec21);
+                                    *ptr9.add(28).cast::<usize>() = len21;
+                                    *ptr9.add(24).cast::<*mut u8>() = ptr21.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr9.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr9.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec22 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr22 = vec22.as_ptr().cast::<u8>();
+                                    let len22 = vec22.len();
+                                    ::core::mem::forget(vec22);
+                                    *ptr9.add(20).cast::<usize>() = len22;
+                                    *ptr9.add(16).cast::<*mut u8>() = ptr22.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec23 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr23 = vec23.as_ptr().cast::<u8>();
+                                    let len23 = vec23.len();
+                                    ::core::mem::forget(vec23);
+                                    *ptr9.add(20).cast::<usize>() = len23;
+                                    *ptr9.add(16).cast::<*mut u8>() = ptr23.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec24 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr24 = vec24.as_ptr().cast::<u8>();
+                                    let len24 = vec24.len();
+                                    ::core::mem::forget(vec24);
+                                    *ptr9.add(20).cast::<usize>() = len24;
+                                    *ptr9.add(16).cast::<*mut u8>() = ptr24.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message25,
+                                        line: line25,
+                                        column: column25,
+                                    } = e;
+                                    let vec26 = (message25.into_bytes()).into_boxed_slice();
+                                    let ptr26 = vec26.as_ptr().cast::<u8>();
+                                    let len26 = vec26.len();
+                                    ::core::mem::forget(vec26);
+                                    *ptr9.add(20).cast::<usize>() = len26;
+                                    *ptr9.add(16).cast::<*mut u8>() = ptr26.cast_mut();
+                                    *ptr9.add(24).cast::<i32>() = _rt::as_i32(line25);
+                                    *ptr9.add(28).cast::<i32>() = _rt::as_i32(column25);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec27 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                                    let len27 = vec27.len();
+                                    ::core::mem::forget(vec27);
+                                    *ptr9.add(20).cast::<usize>() = len27;
+                                    *ptr9.add(16).cast::<*mut u8>() = ptr27.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr9
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_graph_emit_wac<T: GuestGraph>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                        _ => {
+                            let l3 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l3 {
+                                0 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                1 => {
+                                    let l6 = *arg0.add(16).cast::<*mut u8>();
+                                    let l7 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                                2 => {
+                                    let l8 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l8 {
+                                        0 => {}
+                                        _ => {
+                                            let l9 = *arg0.add(20).cast::<*mut u8>();
+                                            let l10 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l9, l10, 1);
+                                        }
+                                    }
+                                    let l11 = *arg0.add(28).cast::<*mut u8>();
+                                    let l12 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l11, l12, 1);
+                                    let l13 = *arg0.add(36).cast::<*mut u8>();
+                                    let l14 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l13, l14, 1);
+                                }
+                                3 => {
+                                    let l15 = *arg0.add(16).cast::<*mut u8>();
+                                    let l16 = *arg0.add(20).cast::<usize>();
+                                    let base17 = l15;
+                                    let len17 = l16;
+                                    _rt::cabi_dealloc(base17, len17 * 4, 4);
+                                    let l18 = *arg0.add(24).cast::<*mut u8>();
+                                    let l19 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l18, l19, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l20 = *arg0.add(16).cast::<*mut u8>();
+                                    let l21 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l20, l21, 1);
+                                }
+                                7 => {
+                                    let l22 = *arg0.add(16).cast::<*mut u8>();
+                                    let l23 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l22, l23, 1);
+                                }
+                                8 => {
+                                    let l24 = *arg0.add(16).cast::<*mut u8>();
+                                    let l25 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l24, l25, 1);
+                                }
+                                9 => {
+                                    let l26 = *arg0.add(16).cast::<*mut u8>();
+                                    let l27 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l26, l27, 1);
+                                }
+                                _ => {
+                                    let l28 = *arg0.add(16).cast::<*mut u8>();
+                                    let l29 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l28, l29, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_graph_load_wac_cabi<T: GuestGraph>(
                     arg0: *mut u8,
                     arg1: *mut u8,
                     arg2: usize,
@@ -300,112 +12391,744 @@ pub mod exports {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
                     let len0 = arg2;
                     let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
-                    let len1 = arg4;
-                    let result2 = T::add_component(
+                    let base7 = arg3;
+                    let len7 = arg4;
+                    let mut result7 = _rt::Vec::with_capacity(len7);
+                    for i in 0..len7 {
+                        let base = base7.add(i * 16);
+                        let e7 = {
+                            let l1 = *base.add(0).cast::<*mut u8>();
+                            let l2 = *base.add(4).cast::<usize>();
+                            let len3 = l2;
+                            let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                            let l4 = *base.add(8).cast::<*mut u8>();
+                            let l5 = *base.add(12).cast::<usize>();
+                            let len6 = l5;
+                            (
+                                _rt::string_lift(bytes3),
+                                _rt::Vec::from_raw_parts(l4.cast(), len6, len6),
+                            )
+                        };
+                        result7.push(e7);
+                    }
+                    _rt::cabi_dealloc(base7, len7 * 16, 4);
+                    let result8 = T::load_wac(
                         GraphBorrow::lift(arg0 as u32 as usize).get(),
                         _rt::string_lift(bytes0),
-                        _rt::Vec::from_raw_parts(arg3.cast(), len1, len1),
+                        result7,
                     );
-                    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
-                    match result2 {
+                    let ptr9 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result8 {
                         Ok(e) => {
-                            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
-                            let Component {
-                                id: id4,
-                                name: name4,
-                                imports: imports4,
-                                exports: exports4,
-                                wit: wit4,
+                            *ptr9.add(0).cast::<u8>() = (0i32) as u8;
+                            let Project {
+                                components: components10,
+                                instances: instances10,
+                                connections: connections10,
+                                export: export10,
+                                exports: exports10,
                             } = e;
-                            *ptr3.add(4).cast::<i32>() = _rt::as_i32(id4);
-                            let vec5 = (name4.into_bytes()).into_boxed_slice();
-                            let ptr5 = vec5.as_ptr().cast::<u8>();
-                            let len5 = vec5.len();
-                            ::core::mem::forget(vec5);
-                            *ptr3.add(12).cast::<usize>() = len5;
-                            *ptr3.add(8).cast::<*mut u8>() = ptr5.cast_mut();
-                            let vec8 = imports4;
-                            let len8 = vec8.len();
-                            let layout8 = _rt::alloc::Layout::from_size_align_unchecked(
-                                vec8.len() * 12,
+                            let vec47 = components10;
+                            let len47 = vec47.len();
+                            let layout47 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec47.len() * 168,
                                 4,
                             );
-                            let result8 = if layout8.size() != 0 {
-                                let ptr = _rt::alloc::alloc(layout8).cast::<u8>();
+                            let result47 = if layout47.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout47).cast::<u8>();
                                 if ptr.is_null() {
-                                    _rt::alloc::handle_alloc_error(layout8);
+                                    _rt::alloc::handle_alloc_error(layout47);
                                 }
                                 ptr
                             } else {
                                 { ::core::ptr::null_mut() }
                             };
-                            for (i, e) in vec8.into_iter().enumerate() {
-                                let base = result8.add(i * 12);
+                            for (i, e) in vec47.into_iter().enumerate() {
+                                let base = result47.add(i * 168);
                                 {
-                                    let Import { name: name6, kind: kind6 } = e;
-                                    let vec7 = (name6.into_bytes()).into_boxed_slice();
-                                    let ptr7 = vec7.as_ptr().cast::<u8>();
-                                    let len7 = vec7.len();
-                                    ::core::mem::forget(vec7);
-                                    *base.add(4).cast::<usize>() = len7;
-                                    *base.add(0).cast::<*mut u8>() = ptr7.cast_mut();
-                                    *base.add(8).cast::<u8>() = (kind6.clone() as i32) as u8;
+                                    let Component {
+                                        id: id11,
+                                        name: name11,
+                                        package_name: package_name11,
+                                        version: version11,
+                                        digest: digest11,
+                                        imports: imports11,
+                                        exports: exports11,
+                                        wit: wit11,
+                                        metadata: metadata11,
+                                    } = e;
+                                    *base.add(0).cast::<i32>() = _rt::as_i32(id11);
+                                    let vec12 = (name11.into_bytes()).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *base.add(8).cast::<usize>() = len12;
+                                    *base.add(4).cast::<*mut u8>() = ptr12.cast_mut();
+                                    let vec13 = (package_name11.into_bytes())
+                                        .into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *base.add(16).cast::<usize>() = len13;
+                                    *base.add(12).cast::<*mut u8>() = ptr13.cast_mut();
+                                    match version11 {
+                                        Some(e) => {
+                                            *base.add(20).cast::<u8>() = (1i32) as u8;
+                                            let vec14 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr14 = vec14.as_ptr().cast::<u8>();
+                                            let len14 = vec14.len();
+                                            ::core::mem::forget(vec14);
+                                            *base.add(28).cast::<usize>() = len14;
+                                            *base.add(24).cast::<*mut u8>() = ptr14.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(20).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec15 = (digest11.into_bytes()).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *base.add(36).cast::<usize>() = len15;
+                                    *base.add(32).cast::<*mut u8>() = ptr15.cast_mut();
+                                    let vec23 = imports11;
+                                    let len23 = vec23.len();
+                                    let layout23 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec23.len() * 28,
+                                        4,
+                                    );
+                                    let result23 = if layout23.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout23).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout23);
+                                        }
+                                        ptr
+                                    } else {
+                                        { ::core::ptr::null_mut() }
+                                    };
+                                    for (i, e) in vec23.into_iter().enumerate() {
+                                        let base = result23.add(i * 28);
+                                        {
+                                            let Import {
+                                                name: name16,
+                                                kind: kind16,
+                                                signature: signature16,
+                                                members: members16,
+                                            } = e;
+                                            let vec17 = (name16.into_bytes()).into_boxed_slice();
+                                            let ptr17 = vec17.as_ptr().cast::<u8>();
+                                            let len17 = vec17.len();
+                                            ::core::mem::forget(vec17);
+                                            *base.add(4).cast::<usize>() = len17;
+                                            *base.add(0).cast::<*mut u8>() = ptr17.cast_mut();
+                                            *base.add(8).cast::<u8>() = (kind16.clone() as i32) as u8;
+                                            let vec18 = (signature16.into_bytes()).into_boxed_slice();
+                                            let ptr18 = vec18.as_ptr().cast::<u8>();
+                                            let len18 = vec18.len();
+                                            ::core::mem::forget(vec18);
+                                            *base.add(16).cast::<usize>() = len18;
+                                            *base.add(12).cast::<*mut u8>() = ptr18.cast_mut();
+                                            let vec22 = members16;
+                                            let len22 = vec22.len();
+                                            let layout22 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec22.len() * 20,
+                                                4,
+                                            );
+                                            let result22 = if layout22.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout22).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout22);
+                                                }
+                                                ptr
+                                            } else {
+                                                { ::core::ptr::null_mut() }
+                                            };
+                                            for (i, e) in vec22.into_iter().enumerate() {
+                                                let base = result22.add(i * 20);
+                                                {
+                                                    let Member {
+                                                        name: name19,
+                                                        kind: kind19,
+                                                        signature: signature19,
+                                                    } = e;
+                                                    let vec20 = (name19.into_bytes()).into_boxed_slice();
+                                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                                    let len20 = vec20.len();
+                                                    ::core::mem::forget(vec20);
+                                                    *base.add(4).cast::<usize>() = len20;
+                                                    *base.add(0).cast::<*mut u8>() = ptr20.cast_mut();
+                                                    *base.add(8).cast::<u8>() = (kind19.clone() as i32) as u8;
+                                                    let vec21 = (signature19.into_bytes()).into_boxed_slice();
+                                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                                    let len21 = vec21.len();
+                                                    ::core::mem::forget(vec21);
+                                                    *base.add(16).cast::<usize>() = len21;
+                                                    *base.add(12).cast::<*mut u8>() = ptr21.cast_mut();
+                                                }
+                                            }
+                                            *base.add(24).cast::<usize>() = len22;
+                                            *base.add(20).cast::<*mut u8>() = result22;
+                                        }
+                                    }
+                                    *base.add(44).cast::<usize>() = len23;
+                                    *base.add(40).cast::<*mut u8>() = result23;
+                                    let vec31 = exports11;
+                                    let len31 = vec31.len();
+                                    let layout31 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec31.len() * 28,
+                                        4,
+                                    );
+                                    let result31 = if layout31.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout31).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout31);
+                                        }
+                                        ptr
+                                    } else {
+                                        { ::core::ptr::null_mut() }
+                                    };
+                                    for (i, e) in vec31.into_iter().enumerate() {
+                                        let base = result31.add(i * 28);
+                                        {
+                                            let Export {
+                                                name: name24,
+                                                kind: kind24,
+                                                signature: signature24,
+                                                members: members24,
+                                            } = e;
+                                            let vec25 = (name24.into_bytes()).into_boxed_slice();
+                                            let ptr25 = vec25.as_ptr().cast::<u8>();
+                                            let len25 = vec25.len();
+                                            ::core::mem::forget(vec25);
+                                            *base.add(4).cast::<usize>() = len25;
+                                            *base.add(0).cast::<*mut u8>() = ptr25.cast_mut();
+                                            *base.add(8).cast::<u8>() = (kind24.clone() as i32) as u8;
+                                            let vec26 = (signature24.into_bytes()).into_boxed_slice();
+                                            let ptr26 = vec26.as_ptr().cast::<u8>();
+                                            let len26 = vec26.len();
+                                            ::core::mem::forget(vec26);
+                                            *base.add(16).cast::<usize>() = len26;
+                                            *base.add(12).cast::<*mut u8>() = ptr26.cast_mut();
+                                            let vec30 = members24;
+                                            let len30 = vec30.len();
+                                            let layout30 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec30.len() * 20,
+                                                4,
+                                            );
+                                            let result30 = if layout30.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout30).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout30);
+                                                }
+                                                ptr
+                                            } else {
+                                                { ::core::ptr::null_mut() }
+                                            };
+                                            for (i, e) in vec30.into_iter().enumerate() {
+                                                let base = result30.add(i * 20);
+                                                {
+                                                    let Member {
+                                                        name: name27,
+                                                        kind: kind27,
+                                                        signature: signature27,
+                                                    } = e;
+                                                    let vec28 = (name27.into_bytes()).into_boxed_slice();
+                                                    let ptr28 = vec28.as_ptr().cast::<u8>();
+                                                    let len28 = vec28.len();
+                                                    ::core::mem::forget(vec28);
+                                                    *base.add(4).cast::<usize>() = len28;
+                                                    *base.add(0).cast::<*mut u8>() = ptr28.cast_mut();
+                                                    *base.add(8).cast::<u8>() = (kind27.clone() as i32) as u8;
+                                                    let vec29 = (signature27.into_bytes()).into_boxed_slice();
+                                                    let ptr29 = vec29.as_ptr().cast::<u8>();
+                                                    let len29 = vec29.len();
+                                                    ::core::mem::forget(vec29);
+                                                    *base.add(16).cast::<usize>() = len29;
+                                                    *base.add(12).cast::<*mut u8>() = ptr29.cast_mut();
+                                                }
+                                            }
+                                            *base.add(24).cast::<usize>() = len30;
+                                            *base.add(20).cast::<*mut u8>() = result30;
+                                        }
+                                    }
+                                    *base.add(52).cast::<usize>() = len31;
+                                    *base.add(48).cast::<*mut u8>() = result31;
+                                    let vec32 = (wit11.into_bytes()).into_boxed_slice();
+                                    let ptr32 = vec32.as_ptr().cast::<u8>();
+                                    let len32 = vec32.len();
+                                    ::core::mem::forget(vec32);
+                                    *base.add(60).cast::<usize>() = len32;
+                                    *base.add(56).cast::<*mut u8>() = ptr32.cast_mut();
+                                    let ComponentMetadata {
+                                        name: name33,
+                                        producers: producers33,
+                                        authors: authors33,
+                                        description: description33,
+                                        licenses: licenses33,
+                                        source: source33,
+                                        homepage: homepage33,
+                                        revision: revision33,
+                                        version: version33,
+                                    } = metadata11;
+                                    match name33 {
+                                        Some(e) => {
+                                            *base.add(64).cast::<u8>() = (1i32) as u8;
+                                            let vec34 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr34 = vec34.as_ptr().cast::<u8>();
+                                            let len34 = vec34.len();
+                                            ::core::mem::forget(vec34);
+                                            *base.add(72).cast::<usize>() = len34;
+                                            *base.add(68).cast::<*mut u8>() = ptr34.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(64).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec39 = producers33;
+                                    let len39 = vec39.len();
+                                    let layout39 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec39.len() * 24,
+                                        4,
+                                    );
+                                    let result39 = if layout39.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout39).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout39);
+                                        }
+                                        ptr
+                                    } else {
+                                        { ::core::ptr::null_mut() }
+                                    };
+                                    for (i, e) in vec39.into_iter().enumerate() {
+                                        let base = result39.add(i * 24);
+                                        {
+                                            let Producer {
+                                                field: field35,
+                                                name: name35,
+                                                version: version35,
+                                            } = e;
+                                            let vec36 = (field35.into_bytes()).into_boxed_slice();
+                                            let ptr36 = vec36.as_ptr().cast::<u8>();
+                                            let len36 = vec36.len();
+                                            ::core::mem::forget(vec36);
+                                            *base.add(4).cast::<usize>() = len36;
+                                            *base.add(0).cast::<*mut u8>() = ptr36.cast_mut();
+                                            let vec37 = (name35.into_bytes()).into_boxed_slice();
+                                            let ptr37 = vec37.as_ptr().cast::<u8>();
+                                            let len37 = vec37.len();
+                                            ::core::mem::forget(vec37);
+                                            *base.add(12).cast::<usize>() = len37;
+                                            *base.add(8).cast::<*mut u8>() = ptr37.cast_mut();
+                                            let vec38 = (version35.into_bytes()).into_boxed_slice();
+                                            let ptr38 = vec38.as_ptr().cast::<u8>();
+                                            let len38 = vec38.len();
+                                            ::core::mem::forget(vec38);
+                                            *base.add(20).cast::<usize>() = len38;
+                                            *base.add(16).cast::<*mut u8>() = ptr38.cast_mut();
+                                        }
+                                    }
+                                    *base.add(80).cast::<usize>() = len39;
+                                    *base.add(76).cast::<*mut u8>() = result39;
+                                    match authors33 {
+                                        Some(e) => {
+                                            *base.add(84).cast::<u8>() = (1i32) as u8;
+                                            let vec40 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr40 = vec40.as_ptr().cast::<u8>();
+                                            let len40 = vec40.len();
+                                            ::core::mem::forget(vec40);
+                                            *base.add(92).cast::<usize>() = len40;
+                                            *base.add(88).cast::<*mut u8>() = ptr40.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(84).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match description33 {
+                                        Some(e) => {
+                                            *base.add(96).cast::<u8>() = (1i32) as u8;
+                                            let vec41 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr41 = vec41.as_ptr().cast::<u8>();
+                                            let len41 = vec41.len();
+                                            ::core::mem::forget(vec41);
+                                            *base.add(104).cast::<usize>() = len41;
+                                            *base.add(100).cast::<*mut u8>() = ptr41.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(96).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match licenses33 {
+                                        Some(e) => {
+                                            *base.add(108).cast::<u8>() = (1i32) as u8;
+                                            let vec42 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr42 = vec42.as_ptr().cast::<u8>();
+                                            let len42 = vec42.len();
+                                            ::core::mem::forget(vec42);
+                                            *base.add(116).cast::<usize>() = len42;
+                                            *base.add(112).cast::<*mut u8>() = ptr42.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(108).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match source33 {
+                                        Some(e) => {
+                                            *base.add(120).cast::<u8>() = (1i32) as u8;
+                                            let vec43 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr43 = vec43.as_ptr().cast::<u8>();
+                                            let len43 = vec43.len();
+                                            ::core::mem::forget(vec43);
+                                            *base.add(128).cast::<usize>() = len43;
+                                            *base.add(124).cast::<*mut u8>() = ptr43.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(120).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match homepage33 {
+                                        Some(e) => {
+                                            *base.add(132).cast::<u8>() = (1i32) as u8;
+                                            let vec44 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr44 = vec44.as_ptr().cast::<u8>();
+                                            let len44 = vec44.len();
+                                            ::core::mem::forget(vec44);
+                                            *base.add(140).cast::<usize>() = len44;
+                                            *base.add(136).cast::<*mut u8>() = ptr44.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(132).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match revision33 {
+                                        Some(e) => {
+                                            *base.add(144).cast::<u8>() = (1i32) as u8;
+                                            let vec45 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr45 = vec45.as_ptr().cast::<u8>();
+                                            let len45 = vec45.len();
+                                            ::core::mem::forget(vec45);
+                                            *base.add(152).cast::<usize>() = len45;
+                                            *base.add(148).cast::<*mut u8>() = ptr45.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(144).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match version33 {
+                                        Some(e) => {
+                                            *base.add(156).cast::<u8>() = (1i32) as u8;
+                                            let vec46 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr46 = vec46.as_ptr().cast::<u8>();
+                                            let len46 = vec46.len();
+                                            ::core::mem::forget(vec46);
+                                            *base.add(164).cast::<usize>() = len46;
+                                            *base.add(160).cast::<*mut u8>() = ptr46.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(156).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
                                 }
                             }
-                            *ptr3.add(20).cast::<usize>() = len8;
-                            *ptr3.add(16).cast::<*mut u8>() = result8;
-                            let vec11 = exports4;
-                            let len11 = vec11.len();
-                            let layout11 = _rt::alloc::Layout::from_size_align_unchecked(
-                                vec11.len() * 12,
+                            *ptr9.add(12).cast::<usize>() = len47;
+                            *ptr9.add(8).cast::<*mut u8>() = result47;
+                            let vec50 = instances10;
+                            let len50 = vec50.len();
+                            let layout50 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec50.len() * 20,
+                                4,
+                            );
+                            let result50 = if layout50.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout50).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout50);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec50.into_iter().enumerate() {
+                                let base = result50.add(i * 20);
+                                {
+                                    let Instance {
+                                        id: id48,
+                                        component: component48,
+                                        name: name48,
+                                    } = e;
+                                    *base.add(0).cast::<i32>() = _rt::as_i32(id48);
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(component48);
+                                    match name48 {
+                                        Some(e) => {
+                                            *base.add(8).cast::<u8>() = (1i32) as u8;
+                                            let vec49 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr49 = vec49.as_ptr().cast::<u8>();
+                                            let len49 = vec49.len();
+                                            ::core::mem::forget(vec49);
+                                            *base.add(16).cast::<usize>() = len49;
+                                            *base.add(12).cast::<*mut u8>() = ptr49.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(8).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                            *ptr9.add(20).cast::<usize>() = len50;
+                            *ptr9.add(16).cast::<*mut u8>() = result50;
+                            let vec52 = connections10;
+                            let len52 = vec52.len();
+                            let layout52 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec52.len() * 20,
+                                4,
+                            );
+                            let result52 = if layout52.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout52).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout52);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec52.into_iter().enumerate() {
+                                let base = result52.add(i * 20);
+                                {
+                                    let Connection {
+                                        source: source51,
+                                        source_export: source_export51,
+                                        target: target51,
+                                        target_import: target_import51,
+                                    } = e;
+                                    *base.add(0).cast::<i32>() = _rt::as_i32(source51);
+                                    match source_export51 {
+                                        Some(e) => {
+                                            *base.add(4).cast::<u8>() = (1i32) as u8;
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *base.add(4).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    *base.add(12).cast::<i32>() = _rt::as_i32(target51);
+                                    *base.add(16).cast::<i32>() = _rt::as_i32(target_import51);
+                                }
+                            }
+                            *ptr9.add(28).cast::<usize>() = len52;
+                            *ptr9.add(24).cast::<*mut u8>() = result52;
+                            match export10 {
+                                Some(e) => {
+                                    *ptr9.add(32).cast::<u8>() = (1i32) as u8;
+                                    *ptr9.add(36).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                None => {
+                                    *ptr9.add(32).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            let vec55 = exports10;
+                            let len55 = vec55.len();
+                            let layout55 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec55.len() * 20,
                                 4,
                             );
-                            let result11 = if layout11.size() != 0 {
-                                let ptr = _rt::alloc::alloc(layout11).cast::<u8>();
+                            let result55 = if layout55.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout55).cast::<u8>();
                                 if ptr.is_null() {
-                                    _rt::alloc::handle_alloc_error(layout11);
+                                    _rt::alloc::handle_alloc_error(layout55);
                                 }
                                 ptr
                             } else {
                                 { ::core::ptr::null_mut() }
                             };
-                            for (i, e) in vec11.into_iter().enumerate() {
-                                let base = result11.add(i * 12);
+                            for (i, e) in vec55.into_iter().enumerate() {
+                                let base = result55.add(i * 20);
                                 {
-                                    let Export { name: name9, kind: kind9 } = e;
-                                    let vec10 = (name9.into_bytes()).into_boxed_slice();
-                                    let ptr10 = vec10.as_ptr().cast::<u8>();
-                                    let len10 = vec10.len();
-                                    ::core::mem::forget(vec10);
-                                    *base.add(4).cast::<usize>() = len10;
-                                    *base.add(0).cast::<*mut u8>() = ptr10.cast_mut();
-                                    *base.add(8).cast::<u8>() = (kind9.clone() as i32) as u8;
+                                    let ExportItem {
+                                        instance: instance53,
+                                        export: export53,
+                                        name: name53,
+                                    } = e;
+                                    *base.add(0).cast::<i32>() = _rt::as_i32(instance53);
+                                    match export53 {
+                                        Some(e) => {
+                                            *base.add(4).cast::<u8>() = (1i32) as u8;
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *base.add(4).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec54 = (name53.into_bytes()).into_boxed_slice();
+                                    let ptr54 = vec54.as_ptr().cast::<u8>();
+                                    let len54 = vec54.len();
+                                    ::core::mem::forget(vec54);
+                                    *base.add(16).cast::<usize>() = len54;
+                                    *base.add(12).cast::<*mut u8>() = ptr54.cast_mut();
                                 }
                             }
-                            *ptr3.add(28).cast::<usize>() = len11;
-                            *ptr3.add(24).cast::<*mut u8>() = result11;
-                            let vec12 = (wit4.into_bytes()).into_boxed_slice();
-                            let ptr12 = vec12.as_ptr().cast::<u8>();
-                            let len12 = vec12.len();
-                            ::core::mem::forget(vec12);
-                            *ptr3.add(36).cast::<usize>() = len12;
-                            *ptr3.add(32).cast::<*mut u8>() = ptr12.cast_mut();
+                            *ptr9.add(44).cast::<usize>() = len55;
+                            *ptr9.add(40).cast::<*mut u8>() = result55;
                         }
                         Err(e) => {
-                            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
-                            let vec13 = (e.into_bytes()).into_boxed_slice();
-                            let ptr13 = vec13.as_ptr().cast::<u8>();
-                            let len13 = vec13.len();
-                            ::core::mem::forget(vec13);
-                            *ptr3.add(8).cast::<usize>() = len13;
-                            *ptr3.add(4).cast::<*mut u8>() = ptr13.cast_mut();
+                            *ptr9.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message56, offset: offset56 } = e;
+                                    let vec57 = (message56.into_bytes()).into_boxed_slice();
+                                    let ptr57 = vec57.as_ptr().cast::<u8>();
+                                    let len57 = vec57.len();
+                                    ::core::mem::forget(vec57);
+                                    *ptr9.add(20).cast::<usize>() = len57;
+                                    *ptr9.add(16).cast::<*mut u8>() = ptr57.cast_mut();
+                                    match offset56 {
+                                        Some(e) => {
+                                            *ptr9.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr9.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr9.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message58, offset: offset58 } = e;
+                                    let vec59 = (message58.into_bytes()).into_boxed_slice();
+                                    let ptr59 = vec59.as_ptr().cast::<u8>();
+                                    let len59 = vec59.len();
+                                    ::core::mem::forget(vec59);
+                                    *ptr9.add(20).cast::<usize>() = len59;
+                                    *ptr9.add(16).cast::<*mut u8>() = ptr59.cast_mut();
+                                    match offset58 {
+                                        Some(e) => {
+                                            *ptr9.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr9.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr9.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export60,
+                                        import: import60,
+                                        message: message60,
+                                    } = e;
+                                    match export60 {
+                                        Some(e) => {
+                                            *ptr9.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec61 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr61 = vec61.as_ptr().cast::<u8>();
+                                            let len61 = vec61.len();
+                                            ::core::mem::forget(vec61);
+                                            *ptr9.add(24).cast::<usize>() = len61;
+                                            *ptr9.add(20).cast::<*mut u8>() = ptr61.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr9.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec62 = (import60.into_bytes()).into_boxed_slice();
+                                    let ptr62 = vec62.as_ptr().cast::<u8>();
+                                    let len62 = vec62.len();
+                                    ::core::mem::forget(vec62);
+                                    *ptr9.add(32).cast::<usize>() = len62;
+                                    *ptr9.add(28).cast::<*mut u8>() = ptr62.cast_mut();
+                                    let vec63 = (message60.into_bytes()).into_boxed_slice();
+                                    let ptr63 = vec63.as_ptr().cast::<u8>();
+                                    let len63 = vec63.len();
+                                    ::core::mem::forget(vec63);
+                                    *ptr9.add(40).cast::<usize>() = len63;
+                                    *ptr9.add(36).cast::<*mut u8>() = ptr63.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances64,
+                                        message: message64,
+                                    } = e;
+                                    let vec65 = (instances64).into_boxed_slice();
+                                    let ptr65 = vec65.as_ptr().cast::<u8>();
+                                    let len65 = vec65.len();
+                                    ::core::mem::forget(vec65);
+                                    *ptr9.add(20).cast::<usize>() = len65;
+                                    *ptr9.add(16).cast::<*mut u8>() = ptr65.cast_mut();
+                                    let vec66 = (message64.into_bytes()).into_boxed_slice();
+                                    let ptr66 = vec66.as_ptr().cast::<u8>();
+                                    let len66 = vec66.len();
+                                    ::core::mem::forget(vec66);
+                                    *ptr9.add(28).cast::<usize>() = len66;
+                                    *ptr9.add(24).cast::<*mut u8>() = ptr66.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr9.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr9.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec67 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr67 = vec67.as_ptr().cast::<u8>();
+                                    let len67 = vec67.len();
+                                    ::core::mem::forget(vec67);
+                                    *ptr9.add(20).cast::<usize>() = len67;
+                                    *ptr9.add(16).cast::<*mut u8>() = ptr67.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec68 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr68 = vec68.as_ptr().cast::<u8>();
+                                    let len68 = vec68.len();
+                                    ::core::mem::forget(vec68);
+                                    *ptr9.add(20).cast::<usize>() = len68;
+                                    *ptr9.add(16).cast::<*mut u8>() = ptr68.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec69 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr69 = vec69.as_ptr().cast::<u8>();
+                                    let len69 = vec69.len();
+                                    ::core::mem::forget(vec69);
+                                    *ptr9.add(20).cast::<usize>() = len69;
+                                    *ptr9.add(16).cast::<*mut u8>() = ptr69.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message70,
+                                        line: line70,
+                                        column: column70,
+                                    } = e;
+                                    let vec71 = (message70.into_bytes()).into_boxed_slice();
+                                    let ptr71 = vec71.as_ptr().cast::<u8>();
+                                    let len71 = vec71.len();
+                                    ::core::mem::forget(vec71);
+                                    *ptr9.add(20).cast::<usize>() = len71;
+                                    *ptr9.add(16).cast::<*mut u8>() = ptr71.cast_mut();
+                                    *ptr9.add(24).cast::<i32>() = _rt::as_i32(line70);
+                                    *ptr9.add(28).cast::<i32>() = _rt::as_i32(column70);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr9.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec72 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr72 = vec72.as_ptr().cast::<u8>();
+                                    let len72 = vec72.len();
+                                    ::core::mem::forget(vec72);
+                                    *ptr9.add(20).cast::<usize>() = len72;
+                                    *ptr9.add(16).cast::<*mut u8>() = ptr72.cast_mut();
+                                }
+                            }
                         }
                     };
-                    ptr3
+                    ptr9
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn __post_return_method_graph_add_component<T: GuestGraph>(
+                pub unsafe fn __post_return_method_graph_load_wac<T: GuestGraph>(
                     arg0: *mut u8,
                 ) {
                     let l0 = i32::from(*arg0.add(0).cast::<u8>());
@@ -413,305 +13136,1634 @@ pub mod exports {
                         0 => {
                             let l1 = *arg0.add(8).cast::<*mut u8>();
                             let l2 = *arg0.add(12).cast::<usize>();
-                            _rt::cabi_dealloc(l1, l2, 1);
-                            let l3 = *arg0.add(16).cast::<*mut u8>();
-                            let l4 = *arg0.add(20).cast::<usize>();
-                            let base7 = l3;
-                            let len7 = l4;
-                            for i in 0..len7 {
-                                let base = base7.add(i * 12);
+                            let base75 = l1;
+                            let len75 = l2;
+                            for i in 0..len75 {
+                                let base = base75.add(i * 168);
                                 {
-                                    let l5 = *base.add(0).cast::<*mut u8>();
-                                    let l6 = *base.add(4).cast::<usize>();
+                                    let l3 = *base.add(4).cast::<*mut u8>();
+                                    let l4 = *base.add(8).cast::<usize>();
+                                    _rt::cabi_dealloc(l3, l4, 1);
+                                    let l5 = *base.add(12).cast::<*mut u8>();
+                                    let l6 = *base.add(16).cast::<usize>();
                                     _rt::cabi_dealloc(l5, l6, 1);
+                                    let l7 = i32::from(*base.add(20).cast::<u8>());
+                                    match l7 {
+                                        0 => {}
+                                        _ => {
+                                            let l8 = *base.add(24).cast::<*mut u8>();
+                                            let l9 = *base.add(28).cast::<usize>();
+                                            _rt::cabi_dealloc(l8, l9, 1);
+                                        }
+                                    }
+                                    let l10 = *base.add(32).cast::<*mut u8>();
+                                    let l11 = *base.add(36).cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                    let l12 = *base.add(40).cast::<*mut u8>();
+                                    let l13 = *base.add(44).cast::<usize>();
+                                    let base25 = l12;
+                                    let len25 = l13;
+                                    for i in 0..len25 {
+                                        let base = base25.add(i * 28);
+                                        {
+                                            let l14 = *base.add(0).cast::<*mut u8>();
+                                            let l15 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l14, l15, 1);
+                                            let l16 = *base.add(12).cast::<*mut u8>();
+                                            let l17 = *base.add(16).cast::<usize>();
+                                            _rt::cabi_dealloc(l16, l17, 1);
+                                            let l18 = *base.add(20).cast::<*mut u8>();
+                                            let l19 = *base.add(24).cast::<usize>();
+                                            let base24 = l18;
+                                            let len24 = l19;
+                                            for i in 0..len24 {
+                                                let base = base24.add(i * 20);
+                                                {
+                                                    let l20 = *base.add(0).cast::<*mut u8>();
+                                                    let l21 = *base.add(4).cast::<usize>();
+                                                    _rt::cabi_dealloc(l20, l21, 1);
+                                                    let l22 = *base.add(12).cast::<*mut u8>();
+                                                    let l23 = *base.add(16).cast::<usize>();
+                                                    _rt::cabi_dealloc(l22, l23, 1);
+                                                }
+                                            }
+                                            _rt::cabi_dealloc(base24, len24 * 20, 4);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base25, len25 * 28, 4);
+                                    let l26 = *base.add(48).cast::<*mut u8>();
+                                    let l27 = *base.add(52).cast::<usize>();
+                                    let base39 = l26;
+                                    let len39 = l27;
+                                    for i in 0..len39 {
+                                        let base = base39.add(i * 28);
+                                        {
+                                            let l28 = *base.add(0).cast::<*mut u8>();
+                                            let l29 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l28, l29, 1);
+                                            let l30 = *base.add(12).cast::<*mut u8>();
+                                            let l31 = *base.add(16).cast::<usize>();
+                                            _rt::cabi_dealloc(l30, l31, 1);
+                                            let l32 = *base.add(20).cast::<*mut u8>();
+                                            let l33 = *base.add(24).cast::<usize>();
+                                            let base38 = l32;
+                                            let len38 = l33;
+                                            for i in 0..len38 {
+                                                let base = base38.add(i * 20);
+                                                {
+                                                    let l34 = *base.add(0).cast::<*mut u8>();
+                                                    let l35 = *base.add(4).cast::<usize>();
+                                                    _rt::cabi_dealloc(l34, l35, 1);
+                                                    let l36 = *base.add(12).cast::<*mut u8>();
+                                                    let l37 = *base.add(16).cast::<usize>();
+                                                    _rt::cabi_dealloc(l36, l37, 1);
+                                                }
+                                            }
+                                            _rt::cabi_dealloc(base38, len38 * 20, 4);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base39, len39 * 28, 4);
+                                    let l40 = *base.add(56).cast::<*mut u8>();
+                                    let l41 = *base.add(60).cast::<usize>();
+                                    _rt::cabi_dealloc(l40, l41, 1);
+                                    let l42 = i32::from(*base.add(64).cast::<u8>());
+                                    match l42 {
+                                        0 => {}
+                                        _ => {
+                                            let l43 = *base.add(68).cast::<*mut u8>();
+                                            let l44 = *base.add(72).cast::<usize>();
+                                            _rt::cabi_dealloc(l43, l44, 1);
+                                        }
+                                    }
+                                    let l45 = *base.add(76).cast::<*mut u8>();
+                                    let l46 = *base.add(80).cast::<usize>();
+                                    let base53 = l45;
+                                    let len53 = l46;
+                                    for i in 0..len53 {
+                                        let base = base53.add(i * 24);
+                                        {
+                                            let l47 = *base.add(0).cast::<*mut u8>();
+                                            let l48 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l47, l48, 1);
+                                            let l49 = *base.add(8).cast::<*mut u8>();
+                                            let l50 = *base.add(12).cast::<usize>();
+                                            _rt::cabi_dealloc(l49, l50, 1);
+                                            let l51 = *base.add(16).cast::<*mut u8>();
+                                            let l52 = *base.add(20).cast::<usize>();
+                                            _rt::cabi_dealloc(l51, l52, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base53, len53 * 24, 4);
+                                    let l54 = i32::from(*base.add(84).cast::<u8>());
+                                    match l54 {
+                                        0 => {}
+                                        _ => {
+                                            let l55 = *base.add(88).cast::<*mut u8>();
+                                            let l56 = *base.add(92).cast::<usize>();
+                                            _rt::cabi_dealloc(l55, l56, 1);
+                                        }
+                                    }
+                                    let l57 = i32::from(*base.add(96).cast::<u8>());
+                                    match l57 {
+                                        0 => {}
+                                        _ => {
+                                            let l58 = *base.add(100).cast::<*mut u8>();
+                                            let l59 = *base.add(104).cast::<usize>();
+                                            _rt::cabi_dealloc(l58, l59, 1);
+                                        }
+                                    }
+                                    let l60 = i32::from(*base.add(108).cast::<u8>());
+                                    match l60 {
+                                        0 => {}
+                                        _ => {
+                                            let l61 = *base.add(112).cast::<*mut u8>();
+                                            let l62 = *base.add(116).cast::<usize>();
+                                            _rt::cabi_dealloc(l61, l62, 1);
+                                        }
+                                    }
+                                    let l63 = i32::from(*base.add(120).cast::<u8>());
+                                    match l63 {
+                                        0 => {}
+                                        _ => {
+                                            let l64 = *base.add(124).cast::<*mut u8>();
+                                            let l65 = *base.add(128).cast::<usize>();
+                                            _rt::cabi_dealloc(l64, l65, 1);
+                                        }
+                                    }
+                                    let l66 = i32::from(*base.add(132).cast::<u8>());
+                                    match l66 {
+                                        0 => {}
+                                        _ => {
+                                            let l67 = *base.add(136).cast::<*mut u8>();
+                                            let l68 = *base.add(140).cast::<usize>();
+                                            _rt::cabi_dealloc(l67, l68, 1);
+                                        }
+                                    }
+                                    let l69 = i32::from(*base.add(144).cast::<u8>());
+                                    match l69 {
+                                        0 => {}
+                                        _ => {
+                                            let l70 = *base.add(148).cast::<*mut u8>();
+                                            let l71 = *base.add(152).cast::<usize>();
+                                            _rt::cabi_dealloc(l70, l71, 1);
+                                        }
+                                    }
+                                    let l72 = i32::from(*base.add(156).cast::<u8>());
+                                    match l72 {
+                                        0 => {}
+                                        _ => {
+                                            let l73 = *base.add(160).cast::<*mut u8>();
+                                            let l74 = *base.add(164).cast::<usize>();
+                                            _rt::cabi_dealloc(l73, l74, 1);
+                                        }
+                                    }
                                 }
                             }
-                            _rt::cabi_dealloc(base7, len7 * 12, 4);
-                            let l8 = *arg0.add(24).cast::<*mut u8>();
-                            let l9 = *arg0.add(28).cast::<usize>();
-                            let base12 = l8;
-                            let len12 = l9;
-                            for i in 0..len12 {
-                                let base = base12.add(i * 12);
+                            _rt::cabi_dealloc(base75, len75 * 168, 4);
+                            let l76 = *arg0.add(16).cast::<*mut u8>();
+                            let l77 = *arg0.add(20).cast::<usize>();
+                            let base81 = l76;
+                            let len81 = l77;
+                            for i in 0..len81 {
+                                let base = base81.add(i * 20);
                                 {
-                                    let l10 = *base.add(0).cast::<*mut u8>();
-                                    let l11 = *base.add(4).cast::<usize>();
-                                    _rt::cabi_dealloc(l10, l11, 1);
+                                    let l78 = i32::from(*base.add(8).cast::<u8>());
+                                    match l78 {
+                                        0 => {}
+                                        _ => {
+                                            let l79 = *base.add(12).cast::<*mut u8>();
+                                            let l80 = *base.add(16).cast::<usize>();
+                                            _rt::cabi_dealloc(l79, l80, 1);
+                                        }
+                                    }
                                 }
                             }
-                            _rt::cabi_dealloc(base12, len12 * 12, 4);
-                            let l13 = *arg0.add(32).cast::<*mut u8>();
-                            let l14 = *arg0.add(36).cast::<usize>();
-                            _rt::cabi_dealloc(l13, l14, 1);
-                        }
-                        _ => {
-                            let l15 = *arg0.add(4).cast::<*mut u8>();
-                            let l16 = *arg0.add(8).cast::<usize>();
-                            _rt::cabi_dealloc(l15, l16, 1);
-                        }
-                    }
-                }
-                #[doc(hidden)]
-                #[allow(non_snake_case)]
-                pub unsafe fn _export_method_graph_instantiate_component_cabi<
-                    T: GuestGraph,
-                >(arg0: *mut u8, arg1: i32) -> *mut u8 {
-                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::instantiate_component(
-                        GraphBorrow::lift(arg0 as u32 as usize).get(),
-                        arg1 as u32,
-                    );
-                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
-                    match result0 {
-                        Ok(e) => {
-                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
-                            *ptr1.add(4).cast::<i32>() = _rt::as_i32(e);
-                        }
-                        Err(e) => {
-                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
-                            let vec2 = (e.into_bytes()).into_boxed_slice();
-                            let ptr2 = vec2.as_ptr().cast::<u8>();
-                            let len2 = vec2.len();
-                            ::core::mem::forget(vec2);
-                            *ptr1.add(8).cast::<usize>() = len2;
-                            *ptr1.add(4).cast::<*mut u8>() = ptr2.cast_mut();
+                            _rt::cabi_dealloc(base81, len81 * 20, 4);
+                            let l82 = *arg0.add(24).cast::<*mut u8>();
+                            let l83 = *arg0.add(28).cast::<usize>();
+                            let base84 = l82;
+                            let len84 = l83;
+                            _rt::cabi_dealloc(base84, len84 * 20, 4);
+                            let l85 = *arg0.add(40).cast::<*mut u8>();
+                            let l86 = *arg0.add(44).cast::<usize>();
+                            let base89 = l85;
+                            let len89 = l86;
+                            for i in 0..len89 {
+                                let base = base89.add(i * 20);
+                                {
+                                    let l87 = *base.add(12).cast::<*mut u8>();
+                                    let l88 = *base.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l87, l88, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base89, len89 * 20, 4);
                         }
-                    };
-                    ptr1
-                }
-                #[doc(hidden)]
-                #[allow(non_snake_case)]
-                pub unsafe fn __post_return_method_graph_instantiate_component<
-                    T: GuestGraph,
-                >(arg0: *mut u8) {
-                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
-                    match l0 {
-                        0 => {}
                         _ => {
-                            let l1 = *arg0.add(4).cast::<*mut u8>();
-                            let l2 = *arg0.add(8).cast::<usize>();
-                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l90 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l90 {
+                                0 => {
+                                    let l91 = *arg0.add(16).cast::<*mut u8>();
+                                    let l92 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l91, l92, 1);
+                                }
+                                1 => {
+                                    let l93 = *arg0.add(16).cast::<*mut u8>();
+                                    let l94 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l93, l94, 1);
+                                }
+                                2 => {
+                                    let l95 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l95 {
+                                        0 => {}
+                                        _ => {
+                                            let l96 = *arg0.add(20).cast::<*mut u8>();
+                                            let l97 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l96, l97, 1);
+                                        }
+                                    }
+                                    let l98 = *arg0.add(28).cast::<*mut u8>();
+                                    let l99 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l98, l99, 1);
+                                    let l100 = *arg0.add(36).cast::<*mut u8>();
+                                    let l101 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l100, l101, 1);
+                                }
+                                3 => {
+                                    let l102 = *arg0.add(16).cast::<*mut u8>();
+                                    let l103 = *arg0.add(20).cast::<usize>();
+                                    let base104 = l102;
+                                    let len104 = l103;
+                                    _rt::cabi_dealloc(base104, len104 * 4, 4);
+                                    let l105 = *arg0.add(24).cast::<*mut u8>();
+                                    let l106 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l105, l106, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l107 = *arg0.add(16).cast::<*mut u8>();
+                                    let l108 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l107, l108, 1);
+                                }
+                                7 => {
+                                    let l109 = *arg0.add(16).cast::<*mut u8>();
+                                    let l110 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l109, l110, 1);
+                                }
+                                8 => {
+                                    let l111 = *arg0.add(16).cast::<*mut u8>();
+                                    let l112 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l111, l112, 1);
+                                }
+                                9 => {
+                                    let l113 = *arg0.add(16).cast::<*mut u8>();
+                                    let l114 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l113, l114, 1);
+                                }
+                                _ => {
+                                    let l115 = *arg0.add(16).cast::<*mut u8>();
+                                    let l116 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l115, l116, 1);
+                                }
+                            }
                         }
                     }
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_method_graph_connect_instances_cabi<T: GuestGraph>(
+                pub unsafe fn _export_method_graph_save_project_cabi<T: GuestGraph>(
                     arg0: *mut u8,
                     arg1: i32,
                     arg2: i32,
                     arg3: i32,
-                    arg4: i32,
-                    arg5: i32,
+                    arg4: *mut u8,
+                    arg5: usize,
+                    arg6: i32,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::connect_instances(
-                        GraphBorrow::lift(arg0 as u32 as usize).get(),
-                        arg1 as u32,
-                        match arg2 {
-                            0 => None,
-                            1 => {
-                                let e = arg3 as u32;
-                                Some(e)
+                    let base6 = arg4;
+                    let len6 = arg5;
+                    let mut result6 = _rt::Vec::with_capacity(len6);
+                    for i in 0..len6 {
+                        let base = base6.add(i * 20);
+                        let e6 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = i32::from(*base.add(4).cast::<u8>());
+                            let l3 = *base.add(12).cast::<*mut u8>();
+                            let l4 = *base.add(16).cast::<usize>();
+                            let len5 = l4;
+                            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+                            ExportItem {
+                                instance: l0 as u32,
+                                export: match l1 {
+                                    0 => None,
+                                    1 => {
+                                        let e = {
+                                            let l2 = *base.add(8).cast::<i32>();
+                                            l2 as u32
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                name: _rt::string_lift(bytes5),
                             }
-                            _ => _rt::invalid_enum_discriminant(),
+                        };
+                        result6.push(e6);
+                    }
+                    _rt::cabi_dealloc(base6, len6 * 20, 4);
+                    let result7 = T::save_project(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        EncodeOptions {
+                            define_components: _rt::bool_lift(arg1 as u8),
+                            export: match arg2 {
+                                0 => None,
+                                1 => {
+                                    let e = arg3 as u32;
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            exports: result6,
+                            validate: _rt::bool_lift(arg6 as u8),
                         },
-                        arg4 as u32,
-                        arg5 as u32,
                     );
-                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
-                    match result0 {
-                        Ok(_) => {
-                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                    let ptr8 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result7 {
+                        Ok(e) => {
+                            *ptr8.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec9 = (e).into_boxed_slice();
+                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                            let len9 = vec9.len();
+                            ::core::mem::forget(vec9);
+                            *ptr8.add(12).cast::<usize>() = len9;
+                            *ptr8.add(8).cast::<*mut u8>() = ptr9.cast_mut();
                         }
                         Err(e) => {
-                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
-                            let vec2 = (e.into_bytes()).into_boxed_slice();
-                            let ptr2 = vec2.as_ptr().cast::<u8>();
-                            let len2 = vec2.len();
-                            ::core::mem::forget(vec2);
-                            *ptr1.add(8).cast::<usize>() = len2;
-                            *ptr1.add(4).cast::<*mut u8>() = ptr2.cast_mut();
+                            *ptr8.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message10, offset: offset10 } = e;
+                                    let vec11 = (message10.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr8.add(20).cast::<usize>() = len11;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                    match offset10 {
+                                        Some(e) => {
+                                            *ptr8.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr8.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr8.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message12, offset: offset12 } = e;
+                                    let vec13 = (message12.into_bytes()).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr8.add(20).cast::<usize>() = len13;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr13.cast_mut();
+                                    match offset12 {
+                                        Some(e) => {
+                                            *ptr8.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr8.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr8.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export14,
+                                        import: import14,
+                                        message: message14,
+                                    } = e;
+                                    match export14 {
+                                        Some(e) => {
+                                            *ptr8.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec15 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr15 = vec15.as_ptr().cast::<u8>();
+                                            let len15 = vec15.len();
+                                            ::core::mem::forget(vec15);
+                                            *ptr8.add(24).cast::<usize>() = len15;
+                                            *ptr8.add(20).cast::<*mut u8>() = ptr15.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr8.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec16 = (import14.into_bytes()).into_boxed_slice();
+                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                    let len16 = vec16.len();
+                                    ::core::mem::forget(vec16);
+                                    *ptr8.add(32).cast::<usize>() = len16;
+                                    *ptr8.add(28).cast::<*mut u8>() = ptr16.cast_mut();
+                                    let vec17 = (message14.into_bytes()).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr8.add(40).cast::<usize>() = len17;
+                                    *ptr8.add(36).cast::<*mut u8>() = ptr17.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances18,
+                                        message: message18,
+                                    } = e;
+                                    let vec19 = (instances18).into_boxed_slice();
+                                    let ptr19 = vec19.as_ptr().cast::<u8>();
+                                    let len19 = vec19.len();
+                                    ::core::mem::forget(vec19);
+                                    *ptr8.add(20).cast::<usize>() = len19;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr19.cast_mut();
+                                    let vec20 = (message18.into_bytes()).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *ptr8.add(28).cast::<usize>() = len20;
+                                    *ptr8.add(24).cast::<*mut u8>() = ptr20.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr8.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr8.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec21 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                    let len21 = vec21.len();
+                                    ::core::mem::forget(vec21);
+                                    *ptr8.add(20).cast::<usize>() = len21;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr21.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec22 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr22 = vec22.as_ptr().cast::<u8>();
+                                    let len22 = vec22.len();
+                                    ::core::mem::forget(vec22);
+                                    *ptr8.add(20).cast::<usize>() = len22;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr22.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec23 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr23 = vec23.as_ptr().cast::<u8>();
+                                    let len23 = vec23.len();
+                                    ::core::mem::forget(vec23);
+                                    *ptr8.add(20).cast::<usize>() = len23;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr23.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message24,
+                                        line: line24,
+                                        column: column24,
+                                    } = e;
+                                    let vec25 = (message24.into_bytes()).into_boxed_slice();
+                                    let ptr25 = vec25.as_ptr().cast::<u8>();
+                                    let len25 = vec25.len();
+                                    ::core::mem::forget(vec25);
+                                    *ptr8.add(20).cast::<usize>() = len25;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr25.cast_mut();
+                                    *ptr8.add(24).cast::<i32>() = _rt::as_i32(line24);
+                                    *ptr8.add(28).cast::<i32>() = _rt::as_i32(column24);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr8.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec26 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr26 = vec26.as_ptr().cast::<u8>();
+                                    let len26 = vec26.len();
+                                    ::core::mem::forget(vec26);
+                                    *ptr8.add(20).cast::<usize>() = len26;
+                                    *ptr8.add(16).cast::<*mut u8>() = ptr26.cast_mut();
+                                }
+                            }
                         }
                     };
-                    ptr1
-                }
-                #[doc(hidden)]
-                #[allow(non_snake_case)]
-                pub unsafe fn __post_return_method_graph_connect_instances<
-                    T: GuestGraph,
-                >(arg0: *mut u8) {
-                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
-                    match l0 {
-                        0 => {}
-                        _ => {
-                            let l1 = *arg0.add(4).cast::<*mut u8>();
-                            let l2 = *arg0.add(8).cast::<usize>();
-                            _rt::cabi_dealloc(l1, l2, 1);
-                        }
-                    }
-                }
-                #[doc(hidden)]
-                #[allow(non_snake_case)]
-                pub unsafe fn _export_method_graph_remove_component_cabi<T: GuestGraph>(
-                    arg0: *mut u8,
-                    arg1: i32,
-                ) {
-                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    T::remove_component(
-                        GraphBorrow::lift(arg0 as u32 as usize).get(),
-                        arg1 as u32,
-                    );
+                    ptr8
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_method_graph_remove_instance_cabi<T: GuestGraph>(
+                pub unsafe fn __post_return_method_graph_save_project<T: GuestGraph>(
                     arg0: *mut u8,
-                    arg1: i32,
                 ) {
-                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    T::remove_instance(
-                        GraphBorrow::lift(arg0 as u32 as usize).get(),
-                        arg1 as u32,
-                    );
-                }
-                #[doc(hidden)]
-                #[allow(non_snake_case)]
-                pub unsafe fn _export_method_graph_disconnect_instances_cabi<
-                    T: GuestGraph,
-                >(arg0: *mut u8, arg1: i32, arg2: i32, arg3: i32) -> *mut u8 {
-                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::disconnect_instances(
-                        GraphBorrow::lift(arg0 as u32 as usize).get(),
-                        arg1 as u32,
-                        arg2 as u32,
-                        arg3 as u32,
-                    );
-                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
-                    match result0 {
-                        Ok(_) => {
-                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
-                        }
-                        Err(e) => {
-                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
-                            let vec2 = (e.into_bytes()).into_boxed_slice();
-                            let ptr2 = vec2.as_ptr().cast::<u8>();
-                            let len2 = vec2.len();
-                            ::core::mem::forget(vec2);
-                            *ptr1.add(8).cast::<usize>() = len2;
-                            *ptr1.add(4).cast::<*mut u8>() = ptr2.cast_mut();
-                        }
-                    };
-                    ptr1
-                }
-                #[doc(hidden)]
-                #[allow(non_snake_case)]
-                pub unsafe fn __post_return_method_graph_disconnect_instances<
-                    T: GuestGraph,
-                >(arg0: *mut u8) {
                     let l0 = i32::from(*arg0.add(0).cast::<u8>());
                     match l0 {
-                        0 => {}
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            let base3 = l1;
+                            let len3 = l2;
+                            _rt::cabi_dealloc(base3, len3 * 1, 1);
+                        }
                         _ => {
-                            let l1 = *arg0.add(4).cast::<*mut u8>();
-                            let l2 = *arg0.add(8).cast::<usize>();
-                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l4 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l4 {
+                                0 => {
+                                    let l5 = *arg0.add(16).cast::<*mut u8>();
+                                    let l6 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                }
+                                1 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l7, l8, 1);
+                                }
+                                2 => {
+                                    let l9 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l9 {
+                                        0 => {}
+                                        _ => {
+                                            let l10 = *arg0.add(20).cast::<*mut u8>();
+                                            let l11 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l10, l11, 1);
+                                        }
+                                    }
+                                    let l12 = *arg0.add(28).cast::<*mut u8>();
+                                    let l13 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                    let l14 = *arg0.add(36).cast::<*mut u8>();
+                                    let l15 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l14, l15, 1);
+                                }
+                                3 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 4, 4);
+                                    let l19 = *arg0.add(24).cast::<*mut u8>();
+                                    let l20 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l19, l20, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l21 = *arg0.add(16).cast::<*mut u8>();
+                                    let l22 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l21, l22, 1);
+                                }
+                                7 => {
+                                    let l23 = *arg0.add(16).cast::<*mut u8>();
+                                    let l24 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l23, l24, 1);
+                                }
+                                8 => {
+                                    let l25 = *arg0.add(16).cast::<*mut u8>();
+                                    let l26 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l25, l26, 1);
+                                }
+                                9 => {
+                                    let l27 = *arg0.add(16).cast::<*mut u8>();
+                                    let l28 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l27, l28, 1);
+                                }
+                                _ => {
+                                    let l29 = *arg0.add(16).cast::<*mut u8>();
+                                    let l30 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l29, l30, 1);
+                                }
+                            }
                         }
                     }
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_method_graph_print_graph_cabi<T: GuestGraph>(
-                    arg0: *mut u8,
-                ) -> *mut u8 {
-                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::print_graph(
-                        GraphBorrow::lift(arg0 as u32 as usize).get(),
-                    );
-                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
-                    let vec2 = (result0.into_bytes()).into_boxed_slice();
-                    let ptr2 = vec2.as_ptr().cast::<u8>();
-                    let len2 = vec2.len();
-                    ::core::mem::forget(vec2);
-                    *ptr1.add(4).cast::<usize>() = len2;
-                    *ptr1.add(0).cast::<*mut u8>() = ptr2.cast_mut();
-                    ptr1
-                }
-                #[doc(hidden)]
-                #[allow(non_snake_case)]
-                pub unsafe fn __post_return_method_graph_print_graph<T: GuestGraph>(
-                    arg0: *mut u8,
-                ) {
-                    let l0 = *arg0.add(0).cast::<*mut u8>();
-                    let l1 = *arg0.add(4).cast::<usize>();
-                    _rt::cabi_dealloc(l0, l1, 1);
-                }
-                #[doc(hidden)]
-                #[allow(non_snake_case)]
-                pub unsafe fn _export_method_graph_encode_graph_cabi<T: GuestGraph>(
-                    arg0: *mut u8,
-                    arg1: i32,
-                    arg2: i32,
-                    arg3: i32,
-                    arg4: i32,
-                ) -> *mut u8 {
-                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::encode_graph(
-                        GraphBorrow::lift(arg0 as u32 as usize).get(),
-                        EncodeOptions {
-                            define_components: _rt::bool_lift(arg1 as u8),
-                            export: match arg2 {
-                                0 => None,
-                                1 => {
-                                    let e = arg3 as u32;
-                                    Some(e)
-                                }
-                                _ => _rt::invalid_enum_discriminant(),
-                            },
-                            validate: _rt::bool_lift(arg4 as u8),
-                        },
+                pub unsafe fn _export_method_graph_load_project_cabi<T: GuestGraph>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let result1 = T::load_project(
+                        GraphBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::Vec::from_raw_parts(arg1.cast(), len0, len0),
                     );
-                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
-                    match result0 {
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
                         Ok(e) => {
-                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
-                            let vec2 = (e).into_boxed_slice();
-                            let ptr2 = vec2.as_ptr().cast::<u8>();
-                            let len2 = vec2.len();
-                            ::core::mem::forget(vec2);
-                            *ptr1.add(8).cast::<usize>() = len2;
-                            *ptr1.add(4).cast::<*mut u8>() = ptr2.cast_mut();
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            let Project {
+                                components: components3,
+                                instances: instances3,
+                                connections: connections3,
+                                export: export3,
+                                exports: exports3,
+                            } = e;
+                            let vec40 = components3;
+                            let len40 = vec40.len();
+                            let layout40 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec40.len() * 168,
+                                4,
+                            );
+                            let result40 = if layout40.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout40).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout40);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec40.into_iter().enumerate() {
+                                let base = result40.add(i * 168);
+                                {
+                                    let Component {
+                                        id: id4,
+                                        name: name4,
+                                        package_name: package_name4,
+                                        version: version4,
+                                        digest: digest4,
+                                        imports: imports4,
+                                        exports: exports4,
+                                        wit: wit4,
+                                        metadata: metadata4,
+                                    } = e;
+                                    *base.add(0).cast::<i32>() = _rt::as_i32(id4);
+                                    let vec5 = (name4.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *base.add(8).cast::<usize>() = len5;
+                                    *base.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+                                    let vec6 = (package_name4.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *base.add(16).cast::<usize>() = len6;
+                                    *base.add(12).cast::<*mut u8>() = ptr6.cast_mut();
+                                    match version4 {
+                                        Some(e) => {
+                                            *base.add(20).cast::<u8>() = (1i32) as u8;
+                                            let vec7 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                                            let len7 = vec7.len();
+                                            ::core::mem::forget(vec7);
+                                            *base.add(28).cast::<usize>() = len7;
+                                            *base.add(24).cast::<*mut u8>() = ptr7.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(20).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec8 = (digest4.into_bytes()).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *base.add(36).cast::<usize>() = len8;
+                                    *base.add(32).cast::<*mut u8>() = ptr8.cast_mut();
+                                    let vec16 = imports4;
+                                    let len16 = vec16.len();
+                                    let layout16 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec16.len() * 28,
+                                        4,
+                                    );
+                                    let result16 = if layout16.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout16).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout16);
+                                        }
+                                        ptr
+                                    } else {
+                                        { ::core::ptr::null_mut() }
+                                    };
+                                    for (i, e) in vec16.into_iter().enumerate() {
+                                        let base = result16.add(i * 28);
+                                        {
+                                            let Import {
+                                                name: name9,
+                                                kind: kind9,
+                                                signature: signature9,
+                                                members: members9,
+                                            } = e;
+                                            let vec10 = (name9.into_bytes()).into_boxed_slice();
+                                            let ptr10 = vec10.as_ptr().cast::<u8>();
+                                            let len10 = vec10.len();
+                                            ::core::mem::forget(vec10);
+                                            *base.add(4).cast::<usize>() = len10;
+                                            *base.add(0).cast::<*mut u8>() = ptr10.cast_mut();
+                                            *base.add(8).cast::<u8>() = (kind9.clone() as i32) as u8;
+                                            let vec11 = (signature9.into_bytes()).into_boxed_slice();
+                                            let ptr11 = vec11.as_ptr().cast::<u8>();
+                                            let len11 = vec11.len();
+                                            ::core::mem::forget(vec11);
+                                            *base.add(16).cast::<usize>() = len11;
+                                            *base.add(12).cast::<*mut u8>() = ptr11.cast_mut();
+                                            let vec15 = members9;
+                                            let len15 = vec15.len();
+                                            let layout15 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec15.len() * 20,
+                                                4,
+                                            );
+                                            let result15 = if layout15.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout15).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout15);
+                                                }
+                                                ptr
+                                            } else {
+                                                { ::core::ptr::null_mut() }
+                                            };
+                                            for (i, e) in vec15.into_iter().enumerate() {
+                                                let base = result15.add(i * 20);
+                                                {
+                                                    let Member {
+                                                        name: name12,
+                                                        kind: kind12,
+                                                        signature: signature12,
+                                                    } = e;
+                                                    let vec13 = (name12.into_bytes()).into_boxed_slice();
+                                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                                    let len13 = vec13.len();
+                                                    ::core::mem::forget(vec13);
+                                                    *base.add(4).cast::<usize>() = len13;
+                                                    *base.add(0).cast::<*mut u8>() = ptr13.cast_mut();
+                                                    *base.add(8).cast::<u8>() = (kind12.clone() as i32) as u8;
+                                                    let vec14 = (signature12.into_bytes()).into_boxed_slice();
+                                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                                    let len14 = vec14.len();
+                                                    ::core::mem::forget(vec14);
+                                                    *base.add(16).cast::<usize>() = len14;
+                                                    *base.add(12).cast::<*mut u8>() = ptr14.cast_mut();
+                                                }
+                                            }
+                                            *base.add(24).cast::<usize>() = len15;
+                                            *base.add(20).cast::<*mut u8>() = result15;
+                                        }
+                                    }
+                                    *base.add(44).cast::<usize>() = len16;
+                                    *base.add(40).cast::<*mut u8>() = result16;
+                                    let vec24 = exports4;
+                                    let len24 = vec24.len();
+                                    let layout24 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec24.len() * 28,
+                                        4,
+                                    );
+                                    let result24 = if layout24.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout24).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout24);
+                                        }
+                                        ptr
+                                    } else {
+                                        { ::core::ptr::null_mut() }
+                                    };
+                                    for (i, e) in vec24.into_iter().enumerate() {
+                                        let base = result24.add(i * 28);
+                                        {
+                                            let Export {
+                                                name: name17,
+                                                kind: kind17,
+                                                signature: signature17,
+                                                members: members17,
+                                            } = e;
+                                            let vec18 = (name17.into_bytes()).into_boxed_slice();
+                                            let ptr18 = vec18.as_ptr().cast::<u8>();
+                                            let len18 = vec18.len();
+                                            ::core::mem::forget(vec18);
+                                            *base.add(4).cast::<usize>() = len18;
+                                            *base.add(0).cast::<*mut u8>() = ptr18.cast_mut();
+                                            *base.add(8).cast::<u8>() = (kind17.clone() as i32) as u8;
+                                            let vec19 = (signature17.into_bytes()).into_boxed_slice();
+                                            let ptr19 = vec19.as_ptr().cast::<u8>();
+                                            let len19 = vec19.len();
+                                            ::core::mem::forget(vec19);
+                                            *base.add(16).cast::<usize>() = len19;
+                                            *base.add(12).cast::<*mut u8>() = ptr19.cast_mut();
+                                            let vec23 = members17;
+                                            let len23 = vec23.len();
+                                            let layout23 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec23.len() * 20,
+                                                4,
+                                            );
+                                            let result23 = if layout23.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout23).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout23);
+                                                }
+                                                ptr
+                                            } else {
+                                                { ::core::ptr::null_mut() }
+                                            };
+                                            for (i, e) in vec23.into_iter().enumerate() {
+                                                let base = result23.add(i * 20);
+                                                {
+                                                    let Member {
+                                                        name: name20,
+                                                        kind: kind20,
+                                                        signature: signature20,
+                                                    } = e;
+                                                    let vec21 = (name20.into_bytes()).into_boxed_slice();
+                                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                                    let len21 = vec21.len();
+                                                    ::core::mem::forget(vec21);
+                                                    *base.add(4).cast::<usize>() = len21;
+                                                    *base.add(0).cast::<*mut u8>() = ptr21.cast_mut();
+                                                    *base.add(8).cast::<u8>() = (kind20.clone() as i32) as u8;
+                                                    let vec22 = (signature20.into_bytes()).into_boxed_slice();
+                                                    let ptr22 = vec22.as_ptr().cast::<u8>();
+                                                    let len22 = vec22.len();
+                                                    ::core::mem::forget(vec22);
+                                                    *base.add(16).cast::<usize>() = len22;
+                                                    *base.add(12).cast::<*mut u8>() = ptr22.cast_mut();
+                                                }
+                                            }
+                                            *base.add(24).cast::<usize>() = len23;
+                                            *base.add(20).cast::<*mut u8>() = result23;
+                                        }
+                                    }
+                                    *base.add(52).cast::<usize>() = len24;
+                                    *base.add(48).cast::<*mut u8>() = result24;
+                                    let vec25 = (wit4.into_bytes()).into_boxed_slice();
+                                    let ptr25 = vec25.as_ptr().cast::<u8>();
+                                    let len25 = vec25.len();
+                                    ::core::mem::forget(vec25);
+                                    *base.add(60).cast::<usize>() = len25;
+                                    *base.add(56).cast::<*mut u8>() = ptr25.cast_mut();
+                                    let ComponentMetadata {
+                                        name: name26,
+                                        producers: producers26,
+                                        authors: authors26,
+                                        description: description26,
+                                        licenses: licenses26,
+                                        source: source26,
+                                        homepage: homepage26,
+                                        revision: revision26,
+                                        version: version26,
+                                    } = metadata4;
+                                    match name26 {
+                                        Some(e) => {
+                                            *base.add(64).cast::<u8>() = (1i32) as u8;
+                                            let vec27 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr27 = vec27.as_ptr().cast::<u8>();
+                                            let len27 = vec27.len();
+                                            ::core::mem::forget(vec27);
+                                            *base.add(72).cast::<usize>() = len27;
+                                            *base.add(68).cast::<*mut u8>() = ptr27.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(64).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec32 = producers26;
+                                    let len32 = vec32.len();
+                                    let layout32 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec32.len() * 24,
+                                        4,
+                                    );
+                                    let result32 = if layout32.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout32).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout32);
+                                        }
+                                        ptr
+                                    } else {
+                                        { ::core::ptr::null_mut() }
+                                    };
+                                    for (i, e) in vec32.into_iter().enumerate() {
+                                        let base = result32.add(i * 24);
+                                        {
+                                            let Producer {
+                                                field: field28,
+                                                name: name28,
+                                                version: version28,
+                                            } = e;
+                                            let vec29 = (field28.into_bytes()).into_boxed_slice();
+                                            let ptr29 = vec29.as_ptr().cast::<u8>();
+                                            let len29 = vec29.len();
+                                            ::core::mem::forget(vec29);
+                                            *base.add(4).cast::<usize>() = len29;
+                                            *base.add(0).cast::<*mut u8>() = ptr29.cast_mut();
+                                            let vec30 = (name28.into_bytes()).into_boxed_slice();
+                                            let ptr30 = vec30.as_ptr().cast::<u8>();
+                                            let len30 = vec30.len();
+                                            ::core::mem::forget(vec30);
+                                            *base.add(12).cast::<usize>() = len30;
+                                            *base.add(8).cast::<*mut u8>() = ptr30.cast_mut();
+                                            let vec31 = (version28.into_bytes()).into_boxed_slice();
+                                            let ptr31 = vec31.as_ptr().cast::<u8>();
+                                            let len31 = vec31.len();
+                                            ::core::mem::forget(vec31);
+                                            *base.add(20).cast::<usize>() = len31;
+                                            *base.add(16).cast::<*mut u8>() = ptr31.cast_mut();
+                                        }
+                                    }
+                                    *base.add(80).cast::<usize>() = len32;
+                                    *base.add(76).cast::<*mut u8>() = result32;
+                                    match authors26 {
+                                        Some(e) => {
+                                            *base.add(84).cast::<u8>() = (1i32) as u8;
+                                            let vec33 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr33 = vec33.as_ptr().cast::<u8>();
+                                            let len33 = vec33.len();
+                                            ::core::mem::forget(vec33);
+                                            *base.add(92).cast::<usize>() = len33;
+                                            *base.add(88).cast::<*mut u8>() = ptr33.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(84).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match description26 {
+                                        Some(e) => {
+                                            *base.add(96).cast::<u8>() = (1i32) as u8;
+                                            let vec34 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr34 = vec34.as_ptr().cast::<u8>();
+                                            let len34 = vec34.len();
+                                            ::core::mem::forget(vec34);
+                                            *base.add(104).cast::<usize>() = len34;
+                                            *base.add(100).cast::<*mut u8>() = ptr34.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(96).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match licenses26 {
+                                        Some(e) => {
+                                            *base.add(108).cast::<u8>() = (1i32) as u8;
+                                            let vec35 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr35 = vec35.as_ptr().cast::<u8>();
+                                            let len35 = vec35.len();
+                                            ::core::mem::forget(vec35);
+                                            *base.add(116).cast::<usize>() = len35;
+                                            *base.add(112).cast::<*mut u8>() = ptr35.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(108).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match source26 {
+                                        Some(e) => {
+                                            *base.add(120).cast::<u8>() = (1i32) as u8;
+                                            let vec36 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr36 = vec36.as_ptr().cast::<u8>();
+                                            let len36 = vec36.len();
+                                            ::core::mem::forget(vec36);
+                                            *base.add(128).cast::<usize>() = len36;
+                                            *base.add(124).cast::<*mut u8>() = ptr36.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(120).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match homepage26 {
+                                        Some(e) => {
+                                            *base.add(132).cast::<u8>() = (1i32) as u8;
+                                            let vec37 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr37 = vec37.as_ptr().cast::<u8>();
+                                            let len37 = vec37.len();
+                                            ::core::mem::forget(vec37);
+                                            *base.add(140).cast::<usize>() = len37;
+                                            *base.add(136).cast::<*mut u8>() = ptr37.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(132).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match revision26 {
+                                        Some(e) => {
+                                            *base.add(144).cast::<u8>() = (1i32) as u8;
+                                            let vec38 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr38 = vec38.as_ptr().cast::<u8>();
+                                            let len38 = vec38.len();
+                                            ::core::mem::forget(vec38);
+                                            *base.add(152).cast::<usize>() = len38;
+                                            *base.add(148).cast::<*mut u8>() = ptr38.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(144).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match version26 {
+                                        Some(e) => {
+                                            *base.add(156).cast::<u8>() = (1i32) as u8;
+                                            let vec39 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr39 = vec39.as_ptr().cast::<u8>();
+                                            let len39 = vec39.len();
+                                            ::core::mem::forget(vec39);
+                                            *base.add(164).cast::<usize>() = len39;
+                                            *base.add(160).cast::<*mut u8>() = ptr39.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(156).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                            *ptr2.add(12).cast::<usize>() = len40;
+                            *ptr2.add(8).cast::<*mut u8>() = result40;
+                            let vec43 = instances3;
+                            let len43 = vec43.len();
+                            let layout43 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec43.len() * 20,
+                                4,
+                            );
+                            let result43 = if layout43.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout43).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout43);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec43.into_iter().enumerate() {
+                                let base = result43.add(i * 20);
+                                {
+                                    let Instance {
+                                        id: id41,
+                                        component: component41,
+                                        name: name41,
+                                    } = e;
+                                    *base.add(0).cast::<i32>() = _rt::as_i32(id41);
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(component41);
+                                    match name41 {
+                                        Some(e) => {
+                                            *base.add(8).cast::<u8>() = (1i32) as u8;
+                                            let vec42 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr42 = vec42.as_ptr().cast::<u8>();
+                                            let len42 = vec42.len();
+                                            ::core::mem::forget(vec42);
+                                            *base.add(16).cast::<usize>() = len42;
+                                            *base.add(12).cast::<*mut u8>() = ptr42.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(8).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                            *ptr2.add(20).cast::<usize>() = len43;
+                            *ptr2.add(16).cast::<*mut u8>() = result43;
+                            let vec45 = connections3;
+                            let len45 = vec45.len();
+                            let layout45 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec45.len() * 20,
+                                4,
+                            );
+                            let result45 = if layout45.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout45).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout45);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec45.into_iter().enumerate() {
+                                let base = result45.add(i * 20);
+                                {
+                                    let Connection {
+                                        source: source44,
+                                        source_export: source_export44,
+                                        target: target44,
+                                        target_import: target_import44,
+                                    } = e;
+                                    *base.add(0).cast::<i32>() = _rt::as_i32(source44);
+                                    match source_export44 {
+                                        Some(e) => {
+                                            *base.add(4).cast::<u8>() = (1i32) as u8;
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *base.add(4).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    *base.add(12).cast::<i32>() = _rt::as_i32(target44);
+                                    *base.add(16).cast::<i32>() = _rt::as_i32(target_import44);
+                                }
+                            }
+                            *ptr2.add(28).cast::<usize>() = len45;
+                            *ptr2.add(24).cast::<*mut u8>() = result45;
+                            match export3 {
+                                Some(e) => {
+                                    *ptr2.add(32).cast::<u8>() = (1i32) as u8;
+                                    *ptr2.add(36).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                None => {
+                                    *ptr2.add(32).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            let vec48 = exports3;
+                            let len48 = vec48.len();
+                            let layout48 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec48.len() * 20,
+                                4,
+                            );
+                            let result48 = if layout48.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout48).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout48);
+                                }
+                                ptr
+                            } else {
+                                { ::core::ptr::null_mut() }
+                            };
+                            for (i, e) in vec48.into_iter().enumerate() {
+                                let base = result48.add(i * 20);
+                                {
+                                    let ExportItem {
+                                        instance: instance46,
+                                        export: export46,
+                                        name: name46,
+                                    } = e;
+                                    *base.add(0).cast::<i32>() = _rt::as_i32(instance46);
+                                    match export46 {
+                                        Some(e) => {
+                                            *base.add(4).cast::<u8>() = (1i32) as u8;
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *base.add(4).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec47 = (name46.into_bytes()).into_boxed_slice();
+                                    let ptr47 = vec47.as_ptr().cast::<u8>();
+                                    let len47 = vec47.len();
+                                    ::core::mem::forget(vec47);
+                                    *base.add(16).cast::<usize>() = len47;
+                                    *base.add(12).cast::<*mut u8>() = ptr47.cast_mut();
+                                }
+                            }
+                            *ptr2.add(44).cast::<usize>() = len48;
+                            *ptr2.add(40).cast::<*mut u8>() = result48;
                         }
                         Err(e) => {
-                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
-                            let vec3 = (e.into_bytes()).into_boxed_slice();
-                            let ptr3 = vec3.as_ptr().cast::<u8>();
-                            let len3 = vec3.len();
-                            ::core::mem::forget(vec3);
-                            *ptr1.add(8).cast::<usize>() = len3;
-                            *ptr1.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                GraphError::Parse(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (0i32) as u8;
+                                    let WasmError { message: message49, offset: offset49 } = e;
+                                    let vec50 = (message49.into_bytes()).into_boxed_slice();
+                                    let ptr50 = vec50.as_ptr().cast::<u8>();
+                                    let len50 = vec50.len();
+                                    ::core::mem::forget(vec50);
+                                    *ptr2.add(20).cast::<usize>() = len50;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr50.cast_mut();
+                                    match offset49 {
+                                        Some(e) => {
+                                            *ptr2.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr2.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr2.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::Validation(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (1i32) as u8;
+                                    let WasmError { message: message51, offset: offset51 } = e;
+                                    let vec52 = (message51.into_bytes()).into_boxed_slice();
+                                    let ptr52 = vec52.as_ptr().cast::<u8>();
+                                    let len52 = vec52.len();
+                                    ::core::mem::forget(vec52);
+                                    *ptr2.add(20).cast::<usize>() = len52;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr52.cast_mut();
+                                    match offset51 {
+                                        Some(e) => {
+                                            *ptr2.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr2.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr2.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                GraphError::TypeMismatch(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (2i32) as u8;
+                                    let TypeMismatchError {
+                                        export: export53,
+                                        import: import53,
+                                        message: message53,
+                                    } = e;
+                                    match export53 {
+                                        Some(e) => {
+                                            *ptr2.add(16).cast::<u8>() = (1i32) as u8;
+                                            let vec54 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr54 = vec54.as_ptr().cast::<u8>();
+                                            let len54 = vec54.len();
+                                            ::core::mem::forget(vec54);
+                                            *ptr2.add(24).cast::<usize>() = len54;
+                                            *ptr2.add(20).cast::<*mut u8>() = ptr54.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr2.add(16).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec55 = (import53.into_bytes()).into_boxed_slice();
+                                    let ptr55 = vec55.as_ptr().cast::<u8>();
+                                    let len55 = vec55.len();
+                                    ::core::mem::forget(vec55);
+                                    *ptr2.add(32).cast::<usize>() = len55;
+                                    *ptr2.add(28).cast::<*mut u8>() = ptr55.cast_mut();
+                                    let vec56 = (message53.into_bytes()).into_boxed_slice();
+                                    let ptr56 = vec56.as_ptr().cast::<u8>();
+                                    let len56 = vec56.len();
+                                    ::core::mem::forget(vec56);
+                                    *ptr2.add(40).cast::<usize>() = len56;
+                                    *ptr2.add(36).cast::<*mut u8>() = ptr56.cast_mut();
+                                }
+                                GraphError::Cycle(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (3i32) as u8;
+                                    let CycleError {
+                                        instances: instances57,
+                                        message: message57,
+                                    } = e;
+                                    let vec58 = (instances57).into_boxed_slice();
+                                    let ptr58 = vec58.as_ptr().cast::<u8>();
+                                    let len58 = vec58.len();
+                                    ::core::mem::forget(vec58);
+                                    *ptr2.add(20).cast::<usize>() = len58;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr58.cast_mut();
+                                    let vec59 = (message57.into_bytes()).into_boxed_slice();
+                                    let ptr59 = vec59.as_ptr().cast::<u8>();
+                                    let len59 = vec59.len();
+                                    ::core::mem::forget(vec59);
+                                    *ptr2.add(28).cast::<usize>() = len59;
+                                    *ptr2.add(24).cast::<*mut u8>() = ptr59.cast_mut();
+                                }
+                                GraphError::UnknownComponent(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (4i32) as u8;
+                                    *ptr2.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::UnknownInstance(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (5i32) as u8;
+                                    *ptr2.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                GraphError::Encode(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (6i32) as u8;
+                                    let vec60 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr60 = vec60.as_ptr().cast::<u8>();
+                                    let len60 = vec60.len();
+                                    ::core::mem::forget(vec60);
+                                    *ptr2.add(20).cast::<usize>() = len60;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr60.cast_mut();
+                                }
+                                GraphError::InvalidOperation(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (7i32) as u8;
+                                    let vec61 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr61 = vec61.as_ptr().cast::<u8>();
+                                    let len61 = vec61.len();
+                                    ::core::mem::forget(vec61);
+                                    *ptr2.add(20).cast::<usize>() = len61;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr61.cast_mut();
+                                }
+                                GraphError::InvalidProject(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (8i32) as u8;
+                                    let vec62 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr62 = vec62.as_ptr().cast::<u8>();
+                                    let len62 = vec62.len();
+                                    ::core::mem::forget(vec62);
+                                    *ptr2.add(20).cast::<usize>() = len62;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr62.cast_mut();
+                                }
+                                GraphError::Wac(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (9i32) as u8;
+                                    let SourceError {
+                                        message: message63,
+                                        line: line63,
+                                        column: column63,
+                                    } = e;
+                                    let vec64 = (message63.into_bytes()).into_boxed_slice();
+                                    let ptr64 = vec64.as_ptr().cast::<u8>();
+                                    let len64 = vec64.len();
+                                    ::core::mem::forget(vec64);
+                                    *ptr2.add(20).cast::<usize>() = len64;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr64.cast_mut();
+                                    *ptr2.add(24).cast::<i32>() = _rt::as_i32(line63);
+                                    *ptr2.add(28).cast::<i32>() = _rt::as_i32(column63);
+                                }
+                                GraphError::Wit(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (10i32) as u8;
+                                    let vec65 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr65 = vec65.as_ptr().cast::<u8>();
+                                    let len65 = vec65.len();
+                                    ::core::mem::forget(vec65);
+                                    *ptr2.add(20).cast::<usize>() = len65;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr65.cast_mut();
+                                }
+                            }
                         }
                     };
-                    ptr1
+                    ptr2
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn __post_return_method_graph_encode_graph<T: GuestGraph>(
+                pub unsafe fn __post_return_method_graph_load_project<T: GuestGraph>(
                     arg0: *mut u8,
                 ) {
                     let l0 = i32::from(*arg0.add(0).cast::<u8>());
                     match l0 {
                         0 => {
-                            let l1 = *arg0.add(4).cast::<*mut u8>();
-                            let l2 = *arg0.add(8).cast::<usize>();
-                            let base3 = l1;
-                            let len3 = l2;
-                            _rt::cabi_dealloc(base3, len3 * 1, 1);
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            let base75 = l1;
+                            let len75 = l2;
+                            for i in 0..len75 {
+                                let base = base75.add(i * 168);
+                                {
+                                    let l3 = *base.add(4).cast::<*mut u8>();
+                                    let l4 = *base.add(8).cast::<usize>();
+                                    _rt::cabi_dealloc(l3, l4, 1);
+                                    let l5 = *base.add(12).cast::<*mut u8>();
+                                    let l6 = *base.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                    let l7 = i32::from(*base.add(20).cast::<u8>());
+                                    match l7 {
+                                        0 => {}
+                                        _ => {
+                                            let l8 = *base.add(24).cast::<*mut u8>();
+                                            let l9 = *base.add(28).cast::<usize>();
+                                            _rt::cabi_dealloc(l8, l9, 1);
+                                        }
+                                    }
+                                    let l10 = *base.add(32).cast::<*mut u8>();
+                                    let l11 = *base.add(36).cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                    let l12 = *base.add(40).cast::<*mut u8>();
+                                    let l13 = *base.add(44).cast::<usize>();
+                                    let base25 = l12;
+                                    let len25 = l13;
+                                    for i in 0..len25 {
+                                        let base = base25.add(i * 28);
+                                        {
+                                            let l14 = *base.add(0).cast::<*mut u8>();
+                                            let l15 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l14, l15, 1);
+                                            let l16 = *base.add(12).cast::<*mut u8>();
+                                            let l17 = *base.add(16).cast::<usize>();
+                                            _rt::cabi_dealloc(l16, l17, 1);
+                                            let l18 = *base.add(20).cast::<*mut u8>();
+                                            let l19 = *base.add(24).cast::<usize>();
+                                            let base24 = l18;
+                                            let len24 = l19;
+                                            for i in 0..len24 {
+                                                let base = base24.add(i * 20);
+                                                {
+                                                    let l20 = *base.add(0).cast::<*mut u8>();
+                                                    let l21 = *base.add(4).cast::<usize>();
+                                                    _rt::cabi_dealloc(l20, l21, 1);
+                                                    let l22 = *base.add(12).cast::<*mut u8>();
+                                                    let l23 = *base.add(16).cast::<usize>();
+                                                    _rt::cabi_dealloc(l22, l23, 1);
+                                                }
+                                            }
+                                            _rt::cabi_dealloc(base24, len24 * 20, 4);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base25, len25 * 28, 4);
+                                    let l26 = *base.add(48).cast::<*mut u8>();
+                                    let l27 = *base.add(52).cast::<usize>();
+                                    let base39 = l26;
+                                    let len39 = l27;
+                                    for i in 0..len39 {
+                                        let base = base39.add(i * 28);
+                                        {
+                                            let l28 = *base.add(0).cast::<*mut u8>();
+                                            let l29 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l28, l29, 1);
+                                            let l30 = *base.add(12).cast::<*mut u8>();
+                                            let l31 = *base.add(16).cast::<usize>();
+                                            _rt::cabi_dealloc(l30, l31, 1);
+                                            let l32 = *base.add(20).cast::<*mut u8>();
+                                            let l33 = *base.add(24).cast::<usize>();
+                                            let base38 = l32;
+                                            let len38 = l33;
+                                            for i in 0..len38 {
+                                                let base = base38.add(i * 20);
+                                                {
+                                                    let l34 = *base.add(0).cast::<*mut u8>();
+                                                    let l35 = *base.add(4).cast::<usize>();
+                                                    _rt::cabi_dealloc(l34, l35, 1);
+                                                    let l36 = *base.add(12).cast::<*mut u8>();
+                                                    let l37 = *base.add(16).cast::<usize>();
+                                                    _rt::cabi_dealloc(l36, l37, 1);
+                                                }
+                                            }
+                                            _rt::cabi_dealloc(base38, len38 * 20, 4);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base39, len39 * 28, 4);
+                                    let l40 = *base.add(56).cast::<*mut u8>();
+                                    let l41 = *base.add(60).cast::<usize>();
+                                    _rt::cabi_dealloc(l40, l41, 1);
+                                    let l42 = i32::from(*base.add(64).cast::<u8>());
+                                    match l42 {
+                                        0 => {}
+                                        _ => {
+                                            let l43 = *base.add(68).cast::<*mut u8>();
+                                            let l44 = *base.add(72).cast::<usize>();
+                                            _rt::cabi_dealloc(l43, l44, 1);
+                                        }
+                                    }
+                                    let l45 = *base.add(76).cast::<*mut u8>();
+                                    let l46 = *base.add(80).cast::<usize>();
+                                    let base53 = l45;
+                                    let len53 = l46;
+                                    for i in 0..len53 {
+                                        let base = base53.add(i * 24);
+                                        {
+                                            let l47 = *base.add(0).cast::<*mut u8>();
+                                            let l48 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l47, l48, 1);
+                                            let l49 = *base.add(8).cast::<*mut u8>();
+                                            let l50 = *base.add(12).cast::<usize>();
+                                            _rt::cabi_dealloc(l49, l50, 1);
+                                            let l51 = *base.add(16).cast::<*mut u8>();
+                                            let l52 = *base.add(20).cast::<usize>();
+                                            _rt::cabi_dealloc(l51, l52, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base53, len53 * 24, 4);
+                                    let l54 = i32::from(*base.add(84).cast::<u8>());
+                                    match l54 {
+                                        0 => {}
+                                        _ => {
+                                            let l55 = *base.add(88).cast::<*mut u8>();
+                                            let l56 = *base.add(92).cast::<usize>();
+                                            _rt::cabi_dealloc(l55, l56, 1);
+                                        }
+                                    }
+                                    let l57 = i32::from(*base.add(96).cast::<u8>());
+                                    match l57 {
+                                        0 => {}
+                                        _ => {
+                                            let l58 = *base.add(100).cast::<*mut u8>();
+                                            let l59 = *base.add(104).cast::<usize>();
+                                            _rt::cabi_dealloc(l58, l59, 1);
+                                        }
+                                    }
+                                    let l60 = i32::from(*base.add(108).cast::<u8>());
+                                    match l60 {
+                                        0 => {}
+                                        _ => {
+                                            let l61 = *base.add(112).cast::<*mut u8>();
+                                            let l62 = *base.add(116).cast::<usize>();
+                                            _rt::cabi_dealloc(l61, l62, 1);
+                                        }
+                                    }
+                                    let l63 = i32::from(*base.add(120).cast::<u8>());
+                                    match l63 {
+                                        0 => {}
+                                        _ => {
+                                            let l64 = *base.add(124).cast::<*mut u8>();
+                                            let l65 = *base.add(128).cast::<usize>();
+                                            _rt::cabi_dealloc(l64, l65, 1);
+                                        }
+                                    }
+                                    let l66 = i32::from(*base.add(132).cast::<u8>());
+                                    match l66 {
+                                        0 => {}
+                                        _ => {
+                                            let l67 = *base.add(136).cast::<*mut u8>();
+                                            let l68 = *base.add(140).cast::<usize>();
+                                            _rt::cabi_dealloc(l67, l68, 1);
+                                        }
+                                    }
+                                    let l69 = i32::from(*base.add(144).cast::<u8>());
+                                    match l69 {
+                                        0 => {}
+                                        _ => {
+                                            let l70 = *base.add(148).cast::<*mut u8>();
+                                            let l71 = *base.add(152).cast::<usize>();
+                                            _rt::cabi_dealloc(l70, l71, 1);
+                                        }
+                                    }
+                                    let l72 = i32::from(*base.add(156).cast::<u8>());
+                                    match l72 {
+                                        0 => {}
+                                        _ => {
+                                            let l73 = *base.add(160).cast::<*mut u8>();
+                                            let l74 = *base.add(164).cast::<usize>();
+                                            _rt::cabi_dealloc(l73, l74, 1);
+                                        }
+                                    }
+                                }
+                            }
+                            _rt::cabi_dealloc(base75, len75 * 168, 4);
+                            let l76 = *arg0.add(16).cast::<*mut u8>();
+                            let l77 = *arg0.add(20).cast::<usize>();
+                            let base81 = l76;
+                            let len81 = l77;
+                            for i in 0..len81 {
+                                let base = base81.add(i * 20);
+                                {
+                                    let l78 = i32::from(*base.add(8).cast::<u8>());
+                                    match l78 {
+                                        0 => {}
+                                        _ => {
+                                            let l79 = *base.add(12).cast::<*mut u8>();
+                                            let l80 = *base.add(16).cast::<usize>();
+                                            _rt::cabi_dealloc(l79, l80, 1);
+                                        }
+                                    }
+                                }
+                            }
+                            _rt::cabi_dealloc(base81, len81 * 20, 4);
+                            let l82 = *arg0.add(24).cast::<*mut u8>();
+                            let l83 = *arg0.add(28).cast::<usize>();
+                            let base84 = l82;
+                            let len84 = l83;
+                            _rt::cabi_dealloc(base84, len84 * 20, 4);
+                            let l85 = *arg0.add(40).cast::<*mut u8>();
+                            let l86 = *arg0.add(44).cast::<usize>();
+                            let base89 = l85;
+                            let len89 = l86;
+                            for i in 0..len89 {
+                                let base = base89.add(i * 20);
+                                {
+                                    let l87 = *base.add(12).cast::<*mut u8>();
+                                    let l88 = *base.add(16).cast::<usize>();
+                                    _rt::cabi_dealloc(l87, l88, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base89, len89 * 20, 4);
                         }
                         _ => {
-                            let l4 = *arg0.add(4).cast::<*mut u8>();
-                            let l5 = *arg0.add(8).cast::<usize>();
-                            _rt::cabi_dealloc(l4, l5, 1);
+                            let l90 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l90 {
+                                0 => {
+                                    let l91 = *arg0.add(16).cast::<*mut u8>();
+                                    let l92 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l91, l92, 1);
+                                }
+                                1 => {
+                                    let l93 = *arg0.add(16).cast::<*mut u8>();
+                                    let l94 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l93, l94, 1);
+                                }
+                                2 => {
+                                    let l95 = i32::from(*arg0.add(16).cast::<u8>());
+                                    match l95 {
+                                        0 => {}
+                                        _ => {
+                                            let l96 = *arg0.add(20).cast::<*mut u8>();
+                                            let l97 = *arg0.add(24).cast::<usize>();
+                                            _rt::cabi_dealloc(l96, l97, 1);
+                                        }
+                                    }
+                                    let l98 = *arg0.add(28).cast::<*mut u8>();
+                                    let l99 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l98, l99, 1);
+                                    let l100 = *arg0.add(36).cast::<*mut u8>();
+                                    let l101 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l100, l101, 1);
+                                }
+                                3 => {
+                                    let l102 = *arg0.add(16).cast::<*mut u8>();
+                                    let l103 = *arg0.add(20).cast::<usize>();
+                                    let base104 = l102;
+                                    let len104 = l103;
+                                    _rt::cabi_dealloc(base104, len104 * 4, 4);
+                                    let l105 = *arg0.add(24).cast::<*mut u8>();
+                                    let l106 = *arg0.add(28).cast::<usize>();
+                                    _rt::cabi_dealloc(l105, l106, 1);
+                                }
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l107 = *arg0.add(16).cast::<*mut u8>();
+                                    let l108 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l107, l108, 1);
+                                }
+                                7 => {
+                                    let l109 = *arg0.add(16).cast::<*mut u8>();
+                                    let l110 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l109, l110, 1);
+                                }
+                                8 => {
+                                    let l111 = *arg0.add(16).cast::<*mut u8>();
+                                    let l112 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l111, l112, 1);
+                                }
+                                9 => {
+                                    let l113 = *arg0.add(16).cast::<*mut u8>();
+                                    let l114 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l113, l114, 1);
+                                }
+                                _ => {
+                                    let l115 = *arg0.add(16).cast::<*mut u8>();
+                                    let l116 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l115, l116, 1);
+                                }
+                            }
                         }
                     }
                 }
@@ -766,16 +14818,75 @@ pub mod exports {
                     /// Constructs a new graph.
                     fn new() -> Self;
                     /// Adds a component to the graph.
+                    ///
+                    /// Core modules are converted into components.
+                    ///
+                    /// The component may be given any name, such as the name of its file; the
+                    /// name of the package it is registered as is derived from it.
                     fn add_component(
                         &self,
                         name: _rt::String,
                         bytes: _rt::Vec<u8>,
-                    ) -> Result<Component, _rt::String>;
+                        options: AddOptions,
+                    ) -> Result<Component, GraphError>;
+                    /// Registers a component with the graph as a package with the given name and version.
+                    ///
+                    /// Components with the same name may be registered with different versions.
+                    fn register_package(
+                        &self,
+                        name: _rt::String,
+                        version: Option<_rt::String>,
+                        bytes: _rt::Vec<u8>,
+                        options: AddOptions,
+                    ) -> Result<Component, GraphError>;
+                    /// Adds a stub component for a world of the given WIT document to the graph.
+                    ///
+                    /// The component imports and exports everything in the world.
+                    fn add_stub_component(
+                        &self,
+                        name: _rt::String,
+                        wit: _rt::String,
+                        world: _rt::String,
+                        behavior: StubBehavior,
+                    ) -> Result<Component, GraphError>;
+                    /// Adds a component to the graph that exports `wasi:cli/environment`
+                    /// and/or `wasi:config/store` with the given values.
+                    ///
+                    /// Connecting the component to instances provides them with fixed
+                    /// configuration instead of configuration from the host.
+                    ///
+                    /// The interfaces are exported at the pinned versions `wasi:cli@0.2.0`
+                    /// and `wasi:config@0.2.0-draft`; instances importing other versions are
+                    /// not auto-connected to the component.
+                    fn add_config_component(
+                        &self,
+                        name: _rt::String,
+                        values: ConfigValues,
+                    ) -> Result<Component, GraphError>;
+                    /// Adds a component to the graph that exports the given interfaces with stub implementations.
+                    ///
+                    /// The types of the interfaces come from the WIT of components in the graph. A name
+                    /// may end with `/*` to match every interface of a package, such as `wasi:sockets/*`.
+                    ///
+                    /// Connecting the component to instances prevents them from reaching the host's
+                    /// implementation of the interfaces.
+                    fn add_deny_component(
+                        &self,
+                        name: _rt::String,
+                        interfaces: _rt::Vec<_rt::String>,
+                        behavior: StubBehavior,
+                    ) -> Result<Component, GraphError>;
+                    /// Finds the component registered as the package with the given package name and version.
+                    fn find_package(
+                        &self,
+                        name: _rt::String,
+                        version: Option<_rt::String>,
+                    ) -> Result<Option<ComponentId>, GraphError>;
                     /// Instantiates a component in the graph.
                     fn instantiate_component(
                         &self,
                         id: ComponentId,
-                    ) -> Result<InstanceId, _rt::String>;
+                    ) -> Result<InstanceId, GraphError>;
                     /// Connects two instances in the graph.
                     fn connect_instances(
                         &self,
@@ -783,7 +14894,39 @@ pub mod exports {
                         source_export: Option<u32>,
                         target: InstanceId,
                         target_import: u32,
-                    ) -> Result<(), _rt::String>;
+                    ) -> Result<(), GraphError>;
+                    /// Get the connections that could be made to an import of the target instance.
+                    fn compatible_sources(
+                        &self,
+                        target: InstanceId,
+                        target_import: u32,
+                    ) -> Result<_rt::Vec<Connection>, GraphError>;
+                    /// Get the connections that could be made from an export of the source instance.
+                    ///
+                    /// If `source-export` is `none`, the source instance itself is connected.
+                    fn compatible_targets(
+                        &self,
+                        source: InstanceId,
+                        source_export: Option<u32>,
+                    ) -> Result<_rt::Vec<Connection>, GraphError>;
+                    /// Connect every unconnected import of the target instance to an export of
+                    /// another instance with the same name and a compatible type.
+                    ///
+                    /// Imports that match exports on more than one instance are left unconnected.
+                    fn auto_connect(
+                        &self,
+                        target: InstanceId,
+                    ) -> Result<AutoConnectResult, GraphError>;
+                    /// Set the name of an instance in the graph.
+                    ///
+                    /// The name is recorded in the names section of the encoded component.
+                    fn set_instance_name(
+                        &self,
+                        id: InstanceId,
+                        name: Option<_rt::String>,
+                    ) -> Result<(), GraphError>;
+                    /// Get the aliased instance exports in the graph.
+                    fn aliases(&self) -> _rt::Vec<Alias>;
                     /// Remove a component from the graph.
                     fn remove_component(&self, id: ComponentId);
                     /// Remove an instance from the graph.
@@ -794,14 +14937,97 @@ pub mod exports {
                         source: InstanceId,
                         target: InstanceId,
                         target_import: u32,
-                    ) -> Result<(), _rt::String>;
-                    /// Print the current graph state.
-                    fn print_graph(&self) -> _rt::String;
+                    ) -> Result<(), GraphError>;
+                    /// Undo the last operation performed on the graph.
+                    ///
+                    /// Returns the changes made to the graph.
+                    fn undo(&self) -> Result<_rt::Vec<Change>, GraphError>;
+                    /// Redo the last undone operation on the graph.
+                    ///
+                    /// Returns the changes made to the graph.
+                    fn redo(&self) -> Result<_rt::Vec<Change>, GraphError>;
+                    /// Determine if there is an operation to undo.
+                    fn can_undo(&self) -> bool;
+                    /// Determine if there is an undone operation to redo.
+                    fn can_redo(&self) -> bool;
+                    /// Render the instances and connections of the current graph state as a diagram.
+                    ///
+                    /// Instances are nodes labelled with their component; connections are edges
+                    /// labelled with the export and import they connect.
+                    fn print_graph(&self, format: GraphFormat) -> _rt::String;
+                    /// Analyze the unconnected imports and unreachable instances of the graph.
+                    ///
+                    /// The instances exported by the given options are used to determine reachability.
+                    fn analyze(
+                        &self,
+                        options: EncodeOptions,
+                    ) -> Result<Analysis, GraphError>;
+                    /// Audits the host capabilities reachable from each instance in the graph.
+                    ///
+                    /// The capabilities of an instance are the imports of the encoded graph that
+                    /// it can reach through its own imports and those of its dependencies.
+                    fn audit(&self) -> AuditReport;
+                    /// Compares the world of component `a` with the world of component `b`.
+                    ///
+                    /// Added, removed and changed imports, exports, functions, record fields and
+                    /// resource methods are reported. A difference is breaking if it breaks a
+                    /// connection of an instance of `a` were `a` replaced by `b`.
+                    fn diff_components(
+                        &self,
+                        a: ComponentId,
+                        b: ComponentId,
+                    ) -> Result<ComponentDiff, GraphError>;
+                    /// Previews the WIT world of the current graph state encoded with the given options.
+                    ///
+                    /// Each import and export is attributed to the instances it comes from. Components
+                    /// are always defined in the previewed graph.
+                    fn preview_world(
+                        &self,
+                        options: EncodeOptions,
+                    ) -> Result<WorldPreview, GraphError>;
+                    /// Disassembles a component into the WebAssembly text format.
+                    ///
+                    /// If no component is given, the current graph state encoded with the options'
+                    /// encode options is disassembled.
+                    fn component_wat(
+                        &self,
+                        id: Option<ComponentId>,
+                        options: WatOptions,
+                    ) -> Result<Wat, GraphError>;
                     /// Encode the current graph state as a new component.
                     fn encode_graph(
                         &self,
                         options: EncodeOptions,
-                    ) -> Result<_rt::Vec<u8>, _rt::String>;
+                    ) -> Result<_rt::Vec<u8>, GraphError>;
+                    /// Emit the current graph state as a WAC document for the given package.
+                    ///
+                    /// Only the exports of the given options are used; components are referenced
+                    /// as packages in the namespace of the document's package.
+                    fn emit_wac(
+                        &self,
+                        package: _rt::String,
+                        options: EncodeOptions,
+                    ) -> Result<_rt::String, GraphError>;
+                    /// Load a WAC document, replacing the current graph state.
+                    ///
+                    /// The packages instantiated by the document are given as a list of package names and component bytes.
+                    fn load_wac(
+                        &self,
+                        source: _rt::String,
+                        packages: _rt::Vec<(_rt::String, _rt::Vec<u8>)>,
+                    ) -> Result<Project, GraphError>;
+                    /// Save the current graph state as a project file.
+                    ///
+                    /// Only the exports of the given options are saved.
+                    fn save_project(
+                        &self,
+                        options: EncodeOptions,
+                    ) -> Result<_rt::Vec<u8>, GraphError>;
+                    /// Load a project file, replacing the current graph state.
+                    fn load_project(
+                        &self,
+                        bytes: _rt::Vec<u8>,
+                    ) -> Result<Project, GraphError>;
                 }
                 #[doc(hidden)]
                 macro_rules! __export_wasmbuilder_app_graph_provider_cabi {
@@ -814,15 +15040,84 @@ pub mod exports {
                         "wasmbuilder-app:graph/provider#[method]graph.add-component"]
                         unsafe extern "C" fn export_method_graph_add_component(arg0 : *
                         mut u8, arg1 : * mut u8, arg2 : usize, arg3 : * mut u8, arg4 :
-                        usize,) -> * mut u8 { $($path_to_types)*::
+                        usize, arg5 : i32, arg6 : i32, arg7 : i32,) -> * mut u8 {
+                        $($path_to_types)*::
                         _export_method_graph_add_component_cabi::<<$ty as
                         $($path_to_types)*:: Guest >::Graph > (arg0, arg1, arg2, arg3,
-                        arg4) } #[export_name =
+                        arg4, arg5, arg6, arg7) } #[export_name =
                         "cabi_post_wasmbuilder-app:graph/provider#[method]graph.add-component"]
                         unsafe extern "C" fn _post_return_method_graph_add_component(arg0
                         : * mut u8,) { $($path_to_types)*::
                         __post_return_method_graph_add_component::<<$ty as
                         $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.register-package"]
+                        unsafe extern "C" fn export_method_graph_register_package(arg0 :
+                        * mut u8, arg1 : * mut u8, arg2 : usize, arg3 : i32, arg4 : * mut
+                        u8, arg5 : usize, arg6 : * mut u8, arg7 : usize, arg8 : i32, arg9
+                        : i32, arg10 : i32,) -> * mut u8 { $($path_to_types)*::
+                        _export_method_graph_register_package_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6, arg7, arg8, arg9, arg10) } #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.register-package"]
+                        unsafe extern "C" fn
+                        _post_return_method_graph_register_package(arg0 : * mut u8,) {
+                        $($path_to_types)*::
+                        __post_return_method_graph_register_package::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.add-stub-component"]
+                        unsafe extern "C" fn export_method_graph_add_stub_component(arg0
+                        : * mut u8, arg1 : * mut u8, arg2 : usize, arg3 : * mut u8, arg4
+                        : usize, arg5 : * mut u8, arg6 : usize, arg7 : i32,) -> * mut u8
+                        { $($path_to_types)*::
+                        _export_method_graph_add_stub_component_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6, arg7) } #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.add-stub-component"]
+                        unsafe extern "C" fn
+                        _post_return_method_graph_add_stub_component(arg0 : * mut u8,) {
+                        $($path_to_types)*::
+                        __post_return_method_graph_add_stub_component::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.add-config-component"]
+                        unsafe extern "C" fn
+                        export_method_graph_add_config_component(arg0 : * mut u8, arg1 :
+                        * mut u8, arg2 : usize, arg3 : i32, arg4 : * mut u8, arg5 :
+                        usize, arg6 : i32, arg7 : * mut u8, arg8 : usize,) -> * mut u8 {
+                        $($path_to_types)*::
+                        _export_method_graph_add_config_component_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6, arg7, arg8) } #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.add-config-component"]
+                        unsafe extern "C" fn
+                        _post_return_method_graph_add_config_component(arg0 : * mut u8,)
+                        { $($path_to_types)*::
+                        __post_return_method_graph_add_config_component::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.add-deny-component"]
+                        unsafe extern "C" fn export_method_graph_add_deny_component(arg0
+                        : * mut u8, arg1 : * mut u8, arg2 : usize, arg3 : * mut u8, arg4
+                        : usize, arg5 : i32,) -> * mut u8 { $($path_to_types)*::
+                        _export_method_graph_add_deny_component_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0, arg1, arg2, arg3,
+                        arg4, arg5) } #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.add-deny-component"]
+                        unsafe extern "C" fn
+                        _post_return_method_graph_add_deny_component(arg0 : * mut u8,) {
+                        $($path_to_types)*::
+                        __post_return_method_graph_add_deny_component::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.find-package"]
+                        unsafe extern "C" fn export_method_graph_find_package(arg0 : *
+                        mut u8, arg1 : * mut u8, arg2 : usize, arg3 : i32, arg4 : * mut
+                        u8, arg5 : usize,) -> * mut u8 { $($path_to_types)*::
+                        _export_method_graph_find_package_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0, arg1, arg2, arg3,
+                        arg4, arg5) } #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.find-package"]
+                        unsafe extern "C" fn _post_return_method_graph_find_package(arg0
+                        : * mut u8,) { $($path_to_types)*::
+                        __post_return_method_graph_find_package::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
                         "wasmbuilder-app:graph/provider#[method]graph.instantiate-component"]
                         unsafe extern "C" fn
                         export_method_graph_instantiate_component(arg0 : * mut u8, arg1 :
@@ -849,6 +15144,66 @@ pub mod exports {
                         $($path_to_types)*::
                         __post_return_method_graph_connect_instances::<<$ty as
                         $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.compatible-sources"]
+                        unsafe extern "C" fn export_method_graph_compatible_sources(arg0
+                        : * mut u8, arg1 : i32, arg2 : i32,) -> * mut u8 {
+                        $($path_to_types)*::
+                        _export_method_graph_compatible_sources_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0, arg1, arg2) }
+                        #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.compatible-sources"]
+                        unsafe extern "C" fn
+                        _post_return_method_graph_compatible_sources(arg0 : * mut u8,) {
+                        $($path_to_types)*::
+                        __post_return_method_graph_compatible_sources::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.compatible-targets"]
+                        unsafe extern "C" fn export_method_graph_compatible_targets(arg0
+                        : * mut u8, arg1 : i32, arg2 : i32, arg3 : i32,) -> * mut u8 {
+                        $($path_to_types)*::
+                        _export_method_graph_compatible_targets_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0, arg1, arg2, arg3) }
+                        #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.compatible-targets"]
+                        unsafe extern "C" fn
+                        _post_return_method_graph_compatible_targets(arg0 : * mut u8,) {
+                        $($path_to_types)*::
+                        __post_return_method_graph_compatible_targets::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.auto-connect"]
+                        unsafe extern "C" fn export_method_graph_auto_connect(arg0 : *
+                        mut u8, arg1 : i32,) -> * mut u8 { $($path_to_types)*::
+                        _export_method_graph_auto_connect_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0, arg1) }
+                        #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.auto-connect"]
+                        unsafe extern "C" fn _post_return_method_graph_auto_connect(arg0
+                        : * mut u8,) { $($path_to_types)*::
+                        __post_return_method_graph_auto_connect::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.set-instance-name"]
+                        unsafe extern "C" fn export_method_graph_set_instance_name(arg0 :
+                        * mut u8, arg1 : i32, arg2 : i32, arg3 : * mut u8, arg4 : usize,)
+                        -> * mut u8 { $($path_to_types)*::
+                        _export_method_graph_set_instance_name_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0, arg1, arg2, arg3,
+                        arg4) } #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.set-instance-name"]
+                        unsafe extern "C" fn
+                        _post_return_method_graph_set_instance_name(arg0 : * mut u8,) {
+                        $($path_to_types)*::
+                        __post_return_method_graph_set_instance_name::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.aliases"] unsafe
+                        extern "C" fn export_method_graph_aliases(arg0 : * mut u8,) -> *
+                        mut u8 { $($path_to_types)*::
+                        _export_method_graph_aliases_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Graph > (arg0) } #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.aliases"]
+                        unsafe extern "C" fn _post_return_method_graph_aliases(arg0 : *
+                        mut u8,) { $($path_to_types)*::
+                        __post_return_method_graph_aliases::<<$ty as $($path_to_types)*::
+                        Guest >::Graph > (arg0) } #[export_name =
                         "wasmbuilder-app:graph/provider#[method]graph.remove-component"]
                         unsafe extern "C" fn export_method_graph_remove_component(arg0 :
                         * mut u8, arg1 : i32,) { $($path_to_types)*::
@@ -874,27 +15229,165 @@ pub mod exports {
                         { $($path_to_types)*::
                         __post_return_method_graph_disconnect_instances::<<$ty as
                         $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.undo"] unsafe
+                        extern "C" fn export_method_graph_undo(arg0 : * mut u8,) -> * mut
+                        u8 { $($path_to_types)*:: _export_method_graph_undo_cabi::<<$ty
+                        as $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.undo"]
+                        unsafe extern "C" fn _post_return_method_graph_undo(arg0 : * mut
+                        u8,) { $($path_to_types)*::
+                        __post_return_method_graph_undo::<<$ty as $($path_to_types)*::
+                        Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.redo"] unsafe
+                        extern "C" fn export_method_graph_redo(arg0 : * mut u8,) -> * mut
+                        u8 { $($path_to_types)*:: _export_method_graph_redo_cabi::<<$ty
+                        as $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.redo"]
+                        unsafe extern "C" fn _post_return_method_graph_redo(arg0 : * mut
+                        u8,) { $($path_to_types)*::
+                        __post_return_method_graph_redo::<<$ty as $($path_to_types)*::
+                        Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.can-undo"] unsafe
+                        extern "C" fn export_method_graph_can_undo(arg0 : * mut u8,) ->
+                        i32 { $($path_to_types)*::
+                        _export_method_graph_can_undo_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.can-redo"] unsafe
+                        extern "C" fn export_method_graph_can_redo(arg0 : * mut u8,) ->
+                        i32 { $($path_to_types)*::
+                        _export_method_graph_can_redo_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Graph > (arg0) } #[export_name =
                         "wasmbuilder-app:graph/provider#[method]graph.print-graph"]
                         unsafe extern "C" fn export_method_graph_print_graph(arg0 : * mut
-                        u8,) -> * mut u8 { $($path_to_types)*::
+                        u8, arg1 : i32,) -> * mut u8 { $($path_to_types)*::
                         _export_method_graph_print_graph_cabi::<<$ty as
-                        $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        $($path_to_types)*:: Guest >::Graph > (arg0, arg1) }
+                        #[export_name =
                         "cabi_post_wasmbuilder-app:graph/provider#[method]graph.print-graph"]
                         unsafe extern "C" fn _post_return_method_graph_print_graph(arg0 :
                         * mut u8,) { $($path_to_types)*::
                         __post_return_method_graph_print_graph::<<$ty as
                         $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.analyze"] unsafe
+                        extern "C" fn export_method_graph_analyze(arg0 : * mut u8, arg1 :
+                        i32, arg2 : i32, arg3 : i32, arg4 : * mut u8, arg5 : usize, arg6
+                        : i32,) -> * mut u8 { $($path_to_types)*::
+                        _export_method_graph_analyze_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Graph > (arg0, arg1, arg2, arg3, arg4, arg5, arg6) }
+                        #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.analyze"]
+                        unsafe extern "C" fn _post_return_method_graph_analyze(arg0 : *
+                        mut u8,) { $($path_to_types)*::
+                        __post_return_method_graph_analyze::<<$ty as $($path_to_types)*::
+                        Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.audit"] unsafe
+                        extern "C" fn export_method_graph_audit(arg0 : * mut u8,) -> *
+                        mut u8 { $($path_to_types)*::
+                        _export_method_graph_audit_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Graph > (arg0) } #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.audit"]
+                        unsafe extern "C" fn _post_return_method_graph_audit(arg0 : * mut
+                        u8,) { $($path_to_types)*::
+                        __post_return_method_graph_audit::<<$ty as $($path_to_types)*::
+                        Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.diff-components"]
+                        unsafe extern "C" fn export_method_graph_diff_components(arg0 : *
+                        mut u8, arg1 : i32, arg2 : i32,) -> * mut u8 {
+                        $($path_to_types)*::
+                        _export_method_graph_diff_components_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0, arg1, arg2) }
+                        #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.diff-components"]
+                        unsafe extern "C" fn
+                        _post_return_method_graph_diff_components(arg0 : * mut u8,) {
+                        $($path_to_types)*::
+                        __post_return_method_graph_diff_components::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.preview-world"]
+                        unsafe extern "C" fn export_method_graph_preview_world(arg0 : *
+                        mut u8, arg1 : i32, arg2 : i32, arg3 : i32, arg4 : * mut u8, arg5
+                        : usize, arg6 : i32,) -> * mut u8 { $($path_to_types)*::
+                        _export_method_graph_preview_world_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6) } #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.preview-world"]
+                        unsafe extern "C" fn _post_return_method_graph_preview_world(arg0
+                        : * mut u8,) { $($path_to_types)*::
+                        __post_return_method_graph_preview_world::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.component-wat"]
+                        unsafe extern "C" fn export_method_graph_component_wat(arg0 : *
+                        mut u8, arg1 : i32, arg2 : i32, arg3 : i32, arg4 : i32, arg5 :
+                        i32, arg6 : i32, arg7 : i32, arg8 : i32, arg9 : i32, arg10 : i32,
+                        arg11 : * mut u8, arg12 : usize, arg13 : i32,) -> * mut u8 {
+                        $($path_to_types)*::
+                        _export_method_graph_component_wat_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12, arg13) }
+                        #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.component-wat"]
+                        unsafe extern "C" fn _post_return_method_graph_component_wat(arg0
+                        : * mut u8,) { $($path_to_types)*::
+                        __post_return_method_graph_component_wat::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
                         "wasmbuilder-app:graph/provider#[method]graph.encode-graph"]
                         unsafe extern "C" fn export_method_graph_encode_graph(arg0 : *
-                        mut u8, arg1 : i32, arg2 : i32, arg3 : i32, arg4 : i32,) -> * mut
-                        u8 { $($path_to_types)*::
+                        mut u8, arg1 : i32, arg2 : i32, arg3 : i32, arg4 : * mut u8, arg5
+                        : usize, arg6 : i32,) -> * mut u8 { $($path_to_types)*::
                         _export_method_graph_encode_graph_cabi::<<$ty as
                         $($path_to_types)*:: Guest >::Graph > (arg0, arg1, arg2, arg3,
-                        arg4) } #[export_name =
+                        arg4, arg5, arg6) } #[export_name =
                         "cabi_post_wasmbuilder-app:graph/provider#[method]graph.encode-graph"]
                         unsafe extern "C" fn _post_return_method_graph_encode_graph(arg0
                         : * mut u8,) { $($path_to_types)*::
                         __post_return_method_graph_encode_graph::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.emit-wac"] unsafe
+                        extern "C" fn export_method_graph_emit_wac(arg0 : * mut u8, arg1
+                        : * mut u8, arg2 : usize, arg3 : i32, arg4 : i32, arg5 : i32,
+                        arg6 : * mut u8, arg7 : usize, arg8 : i32,) -> * mut u8 {
+                        $($path_to_types)*:: _export_method_graph_emit_wac_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6, arg7, arg8) } #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.emit-wac"]
+                        unsafe extern "C" fn _post_return_method_graph_emit_wac(arg0 : *
+                        mut u8,) { $($path_to_types)*::
+                        __post_return_method_graph_emit_wac::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.load-wac"] unsafe
+                        extern "C" fn export_method_graph_load_wac(arg0 : * mut u8, arg1
+                        : * mut u8, arg2 : usize, arg3 : * mut u8, arg4 : usize,) -> *
+                        mut u8 { $($path_to_types)*::
+                        _export_method_graph_load_wac_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Graph > (arg0, arg1, arg2, arg3, arg4) } #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.load-wac"]
+                        unsafe extern "C" fn _post_return_method_graph_load_wac(arg0 : *
+                        mut u8,) { $($path_to_types)*::
+                        __post_return_method_graph_load_wac::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.save-project"]
+                        unsafe extern "C" fn export_method_graph_save_project(arg0 : *
+                        mut u8, arg1 : i32, arg2 : i32, arg3 : i32, arg4 : * mut u8, arg5
+                        : usize, arg6 : i32,) -> * mut u8 { $($path_to_types)*::
+                        _export_method_graph_save_project_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6) } #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.save-project"]
+                        unsafe extern "C" fn _post_return_method_graph_save_project(arg0
+                        : * mut u8,) { $($path_to_types)*::
+                        __post_return_method_graph_save_project::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0) } #[export_name =
+                        "wasmbuilder-app:graph/provider#[method]graph.load-project"]
+                        unsafe extern "C" fn export_method_graph_load_project(arg0 : *
+                        mut u8, arg1 : * mut u8, arg2 : usize,) -> * mut u8 {
+                        $($path_to_types)*::
+                        _export_method_graph_load_project_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Graph > (arg0, arg1, arg2) }
+                        #[export_name =
+                        "cabi_post_wasmbuilder-app:graph/provider#[method]graph.load-project"]
+                        unsafe extern "C" fn _post_return_method_graph_load_project(arg0
+                        : * mut u8,) { $($path_to_types)*::
+                        __post_return_method_graph_load_project::<<$ty as
                         $($path_to_types)*:: Guest >::Graph > (arg0) } const _ : () = {
                         #[doc(hidden)] #[export_name =
                         "wasmbuilder-app:graph/provider#[dtor]graph"]
@@ -905,10 +15398,10 @@ pub mod exports {
                 }
                 #[doc(hidden)]
                 pub(crate) use __export_wasmbuilder_app_graph_provider_cabi;
-                #[repr(align(4))]
-                struct _RetArea([::core::mem::MaybeUninit<u8>; 40]);
+                #[repr(align(8))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 176]);
                 static mut _RET_AREA: _RetArea = _RetArea(
-                    [::core::mem::MaybeUninit::uninit(); 40],
+                    [::core::mem::MaybeUninit::uninit(); 176],
                 );
             }
         }
@@ -1003,6 +15496,13 @@ mod _rt {
             String::from_utf8_unchecked(bytes)
         }
     }
+    pub unsafe fn invalid_enum_discriminant<T>() -> T {
+        if cfg!(debug_assertions) {
+            panic!("invalid enum discriminant")
+        } else {
+            core::hint::unreachable_unchecked()
+        }
+    }
     pub fn as_i32<T: AsI32>(t: T) -> i32 {
         t.as_i32()
     }
@@ -1063,6 +15563,29 @@ mod _rt {
         }
     }
     pub use alloc_crate::alloc;
+    pub fn as_i64<T: AsI64>(t: T) -> i64 {
+        t.as_i64()
+    }
+    pub trait AsI64 {
+        fn as_i64(self) -> i64;
+    }
+    impl<'a, T: Copy + AsI64> AsI64 for &'a T {
+        fn as_i64(self) -> i64 {
+            (*self).as_i64()
+        }
+    }
+    impl AsI64 for i64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+    impl AsI64 for u64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
     pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
         if size == 0 {
             return;
@@ -1070,13 +15593,6 @@ mod _rt {
         let layout = alloc::Layout::from_size_align_unchecked(size, align);
         alloc::dealloc(ptr, layout);
     }
-    pub unsafe fn invalid_enum_discriminant<T>() -> T {
-        if cfg!(debug_assertions) {
-            panic!("invalid enum discriminant")
-        } else {
-            core::hint::unreachable_unchecked()
-        }
-    }
     pub unsafe fn bool_lift(val: u8) -> bool {
         if cfg!(debug_assertions) {
             match val {
@@ -1124,29 +15640,105 @@ pub(crate) use __export_component_impl as export;
 #[cfg(target_arch = "wasm32")]
 #[link_section = "component-type:wit-bindgen:0.30.0:component:encoded world"]
 #[doc(hidden)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 1034] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\x8a\x07\x01A\x02\x01\
-A\x02\x01B,\x01m\x06\x06module\x08function\x05value\x04type\x08instance\x09compo\
-nent\x04\0\x09item-kind\x03\0\0\x01r\x02\x04names\x04kind\x01\x04\0\x06import\x03\
-\0\x02\x01r\x02\x04names\x04kind\x01\x04\0\x06export\x03\0\x04\x01y\x04\0\x0ccom\
-ponent-id\x03\0\x06\x01p\x03\x01p\x05\x01r\x05\x02id\x07\x04names\x07imports\x08\
-\x07exports\x09\x03wits\x04\0\x09component\x03\0\x0a\x01y\x04\0\x0binstance-id\x03\
-\0\x0c\x01k\x0d\x01r\x03\x11define-components\x7f\x06export\x0e\x08validate\x7f\x04\
-\0\x0eencode-options\x03\0\x0f\x04\0\x05graph\x03\x01\x01i\x11\x01@\0\0\x12\x04\0\
-\x12[constructor]graph\x01\x13\x01h\x11\x01p}\x01j\x01\x0b\x01s\x01@\x03\x04self\
-\x14\x04names\x05bytes\x15\0\x16\x04\0\x1b[method]graph.add-component\x01\x17\x01\
-j\x01\x0d\x01s\x01@\x02\x04self\x14\x02id\x07\0\x18\x04\0#[method]graph.instanti\
-ate-component\x01\x19\x01ky\x01j\0\x01s\x01@\x05\x04self\x14\x06source\x0d\x0dso\
-urce-export\x1a\x06target\x0d\x0dtarget-importy\0\x1b\x04\0\x1f[method]graph.con\
-nect-instances\x01\x1c\x01@\x02\x04self\x14\x02id\x07\x01\0\x04\0\x1e[method]gra\
-ph.remove-component\x01\x1d\x01@\x02\x04self\x14\x02id\x0d\x01\0\x04\0\x1d[metho\
-d]graph.remove-instance\x01\x1e\x01@\x04\x04self\x14\x06source\x0d\x06target\x0d\
-\x0dtarget-importy\0\x1b\x04\0\"[method]graph.disconnect-instances\x01\x1f\x01@\x01\
-\x04self\x14\0s\x04\0\x19[method]graph.print-graph\x01\x20\x01j\x01\x15\x01s\x01\
-@\x02\x04self\x14\x07options\x10\0!\x04\0\x1a[method]graph.encode-graph\x01\"\x04\
-\x01\x1ewasmbuilder-app:graph/provider\x05\0\x04\x01\x1fwasmbuilder-app:graph/co\
-mponent\x04\0\x0b\x0f\x01\0\x09component\x03\0\0\0G\x09producers\x01\x0cprocesse\
-d-by\x02\x0dwit-component\x070.215.0\x10wit-bindgen-rust\x060.30.0";
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 4643] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xa3#\x01A\x02\x01A\x02\
+\x01B\xc7\x01\x01m\x06\x06module\x08function\x05value\x04type\x08instance\x09com\
+ponent\x04\0\x09item-kind\x03\0\0\x01m\x03\x08function\x04type\x08resource\x04\0\
+\x0bmember-kind\x03\0\x02\x01r\x03\x04names\x04kind\x03\x09signatures\x04\0\x06m\
+ember\x03\0\x04\x01p\x05\x01r\x04\x04names\x04kind\x01\x09signatures\x07members\x06\
+\x04\0\x06import\x03\0\x07\x01r\x04\x04names\x04kind\x01\x09signatures\x07member\
+s\x06\x04\0\x06export\x03\0\x09\x01r\x03\x05fields\x04names\x07versions\x04\0\x08\
+producer\x03\0\x0b\x01ks\x01p\x0c\x01r\x09\x04name\x0d\x09producers\x0e\x07autho\
+rs\x0d\x0bdescription\x0d\x08licenses\x0d\x06source\x0d\x08homepage\x0d\x08revis\
+ion\x0d\x07version\x0d\x04\0\x12component-metadata\x03\0\x0f\x01kw\x01r\x02\x07m\
+essages\x06offset\x11\x04\0\x0awasm-error\x03\0\x12\x01r\x03\x06export\x0d\x06im\
+ports\x07messages\x04\0\x13type-mismatch-error\x03\0\x14\x01r\x03\x07messages\x04\
+liney\x06columny\x04\0\x0csource-error\x03\0\x16\x01m\x03\x05allow\x06reject\x05\
+alias\x04\0\x10duplicate-policy\x03\0\x18\x01m\x03\x07reactor\x07command\x05prox\
+y\x04\0\x0cwasi-adapter\x03\0\x1a\x01k\x1b\x01r\x02\x0aduplicates\x19\x07adapter\
+\x1c\x04\0\x0badd-options\x03\0\x1d\x01m\x03\x05added\x07removed\x07changed\x04\0\
+\x0fdifference-kind\x03\0\x1f\x01r\x04\x04items\x04kind\x20\x0bdescriptions\x08b\
+reaking\x7f\x04\0\x0adifference\x03\0!\x01m\x02\x03dot\x07mermaid\x04\0\x0cgraph\
+-format\x03\0#\x01r\x02\x04texts\x09truncated\x7f\x04\0\x03wat\x03\0%\x01m\x03\x04\
+trap\x08defaults\x06errors\x04\0\x0dstub-behavior\x03\0'\x01o\x02ss\x01p)\x01k*\x01\
+r\x02\x0benvironment+\x06config+\x04\0\x0dconfig-values\x03\0,\x01y\x04\0\x0ccom\
+ponent-id\x03\0.\x01p\x08\x01p\x0a\x01r\x09\x02id/\x04names\x0cpackage-names\x07\
+version\x0d\x06digests\x07imports0\x07exports1\x03wits\x08metadata\x10\x04\0\x09\
+component\x03\02\x01y\x04\0\x0binstance-id\x03\04\x01r\x03\x02id5\x09component/\x04\
+name\x0d\x04\0\x08instance\x03\06\x01ky\x01r\x04\x06source5\x0dsource-export8\x06\
+target5\x0dtarget-importy\x04\0\x0aconnection\x03\09\x01r\x03\x08instance5\x06ex\
+porty\x04names\x04\0\x05alias\x03\0;\x01p:\x01r\x02\x06importy\x0acandidates=\x04\
+\0\x09ambiguity\x03\0>\x01p?\x01py\x01r\x03\x0bconnections=\x0bambiguities\xc0\0\
+\x0aunresolved\xc1\0\x04\0\x13auto-connect-result\x03\0B\x01p5\x01r\x03\x06impor\
+ty\x04names\x0bmerged-with\xc4\0\x04\0\x12unconnected-import\x03\0E\x01p\xc6\0\x01\
+r\x02\x08instance5\x07imports\xc7\0\x04\0\x10instance-imports\x03\0H\x01p\xc9\0\x01\
+r\x02\x07imports\xca\0\x0bunreachable\xc4\0\x04\0\x08analysis\x03\0K\x01q\x07\x0d\
+add-component\x013\0\x10remove-component\x01/\0\x0cadd-instance\x017\0\x0fremove\
+-instance\x015\0\x0frename-instance\x017\0\x07connect\x01:\0\x0adisconnect\x01:\0\
+\x04\0\x06change\x03\0M\x01r\x02\x09instances\xc4\0\x07messages\x04\0\x0bcycle-e\
+rror\x03\0O\x01q\x0b\x05parse\x01\x13\0\x0avalidation\x01\x13\0\x0dtype-mismatch\
+\x01\x15\0\x05cycle\x01\xd0\0\0\x11unknown-component\x01/\0\x10unknown-instance\x01\
+5\0\x06encode\x01s\0\x11invalid-operation\x01s\0\x0finvalid-project\x01s\0\x03wa\
+c\x01\x17\0\x03wit\x01s\0\x04\0\x0bgraph-error\x03\0Q\x01r\x02\x04names\x0bimpor\
+ted-by\xc4\0\x04\0\x0acapability\x03\0S\x01p\xd4\0\x01r\x03\x08instance5\x0cdepe\
+ndencies\xc4\0\x0ccapabilities\xd5\0\x04\0\x0einstance-audit\x03\0V\x01p\xd7\0\x01\
+r\x02\x09instances\xd8\0\x08markdowns\x04\0\x0caudit-report\x03\0Y\x01r\x02\x0ac\
+onnection:\x07messages\x04\0\x11broken-connection\x03\0[\x01p\"\x01p\xdc\0\x01r\x03\
+\x0bdifferences\xdd\0\x12broken-connections\xde\0\x0acompatible\x7f\x04\0\x0ecom\
+ponent-diff\x03\0_\x01r\x02\x04names\x09instances\xc4\0\x04\0\x0cpreview-item\x03\
+\0a\x01p\xe2\0\x01r\x03\x03wits\x07imports\xe3\0\x07exports\xe3\0\x04\0\x0dworld\
+-preview\x03\0d\x01r\x03\x08instance5\x06export8\x04names\x04\0\x0bexport-item\x03\
+\0f\x01p3\x01p7\x01k5\x01p\xe7\0\x01r\x05\x0acomponents\xe8\0\x09instances\xe9\0\
+\x0bconnections=\x06export\xea\0\x07exports\xeb\0\x04\0\x07project\x03\0l\x01r\x04\
+\x11define-components\x7f\x06export\xea\0\x07exports\xeb\0\x08validate\x7f\x04\0\
+\x0eencode-options\x03\0n\x01r\x05\x10fold-expressions\x7f\x14skip-function-bodi\
+es\x7f\x0estructure-only\x7f\x0amax-length8\x06encode\xef\0\x04\0\x0bwat-options\
+\x03\0p\x04\0\x05graph\x03\x01\x01ir\x01@\0\0\xf3\0\x04\0\x12[constructor]graph\x01\
+t\x01hr\x01p}\x01j\x013\x01\xd2\0\x01@\x04\x04self\xf5\0\x04names\x05bytes\xf6\0\
+\x07options\x1e\0\xf7\0\x04\0\x1b[method]graph.add-component\x01x\x01@\x05\x04se\
+lf\xf5\0\x04names\x07version\x0d\x05bytes\xf6\0\x07options\x1e\0\xf7\0\x04\0\x1e\
+[method]graph.register-package\x01y\x01@\x05\x04self\xf5\0\x04names\x03wits\x05w\
+orlds\x08behavior(\0\xf7\0\x04\0\x20[method]graph.add-stub-component\x01z\x01@\x03\
+\x04self\xf5\0\x04names\x06values-\0\xf7\0\x04\0\"[method]graph.add-config-compo\
+nent\x01{\x01ps\x01@\x04\x04self\xf5\0\x04names\x0ainterfaces\xfc\0\x08behavior(\
+\0\xf7\0\x04\0\x20[method]graph.add-deny-component\x01}\x01k/\x01j\x01\xfe\0\x01\
+\xd2\0\x01@\x03\x04self\xf5\0\x04names\x07version\x0d\0\xff\0\x04\0\x1a[method]g\
+raph.find-package\x01\x80\x01\x01j\x015\x01\xd2\0\x01@\x02\x04self\xf5\0\x02id/\0\
+\x81\x01\x04\0#[method]graph.instantiate-component\x01\x82\x01\x01j\0\x01\xd2\0\x01\
+@\x05\x04self\xf5\0\x06source5\x0dsource-export8\x06target5\x0dtarget-importy\0\x83\
+\x01\x04\0\x1f[method]graph.connect-instances\x01\x84\x01\x01j\x01=\x01\xd2\0\x01\
+@\x03\x04self\xf5\0\x06target5\x0dtarget-importy\0\x85\x01\x04\0\x20[method]grap\
+h.compatible-sources\x01\x86\x01\x01@\x03\x04self\xf5\0\x06source5\x0dsource-exp\
+ort8\0\x85\x01\x04\0\x20[method]graph.compatible-targets\x01\x87\x01\x01j\x01\xc3\
+\0\x01\xd2\0\x01@\x02\x04self\xf5\0\x06target5\0\x88\x01\x04\0\x1a[method]graph.\
+auto-connect\x01\x89\x01\x01@\x03\x04self\xf5\0\x02id5\x04name\x0d\0\x83\x01\x04\
+\0\x1f[method]graph.set-instance-name\x01\x8a\x01\x01p<\x01@\x01\x04self\xf5\0\0\
+\x8b\x01\x04\0\x15[method]graph.aliases\x01\x8c\x01\x01@\x02\x04self\xf5\0\x02id\
+/\x01\0\x04\0\x1e[method]graph.remove-component\x01\x8d\x01\x01@\x02\x04self\xf5\
+\0\x02id5\x01\0\x04\0\x1d[method]graph.remove-instance\x01\x8e\x01\x01@\x04\x04s\
+elf\xf5\0\x06source5\x06target5\x0dtarget-importy\0\x83\x01\x04\0\"[method]graph\
+.disconnect-instances\x01\x8f\x01\x01p\xce\0\x01j\x01\x90\x01\x01\xd2\0\x01@\x01\
+\x04self\xf5\0\0\x91\x01\x04\0\x12[method]graph.undo\x01\x92\x01\x04\0\x12[metho\
+d]graph.redo\x01\x92\x01\x01@\x01\x04self\xf5\0\0\x7f\x04\0\x16[method]graph.can\
+-undo\x01\x93\x01\x04\0\x16[method]graph.can-redo\x01\x93\x01\x01@\x02\x04self\xf5\
+\0\x06format$\0s\x04\0\x19[method]graph.print-graph\x01\x94\x01\x01j\x01\xcc\0\x01\
+\xd2\0\x01@\x02\x04self\xf5\0\x07options\xef\0\0\x95\x01\x04\0\x15[method]graph.\
+analyze\x01\x96\x01\x01@\x01\x04self\xf5\0\0\xda\0\x04\0\x13[method]graph.audit\x01\
+\x97\x01\x01j\x01\xe0\0\x01\xd2\0\x01@\x03\x04self\xf5\0\x01a/\x01b/\0\x98\x01\x04\
+\0\x1d[method]graph.diff-components\x01\x99\x01\x01j\x01\xe5\0\x01\xd2\0\x01@\x02\
+\x04self\xf5\0\x07options\xef\0\0\x9a\x01\x04\0\x1b[method]graph.preview-world\x01\
+\x9b\x01\x01j\x01&\x01\xd2\0\x01@\x03\x04self\xf5\0\x02id\xfe\0\x07options\xf1\0\
+\0\x9c\x01\x04\0\x1b[method]graph.component-wat\x01\x9d\x01\x01j\x01\xf6\0\x01\xd2\
+\0\x01@\x02\x04self\xf5\0\x07options\xef\0\0\x9e\x01\x04\0\x1a[method]graph.enco\
+de-graph\x01\x9f\x01\x01j\x01s\x01\xd2\0\x01@\x03\x04self\xf5\0\x07packages\x07o\
+ptions\xef\0\0\xa0\x01\x04\0\x16[method]graph.emit-wac\x01\xa1\x01\x01o\x02s\xf6\
+\0\x01p\xa2\x01\x01j\x01\xed\0\x01\xd2\0\x01@\x03\x04self\xf5\0\x06sources\x08pa\
+ckages\xa3\x01\0\xa4\x01\x04\0\x16[method]graph.load-wac\x01\xa5\x01\x04\0\x1a[m\
+ethod]graph.save-project\x01\x9f\x01\x01@\x02\x04self\xf5\0\x05bytes\xf6\0\0\xa4\
+\x01\x04\0\x1a[method]graph.load-project\x01\xa6\x01\x04\x01\x1ewasmbuilder-app:\
+graph/provider\x05\0\x04\x01\x1fwasmbuilder-app:graph/component\x04\0\x0b\x0f\x01\
+\0\x09component\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\
+\x070.215.0\x10wit-bindgen-rust\x060.30.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
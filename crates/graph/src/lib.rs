@@ -1,19 +1,28 @@
+//! The `graph` component used by wasmbuilder.app.
+//!
+//! The component is a thin adapter that converts between the types of the
+//! provider interface and those of the `graph-core` crate.
 #[allow(warnings)]
 mod bindings;
 
-use anyhow::Result;
 use bindings::exports::wasmbuilder_app::graph::provider::{
-    Component, ComponentId, EncodeOptions, Export, Guest, GuestGraph, Import, InstanceId, ItemKind,
+    AddOptions, Alias, Ambiguity, Analysis, AuditReport, AutoConnectResult, BrokenConnection,
+    Capability, Change, Component, ComponentDiff, ComponentId, ComponentMetadata, ConfigValues,
+    Connection, CycleError, Difference, DifferenceKind, DuplicatePolicy, EncodeOptions, Export,
+    ExportItem, GraphError, GraphFormat, Guest, GuestGraph, Import, Instance, InstanceAudit,
+    InstanceId, InstanceImports, ItemKind, Member, MemberKind, PreviewItem, Producer, Project,
+    SourceError, StubBehavior, TypeMismatchError, UnconnectedImport, WasiAdapter, WasmError, Wat,
+    WatOptions, WorldPreview,
+};
+use graph_core::{
+    audit, componentize, config, diagram, diff, disassemble, error, metadata, model, preview,
+    signature, state, stub,
 };
 use std::cell::RefCell;
-use wasm_compose::graph::CompositionGraph;
-use wasmparser::{ComponentExternalKind, ComponentTypeRef};
-use wit_component::WitPrinter;
 
-pub struct Graph {
-    graph: RefCell<CompositionGraph<'static>>,
-}
+pub struct Graph(RefCell<graph_core::Graph>);
 
+#[cfg(target_arch = "wasm32")]
 bindings::export!(Graph with_types_in bindings);
 
 impl Guest for Graph {
@@ -22,88 +31,91 @@ impl Guest for Graph {
 
 impl GuestGraph for Graph {
     fn new() -> Self {
-        Self {
-            graph: RefCell::new(CompositionGraph::new()),
-        }
+        Self(RefCell::new(graph_core::Graph::new()))
     }
 
-    fn add_component(&self, name: String, bytes: Vec<u8>) -> Result<Component, String> {
-        let component = wasm_compose::graph::Component::from_bytes(name, bytes)
-            .map_err(|e| format!("{e:#}"))?;
+    fn add_component(
+        &self,
+        name: String,
+        bytes: Vec<u8>,
+        options: AddOptions,
+    ) -> Result<Component, GraphError> {
+        Ok(self
+            .0
+            .borrow_mut()
+            .add_component(name, bytes, options.into())?
+            .into())
+    }
 
-        let mut graph = self.graph.borrow_mut();
+    fn register_package(
+        &self,
+        name: String,
+        version: Option<String>,
+        bytes: Vec<u8>,
+        options: AddOptions,
+    ) -> Result<Component, GraphError> {
+        Ok(self
+            .0
+            .borrow_mut()
+            .register_package(name, version.as_deref(), bytes, options.into())?
+            .into())
+    }
 
-        let id = graph
-            .add_component(component)
-            .map_err(|e| format!("{e:#}"))?;
+    fn add_stub_component(
+        &self,
+        name: String,
+        wit: String,
+        world: String,
+        behavior: StubBehavior,
+    ) -> Result<Component, GraphError> {
+        Ok(self
+            .0
+            .borrow_mut()
+            .add_stub_component(name, &wit, &world, behavior.into())?
+            .into())
+    }
 
-        let component = graph.get_component(id).unwrap();
-        let wit = match wit_component::decode(component.bytes()) {
-            Ok(decoded) => {
-                // Print the wit for the component
-                let resolve = decoded.resolve();
-                let mut printer = WitPrinter::default();
-                let nested = resolve
-                    .packages
-                    .iter()
-                    .map(|(id, _)| id)
-                    .filter(|id| *id != decoded.package())
-                    .collect::<Vec<_>>();
+    fn add_config_component(
+        &self,
+        name: String,
+        values: ConfigValues,
+    ) -> Result<Component, GraphError> {
+        Ok(self
+            .0
+            .borrow_mut()
+            .add_config_component(
+                name,
+                &config::Values {
+                    environment: values.environment,
+                    config: values.config,
+                },
+            )?
+            .into())
+    }
 
-                printer
-                    .print(resolve, decoded.package(), &nested)
-                    .unwrap_or_else(|e| {
-                        // If we can't print the document, just use the error text
-                        format!("{e:#}")
-                    })
-            }
-            Err(e) => {
-                // If we can't decode the component, just use the error text
-                format!("{e:#}")
-            }
-        };
+    fn add_deny_component(
+        &self,
+        name: String,
+        interfaces: Vec<String>,
+        behavior: StubBehavior,
+    ) -> Result<Component, GraphError> {
+        Ok(self
+            .0
+            .borrow_mut()
+            .add_deny_component(name, &interfaces, behavior.into())?
+            .into())
+    }
 
-        Ok(Component {
-            id: id.0 as u32,
-            name: component.name().to_string(),
-            imports: component
-                .imports()
-                .map(|(_, name, ty)| Import {
-                    name: name.to_string(),
-                    kind: match ty {
-                        ComponentTypeRef::Module(_) => ItemKind::Module,
-                        ComponentTypeRef::Func(_) => ItemKind::Function,
-                        ComponentTypeRef::Value(_) => ItemKind::Value,
-                        ComponentTypeRef::Type(_) => ItemKind::Type,
-                        ComponentTypeRef::Instance(_) => ItemKind::Instance,
-                        ComponentTypeRef::Component(_) => ItemKind::Component,
-                    },
-                })
-                .collect(),
-            exports: component
-                .exports()
-                .map(|(_, name, kind, _)| Export {
-                    name: name.to_string(),
-                    kind: match kind {
-                        ComponentExternalKind::Module => ItemKind::Module,
-                        ComponentExternalKind::Func => ItemKind::Function,
-                        ComponentExternalKind::Value => ItemKind::Value,
-                        ComponentExternalKind::Type => ItemKind::Type,
-                        ComponentExternalKind::Instance => ItemKind::Instance,
-                        ComponentExternalKind::Component => ItemKind::Component,
-                    },
-                })
-                .collect(),
-            wit,
-        })
+    fn find_package(
+        &self,
+        name: String,
+        version: Option<String>,
+    ) -> Result<Option<ComponentId>, GraphError> {
+        Ok(self.0.borrow().find_package(&name, version.as_deref())?)
     }
 
-    fn instantiate_component(&self, id: ComponentId) -> Result<InstanceId, String> {
-        self.graph
-            .borrow_mut()
-            .instantiate(id as usize)
-            .map(|id| id.0 as u32)
-            .map_err(|e| format!("{e:#}"))
+    fn instantiate_component(&self, id: ComponentId) -> Result<InstanceId, GraphError> {
+        Ok(self.0.borrow_mut().instantiate_component(id)?)
     }
 
     fn connect_instances(
@@ -112,24 +124,51 @@ impl GuestGraph for Graph {
         source_export: Option<u32>,
         target: InstanceId,
         target_import: u32,
-    ) -> Result<(), String> {
-        self.graph
+    ) -> Result<(), GraphError> {
+        Ok(self
+            .0
             .borrow_mut()
-            .connect(
-                source as usize,
-                source_export.map(|e| e as usize),
-                target as usize,
-                target_import as usize,
-            )
-            .map_err(|e| format!("{e:#}"))
+            .connect_instances(source, source_export, target, target_import)?)
+    }
+
+    fn compatible_sources(
+        &self,
+        target: InstanceId,
+        target_import: u32,
+    ) -> Result<Vec<Connection>, GraphError> {
+        Ok(convert(
+            self.0.borrow().compatible_sources(target, target_import)?,
+        ))
+    }
+
+    fn compatible_targets(
+        &self,
+        source: InstanceId,
+        source_export: Option<u32>,
+    ) -> Result<Vec<Connection>, GraphError> {
+        Ok(convert(
+            self.0.borrow().compatible_targets(source, source_export)?,
+        ))
+    }
+
+    fn auto_connect(&self, target: InstanceId) -> Result<AutoConnectResult, GraphError> {
+        Ok(self.0.borrow_mut().auto_connect(target)?.into())
+    }
+
+    fn set_instance_name(&self, id: InstanceId, name: Option<String>) -> Result<(), GraphError> {
+        Ok(self.0.borrow_mut().set_instance_name(id, name)?)
+    }
+
+    fn aliases(&self) -> Vec<Alias> {
+        convert(self.0.borrow().aliases())
     }
 
     fn remove_component(&self, id: ComponentId) {
-        self.graph.borrow_mut().remove_component(id as usize);
+        self.0.borrow_mut().remove_component(id);
     }
 
     fn remove_instance(&self, id: InstanceId) {
-        self.graph.borrow_mut().remove_instance(id as usize);
+        self.0.borrow_mut().remove_instance(id);
     }
 
     fn disconnect_instances(
@@ -137,27 +176,457 @@ impl GuestGraph for Graph {
         source: InstanceId,
         target: InstanceId,
         target_import: u32,
-    ) -> Result<(), String> {
-        self.graph
+    ) -> Result<(), GraphError> {
+        Ok(self
+            .0
             .borrow_mut()
-            .disconnect(source as usize, target as usize, target_import as usize)
-            .map_err(|e| format!("{e:#}"))
+            .disconnect_instances(source, target, target_import)?)
+    }
+
+    fn undo(&self) -> Result<Vec<Change>, GraphError> {
+        Ok(convert(self.0.borrow_mut().undo()?))
+    }
+
+    fn redo(&self) -> Result<Vec<Change>, GraphError> {
+        Ok(convert(self.0.borrow_mut().redo()?))
+    }
+
+    fn can_undo(&self) -> bool {
+        self.0.borrow().can_undo()
+    }
+
+    fn can_redo(&self) -> bool {
+        self.0.borrow().can_redo()
+    }
+
+    fn print_graph(&self, format: GraphFormat) -> String {
+        self.0.borrow().print_graph(format.into())
+    }
+
+    fn analyze(&self, options: EncodeOptions) -> Result<Analysis, GraphError> {
+        Ok(self.0.borrow().analyze(&options.into())?.into())
+    }
+
+    fn audit(&self) -> AuditReport {
+        self.0.borrow().audit().into()
+    }
+
+    fn diff_components(&self, a: ComponentId, b: ComponentId) -> Result<ComponentDiff, GraphError> {
+        Ok(self.0.borrow().diff_components(a, b)?.into())
+    }
+
+    fn preview_world(&self, options: EncodeOptions) -> Result<WorldPreview, GraphError> {
+        Ok(self.0.borrow().preview_world(&options.into())?.into())
+    }
+
+    fn component_wat(
+        &self,
+        id: Option<ComponentId>,
+        options: WatOptions,
+    ) -> Result<Wat, GraphError> {
+        let wat = self.0.borrow().component_wat(
+            id,
+            &options.encode.into(),
+            &disassemble::Options {
+                fold_expressions: options.fold_expressions,
+                skip_function_bodies: options.skip_function_bodies,
+                structure_only: options.structure_only,
+                max_length: options.max_length,
+            },
+        )?;
+
+        Ok(Wat {
+            text: wat.text,
+            truncated: wat.truncated,
+        })
+    }
+
+    fn encode_graph(&self, options: EncodeOptions) -> Result<Vec<u8>, GraphError> {
+        Ok(self.0.borrow().encode_graph(&options.into())?)
+    }
+
+    fn emit_wac(&self, package: String, options: EncodeOptions) -> Result<String, GraphError> {
+        Ok(self.0.borrow().emit_wac(&package, &options.into())?)
+    }
+
+    fn save_project(&self, options: EncodeOptions) -> Result<Vec<u8>, GraphError> {
+        Ok(self.0.borrow().save_project(&options.into())?)
+    }
+
+    fn load_project(&self, bytes: Vec<u8>) -> Result<Project, GraphError> {
+        Ok(self.0.borrow_mut().load_project(&bytes)?.into())
+    }
+
+    fn load_wac(
+        &self,
+        source: String,
+        packages: Vec<(String, Vec<u8>)>,
+    ) -> Result<Project, GraphError> {
+        Ok(self.0.borrow_mut().load_wac(&source, packages)?.into())
+    }
+}
+
+/// Converts a list of graph records into a list of provider records.
+fn convert<T: Into<U>, U>(items: Vec<T>) -> Vec<U> {
+    items.into_iter().map(Into::into).collect()
+}
+
+impl From<error::Error> for GraphError {
+    fn from(e: error::Error) -> Self {
+        match e {
+            error::Error::Parse { message, offset } => Self::Parse(WasmError { message, offset }),
+            error::Error::Validation { message, offset } => {
+                Self::Validation(WasmError { message, offset })
+            }
+            error::Error::TypeMismatch {
+                export,
+                import,
+                message,
+            } => Self::TypeMismatch(TypeMismatchError {
+                export,
+                import,
+                message,
+            }),
+            error::Error::Cycle { instances, message } => {
+                Self::Cycle(CycleError { instances, message })
+            }
+            error::Error::UnknownComponent(id) => Self::UnknownComponent(id),
+            error::Error::UnknownInstance(id) => Self::UnknownInstance(id),
+            error::Error::Encode(message) => Self::Encode(message),
+            error::Error::InvalidOperation(message) => Self::InvalidOperation(message),
+            error::Error::InvalidProject(message) => Self::InvalidProject(message),
+            error::Error::Wac {
+                message,
+                line,
+                column,
+            } => Self::Wac(SourceError {
+                message,
+                line,
+                column,
+            }),
+            error::Error::Wit(message) => Self::Wit(message),
+        }
+    }
+}
+
+impl From<AddOptions> for state::AddOptions {
+    fn from(options: AddOptions) -> Self {
+        Self {
+            duplicates: match options.duplicates {
+                DuplicatePolicy::Allow => state::Duplicates::Allow,
+                DuplicatePolicy::Reject => state::Duplicates::Reject,
+                DuplicatePolicy::Alias => state::Duplicates::Alias,
+            },
+            adapter: options.adapter.map(|a| match a {
+                WasiAdapter::Reactor => componentize::Adapter::Reactor,
+                WasiAdapter::Command => componentize::Adapter::Command,
+                WasiAdapter::Proxy => componentize::Adapter::Proxy,
+            }),
+        }
+    }
+}
+
+impl From<StubBehavior> for stub::Behavior {
+    fn from(behavior: StubBehavior) -> Self {
+        match behavior {
+            StubBehavior::Trap => Self::Trap,
+            StubBehavior::Defaults => Self::Defaults,
+            StubBehavior::Errors => Self::Errors,
+        }
+    }
+}
+
+impl From<GraphFormat> for diagram::Format {
+    fn from(format: GraphFormat) -> Self {
+        match format {
+            GraphFormat::Dot => Self::Dot,
+            GraphFormat::Mermaid => Self::Mermaid,
+        }
     }
+}
+
+impl From<EncodeOptions> for model::EncodeOptions {
+    fn from(options: EncodeOptions) -> Self {
+        Self {
+            define_components: options.define_components,
+            export: options.export,
+            exports: convert(options.exports),
+            validate: options.validate,
+        }
+    }
+}
+
+impl From<ExportItem> for state::ExportItem {
+    fn from(item: ExportItem) -> Self {
+        Self {
+            instance: item.instance,
+            export: item.export,
+            name: item.name,
+        }
+    }
+}
+
+impl From<state::ExportItem> for ExportItem {
+    fn from(item: state::ExportItem) -> Self {
+        Self {
+            instance: item.instance,
+            export: item.export,
+            name: item.name,
+        }
+    }
+}
+
+impl From<state::Connection> for Connection {
+    fn from(connection: state::Connection) -> Self {
+        Self {
+            source: connection.source,
+            source_export: connection.source_export,
+            target: connection.target,
+            target_import: connection.target_import,
+        }
+    }
+}
+
+impl From<state::Alias> for Alias {
+    fn from(alias: state::Alias) -> Self {
+        Self {
+            instance: alias.instance,
+            export: alias.export,
+            name: alias.name,
+        }
+    }
+}
 
-    fn print_graph(&self) -> String {
-        format!("{:#?}", self.graph.borrow())
+impl From<state::AutoConnect> for AutoConnectResult {
+    fn from(result: state::AutoConnect) -> Self {
+        Self {
+            connections: convert(result.connections),
+            ambiguities: result
+                .ambiguities
+                .into_iter()
+                .map(|a| Ambiguity {
+                    import: a.import,
+                    candidates: convert(a.candidates),
+                })
+                .collect(),
+            unresolved: result.unresolved,
+        }
+    }
+}
+
+impl From<state::Analysis> for Analysis {
+    fn from(analysis: state::Analysis) -> Self {
+        Self {
+            imports: analysis
+                .imports
+                .into_iter()
+                .map(|(instance, imports)| InstanceImports {
+                    instance,
+                    imports: imports
+                        .into_iter()
+                        .map(|i| UnconnectedImport {
+                            import: i.import,
+                            name: i.name,
+                            merged_with: i.merged_with,
+                        })
+                        .collect(),
+                })
+                .collect(),
+            unreachable: analysis.unreachable,
+        }
+    }
+}
+
+impl From<model::AuditReport> for AuditReport {
+    fn from(report: model::AuditReport) -> Self {
+        Self {
+            instances: report
+                .instances
+                .into_iter()
+                .map(|a: audit::InstanceAudit| InstanceAudit {
+                    instance: a.instance,
+                    dependencies: a.dependencies,
+                    capabilities: a
+                        .capabilities
+                        .into_iter()
+                        .map(|c| Capability {
+                            name: c.name,
+                            imported_by: c.imported_by,
+                        })
+                        .collect(),
+                })
+                .collect(),
+            markdown: report.markdown,
+        }
     }
+}
 
-    fn encode_graph(&self, options: EncodeOptions) -> Result<Vec<u8>, String> {
-        self.graph
-            .borrow()
-            .encode(wasm_compose::graph::EncodeOptions {
-                define_components: options.define_components,
-                export: options
-                    .export
-                    .map(|i| wasm_compose::graph::InstanceId(i as usize)),
-                validate: options.validate,
-            })
-            .map_err(|e| format!("{e:#}"))
+impl From<diff::ComponentDiff> for ComponentDiff {
+    fn from(diff: diff::ComponentDiff) -> Self {
+        Self {
+            differences: diff
+                .differences
+                .into_iter()
+                .map(|d| Difference {
+                    item: d.item,
+                    kind: match d.kind {
+                        diff::DifferenceKind::Added => DifferenceKind::Added,
+                        diff::DifferenceKind::Removed => DifferenceKind::Removed,
+                        diff::DifferenceKind::Changed => DifferenceKind::Changed,
+                    },
+                    description: d.description,
+                    breaking: d.breaking,
+                })
+                .collect(),
+            broken_connections: diff
+                .broken_connections
+                .into_iter()
+                .map(|c| BrokenConnection {
+                    connection: c.connection.into(),
+                    message: c.message,
+                })
+                .collect(),
+            compatible: diff.compatible,
+        }
+    }
+}
+
+impl From<preview::WorldPreview> for WorldPreview {
+    fn from(preview: preview::WorldPreview) -> Self {
+        Self {
+            wit: preview.wit,
+            imports: convert(preview.imports),
+            exports: convert(preview.exports),
+        }
+    }
+}
+
+impl From<preview::PreviewItem> for PreviewItem {
+    fn from(item: preview::PreviewItem) -> Self {
+        Self {
+            name: item.name,
+            instances: item.instances,
+        }
+    }
+}
+
+impl From<model::Snapshot> for Project {
+    fn from(snapshot: model::Snapshot) -> Self {
+        Self {
+            components: convert(snapshot.components),
+            instances: convert(snapshot.instances),
+            connections: convert(snapshot.connections),
+            export: snapshot.export,
+            exports: convert(snapshot.exports),
+        }
+    }
+}
+
+impl From<model::Change> for Change {
+    fn from(change: model::Change) -> Self {
+        match change {
+            model::Change::AddComponent(c) => Self::AddComponent((*c).into()),
+            model::Change::RemoveComponent(id) => Self::RemoveComponent(id),
+            model::Change::AddInstance(i) => Self::AddInstance(i.into()),
+            model::Change::RemoveInstance(id) => Self::RemoveInstance(id),
+            model::Change::RenameInstance(i) => Self::RenameInstance(i.into()),
+            model::Change::Connect(c) => Self::Connect(c.into()),
+            model::Change::Disconnect(c) => Self::Disconnect(c.into()),
+        }
+    }
+}
+
+impl From<model::Instance> for Instance {
+    fn from(instance: model::Instance) -> Self {
+        Self {
+            id: instance.id,
+            component: instance.component,
+            name: instance.name,
+        }
+    }
+}
+
+impl From<model::Component> for Component {
+    fn from(component: model::Component) -> Self {
+        Self {
+            id: component.id,
+            name: component.name,
+            package_name: component.package_name,
+            version: component.version,
+            digest: component.digest,
+            imports: component
+                .imports
+                .into_iter()
+                .map(|i| Import {
+                    name: i.name,
+                    kind: i.kind.into(),
+                    signature: i.signature,
+                    members: convert(i.members),
+                })
+                .collect(),
+            exports: component
+                .exports
+                .into_iter()
+                .map(|e| Export {
+                    name: e.name,
+                    kind: e.kind.into(),
+                    signature: e.signature,
+                    members: convert(e.members),
+                })
+                .collect(),
+            wit: component.wit,
+            metadata: component.metadata.into(),
+        }
+    }
+}
+
+impl From<model::ItemKind> for ItemKind {
+    fn from(kind: model::ItemKind) -> Self {
+        match kind {
+            model::ItemKind::Module => Self::Module,
+            model::ItemKind::Function => Self::Function,
+            model::ItemKind::Value => Self::Value,
+            model::ItemKind::Type => Self::Type,
+            model::ItemKind::Instance => Self::Instance,
+            model::ItemKind::Component => Self::Component,
+        }
+    }
+}
+
+impl From<signature::Member> for Member {
+    fn from(member: signature::Member) -> Self {
+        Self {
+            name: member.name,
+            kind: match member.kind {
+                signature::MemberKind::Function => MemberKind::Function,
+                signature::MemberKind::Type => MemberKind::Type,
+                signature::MemberKind::Resource => MemberKind::Resource,
+            },
+            signature: member.signature,
+        }
+    }
+}
+
+impl From<metadata::Metadata> for ComponentMetadata {
+    fn from(metadata: metadata::Metadata) -> Self {
+        Self {
+            name: metadata.name,
+            producers: metadata
+                .producers
+                .into_iter()
+                .map(|p| Producer {
+                    field: p.field,
+                    name: p.name,
+                    version: p.version,
+                })
+                .collect(),
+            authors: metadata.authors,
+            description: metadata.description,
+            licenses: metadata.licenses,
+            source: metadata.source,
+            homepage: metadata.homepage,
+            revision: metadata.revision,
+            version: metadata.version,
+        }
     }
 }
@@ -45,6 +45,24 @@ export const ComponentDetails = ({
   const selectedImports = selectedComponent?.imports ?? [];
   const selectedExports = selectedComponent?.exports ?? [];
   const selectedWit = selectedComponent?.wit ?? "";
+  const selectedMetadata = selectedComponent?.metadata;
+  const metadataRows: [string, string][] = selectedMetadata
+    ? [
+        ["Version", selectedMetadata.version],
+        ["Authors", selectedMetadata.authors],
+        ["Description", selectedMetadata.description],
+        ["Licenses", selectedMetadata.licenses],
+        ["Source", selectedMetadata.source],
+        ["Homepage", selectedMetadata.homepage],
+        ["Revision", selectedMetadata.revision],
+        ...selectedMetadata.producers.map(
+          ({ field, name, version }): [string, string] => [
+            field,
+            `${name} ${version}`,
+          ],
+        ),
+      ].filter((row): row is [string, string] => row[1] != null)
+    : [];
 
   const handleConfirmRemoveComponent = (remove: boolean) => {
     setShowConfirmDialog(false);
@@ -127,6 +145,28 @@ export const ComponentDetails = ({
                         </div>
                       </div>
 
+                      {metadataRows.length > 0 && (
+                        <div className="px-4 mt-4">
+                          <div>
+                            <h1 className="text-xl font-semibold text-gray-900">
+                              Metadata
+                            </h1>
+                          </div>
+                          <dl className="mt-2 grid grid-cols-3 gap-x-2 gap-y-1 text-sm">
+                            {metadataRows.map(([label, value], i) => (
+                              <Fragment key={i}>
+                                <dt className="font-medium text-gray-600">
+                                  {label}
+                                </dt>
+                                <dd className="col-span-2 break-all text-gray-900">
+                                  {value}
+                                </dd>
+                              </Fragment>
+                            ))}
+                          </dl>
+                        </div>
+                      )}
+
                       <div className="px-4 mt-4">
                         <div>
                           <h1 className="text-xl font-semibold text-gray-900">
@@ -153,12 +193,15 @@ export const ComponentDetails = ({
                                 </tr>
                               </thead>
                               <tbody className="divide-y divide-gray-200 bg-white">
-                                {selectedImports.map(({ name, kind }) => (
+                                {selectedImports.map(({ name, kind, signature }) => (
                                   <tr key={name}>
                                     <td className="whitespace-nowrap px-2 py-2 text-sm text-gray-900">
                                       {name}
                                     </td>
-                                    <td className="whitespace-nowrap px-2 py-2 text-sm text-gray-900">
+                                    <td
+                                      className="whitespace-nowrap px-2 py-2 text-sm text-gray-900"
+                                      title={signature}
+                                    >
                                       {kind}
                                     </td>
                                   </tr>
@@ -199,12 +242,15 @@ export const ComponentDetails = ({
                                 </tr>
                               </thead>
                               <tbody className="divide-y divide-gray-200 bg-white">
-                                {selectedExports.map(({ name, kind }) => (
+                                {selectedExports.map(({ name, kind, signature }) => (
                                   <tr key={name}>
                                     <td className="whitespace-nowrap px-2 py-2 text-sm text-gray-900">
                                       {name}
                                     </td>
-                                    <td className="whitespace-nowrap px-2 py-2 text-sm text-gray-900">
+                                    <td
+                                      className="whitespace-nowrap px-2 py-2 text-sm text-gray-900"
+                                      title={signature}
+                                    >
                                       {kind}
                                     </td>
                                   </tr>
@@ -5,7 +5,17 @@ import {
   ExclamationTriangleIcon,
   ExclamationCircleIcon,
 } from "@heroicons/react/24/outline";
-import { Component, useAppState, NotificationType, Graph } from "./state";
+import {
+  Component,
+  useAppState,
+  NotificationType,
+  Graph,
+  errorMessage,
+} from "./state";
+import {
+  DuplicatePolicy,
+  WasiAdapter,
+} from "./interfaces/wasmbuilder-app-graph-provider";
 import { CheckIcon, ChevronUpDownIcon } from "@heroicons/react/20/solid";
 
 const Colors = [
@@ -36,6 +46,18 @@ const randomColor = () => {
   return Colors[Math.floor(Math.random() * Colors.length)];
 };
 
+const DuplicatePolicies: { value: DuplicatePolicy; label: string }[] = [
+  { value: "allow", label: "Add a separate copy" },
+  { value: "alias", label: "Reuse the existing component" },
+  { value: "reject", label: "Reject the component" },
+];
+
+const WasiAdapters: { value: WasiAdapter; label: string }[] = [
+  { value: "reactor", label: "Reactor (exports functions to be called)" },
+  { value: "command", label: "Command (has a _start function)" },
+  { value: "proxy", label: "Proxy (handles HTTP requests)" },
+];
+
 const downloadFile = (name: string, bytes: Uint8Array) => {
   const blob = new Blob([bytes], { type: "application/wasm" });
   const link = document.createElement("a");
@@ -58,18 +80,23 @@ export const AddComponentDialog = ({
   onClose,
   onSubmit,
 }: AddComponentDialogProps) => {
+  const { components } = useAppState();
   const nameInputRef = useRef<HTMLInputElement>(null);
   const [nameError, setNameError] = useState("");
   const descriptionInputRef = useRef<HTMLInputElement>(null);
   const [file, setFile] = useState<File>(null);
   const [fileError, setFileError] = useState("");
   const [selectedColor, setSelectedColor] = useState(randomColor);
+  const [duplicates, setDuplicates] = useState<DuplicatePolicy>("allow");
+  const [adapter, setAdapter] = useState<WasiAdapter>("reactor");
 
   const handleAfterLeave = () => {
     setNameError("");
     setFileError("");
     setFile(null);
     setSelectedColor(randomColor());
+    setDuplicates("allow");
+    setAdapter("reactor");
   };
 
   const handleSubmit = async (e: FormEvent) => {
@@ -88,7 +115,7 @@ export const AddComponentDialog = ({
     }
 
     if (!file) {
-      fileError = "WebAssembly component required";
+      fileError = "WebAssembly component or module required";
     }
 
     setNameError(nameError);
@@ -99,12 +126,26 @@ export const AddComponentDialog = ({
 
     const bytes = new Uint8Array(await file.arrayBuffer());
     try {
-      const component = Graph.addComponent(name, bytes) as Component;
+      // Core modules are componentized with the adapter for any WASI imports
+      const component = Graph.addComponent(name, bytes, {
+        duplicates,
+        adapter,
+      }) as Component;
+
+      // A reused component is already in the library
+      const existing = Object.values(components).find(
+        (c) => c.id === component.id,
+      );
+      if (existing) {
+        onClose(existing);
+        return;
+      }
+
       component.color = selectedColor;
       component.description = description;
       onClose(component);
     } catch (e) {
-      setFileError(e.payload);
+      setFileError(errorMessage(e.payload));
     }
   };
 
@@ -302,6 +343,52 @@ export const AddComponentDialog = ({
                               )}
                             </Listbox>
                           </div>
+                          <div>
+                            <label
+                              htmlFor="duplicates"
+                              className="block text-sm font-medium text-gray-600"
+                            >
+                              If the component is already in the library
+                            </label>
+                            <select
+                              id="duplicates"
+                              name="duplicates"
+                              value={duplicates}
+                              onChange={(e) =>
+                                setDuplicates(e.target.value as DuplicatePolicy)
+                              }
+                              className="mt-1 block w-full rounded-md border-gray-300 shadow-sm focus:border-indigo-500 focus:ring-indigo-500 sm:text-sm"
+                            >
+                              {DuplicatePolicies.map(({ value, label }) => (
+                                <option key={value} value={value}>
+                                  {label}
+                                </option>
+                              ))}
+                            </select>
+                          </div>
+                          <div>
+                            <label
+                              htmlFor="adapter"
+                              className="block text-sm font-medium text-gray-600"
+                            >
+                              WASI adapter for core modules
+                            </label>
+                            <select
+                              id="adapter"
+                              name="adapter"
+                              value={adapter}
+                              onChange={(e) =>
+                                setAdapter(e.target.value as WasiAdapter)
+                              }
+                              className="mt-1 block w-full rounded-md border-gray-300 shadow-sm focus:border-indigo-500 focus:ring-indigo-500 sm:text-sm"
+                            >
+                              {WasiAdapters.map(({ value, label }) => (
+                                <option key={value} value={value}>
+                                  {label}
+                                </option>
+                              ))}
+                            </select>
+                          </div>
                         </div>
                         <div className="border-none">
                           <div className="flex items-center justify-center w-full">
@@ -424,6 +511,8 @@ export const DownloadComponentDialog = ({
       const bytes = Graph.encodeGraph({
         defineComponents,
         export: exportedInstance?.id,
+        // Additional export items are only supported by the command line interface
+        exports: [],
         validate: true,
       });
 
@@ -431,7 +520,7 @@ export const DownloadComponentDialog = ({
 
       let component = null;
       if (addComponent) {
-        component = Graph.addComponent(name, bytes);
+        component = Graph.addComponent(name, bytes, { duplicates: "allow" });
         component.color = selectedColor;
         component.description = description;
       }
@@ -441,7 +530,7 @@ export const DownloadComponentDialog = ({
       pushNotification({
         type: NotificationType.Error,
         title: "Download Failed",
-        message: e.payload,
+        message: errorMessage(e.payload),
       });
 
       onClose(null);
@@ -10,7 +10,10 @@ import {
 } from "reactflow";
 import { create } from "zustand";
 import { immer } from "zustand/middleware/immer";
-import { Component as GraphComponent } from "./interfaces/wasmbuilder-app-graph-provider";
+import {
+  Component as GraphComponent,
+  GraphError,
+} from "./interfaces/wasmbuilder-app-graph-provider";
 import { $init, provider } from "./graph";
 import process from "process";
 
@@ -24,6 +27,24 @@ $init.then(() => {
   }
 });
 
+export function errorMessage(error: GraphError): string {
+  switch (error.tag) {
+    case "unknown-component":
+      return `component ${error.val} does not exist in the graph`;
+    case "unknown-instance":
+      return `instance ${error.val} does not exist in the graph`;
+    case "encode":
+    case "invalid-operation":
+    case "invalid-project":
+    case "wit":
+      return error.val;
+    case "wac":
+      return `${error.val.line}:${error.val.column}: ${error.val.message}`;
+    default:
+      return error.val.message;
+  }
+}
+
 export type Component = GraphComponent & {
   description: string;
   color: string;
@@ -173,7 +194,7 @@ export const useAppState = create<AppState>()(
             state.notifications.push({
               type: NotificationType.Error,
               title: "Invalid connection",
-              message: e.payload,
+              message: errorMessage(e.payload),
             });
           });
           return;